@@ -0,0 +1,103 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replays a transcript recorded by a server with `transcript_dir` set and prints the reply
+//! lines which differ from the recorded ones, ie. to check that a fix changes what a client
+//! sees.
+//!
+//! ```shell
+//! ./target/examples/replay /var/spool/rsmtp/42.transcript
+//! ```
+//!
+//! The session is replayed with the default event handler and the lowest limits allowed, so
+//! replies which depend on the event handler or the config of the recording server may differ.
+
+extern crate rsmtp;
+
+use std::os;
+use std::io::{BufferedReader, File};
+use std::io::net::ip::Ipv4Addr;
+use rsmtp::server::{SmtpServerConfig, SmtpServerEventHandler};
+use rsmtp::server::replay::replay;
+use rsmtp::common::transcript::read_transcript;
+use rsmtp::common::stream::RejectBareLineEndings;
+use rsmtp::common::log::{LogDebug, StdoutLogSink};
+use rsmtp::common::{
+    MIN_ALLOWED_MESSAGE_SIZE,
+    MIN_ALLOWED_COMMAND_LINE_SIZE,
+    MIN_ALLOWED_TEXT_LINE_SIZE,
+    MIN_ALLOWED_RECIPIENTS
+};
+
+struct Handler;
+
+impl SmtpServerEventHandler for Handler {}
+
+fn main() {
+    let args = os::args();
+    if args.len() != 2 {
+        println!("usage: {} <transcript file>", args[0]);
+        os::set_exit_status(1);
+        return;
+    }
+
+    let file = match File::open(&Path::new(args[1].as_slice())) {
+        Ok(file) => file,
+        Err(err) => {
+            println!("could not open {}: {}", args[1], err);
+            os::set_exit_status(1);
+            return;
+        }
+    };
+    let entries = match read_transcript(&mut BufferedReader::new(file)) {
+        Ok(entries) => entries,
+        Err(err) => {
+            println!("could not read {}: {}", args[1], err);
+            os::set_exit_status(1);
+            return;
+        }
+    };
+
+    let config = SmtpServerConfig {
+        ip: "0.0.0.0",
+        domain: "rustastic.org",
+        port: 25,
+        max_recipients: MIN_ALLOWED_RECIPIENTS,
+        max_message_size: MIN_ALLOWED_MESSAGE_SIZE,
+        max_command_line_size: MIN_ALLOWED_COMMAND_LINE_SIZE,
+        max_text_line_size: MIN_ALLOWED_TEXT_LINE_SIZE,
+        extensions: vec!(),
+        vrfy_enabled: false,
+        expn_enabled: false,
+        help_topics: vec!(),
+        subaddress_separators: vec!(),
+        line_ending_policy: RejectBareLineEndings,
+        log_level: LogDebug,
+        log_sink: box StdoutLogSink,
+        log_redact_auth: true,
+        log_message_data: false,
+        transcript_dir: None,
+        proxy_protocol: None
+    };
+
+    let differences = replay(entries.as_slice(), Ipv4Addr(127, 0, 0, 1), config, Handler);
+    for difference in differences.iter() {
+        println!("line {}:", difference.line);
+        println!("- {}", difference.expected.as_ref().map_or("<none>", |line| line.as_slice()));
+        println!("+ {}", difference.actual.as_ref().map_or("<none>", |line| line.as_slice()));
+    }
+    if differences.len() > 0 {
+        os::set_exit_status(1);
+    }
+}
@@ -14,3 +14,76 @@
 
 //! The `client` module contains things needed to build an SMTP client, but useless for
 //! an SMTP server.
+
+use super::common::dsn::{DsnMailParams, DsnRcptParams};
+use super::common::path::{ReversePath, ForwardPath};
+
+/// Returns the `MAIL` command line to send for a reverse-path and its DSN parameters.
+///
+/// A `NullPath` is what should be used when sending a delivery status notification.
+pub fn get_mail_command(reverse_path: &ReversePath, dsn: &DsnMailParams) -> String {
+    let params = dsn.to_esmtp_string();
+    if params.len() == 0 {
+        format!("MAIL FROM:{}", reverse_path)
+    } else {
+        format!("MAIL FROM:{} {}", reverse_path, params)
+    }
+}
+
+#[test]
+fn test_get_mail_command() {
+    use super::common::dsn::RetHdrs;
+    use super::common::path::NullPath;
+
+    let rust = ReversePath::parse("<rust@rustastic.org>").unwrap();
+    let mut dsn = DsnMailParams::new();
+    assert_eq!("MAIL FROM:<>", get_mail_command(&NullPath, &dsn).as_slice());
+    assert_eq!(
+        "MAIL FROM:<rust@rustastic.org>",
+        get_mail_command(&rust, &dsn).as_slice()
+    );
+
+    dsn.ret = Some(RetHdrs);
+    dsn.envid = Some("QQ314159".into_string());
+    assert_eq!(
+        "MAIL FROM:<rust@rustastic.org> RET=HDRS ENVID=QQ314159",
+        get_mail_command(&rust, &dsn).as_slice()
+    );
+}
+
+/// Returns the `RCPT` command line to send for a forward-path and its DSN parameters.
+pub fn get_rcpt_command(forward_path: &ForwardPath, dsn: &DsnRcptParams) -> String {
+    let params = dsn.to_esmtp_string();
+    if params.len() == 0 {
+        format!("RCPT TO:{}", forward_path)
+    } else {
+        format!("RCPT TO:{} {}", forward_path, params)
+    }
+}
+
+#[test]
+fn test_get_rcpt_command() {
+    use super::common::dsn::{DsnNotify, DsnOriginalRecipient};
+    use super::common::path::PostmasterPath;
+
+    let bob = ForwardPath::parse("<bob@rustastic.org>").unwrap();
+    let mut dsn = DsnRcptParams::new();
+    assert_eq!(
+        "RCPT TO:<bob@rustastic.org>",
+        get_rcpt_command(&bob, &dsn).as_slice()
+    );
+    assert_eq!(
+        "RCPT TO:<Postmaster>",
+        get_rcpt_command(&PostmasterPath, &dsn).as_slice()
+    );
+
+    dsn.notify = Some(DsnNotify { success: false, failure: true, delay: true });
+    dsn.orcpt = Some(DsnOriginalRecipient {
+        addr_type: "rfc822".into_string(),
+        address: "bob+smtp@rustastic.org".into_string()
+    });
+    assert_eq!(
+        "RCPT TO:<bob@rustastic.org> NOTIFY=FAILURE,DELAY ORCPT=rfc822;bob+2Bsmtp@rustastic.org",
+        get_rcpt_command(&bob, &dsn).as_slice()
+    );
+}
@@ -0,0 +1,568 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tools to parse the addresses found in message headers such as `From`, `To` or `Cc`, as
+//! described [in RFC 5322](http://tools.ietf.org/html/rfc5322#section-3.4).
+//!
+//! Unlike `Mailbox::parse`, which expects the bare address used in SMTP, these functions
+//! understand display names, including those encoded as described
+//! [in RFC 2047](http://tools.ietf.org/html/rfc2047), comments and groups.
+
+use std::ascii::AsciiExt;
+use super::utils;
+use super::mailbox::{Mailbox, MailboxParseError};
+
+/// Represents a single address found in a header, ie. `Fred Smith <fred@rustastic.org>`.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub struct Address {
+    /// The display name, decoded, if any.
+    pub display_name: Option<String>,
+    /// The email address.
+    pub mailbox: Mailbox
+}
+
+/// Represents a named group of addresses found in a header, ie.
+/// `Friends: fred@rustastic.org, joe@rustastic.org;`.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub struct Group {
+    /// The display name of the group, decoded.
+    pub display_name: String,
+    /// The addresses in the group, which can be empty.
+    pub members: Vec<Address>
+}
+
+/// Represents an element of an address list, which is either a single address or a group.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub enum AddressListItem {
+    /// A single address.
+    SingleAddress(Address),
+    /// A group of addresses.
+    GroupAddress(Group)
+}
+
+/// Represents an error that occured while trying to parse addresses from a header.
+#[deriving(PartialEq, Eq, Show)]
+pub enum AddressParseError {
+    /// An unexpected character was found at the given byte position.
+    UnexpectedChar(uint),
+    /// The header ended too early, ie. inside a comment or before a closing `>`.
+    UnexpectedEnd,
+    /// The syntax was right, but the email address itself is invalid.
+    InvalidMailbox(MailboxParseError)
+}
+
+/// Parses an address list, as found in the `To`, `Cc` or `Bcc` headers.
+///
+/// The header value should be passed without the header name, ie.
+/// `Fred <fred@rustastic.org>, Friends: joe@rustastic.org;`. It can be folded.
+pub fn parse_address_list(s: &str) -> Result<Vec<AddressListItem>, AddressParseError> {
+    let mut parser = Parser::new(s);
+    parser.parse_list(|p| p.parse_address())
+}
+
+#[test]
+fn test_parse_address_list() {
+    let fred = Mailbox::parse("fred@rustastic.org").unwrap();
+    let joe = Mailbox::parse("joe@rustastic.org").unwrap();
+
+    assert_eq!(Ok(vec!()), parse_address_list(""));
+    assert_eq!(Ok(vec!()), parse_address_list(" (nobody) "));
+    assert_eq!(Ok(vec!(
+        SingleAddress(Address { display_name: Some("Fred Smith".into_string()), mailbox: fred.clone() }),
+        GroupAddress(Group {
+            display_name: "Friends".into_string(),
+            members: vec!(
+                Address { display_name: None, mailbox: joe.clone() },
+                Address { display_name: Some("Fred".into_string()), mailbox: fred.clone() }
+            )
+        }),
+        GroupAddress(Group { display_name: "Undisclosed recipients".into_string(), members: vec!() }),
+        SingleAddress(Address { display_name: None, mailbox: joe.clone() })
+    )), parse_address_list(
+        "Fred Smith <fred@rustastic.org>,\r\n Friends: joe@rustastic.org, Fred <fred@rustastic.org>;, \
+         \"Undisclosed recipients\":;, , joe@rustastic.org"
+    ));
+
+    assert_eq!(Err(UnexpectedChar(19)), parse_address_list("fred@rustastic.org joe@rustastic.org"));
+    assert_eq!(Err(UnexpectedEnd), parse_address_list("Friends: joe@rustastic.org"));
+    assert_eq!(Err(UnexpectedChar(14)), parse_address_list("Friends:Family: joe@rustastic.org;"));
+}
+
+/// Parses a mailbox list, as found in the `From` or `Reply-To` headers. Unlike address lists,
+/// mailbox lists cannot contain groups.
+pub fn parse_mailbox_list(s: &str) -> Result<Vec<Address>, AddressParseError> {
+    let mut parser = Parser::new(s);
+    parser.parse_list(|p| p.parse_mailbox())
+}
+
+#[test]
+fn test_parse_mailbox_list() {
+    let fred = Mailbox::parse("fred@rustastic.org").unwrap();
+    let joe = Mailbox::parse("\"joe smith\"@rustastic.org").unwrap();
+
+    assert_eq!(Ok(vec!(
+        Address { display_name: Some("John Q. Public".into_string()), mailbox: fred.clone() },
+        Address { display_name: None, mailbox: joe.clone() }
+    )), parse_mailbox_list("John Q. Public <fred@rustastic.org>, \"joe smith\"@rustastic.org"));
+
+    assert_eq!(Err(UnexpectedChar(7)), parse_mailbox_list("Friends: joe@rustastic.org;"));
+}
+
+/// Parses a single mailbox, as found in the `Sender` header.
+pub fn parse_mailbox(s: &str) -> Result<Address, AddressParseError> {
+    let mut parser = Parser::new(s);
+    try!(parser.skip_cfws());
+    let address = try!(parser.parse_mailbox());
+    match parser.peek() {
+        None => Ok(address),
+        Some(_) => Err(UnexpectedChar(parser.pos))
+    }
+}
+
+#[test]
+fn test_parse_mailbox() {
+    let fred = Mailbox::parse("fred@rustastic.org").unwrap();
+
+    // Display names.
+    assert_eq!(Ok(Address { display_name: None, mailbox: fred.clone() }), parse_mailbox("<fred@rustastic.org>"));
+    assert_eq!(Ok(Address {
+        display_name: Some("Smith, Fred \"The Rust\"".into_string()),
+        mailbox: fred.clone()
+    }), parse_mailbox("\"Smith, Fred \\\"The Rust\\\"\" <fred@rustastic.org>"));
+    assert_eq!(Ok(Address {
+        display_name: Some("Frédéric Smith".into_string()),
+        mailbox: fred.clone()
+    }), parse_mailbox("=?UTF-8?Q?Fr=C3=A9d=C3=A9ric?= Smith <fred@rustastic.org>"));
+    assert_eq!(Ok(Address {
+        display_name: Some("Frédéric".into_string()),
+        mailbox: fred.clone()
+    }), parse_mailbox("=?iso-8859-1?q?Fr=E9d?= =?utf-8?b?w6lyaWM=?= <fred@rustastic.org>"));
+
+    // Comments and folding white space.
+    assert_eq!(Ok(Address { display_name: Some("Fred".into_string()), mailbox: fred.clone() }), parse_mailbox(
+        " (the (rusty) one) Fred\r\n <fred @ (work) rustastic.org> (really)"
+    ));
+    assert_eq!(Ok(Address { display_name: None, mailbox: fred.clone() }), parse_mailbox(
+        "fred@rustastic.org (Fred Smith)"
+    ));
+
+    // Obsolete source routes are ignored.
+    assert_eq!(Ok(Address { display_name: None, mailbox: fred.clone() }), parse_mailbox(
+        "<@relay1.org,@relay2.org:fred@rustastic.org>"
+    ));
+
+    // Domain literals.
+    assert_eq!(
+        Ok(Address { display_name: None, mailbox: Mailbox::parse("fred@[127.0.0.1]").unwrap() }),
+        parse_mailbox("fred@[127.0.0.1]")
+    );
+
+    // Errors.
+    assert_eq!(Err(UnexpectedEnd), parse_mailbox("Fred <fred@rustastic.org"));
+    assert_eq!(Err(UnexpectedEnd), parse_mailbox("(Fred <fred@rustastic.org>"));
+    assert_eq!(Err(UnexpectedEnd), parse_mailbox("Fred"));
+    assert_eq!(Err(UnexpectedChar(21)), parse_mailbox("<fred@rustastic.org> x"));
+    assert_eq!(
+        Err(InvalidMailbox(super::mailbox::ForeignPartUnrecognized(
+            super::utils::ParseError::new(4, "end of foreign part")
+        ))),
+        parse_mailbox("fred@rust_astic.org")
+    );
+}
+
+// A parser over the value of a header. Positions are byte offsets.
+struct Parser<'a> {
+    s: &'a str,
+    pos: uint
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a str) -> Parser<'a> {
+        Parser {
+            s: s,
+            pos: 0
+        }
+    }
+
+    // Get the next character, without consuming it.
+    fn peek(&self) -> Option<char> {
+        if self.pos < self.s.len() {
+            Some(self.s.char_at(self.pos))
+        } else {
+            None
+        }
+    }
+
+    // Consume the next character.
+    fn bump(&mut self) {
+        self.pos += self.s.char_at(self.pos).len_utf8_bytes();
+    }
+
+    // Get what is left to parse.
+    fn rest(&self) -> &'a str {
+        self.s.slice_from(self.pos)
+    }
+
+    // Get the error for the current position.
+    fn error(&self) -> AddressParseError {
+        if self.pos < self.s.len() {
+            UnexpectedChar(self.pos)
+        } else {
+            UnexpectedEnd
+        }
+    }
+
+    // Consume the given character or fail.
+    fn expect(&mut self, c: char) -> Result<(), AddressParseError> {
+        if self.peek() == Some(c) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    // Skip folding white space and comments, aka CFWS.
+    fn skip_cfws(&mut self) -> Result<(), AddressParseError> {
+        loop {
+            match self.peek() {
+                Some(' ') | Some('\t') | Some('\r') | Some('\n') => self.bump(),
+                Some('(') => try!(self.skip_comment()),
+                _ => return Ok(())
+            }
+        }
+    }
+
+    // Skip a comment, which can contain other comments.
+    fn skip_comment(&mut self) -> Result<(), AddressParseError> {
+        let mut depth = 0u;
+        loop {
+            match self.peek() {
+                None => return Err(UnexpectedEnd),
+                Some('(') => depth += 1,
+                Some(')') => depth -= 1,
+                Some('\\') => {
+                    // Skip the escaping backslash, the escaped char is skipped below.
+                    self.bump();
+                    if self.peek().is_none() {
+                        return Err(UnexpectedEnd);
+                    }
+                },
+                _ => {}
+            }
+            self.bump();
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    // Parse a list of items separated by commas. Empty items are allowed, as in the obsolete
+    // syntax of RFC 5322.
+    fn parse_list<T>(&mut self, parse_item: |&mut Parser<'a>| -> Result<T, AddressParseError>) -> Result<Vec<T>, AddressParseError> {
+        let mut items = vec!();
+        loop {
+            try!(self.skip_cfws());
+            match self.peek() {
+                None => break,
+                Some(',') => {
+                    self.bump();
+                    continue;
+                },
+                _ => {}
+            }
+            items.push(try!(parse_item(self)));
+            match self.peek() {
+                None => break,
+                Some(',') => self.bump(),
+                _ => return Err(self.error())
+            }
+        }
+        Ok(items)
+    }
+
+    // Parse an address, which is either a mailbox or a group.
+    fn parse_address(&mut self) -> Result<AddressListItem, AddressParseError> {
+        let start = self.pos;
+        match try!(self.parse_phrase()) {
+            Some(display_name) => {
+                if self.peek() == Some(':') {
+                    self.bump();
+                    let members = try!(self.parse_group_members());
+                    return Ok(GroupAddress(Group {
+                        display_name: display_name,
+                        members: members
+                    }));
+                }
+            },
+            None => {}
+        }
+        self.pos = start;
+        Ok(SingleAddress(try!(self.parse_mailbox())))
+    }
+
+    // Parse the members of a group, up to and including the ending `;`.
+    fn parse_group_members(&mut self) -> Result<Vec<Address>, AddressParseError> {
+        let mut members = vec!();
+        loop {
+            try!(self.skip_cfws());
+            match self.peek() {
+                None => return Err(UnexpectedEnd),
+                Some(';') => {
+                    self.bump();
+                    break;
+                },
+                Some(',') => {
+                    self.bump();
+                    continue;
+                },
+                _ => {}
+            }
+            members.push(try!(self.parse_mailbox()));
+            match self.peek() {
+                Some(',') => self.bump(),
+                Some(';') => {},
+                _ => return Err(self.error())
+            }
+        }
+        try!(self.skip_cfws());
+        Ok(members)
+    }
+
+    // Parse a mailbox, which is either an addr-spec or an angle-addr with an optional display
+    // name. CFWS after the mailbox is skipped.
+    fn parse_mailbox(&mut self) -> Result<Address, AddressParseError> {
+        let start = self.pos;
+        let display_name = try!(self.parse_phrase());
+        if self.peek() == Some('<') {
+            return Ok(Address {
+                display_name: display_name,
+                mailbox: try!(self.parse_angle_addr())
+            });
+        }
+        self.pos = start;
+        Ok(Address {
+            display_name: None,
+            mailbox: try!(self.parse_addr_spec())
+        })
+    }
+
+    // Parse an addr-spec wrapped in `<` and `>`, ignoring the obsolete source route.
+    fn parse_angle_addr(&mut self) -> Result<Mailbox, AddressParseError> {
+        try!(self.expect('<'));
+        try!(self.skip_cfws());
+        self.pos += utils::get_source_route_len(self.rest()).unwrap_or(0);
+        let mailbox = try!(self.parse_addr_spec());
+        try!(self.expect('>'));
+        try!(self.skip_cfws());
+        Ok(mailbox)
+    }
+
+    // Parse an addr-spec, ie. `fred@rustastic.org`. CFWS around it is skipped.
+    fn parse_addr_spec(&mut self) -> Result<Mailbox, AddressParseError> {
+        try!(self.skip_cfws());
+        let local_part = match utils::get_quoted_string_len(self.rest()) {
+            None => try!(self.parse_dot_atom()),
+            Some(len) => {
+                let quoted = self.rest().slice_to(len).into_string();
+                self.pos += len;
+                try!(self.skip_cfws());
+                quoted
+            }
+        };
+        try!(self.expect('@'));
+        try!(self.skip_cfws());
+        let domain = if self.peek() == Some('[') {
+            match self.rest().find(']') {
+                Some(end) => {
+                    let literal = self.rest().slice_to(end + 1).into_string();
+                    self.pos += end + 1;
+                    try!(self.skip_cfws());
+                    literal
+                },
+                None => return Err(UnexpectedEnd)
+            }
+        } else {
+            try!(self.parse_dot_atom())
+        };
+        Mailbox::new(local_part.as_slice(), domain.as_slice()).map_err(|err| InvalidMailbox(err))
+    }
+
+    // Parse atoms separated by dots. CFWS around the dots and after the last atom is skipped.
+    fn parse_dot_atom(&mut self) -> Result<String, AddressParseError> {
+        let mut out = String::new();
+        loop {
+            let len = match utils::get_atom_len(self.rest()) {
+                Some(len) => len,
+                None => return Err(self.error())
+            };
+            out.push_str(self.rest().slice_to(len));
+            self.pos += len;
+            try!(self.skip_cfws());
+            if self.peek() != Some('.') {
+                return Ok(out);
+            }
+            out.push('.');
+            self.bump();
+            try!(self.skip_cfws());
+        }
+    }
+
+    // Parse a phrase, as used for display names, and return it decoded with its words
+    // separated by single spaces. Dots are allowed between words, as in the obsolete syntax.
+    fn parse_phrase(&mut self) -> Result<Option<String>, AddressParseError> {
+        let mut phrase = String::new();
+        let mut any = false;
+        let mut last_encoded = false;
+        loop {
+            try!(self.skip_cfws());
+            let rest = self.rest();
+            let (word, encoded) = match (utils::get_quoted_string_len(rest), utils::get_atom_len(rest)) {
+                (Some(quoted_len), _) => {
+                    self.pos += quoted_len;
+                    (utils::unescape_quoted_string(rest.slice_to(quoted_len)), false)
+                },
+                (None, Some(atom_len)) => {
+                    self.pos += atom_len;
+                    match decode_encoded_word(rest.slice_to(atom_len)) {
+                        Some(decoded) => (decoded, true),
+                        None => (rest.slice_to(atom_len).into_string(), false)
+                    }
+                },
+                (None, None) if any && self.peek() == Some('.') => {
+                    self.bump();
+                    phrase.push('.');
+                    last_encoded = false;
+                    continue;
+                },
+                // A quoted-string was started but is invalid.
+                (None, None) if self.peek() == Some('"') => return Err(self.error()),
+                (None, None) => break
+            };
+
+            // White space between encoded words is not part of the text.
+            if any && !(encoded && last_encoded) {
+                phrase.push(' ');
+            }
+            phrase.push_str(word.as_slice());
+            any = true;
+            last_encoded = encoded;
+        }
+        if any {
+            Ok(Some(phrase))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+// Decode an encoded word as described in RFC 2047, ie. `=?UTF-8?Q?Fr=C3=A9d?=`.
+//
+// Returns `None` if the word is not an encoded word, or if its charset is not supported, in which
+// case it should be used as is.
+fn decode_encoded_word(word: &str) -> Option<String> {
+    if word.len() < 8 || !word.starts_with("=?") || !word.ends_with("?=") {
+        return None;
+    }
+    let parts: Vec<&str> = word.slice(2, word.len() - 2).split('?').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+
+    // The charset can be followed by a language, ie. `UTF-8*fr`, as described in RFC 2231.
+    let charset = parts[0].split('*').next().unwrap().to_ascii_lower();
+    let bytes = match parts[1] {
+        "B" | "b" => decode_base64(parts[2]),
+        "Q" | "q" => decode_q(parts[2]),
+        _ => None
+    };
+
+    bytes.and_then(|bytes| {
+        match charset.as_slice() {
+            "utf-8" | "us-ascii" => String::from_utf8(bytes).ok(),
+            "iso-8859-1" => Some(bytes.iter().map(|&b| b as char).collect()),
+            _ => None
+        }
+    })
+}
+
+#[test]
+fn test_decode_encoded_word() {
+    assert_eq!(Some("café".into_string()), decode_encoded_word("=?utf-8?q?caf=C3=A9?="));
+    assert_eq!(Some("café au lait".into_string()), decode_encoded_word("=?UTF-8*fr?Q?caf=c3=a9_au_lait?="));
+    assert_eq!(Some("café".into_string()), decode_encoded_word("=?ISO-8859-1?Q?caf=E9?="));
+    assert_eq!(Some("café".into_string()), decode_encoded_word("=?utf-8?B?Y2Fmw6k=?="));
+    assert_eq!(Some("".into_string()), decode_encoded_word("=?utf-8?B??="));
+
+    assert_eq!(None, decode_encoded_word("café"));
+    assert_eq!(None, decode_encoded_word("=?utf-8?x?caf?="));
+    assert_eq!(None, decode_encoded_word("=?koi8-r?q?caf?="));
+    assert_eq!(None, decode_encoded_word("=?utf-8?q?caf=E9?="));
+    assert_eq!(None, decode_encoded_word("=?utf-8?q?caf=E?="));
+    assert_eq!(None, decode_encoded_word("=?utf-8?b?Y2Fm?w6k=?="));
+}
+
+// Decode the text of a "Q" encoded word.
+fn decode_q(s: &str) -> Option<Vec<u8>> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0u;
+    while i < bytes.len() {
+        if bytes[i] == '_' as u8 {
+            out.push(' ' as u8);
+            i += 1;
+        } else if bytes[i] == '=' as u8 {
+            if i + 2 >= bytes.len() {
+                return None;
+            }
+            match (
+                (bytes[i + 1] as char).to_digit(16),
+                (bytes[i + 2] as char).to_digit(16)
+            ) {
+                (Some(high), Some(low)) => out.push((high * 16 + low) as u8),
+                _ => return None
+            }
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    Some(out)
+}
+
+// Decode the text of a "B" encoded word.
+fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(s.len() * 3 / 4);
+    let mut acc = 0u32;
+    let mut bits = 0u;
+    for c in s.trim_right_chars('=').chars() {
+        let value = match c {
+            'A' ... 'Z' => c as u32 - 'A' as u32,
+            'a' ... 'z' => c as u32 - 'a' as u32 + 26,
+            '0' ... '9' => c as u32 - '0' as u32 + 52,
+            '+' => 62,
+            '/' => 63,
+            _ => return None
+        };
+        acc = (acc << 6) | value;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    Some(out)
+}
@@ -0,0 +1,785 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tools for Delivery Status Notifications, as described
+//! [in RFC 3461](http://tools.ietf.org/html/rfc3461) for the SMTP extension and
+//! [in RFC 3464](http://tools.ietf.org/html/rfc3464) for the notifications themselves.
+
+use std::ascii::AsciiExt;
+use std::rand;
+use time;
+use super::utils;
+
+/// Maximum length of the `ENVID` parameter, as sent on the wire.
+static MAX_ENVID_LEN: uint = 100;
+
+/// Maximum length of the `ORCPT` parameter, as sent on the wire.
+static MAX_ORCPT_LEN: uint = 500;
+
+#[test]
+fn test_static_vars() {
+    assert_eq!(100, MAX_ENVID_LEN);
+    assert_eq!(500, MAX_ORCPT_LEN);
+}
+
+/// What should be returned with a DSN, ie. the `RET` parameter of the `MAIL` command.
+#[deriving(PartialEq, Eq, Clone, Show)]
+#[cfg_attr(feature = "serde-support", deriving_serializable)]
+#[cfg_attr(feature = "serde-support", deriving_deserializable)]
+pub enum DsnRet {
+    /// The full message should be returned, `RET=FULL`.
+    RetFull,
+    /// Only the headers of the message should be returned, `RET=HDRS`.
+    RetHdrs
+}
+
+/// When a DSN should be sent, ie. the `NOTIFY` parameter of the `RCPT` command.
+///
+/// If none of the conditions are set, this means `NOTIFY=NEVER`.
+#[deriving(PartialEq, Eq, Clone, Show)]
+#[cfg_attr(feature = "serde-support", deriving_serializable)]
+#[cfg_attr(feature = "serde-support", deriving_deserializable)]
+pub struct DsnNotify {
+    /// Notify on successful delivery.
+    pub success: bool,
+    /// Notify on failed delivery.
+    pub failure: bool,
+    /// Notify when delivery is delayed.
+    pub delay: bool
+}
+
+impl DsnNotify {
+    /// Returns `true` if this means `NOTIFY=NEVER`.
+    pub fn is_never(&self) -> bool {
+        !self.success && !self.failure && !self.delay
+    }
+
+    /// Returns the value of the `NOTIFY` parameter, ie. `SUCCESS,FAILURE`.
+    pub fn to_param_value(&self) -> String {
+        if self.is_never() {
+            return "NEVER".into_string();
+        }
+        let mut conditions = vec!();
+        if self.success {
+            conditions.push("SUCCESS");
+        }
+        if self.failure {
+            conditions.push("FAILURE");
+        }
+        if self.delay {
+            conditions.push("DELAY");
+        }
+        conditions.connect(",")
+    }
+
+    /// Parses the value of the `NOTIFY` parameter.
+    fn parse(value: &str) -> Option<DsnNotify> {
+        let mut notify = DsnNotify {
+            success: false,
+            failure: false,
+            delay: false
+        };
+        if value.to_ascii_upper().as_slice() == "NEVER" {
+            return Some(notify);
+        }
+        for condition in value.split(',') {
+            match condition.to_ascii_upper().as_slice() {
+                "SUCCESS" => notify.success = true,
+                "FAILURE" => notify.failure = true,
+                "DELAY" => notify.delay = true,
+                // This also catches "NEVER", which must not be combined with anything.
+                _ => return None
+            }
+        }
+        Some(notify)
+    }
+}
+
+#[test]
+fn test_dsn_notify() {
+    let never = DsnNotify::parse("never").unwrap();
+    assert!(never.is_never());
+    assert_eq!("NEVER", never.to_param_value().as_slice());
+
+    let some = DsnNotify::parse("FAILURE,delay").unwrap();
+    assert!(!some.is_never());
+    assert!(!some.success);
+    assert!(some.failure);
+    assert!(some.delay);
+    assert_eq!("FAILURE,DELAY", some.to_param_value().as_slice());
+
+    assert_eq!(None, DsnNotify::parse("NEVER,SUCCESS"));
+    assert_eq!(None, DsnNotify::parse("SUCCESS,"));
+    assert_eq!(None, DsnNotify::parse(""));
+}
+
+/// The original recipient of a message, ie. the `ORCPT` parameter of the `RCPT` command.
+#[deriving(PartialEq, Eq, Clone, Show)]
+#[cfg_attr(feature = "serde-support", deriving_serializable)]
+#[cfg_attr(feature = "serde-support", deriving_deserializable)]
+pub struct DsnOriginalRecipient {
+    /// The type of address, usually `rfc822`.
+    pub addr_type: String,
+    /// The address, decoded from `xtext`.
+    pub address: String
+}
+
+impl DsnOriginalRecipient {
+    /// Returns the value of the `ORCPT` parameter, ie. `rfc822;rust@rustastic.org`.
+    pub fn to_param_value(&self) -> String {
+        format!("{};{}", self.addr_type, utils::encode_xtext(self.address.as_slice()))
+    }
+
+    /// Parses the value of the `ORCPT` parameter.
+    fn parse(value: &str) -> Option<DsnOriginalRecipient> {
+        match value.find(';') {
+            Some(pos) => {
+                let addr_type = value.slice_to(pos);
+                if addr_type.len() == 0 || utils::get_atom_len(addr_type) != Some(addr_type.len()) {
+                    return None;
+                }
+                match utils::decode_xtext(value.slice_from(pos + 1)) {
+                    Some(ref address) if address.len() > 0 => Some(DsnOriginalRecipient {
+                        addr_type: addr_type.into_string(),
+                        address: address.clone()
+                    }),
+                    _ => None
+                }
+            },
+            None => None
+        }
+    }
+}
+
+#[test]
+fn test_dsn_original_recipient() {
+    let orcpt = DsnOriginalRecipient::parse("rfc822;rust+2Bsmtp@rustastic.org").unwrap();
+    assert_eq!("rfc822", orcpt.addr_type.as_slice());
+    assert_eq!("rust+smtp@rustastic.org", orcpt.address.as_slice());
+    assert_eq!("rfc822;rust+2Bsmtp@rustastic.org", orcpt.to_param_value().as_slice());
+
+    assert_eq!(None, DsnOriginalRecipient::parse("rfc822"));
+    assert_eq!(None, DsnOriginalRecipient::parse(";rust@rustastic.org"));
+    assert_eq!(None, DsnOriginalRecipient::parse("rfc 822;rust@rustastic.org"));
+    assert_eq!(None, DsnOriginalRecipient::parse("rfc822;"));
+    assert_eq!(None, DsnOriginalRecipient::parse("rfc822;rust+2@rustastic.org"));
+}
+
+/// Represents an error that occured while parsing DSN parameters.
+#[deriving(PartialEq, Eq, Show)]
+pub enum DsnParamError {
+    /// The parameter is not a DSN parameter, or is not allowed with this command.
+    UnknownParam(String),
+    /// The parameter was given more than once.
+    DuplicateParam(String),
+    /// The value of the parameter is missing or invalid.
+    InvalidParamValue(String)
+}
+
+/// The DSN parameters of a `MAIL` command.
+#[deriving(PartialEq, Eq, Clone, Show)]
+#[cfg_attr(feature = "serde-support", deriving_serializable)]
+#[cfg_attr(feature = "serde-support", deriving_deserializable)]
+pub struct DsnMailParams {
+    /// The `RET` parameter, if any.
+    pub ret: Option<DsnRet>,
+    /// The `ENVID` parameter, decoded from `xtext`, if any.
+    pub envid: Option<String>
+}
+
+impl DsnMailParams {
+    /// Creates an empty set of parameters.
+    pub fn new() -> DsnMailParams {
+        DsnMailParams {
+            ret: None,
+            envid: None
+        }
+    }
+
+    /// Parses the parameters found after the reverse-path of a `MAIL` command.
+    pub fn parse(params: &str) -> Result<DsnMailParams, DsnParamError> {
+        let mut out = DsnMailParams::new();
+
+        for &(keyword, value) in utils::split_esmtp_params(params).iter() {
+            let keyword = keyword.to_ascii_upper();
+            match keyword.as_slice() {
+                "RET" => {
+                    if out.ret.is_some() {
+                        return Err(DuplicateParam(keyword.clone()));
+                    }
+                    out.ret = match value.map(|v| v.to_ascii_upper()) {
+                        Some(ref v) if v.as_slice() == "FULL" => Some(RetFull),
+                        Some(ref v) if v.as_slice() == "HDRS" => Some(RetHdrs),
+                        _ => return Err(InvalidParamValue(keyword.clone()))
+                    };
+                },
+                "ENVID" => {
+                    if out.envid.is_some() {
+                        return Err(DuplicateParam(keyword.clone()));
+                    }
+                    out.envid = match value {
+                        Some(v) if v.len() <= MAX_ENVID_LEN => utils::decode_xtext(v),
+                        _ => None
+                    };
+                    if out.envid.is_none() {
+                        return Err(InvalidParamValue(keyword.clone()));
+                    }
+                },
+                _ => {
+                    return Err(UnknownParam(keyword.clone()));
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Returns the parameters as they should be sent after the reverse-path of a `MAIL`
+    /// command, separated by spaces.
+    pub fn to_esmtp_string(&self) -> String {
+        let mut params = vec!();
+        match self.ret {
+            Some(RetFull) => params.push("RET=FULL".into_string()),
+            Some(RetHdrs) => params.push("RET=HDRS".into_string()),
+            None => {}
+        }
+        match self.envid {
+            Some(ref envid) => params.push(
+                format!("ENVID={}", utils::encode_xtext(envid.as_slice()))
+            ),
+            None => {}
+        }
+        params.connect(" ")
+    }
+}
+
+#[test]
+fn test_dsn_mail_params() {
+    let empty = DsnMailParams::parse("").unwrap();
+    assert_eq!(DsnMailParams::new(), empty);
+    assert_eq!("", empty.to_esmtp_string().as_slice());
+
+    let params = DsnMailParams::parse(" ret=hdrs  ENVID=QQ314159+2B2 ").unwrap();
+    assert_eq!(Some(RetHdrs), params.ret);
+    assert_eq!(Some("QQ314159+2".into_string()), params.envid);
+    assert_eq!("RET=HDRS ENVID=QQ314159+2B2", params.to_esmtp_string().as_slice());
+
+    assert_eq!(Err(UnknownParam("SIZE".into_string())), DsnMailParams::parse("SIZE=1000"));
+    assert_eq!(Err(DuplicateParam("RET".into_string())), DsnMailParams::parse("RET=FULL RET=HDRS"));
+    assert_eq!(Err(InvalidParamValue("RET".into_string())), DsnMailParams::parse("RET=ALL"));
+    assert_eq!(Err(InvalidParamValue("RET".into_string())), DsnMailParams::parse("RET"));
+    assert_eq!(Err(InvalidParamValue("ENVID".into_string())), DsnMailParams::parse("ENVID=a+b"));
+    assert_eq!(Err(InvalidParamValue("ENVID".into_string())), DsnMailParams::parse(
+        ("ENVID=".into_string() + String::from_char(MAX_ENVID_LEN + 1, 'a')).as_slice()
+    ));
+}
+
+/// The DSN parameters of a `RCPT` command.
+#[deriving(PartialEq, Eq, Clone, Show)]
+#[cfg_attr(feature = "serde-support", deriving_serializable)]
+#[cfg_attr(feature = "serde-support", deriving_deserializable)]
+pub struct DsnRcptParams {
+    /// The `NOTIFY` parameter, if any.
+    pub notify: Option<DsnNotify>,
+    /// The `ORCPT` parameter, if any.
+    pub orcpt: Option<DsnOriginalRecipient>
+}
+
+impl DsnRcptParams {
+    /// Creates an empty set of parameters.
+    pub fn new() -> DsnRcptParams {
+        DsnRcptParams {
+            notify: None,
+            orcpt: None
+        }
+    }
+
+    /// Parses the parameters found after the forward-path of a `RCPT` command.
+    pub fn parse(params: &str) -> Result<DsnRcptParams, DsnParamError> {
+        let mut out = DsnRcptParams::new();
+
+        for &(keyword, value) in utils::split_esmtp_params(params).iter() {
+            let keyword = keyword.to_ascii_upper();
+            match keyword.as_slice() {
+                "NOTIFY" => {
+                    if out.notify.is_some() {
+                        return Err(DuplicateParam(keyword.clone()));
+                    }
+                    out.notify = value.and_then(|v| DsnNotify::parse(v));
+                    if out.notify.is_none() {
+                        return Err(InvalidParamValue(keyword.clone()));
+                    }
+                },
+                "ORCPT" => {
+                    if out.orcpt.is_some() {
+                        return Err(DuplicateParam(keyword.clone()));
+                    }
+                    out.orcpt = match value {
+                        Some(v) if v.len() <= MAX_ORCPT_LEN => DsnOriginalRecipient::parse(v),
+                        _ => None
+                    };
+                    if out.orcpt.is_none() {
+                        return Err(InvalidParamValue(keyword.clone()));
+                    }
+                },
+                _ => {
+                    return Err(UnknownParam(keyword.clone()));
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Returns the parameters as they should be sent after the forward-path of a `RCPT`
+    /// command, separated by spaces.
+    pub fn to_esmtp_string(&self) -> String {
+        let mut params = vec!();
+        match self.notify {
+            Some(ref notify) => params.push(format!("NOTIFY={}", notify.to_param_value())),
+            None => {}
+        }
+        match self.orcpt {
+            Some(ref orcpt) => params.push(format!("ORCPT={}", orcpt.to_param_value())),
+            None => {}
+        }
+        params.connect(" ")
+    }
+}
+
+#[test]
+fn test_dsn_rcpt_params() {
+    let empty = DsnRcptParams::parse("").unwrap();
+    assert_eq!(DsnRcptParams::new(), empty);
+    assert_eq!("", empty.to_esmtp_string().as_slice());
+
+    let params = DsnRcptParams::parse("NOTIFY=SUCCESS,FAILURE ORCPT=rfc822;Bob@Example.COM").unwrap();
+    assert_eq!(Some(DsnNotify { success: true, failure: true, delay: false }), params.notify);
+    assert_eq!(Some(DsnOriginalRecipient {
+        addr_type: "rfc822".into_string(),
+        address: "Bob@Example.COM".into_string()
+    }), params.orcpt);
+    assert_eq!(
+        "NOTIFY=SUCCESS,FAILURE ORCPT=rfc822;Bob@Example.COM",
+        params.to_esmtp_string().as_slice()
+    );
+
+    assert_eq!(Err(UnknownParam("RET".into_string())), DsnRcptParams::parse("RET=FULL"));
+    assert_eq!(Err(DuplicateParam("NOTIFY".into_string())), DsnRcptParams::parse("NOTIFY=NEVER NOTIFY=DELAY"));
+    assert_eq!(Err(InvalidParamValue("NOTIFY".into_string())), DsnRcptParams::parse("NOTIFY"));
+    assert_eq!(Err(InvalidParamValue("ORCPT".into_string())), DsnRcptParams::parse("ORCPT=rfc822"));
+}
+
+/// The action taken for a recipient, as reported in a delivery status notification.
+///
+/// Actions are as described [in RFC 3464](http://tools.ietf.org/html/rfc3464#section-2.3.3).
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub enum DsnAction {
+    /// The message could not be delivered.
+    ActionFailed,
+    /// The message could not be delivered yet, but delivery will be retried.
+    ActionDelayed,
+    /// The message was delivered.
+    ActionDelivered,
+    /// The message was relayed to a system that does not support DSN.
+    ActionRelayed,
+    /// The message was delivered and then forwarded to several other recipients.
+    ActionExpanded
+}
+
+impl DsnAction {
+    /// Returns the value of the `Action` field for this action.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ActionFailed => "failed",
+            ActionDelayed => "delayed",
+            ActionDelivered => "delivered",
+            ActionRelayed => "relayed",
+            ActionExpanded => "expanded"
+        }
+    }
+}
+
+#[test]
+fn test_dsn_action() {
+    assert_eq!("failed", ActionFailed.as_str());
+    assert_eq!("delayed", ActionDelayed.as_str());
+    assert_eq!("delivered", ActionDelivered.as_str());
+    assert_eq!("relayed", ActionRelayed.as_str());
+    assert_eq!("expanded", ActionExpanded.as_str());
+}
+
+/// The delivery status of a single recipient, as reported in a delivery status notification.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub struct DsnRecipientStatus {
+    /// The recipient as given with `ORCPT`, if any. It is reported untouched.
+    pub original_recipient: Option<DsnOriginalRecipient>,
+    /// The address of the recipient the delivery was attempted for.
+    pub final_recipient: String,
+    /// What was done with the message for this recipient.
+    pub action: DsnAction,
+    /// The enhanced status code, ie. `5.1.1`.
+    pub status: String,
+    /// The server that reported the status, if it was not us.
+    pub remote_mta: Option<String>,
+    /// The reply of the remote server, ie. `550 5.1.1 User unknown`.
+    pub diagnostic_code: Option<String>,
+    /// The date after which no more delivery attempts will be made, for delayed messages.
+    pub will_retry_until: Option<String>
+}
+
+impl DsnRecipientStatus {
+    /// Creates the status of a recipient, with only the required fields.
+    pub fn new(final_recipient: &str, action: DsnAction, status: &str) -> DsnRecipientStatus {
+        DsnRecipientStatus {
+            original_recipient: None,
+            final_recipient: final_recipient.into_string(),
+            action: action,
+            status: status.into_string(),
+            remote_mta: None,
+            diagnostic_code: None,
+            will_retry_until: None
+        }
+    }
+
+    /// Returns the per-recipient fields of the `message/delivery-status` part.
+    fn to_fields(&self) -> String {
+        let mut fields = String::new();
+        match self.original_recipient {
+            Some(ref orcpt) => fields.push_str(format!(
+                "Original-Recipient: {}\r\n",
+                orcpt.to_param_value()
+            ).as_slice()),
+            None => {}
+        }
+        // Internationalized addresses use the `utf-8` type of RFC 6533.
+        let addr_type = if is_ascii(self.final_recipient.as_slice()) { "rfc822" } else { "utf-8" };
+        fields.push_str(format!("Final-Recipient: {}; {}\r\n", addr_type, self.final_recipient).as_slice());
+        fields.push_str(format!("Action: {}\r\n", self.action.as_str()).as_slice());
+        fields.push_str(format!("Status: {}\r\n", self.status).as_slice());
+        match self.remote_mta {
+            Some(ref mta) => fields.push_str(format!("Remote-MTA: dns; {}\r\n", mta).as_slice()),
+            None => {}
+        }
+        match self.diagnostic_code {
+            Some(ref code) => fields.push_str(format!("Diagnostic-Code: smtp; {}\r\n", code).as_slice()),
+            None => {}
+        }
+        match self.will_retry_until {
+            Some(ref date) => fields.push_str(format!("Will-Retry-Until: {}\r\n", date).as_slice()),
+            None => {}
+        }
+        fields
+    }
+}
+
+#[test]
+fn test_dsn_recipient_status() {
+    let mut status = DsnRecipientStatus::new("bob@rustastic.org", ActionFailed, "5.1.1");
+    assert_eq!(
+        "Final-Recipient: rfc822; bob@rustastic.org\r\nAction: failed\r\nStatus: 5.1.1\r\n",
+        status.to_fields().as_slice()
+    );
+
+    status.original_recipient = Some(DsnOriginalRecipient {
+        addr_type: "rfc822".into_string(),
+        address: "Bob@rustastic.org".into_string()
+    });
+    status.remote_mta = Some("mx.rustastic.org".into_string());
+    status.diagnostic_code = Some("550 5.1.1 User unknown".into_string());
+    assert_eq!(
+        "Original-Recipient: rfc822;Bob@rustastic.org\r\n\
+         Final-Recipient: rfc822; bob@rustastic.org\r\n\
+         Action: failed\r\n\
+         Status: 5.1.1\r\n\
+         Remote-MTA: dns; mx.rustastic.org\r\n\
+         Diagnostic-Code: smtp; 550 5.1.1 User unknown\r\n",
+        status.to_fields().as_slice()
+    );
+}
+
+/// A delivery status notification, as described [in RFC 3464](http://tools.ietf.org/html/rfc3464).
+///
+/// This can be used to build bounces and delay notifications. These must be sent with a null
+/// reverse-path, ie. `MAIL FROM:<>`, so that they never trigger other notifications.
+///
+/// # Example
+/// ```
+/// use rsmtp::common::dsn::{DsnMessage, DsnRecipientStatus, ActionFailed};
+///
+/// let mut dsn = DsnMessage::new("mx.rustastic.org", "rust@rustastic.org");
+/// dsn.recipients.push(DsnRecipientStatus::new("bob@rustastic.org", ActionFailed, "5.1.1"));
+/// dsn.original_headers = Some("Subject: Hello\r\n".into_string());
+///
+/// println!("{}", dsn.to_message());
+/// ```
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub struct DsnMessage {
+    /// The domain of the server generating the notification.
+    pub reporting_mta: String,
+    /// The address the notification is sent to, ie. the sender of the original message.
+    pub to: String,
+    /// The `ENVID` given with the original message, if any.
+    pub envid: Option<String>,
+    /// The date at which the original message was received, if known.
+    pub arrival_date: Option<String>,
+    /// The date of the notification, for the `Date` header. Defaults to the current time.
+    pub date: String,
+    /// The `Message-ID` header of the notification, ie. `<1415183203042.9f3a@mx.rustastic.org>`.
+    pub message_id: String,
+    /// The status of each recipient the notification is about.
+    pub recipients: Vec<DsnRecipientStatus>,
+    /// The text meant for humans. If `None`, a text is generated from the recipients.
+    pub human_readable: Option<String>,
+    /// The headers of the original message, if they should be returned.
+    pub original_headers: Option<String>,
+    /// The boundary between the parts of the message. It is unique to each notification, so
+    /// that it can't appear in the returned headers or text.
+    pub boundary: String
+}
+
+// Returns an identifier which is unique to each notification, made of the current time and
+// random bits.
+fn get_unique_id() -> String {
+    let now = time::get_time();
+    let timestamp = now.sec as u64 * 1000 + now.nsec as u64 / 1000000;
+    format!("{}.{:016x}", timestamp, rand::random::<u64>())
+}
+
+// Returns `true` if `s` only contains ASCII chars.
+fn is_ascii(s: &str) -> bool {
+    s.bytes().all(|b| b < 0x80)
+}
+
+impl DsnMessage {
+    /// Creates a notification without any recipient status, dated now.
+    pub fn new(reporting_mta: &str, to: &str) -> DsnMessage {
+        let id = get_unique_id();
+        DsnMessage {
+            reporting_mta: reporting_mta.into_string(),
+            to: to.into_string(),
+            envid: None,
+            arrival_date: None,
+            date: format!("{}", time::now_utc().rfc822z()),
+            message_id: format!("<{}@{}>", id, reporting_mta),
+            recipients: vec!(),
+            human_readable: None,
+            original_headers: None,
+            boundary: format!("rsmtp-dsn.{}", id)
+        }
+    }
+
+    /// Returns `true` if at least one recipient failed, in which case this is a bounce.
+    pub fn is_bounce(&self) -> bool {
+        self.recipients.iter().any(|r| r.action == ActionFailed)
+    }
+
+    /// Returns the subject of the notification.
+    pub fn get_subject(&self) -> &'static str {
+        if self.is_bounce() {
+            "Delivery Status Notification (Failure)"
+        } else if self.recipients.iter().any(|r| r.action == ActionDelayed) {
+            "Delivery Status Notification (Delay)"
+        } else {
+            "Delivery Status Notification (Success)"
+        }
+    }
+
+    /// Returns the text meant for humans, generated from the recipients.
+    fn get_human_readable(&self) -> String {
+        let mut text = format!(
+            "This is the mail system at host {}.\r\n\r\n",
+            self.reporting_mta
+        );
+        for recipient in self.recipients.iter() {
+            let what = match recipient.action {
+                ActionFailed => "could not be delivered to",
+                ActionDelayed => "has not yet been delivered to",
+                ActionDelivered => "was delivered to",
+                ActionRelayed => "was relayed to",
+                ActionExpanded => "was expanded for"
+            };
+            text.push_str(format!(
+                "Your message {} <{}>",
+                what,
+                recipient.final_recipient
+            ).as_slice());
+            match recipient.diagnostic_code {
+                Some(ref code) => text.push_str(format!(": {}\r\n", code).as_slice()),
+                None => text.push_str(".\r\n")
+            }
+        }
+        text
+    }
+
+    /// Returns the whole message, headers included, as a `multipart/report`.
+    pub fn to_message(&self) -> String {
+        let mut message = String::new();
+
+        // Headers.
+        message.push_str(format!(
+            "From: Mail Delivery System <MAILER-DAEMON@{}>\r\n",
+            self.reporting_mta
+        ).as_slice());
+        message.push_str(format!("To: <{}>\r\n", self.to).as_slice());
+        message.push_str(format!("Date: {}\r\n", self.date).as_slice());
+        message.push_str(format!("Message-ID: {}\r\n", self.message_id).as_slice());
+        message.push_str(format!("Subject: {}\r\n", self.get_subject()).as_slice());
+        message.push_str("Auto-Submitted: auto-replied\r\n");
+        message.push_str("MIME-Version: 1.0\r\n");
+        message.push_str(format!(
+            "Content-Type: multipart/report; report-type=delivery-status;\r\n\tboundary=\"{}\"\r\n",
+            self.boundary
+        ).as_slice());
+        message.push_str("\r\nThis is a MIME-encapsulated message.\r\n\r\n");
+
+        // Human readable part. Diagnostics and addresses may not be ASCII.
+        let text = match self.human_readable {
+            Some(ref text) => text.clone(),
+            None => self.get_human_readable()
+        };
+        let charset = if is_ascii(text.as_slice()) { "us-ascii" } else { "utf-8" };
+        message.push_str(format!("--{}\r\n", self.boundary).as_slice());
+        message.push_str(format!("Content-Type: text/plain; charset={}\r\n\r\n", charset).as_slice());
+        message.push_str(text.as_slice());
+        message.push_str("\r\n");
+
+        // Machine readable part. It must be ASCII, unless it uses the types of RFC 6533.
+        let mut status = format!("Reporting-MTA: dns; {}\r\n", self.reporting_mta);
+        match self.envid {
+            Some(ref envid) => status.push_str(
+                format!("Original-Envelope-Id: {}\r\n", utils::encode_xtext(envid.as_slice())).as_slice()
+            ),
+            None => {}
+        }
+        match self.arrival_date {
+            Some(ref date) => status.push_str(format!("Arrival-Date: {}\r\n", date).as_slice()),
+            None => {}
+        }
+        for recipient in self.recipients.iter() {
+            status.push_str("\r\n");
+            status.push_str(recipient.to_fields().as_slice());
+        }
+        let status_type = if is_ascii(status.as_slice()) {
+            "message/delivery-status"
+        } else {
+            "message/global-delivery-status"
+        };
+        message.push_str(format!("--{}\r\n", self.boundary).as_slice());
+        message.push_str(format!("Content-Type: {}\r\n\r\n", status_type).as_slice());
+        message.push_str(status.as_slice());
+        message.push_str("\r\n");
+
+        // Original headers.
+        match self.original_headers {
+            Some(ref headers) => {
+                let headers_type = if is_ascii(headers.as_slice()) {
+                    "text/rfc822-headers"
+                } else {
+                    "message/global-headers"
+                };
+                message.push_str(format!("--{}\r\n", self.boundary).as_slice());
+                message.push_str(format!("Content-Type: {}\r\n\r\n", headers_type).as_slice());
+                message.push_str(headers.as_slice());
+                message.push_str("\r\n");
+            },
+            None => {}
+        }
+
+        message.push_str(format!("--{}--\r\n", self.boundary).as_slice());
+        message
+    }
+}
+
+#[test]
+fn test_dsn_message() {
+    let mut dsn = DsnMessage::new("mx.rustastic.org", "rust@rustastic.org");
+    dsn.date = "Wed, 05 Nov 2014 10:26:43 +0000".into_string();
+    dsn.message_id = "<1415183203042.1@mx.rustastic.org>".into_string();
+    dsn.boundary = "rsmtp-dsn.1415183203042.1".into_string();
+    dsn.envid = Some("QQ314159".into_string());
+    dsn.recipients.push(DsnRecipientStatus::new("bob@rustastic.org", ActionDelayed, "4.4.1"));
+    assert!(!dsn.is_bounce());
+    assert_eq!("Delivery Status Notification (Delay)", dsn.get_subject());
+
+    let mut failed = DsnRecipientStatus::new("alice@rustastic.org", ActionFailed, "5.1.1");
+    failed.diagnostic_code = Some("550 5.1.1 User unknown".into_string());
+    dsn.recipients.push(failed);
+    dsn.original_headers = Some("Subject: Hello\r\n".into_string());
+    assert!(dsn.is_bounce());
+    assert_eq!("Delivery Status Notification (Failure)", dsn.get_subject());
+
+    assert_eq!(
+        "From: Mail Delivery System <MAILER-DAEMON@mx.rustastic.org>\r\n\
+         To: <rust@rustastic.org>\r\n\
+         Date: Wed, 05 Nov 2014 10:26:43 +0000\r\n\
+         Message-ID: <1415183203042.1@mx.rustastic.org>\r\n\
+         Subject: Delivery Status Notification (Failure)\r\n\
+         Auto-Submitted: auto-replied\r\n\
+         MIME-Version: 1.0\r\n\
+         Content-Type: multipart/report; report-type=delivery-status;\r\n\
+         \tboundary=\"rsmtp-dsn.1415183203042.1\"\r\n\
+         \r\n\
+         This is a MIME-encapsulated message.\r\n\
+         \r\n\
+         --rsmtp-dsn.1415183203042.1\r\n\
+         Content-Type: text/plain; charset=us-ascii\r\n\
+         \r\n\
+         This is the mail system at host mx.rustastic.org.\r\n\
+         \r\n\
+         Your message has not yet been delivered to <bob@rustastic.org>.\r\n\
+         Your message could not be delivered to <alice@rustastic.org>: 550 5.1.1 User unknown\r\n\
+         \r\n\
+         --rsmtp-dsn.1415183203042.1\r\n\
+         Content-Type: message/delivery-status\r\n\
+         \r\n\
+         Reporting-MTA: dns; mx.rustastic.org\r\n\
+         Original-Envelope-Id: QQ314159\r\n\
+         \r\n\
+         Final-Recipient: rfc822; bob@rustastic.org\r\n\
+         Action: delayed\r\n\
+         Status: 4.4.1\r\n\
+         \r\n\
+         Final-Recipient: rfc822; alice@rustastic.org\r\n\
+         Action: failed\r\n\
+         Status: 5.1.1\r\n\
+         Diagnostic-Code: smtp; 550 5.1.1 User unknown\r\n\
+         \r\n\
+         --rsmtp-dsn.1415183203042.1\r\n\
+         Content-Type: text/rfc822-headers\r\n\
+         \r\n\
+         Subject: Hello\r\n\
+         \r\n\
+         --rsmtp-dsn.1415183203042.1--\r\n",
+        dsn.to_message().as_slice()
+    );
+
+    // Internationalized addresses are declared as such.
+    dsn.recipients = vec!(DsnRecipientStatus::new("用户@例子.广告", ActionFailed, "5.1.1"));
+    dsn.original_headers = None;
+    let message = dsn.to_message();
+    assert!(message.as_slice().contains("Content-Type: text/plain; charset=utf-8\r\n"));
+    assert!(message.as_slice().contains("Content-Type: message/global-delivery-status\r\n"));
+    assert!(message.as_slice().contains("Final-Recipient: utf-8; 用户@例子.广告\r\n"));
+}
+
+#[test]
+fn test_dsn_message_new() {
+    let first = DsnMessage::new("mx.rustastic.org", "rust@rustastic.org");
+    let second = DsnMessage::new("mx.rustastic.org", "rust@rustastic.org");
+    assert!(first.boundary.as_slice().starts_with("rsmtp-dsn."));
+    assert!(first.boundary != second.boundary);
+    assert!(first.message_id.as_slice().ends_with("@mx.rustastic.org>"));
+    assert!(first.message_id != second.message_id);
+}
@@ -0,0 +1,421 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tools for internationalized domain names, as described
+//! [in UTS #46](http://www.unicode.org/reports/tr46/) and
+//! [in RFC 5891](http://tools.ietf.org/html/rfc5891).
+//!
+//! Domains are converted between their Unicode form, ie. `bücher.example`, made of U-labels and
+//! their ASCII form, ie. `xn--bcher-kva.example`, made of A-labels. The processing is
+//! nontransitional, as required by IDNA 2008, and the STD3 rules are applied.
+
+use std::char;
+use std::ascii::AsciiExt;
+use std::num::{CheckedAdd, CheckedMul};
+use super::utils;
+use super::idna_table::{UTS46_TABLE, Uts46Status, Valid, Mapped, Ignored, Deviation, Disallowed};
+
+/// Maximum length of a label in a domain name.
+static MAX_LABEL_LEN: uint = 63;
+
+/// The prefix of an A-label.
+static ACE_PREFIX: &'static str = "xn--";
+
+// Parameters of the Punycode algorithm, as described in RFC 3492.
+static BASE: u32 = 36;
+static TMIN: u32 = 1;
+static TMAX: u32 = 26;
+static SKEW: u32 = 38;
+static DAMP: u32 = 700;
+static INITIAL_BIAS: u32 = 72;
+static INITIAL_N: u32 = 0x80;
+
+/// Represents an error that occured while converting a domain name.
+#[deriving(PartialEq, Eq, Show)]
+pub enum IdnaError {
+    /// The domain contains a character that is not allowed in domain names.
+    DisallowedChar(char),
+    /// A label starts with `xn--` but is not valid Punycode.
+    InvalidPunycode,
+    /// A label is empty, too long or starts or ends with an hyphen.
+    InvalidLabel
+}
+
+/// Converts a domain to its ASCII form, where U-labels are converted to A-labels.
+///
+/// The domain is mapped first, which means that it is lowercased and that some characters, like
+/// the ideographic full stop, are replaced by their standard form. A domain that is already
+/// in ASCII form is checked and lowercased.
+pub fn to_ascii(domain: &str) -> Result<String, IdnaError> {
+    let mapped = try!(map(domain));
+    let mut labels = vec!();
+    for label in mapped.as_slice().split('.') {
+        try!(check_label(label));
+        if label.bytes().all(|b| b < 0x80) {
+            labels.push(label.into_string());
+        } else {
+            match punycode_encode(label) {
+                Some(encoded) => labels.push(ACE_PREFIX.into_string() + encoded),
+                None => return Err(InvalidPunycode)
+            }
+        }
+    }
+    for label in labels.iter() {
+        if label.len() > MAX_LABEL_LEN {
+            return Err(InvalidLabel);
+        }
+    }
+    let ascii = labels.connect(".");
+    if utils::get_domain_len(ascii.as_slice()) != Some(ascii.len()) {
+        return Err(InvalidLabel);
+    }
+    Ok(ascii)
+}
+
+#[test]
+fn test_to_ascii() {
+    assert_eq!(Ok("rustastic.org".into_string()), to_ascii("rustastic.org"));
+    assert_eq!(Ok("rustastic.org".into_string()), to_ascii("RustAstic.ORG"));
+    assert_eq!(Ok("xn--bcher-kva.example".into_string()), to_ascii("bücher.example"));
+    assert_eq!(Ok("xn--bcher-kva.example".into_string()), to_ascii("BÜCHER.example"));
+    assert_eq!(Ok("xn--bcher-kva.example".into_string()), to_ascii("xn--bcher-kva.example"));
+    assert_eq!(Ok("xn--bcher-kva.example".into_string()), to_ascii("bücher。example"));
+    assert_eq!(Ok("xn--zca.de".into_string()), to_ascii("ß.de"));
+    assert_eq!(Ok("xn--fiqs8s".into_string()), to_ascii("中国"));
+    assert_eq!(Ok("xn--mller-kva.example".into_string()), to_ascii("müller.example"));
+
+    assert_eq!(Err(InvalidLabel), to_ascii(""));
+    assert_eq!(Err(InvalidLabel), to_ascii("bücher..example"));
+    assert_eq!(Err(InvalidLabel), to_ascii("-bücher.example"));
+    assert_eq!(Err(InvalidLabel), to_ascii("bü--cher.example"));
+    assert_eq!(Err(DisallowedChar('_')), to_ascii("rust_astic.org"));
+    assert_eq!(Err(InvalidLabel), to_ascii(
+        String::from_char(MAX_LABEL_LEN, 'ü').as_slice()
+    ));
+    assert_eq!(Err(InvalidPunycode), to_ascii("xn--bcher-kva!.example"));
+    assert_eq!(Err(DisallowedChar(' ')), to_ascii("bü cher.example"));
+}
+
+/// Converts a domain to its Unicode form, where A-labels are converted to U-labels.
+pub fn to_unicode(domain: &str) -> Result<String, IdnaError> {
+    let mapped = try!(map(domain));
+    let mut labels = vec!();
+    for label in mapped.as_slice().split('.') {
+        try!(check_label(label));
+        labels.push(if has_ace_prefix(label) {
+            match punycode_decode(label.slice_from(ACE_PREFIX.len())) {
+                Some(decoded) => decoded,
+                None => return Err(InvalidPunycode)
+            }
+        } else {
+            label.into_string()
+        });
+    }
+    Ok(labels.connect("."))
+}
+
+#[test]
+fn test_to_unicode() {
+    assert_eq!(Ok("rustastic.org".into_string()), to_unicode("RUSTASTIC.org"));
+    assert_eq!(Ok("bücher.example".into_string()), to_unicode("xn--bcher-kva.example"));
+    assert_eq!(Ok("bücher.example".into_string()), to_unicode("XN--BCHER-KVA.example"));
+    assert_eq!(Ok("bücher.example".into_string()), to_unicode("Bücher.example"));
+    assert_eq!(Ok("中国".into_string()), to_unicode("xn--fiqs8s"));
+
+    assert_eq!(Err(InvalidPunycode), to_unicode("xn--bcher-kva9999999999.example"));
+    // Decodes to "bÜcher", which is not in its mapped form.
+    assert_eq!(Err(DisallowedChar('Ü')), to_unicode("xn--bcher-2pa.example"));
+}
+
+// Returns true if a label starts with the A-label prefix, regardless of case.
+fn has_ace_prefix(label: &str) -> bool {
+    label.len() >= ACE_PREFIX.len() &&
+        label.slice_to(ACE_PREFIX.len()).to_ascii_lower().as_slice() == ACE_PREFIX
+}
+
+// Check the validity of a mapped label, as described in section 4.1 of UTS #46. If the label is an
+// A-label, its decoded form is checked instead.
+fn check_label(label: &str) -> Result<(), IdnaError> {
+    let decoded = if has_ace_prefix(label) {
+        match punycode_decode(label.slice_from(ACE_PREFIX.len())) {
+            Some(decoded) => decoded,
+            None => return Err(InvalidPunycode)
+        }
+    } else {
+        label.into_string()
+    };
+    let label = decoded.as_slice();
+
+    if label.len() == 0 ||
+        label.starts_with("-") || label.ends_with("-") ||
+        label.chars().skip(2).take(2).collect::<String>().as_slice() == "--" {
+        return Err(InvalidLabel);
+    }
+    for c in label.chars() {
+        match get_status(c) {
+            // The STD3 rules only allow letters, digits and hyphens in ASCII.
+            _ if (c as u32) < 0x80 && !utils::is_alnum(c) && c != '-' => {
+                return Err(DisallowedChar(c));
+            },
+            (Valid, _) | (Deviation, _) => {},
+            _ => return Err(DisallowedChar(c))
+        }
+    }
+    // The label must already be normalized.
+    if label.nfc_chars().collect::<String>().as_slice() != label {
+        return Err(InvalidLabel);
+    }
+    Ok(())
+}
+
+// Map a domain as described in section 4 of UTS #46, including the normalization.
+fn map(domain: &str) -> Result<String, IdnaError> {
+    let mut mapped = String::with_capacity(domain.len());
+    for c in domain.chars() {
+        match get_status(c) {
+            (Valid, _) | (Deviation, _) => mapped.push(c),
+            (Mapped, mapping) => mapped.push_str(mapping),
+            (Ignored, _) => {},
+            (Disallowed, _) => return Err(DisallowedChar(c))
+        }
+    }
+    Ok(mapped.as_slice().nfc_chars().collect())
+}
+
+#[test]
+fn test_map() {
+    assert_eq!(Ok("bücher.example".into_string()), map("BÜCHER．example"));
+    assert_eq!(Ok("straße".into_string()), map("Stra\u00adße"));
+    assert_eq!(Err(DisallowedChar('\U0010ffff')), map("rust\U0010ffff.org"));
+}
+
+// Get the status of a character in the mapping table, along with its mapping.
+fn get_status(c: char) -> (Uts46Status, &'static str) {
+    let code = c as u32;
+    // Find the last entry starting before the character.
+    let mut low = 0u;
+    let mut high = UTS46_TABLE.len();
+    while high - low > 1 {
+        let middle = (low + high) / 2;
+        let (start, _, _) = UTS46_TABLE[middle];
+        if start <= code {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    let (_, status, mapping) = UTS46_TABLE[low];
+    (status, mapping)
+}
+
+#[test]
+fn test_get_status() {
+    assert_eq!((Valid, ""), get_status('a'));
+    assert_eq!((Mapped, "a"), get_status('A'));
+    assert_eq!((Deviation, "ss"), get_status('ß'));
+    assert_eq!((Ignored, ""), get_status('\u00ad'));
+    assert_eq!((Mapped, "ij"), get_status('ĳ'));
+    assert_eq!((Disallowed, ""), get_status('\U0010ffff'));
+}
+
+// Compute the bias adaptation of Punycode.
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - TMIN) * TMAX) / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+    k + (((BASE - TMIN + 1) * delta) / (delta + SKEW))
+}
+
+// Compute the threshold of Punycode for a given position.
+fn get_threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        TMIN
+    } else if k >= bias + TMAX {
+        TMAX
+    } else {
+        k - bias
+    }
+}
+
+// Get the character representing a Punycode digit.
+fn encode_digit(d: u32) -> char {
+    if d < 26 {
+        (d as u8 + 'a' as u8) as char
+    } else {
+        (d as u8 - 26 + '0' as u8) as char
+    }
+}
+
+// Get the value of a Punycode digit.
+fn decode_digit(c: char) -> Option<u32> {
+    match c {
+        'a' ... 'z' => Some(c as u32 - 'a' as u32),
+        'A' ... 'Z' => Some(c as u32 - 'A' as u32),
+        '0' ... '9' => Some(c as u32 - '0' as u32 + 26),
+        _ => None
+    }
+}
+
+/// Encodes a label with Punycode, as described [in RFC 3492](http://tools.ietf.org/html/rfc3492).
+///
+/// The result does not include the `xn--` prefix. Returns `None` if an overflow occurs.
+pub fn punycode_encode(input: &str) -> Option<String> {
+    let chars: Vec<u32> = input.chars().map(|c| c as u32).collect();
+    let mut output: String = input.chars().filter(|&c| (c as u32) < INITIAL_N).collect();
+    let basic_len = output.len() as u32;
+    if basic_len > 0 {
+        output.push('-');
+    }
+
+    let mut handled = basic_len;
+    let mut n = INITIAL_N;
+    let mut delta = 0u32;
+    let mut bias = INITIAL_BIAS;
+    while (handled as uint) < chars.len() {
+        // Find the smallest code point that is not handled yet.
+        let m = *chars.iter().filter(|&&c| c >= n).min().unwrap();
+        delta = match (m - n).checked_mul(&(handled + 1)).and_then(|d| delta.checked_add(&d)) {
+            Some(delta) => delta,
+            None => return None
+        };
+        n = m;
+        for &c in chars.iter() {
+            if c < n {
+                delta = match delta.checked_add(&1) {
+                    Some(delta) => delta,
+                    None => return None
+                };
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = get_threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, handled + 1, handled == basic_len);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Some(output)
+}
+
+#[test]
+fn test_punycode_encode() {
+    assert_eq!(Some("bcher-kva".into_string()), punycode_encode("bücher"));
+    assert_eq!(Some("fiqs8s".into_string()), punycode_encode("中国"));
+    assert_eq!(Some("rust-".into_string()), punycode_encode("rust"));
+    assert_eq!(Some("".into_string()), punycode_encode(""));
+    // Sample strings from RFC 3492.
+    assert_eq!(
+        Some("egbpdaj6bu4bxfgehfvwxn".into_string()),
+        punycode_encode("ليهمابتكلموشعربي؟")
+    );
+    assert_eq!(
+        Some("-with-SUPER-MONKEYS-pc58ag80a8qai00g7n9n".into_string()),
+        punycode_encode("安室奈美恵-with-SUPER-MONKEYS")
+    );
+}
+
+/// Decodes a label encoded with Punycode, as described
+/// [in RFC 3492](http://tools.ietf.org/html/rfc3492).
+///
+/// The input must not include the `xn--` prefix. Returns `None` if the input is invalid.
+pub fn punycode_decode(input: &str) -> Option<String> {
+    // Basic code points are before the last delimiter.
+    let (mut output, digits): (Vec<char>, &str) = match input.rfind('-') {
+        Some(pos) => (input.slice_to(pos).chars().collect(), input.slice_from(pos + 1)),
+        None => (vec!(), input)
+    };
+    if output.iter().any(|&c| (c as u32) >= INITIAL_N) {
+        return None;
+    }
+
+    let mut n = INITIAL_N;
+    let mut i = 0u32;
+    let mut bias = INITIAL_BIAS;
+    let mut digits = digits.chars();
+    loop {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+        loop {
+            let digit = match digits.next() {
+                Some(c) => match decode_digit(c) {
+                    Some(digit) => digit,
+                    None => return None
+                },
+                // Only stop when a code point is complete.
+                None if k == BASE => return Some(output.into_iter().collect()),
+                None => return None
+            };
+            i = match digit.checked_mul(&w).and_then(|d| i.checked_add(&d)) {
+                Some(i) => i,
+                None => return None
+            };
+            let t = get_threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            w = match w.checked_mul(&(BASE - t)) {
+                Some(w) => w,
+                None => return None
+            };
+            k += BASE;
+        }
+        let len = output.len() as u32 + 1;
+        bias = adapt(i - old_i, len, old_i == 0);
+        n = match n.checked_add(&(i / len)) {
+            Some(n) => n,
+            None => return None
+        };
+        i %= len;
+        match char::from_u32(n) {
+            Some(c) => output.insert(i as uint, c),
+            None => return None
+        }
+        i += 1;
+    }
+}
+
+#[test]
+fn test_punycode_decode() {
+    assert_eq!(Some("bücher".into_string()), punycode_decode("bcher-kva"));
+    assert_eq!(Some("中国".into_string()), punycode_decode("fiqs8s"));
+    assert_eq!(Some("rust".into_string()), punycode_decode("rust-"));
+    assert_eq!(Some("".into_string()), punycode_decode(""));
+    assert_eq!(
+        Some("安室奈美恵-with-SUPER-MONKEYS".into_string()),
+        punycode_decode("-with-SUPER-MONKEYS-pc58ag80a8qai00g7n9n")
+    );
+
+    assert_eq!(None, punycode_decode("bcher-kv"));
+    assert_eq!(None, punycode_decode("bcher-k!a"));
+    assert_eq!(None, punycode_decode("bü-kva"));
+    assert_eq!(None, punycode_decode("99999999999"));
+}
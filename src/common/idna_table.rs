@@ -0,0 +1,8410 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// NOTE: this file is generated from the IDNA mapping table of Unicode UTS #46,
+// version 17.0.0, found at http://www.unicode.org/Public/idna/. Do not edit it by hand.
+
+//! The IDNA mapping table, as described [in UTS #46](http://www.unicode.org/reports/tr46/).
+
+/// The status of a code point in the IDNA mapping table.
+#[deriving(PartialEq, Eq, Show)]
+pub enum Uts46Status {
+    /// The code point is kept as is.
+    Valid,
+    /// The code point is replaced by the associated string.
+    Mapped,
+    /// The code point is removed.
+    Ignored,
+    /// The code point is valid in IDNA 2008, but mapped in transitional processing.
+    Deviation,
+    /// The code point cannot appear in a domain name.
+    Disallowed
+}
+
+/// Each entry applies to the code points from its own code point up to the code point of the
+/// next entry. The string is the mapping of `Mapped` code points.
+pub static UTS46_TABLE: &'static [(u32, Uts46Status, &'static str)] = &[
+    (0x0, Valid, ""),
+    (0x1, Valid, ""),
+    (0x2, Valid, ""),
+    (0x3, Valid, ""),
+    (0x4, Valid, ""),
+    (0x5, Valid, ""),
+    (0x6, Valid, ""),
+    (0x7, Valid, ""),
+    (0x8, Valid, ""),
+    (0x9, Valid, ""),
+    (0xa, Valid, ""),
+    (0xb, Valid, ""),
+    (0xc, Valid, ""),
+    (0xd, Valid, ""),
+    (0xe, Valid, ""),
+    (0xf, Valid, ""),
+    (0x10, Valid, ""),
+    (0x11, Valid, ""),
+    (0x12, Valid, ""),
+    (0x13, Valid, ""),
+    (0x14, Valid, ""),
+    (0x15, Valid, ""),
+    (0x16, Valid, ""),
+    (0x17, Valid, ""),
+    (0x18, Valid, ""),
+    (0x19, Valid, ""),
+    (0x1a, Valid, ""),
+    (0x1b, Valid, ""),
+    (0x1c, Valid, ""),
+    (0x1d, Valid, ""),
+    (0x1e, Valid, ""),
+    (0x1f, Valid, ""),
+    (0x20, Valid, ""),
+    (0x21, Valid, ""),
+    (0x22, Valid, ""),
+    (0x23, Valid, ""),
+    (0x24, Valid, ""),
+    (0x25, Valid, ""),
+    (0x26, Valid, ""),
+    (0x27, Valid, ""),
+    (0x28, Valid, ""),
+    (0x29, Valid, ""),
+    (0x2a, Valid, ""),
+    (0x2b, Valid, ""),
+    (0x2c, Valid, ""),
+    (0x2d, Valid, ""),
+    (0x2e, Valid, ""),
+    (0x2f, Valid, ""),
+    (0x30, Valid, ""),
+    (0x31, Valid, ""),
+    (0x32, Valid, ""),
+    (0x33, Valid, ""),
+    (0x34, Valid, ""),
+    (0x35, Valid, ""),
+    (0x36, Valid, ""),
+    (0x37, Valid, ""),
+    (0x38, Valid, ""),
+    (0x39, Valid, ""),
+    (0x3a, Valid, ""),
+    (0x3b, Valid, ""),
+    (0x3c, Valid, ""),
+    (0x3d, Valid, ""),
+    (0x3e, Valid, ""),
+    (0x3f, Valid, ""),
+    (0x40, Valid, ""),
+    (0x41, Mapped, "a"),
+    (0x42, Mapped, "b"),
+    (0x43, Mapped, "c"),
+    (0x44, Mapped, "d"),
+    (0x45, Mapped, "e"),
+    (0x46, Mapped, "f"),
+    (0x47, Mapped, "g"),
+    (0x48, Mapped, "h"),
+    (0x49, Mapped, "i"),
+    (0x4a, Mapped, "j"),
+    (0x4b, Mapped, "k"),
+    (0x4c, Mapped, "l"),
+    (0x4d, Mapped, "m"),
+    (0x4e, Mapped, "n"),
+    (0x4f, Mapped, "o"),
+    (0x50, Mapped, "p"),
+    (0x51, Mapped, "q"),
+    (0x52, Mapped, "r"),
+    (0x53, Mapped, "s"),
+    (0x54, Mapped, "t"),
+    (0x55, Mapped, "u"),
+    (0x56, Mapped, "v"),
+    (0x57, Mapped, "w"),
+    (0x58, Mapped, "x"),
+    (0x59, Mapped, "y"),
+    (0x5a, Mapped, "z"),
+    (0x5b, Valid, ""),
+    (0x5c, Valid, ""),
+    (0x5d, Valid, ""),
+    (0x5e, Valid, ""),
+    (0x5f, Valid, ""),
+    (0x60, Valid, ""),
+    (0x61, Valid, ""),
+    (0x62, Valid, ""),
+    (0x63, Valid, ""),
+    (0x64, Valid, ""),
+    (0x65, Valid, ""),
+    (0x66, Valid, ""),
+    (0x67, Valid, ""),
+    (0x68, Valid, ""),
+    (0x69, Valid, ""),
+    (0x6a, Valid, ""),
+    (0x6b, Valid, ""),
+    (0x6c, Valid, ""),
+    (0x6d, Valid, ""),
+    (0x6e, Valid, ""),
+    (0x6f, Valid, ""),
+    (0x70, Valid, ""),
+    (0x71, Valid, ""),
+    (0x72, Valid, ""),
+    (0x73, Valid, ""),
+    (0x74, Valid, ""),
+    (0x75, Valid, ""),
+    (0x76, Valid, ""),
+    (0x77, Valid, ""),
+    (0x78, Valid, ""),
+    (0x79, Valid, ""),
+    (0x7a, Valid, ""),
+    (0x7b, Valid, ""),
+    (0x7c, Valid, ""),
+    (0x7d, Valid, ""),
+    (0x7e, Valid, ""),
+    (0x7f, Valid, ""),
+    (0x80, Disallowed, ""),
+    (0x81, Disallowed, ""),
+    (0x82, Disallowed, ""),
+    (0x83, Disallowed, ""),
+    (0x84, Disallowed, ""),
+    (0x85, Disallowed, ""),
+    (0x86, Disallowed, ""),
+    (0x87, Disallowed, ""),
+    (0x88, Disallowed, ""),
+    (0x89, Disallowed, ""),
+    (0x8a, Disallowed, ""),
+    (0x8b, Disallowed, ""),
+    (0x8c, Disallowed, ""),
+    (0x8d, Disallowed, ""),
+    (0x8e, Disallowed, ""),
+    (0x8f, Disallowed, ""),
+    (0x90, Disallowed, ""),
+    (0x91, Disallowed, ""),
+    (0x92, Disallowed, ""),
+    (0x93, Disallowed, ""),
+    (0x94, Disallowed, ""),
+    (0x95, Disallowed, ""),
+    (0x96, Disallowed, ""),
+    (0x97, Disallowed, ""),
+    (0x98, Disallowed, ""),
+    (0x99, Disallowed, ""),
+    (0x9a, Disallowed, ""),
+    (0x9b, Disallowed, ""),
+    (0x9c, Disallowed, ""),
+    (0x9d, Disallowed, ""),
+    (0x9e, Disallowed, ""),
+    (0x9f, Disallowed, ""),
+    (0xa0, Mapped, " "),
+    (0xa1, Valid, ""),
+    (0xa2, Valid, ""),
+    (0xa3, Valid, ""),
+    (0xa4, Valid, ""),
+    (0xa5, Valid, ""),
+    (0xa6, Valid, ""),
+    (0xa7, Valid, ""),
+    (0xa8, Mapped, " \u0308"),
+    (0xa9, Valid, ""),
+    (0xaa, Mapped, "a"),
+    (0xab, Valid, ""),
+    (0xac, Valid, ""),
+    (0xad, Ignored, ""),
+    (0xae, Valid, ""),
+    (0xaf, Mapped, " \u0304"),
+    (0xb0, Valid, ""),
+    (0xb1, Valid, ""),
+    (0xb2, Mapped, "2"),
+    (0xb3, Mapped, "3"),
+    (0xb4, Mapped, " \u0301"),
+    (0xb5, Mapped, "\u03bc"),
+    (0xb6, Valid, ""),
+    (0xb7, Valid, ""),
+    (0xb8, Mapped, " \u0327"),
+    (0xb9, Mapped, "1"),
+    (0xba, Mapped, "o"),
+    (0xbb, Valid, ""),
+    (0xbc, Mapped, "1\u20444"),
+    (0xbd, Mapped, "1\u20442"),
+    (0xbe, Mapped, "3\u20444"),
+    (0xbf, Valid, ""),
+    (0xc0, Mapped, "\u00e0"),
+    (0xc1, Mapped, "\u00e1"),
+    (0xc2, Mapped, "\u00e2"),
+    (0xc3, Mapped, "\u00e3"),
+    (0xc4, Mapped, "\u00e4"),
+    (0xc5, Mapped, "\u00e5"),
+    (0xc6, Mapped, "\u00e6"),
+    (0xc7, Mapped, "\u00e7"),
+    (0xc8, Mapped, "\u00e8"),
+    (0xc9, Mapped, "\u00e9"),
+    (0xca, Mapped, "\u00ea"),
+    (0xcb, Mapped, "\u00eb"),
+    (0xcc, Mapped, "\u00ec"),
+    (0xcd, Mapped, "\u00ed"),
+    (0xce, Mapped, "\u00ee"),
+    (0xcf, Mapped, "\u00ef"),
+    (0xd0, Mapped, "\u00f0"),
+    (0xd1, Mapped, "\u00f1"),
+    (0xd2, Mapped, "\u00f2"),
+    (0xd3, Mapped, "\u00f3"),
+    (0xd4, Mapped, "\u00f4"),
+    (0xd5, Mapped, "\u00f5"),
+    (0xd6, Mapped, "\u00f6"),
+    (0xd7, Valid, ""),
+    (0xd8, Mapped, "\u00f8"),
+    (0xd9, Mapped, "\u00f9"),
+    (0xda, Mapped, "\u00fa"),
+    (0xdb, Mapped, "\u00fb"),
+    (0xdc, Mapped, "\u00fc"),
+    (0xdd, Mapped, "\u00fd"),
+    (0xde, Mapped, "\u00fe"),
+    (0xdf, Deviation, "ss"),
+    (0xe0, Valid, ""),
+    (0xe1, Valid, ""),
+    (0xe2, Valid, ""),
+    (0xe3, Valid, ""),
+    (0xe4, Valid, ""),
+    (0xe5, Valid, ""),
+    (0xe6, Valid, ""),
+    (0xe7, Valid, ""),
+    (0xe8, Valid, ""),
+    (0xe9, Valid, ""),
+    (0xea, Valid, ""),
+    (0xeb, Valid, ""),
+    (0xec, Valid, ""),
+    (0xed, Valid, ""),
+    (0xee, Valid, ""),
+    (0xef, Valid, ""),
+    (0xf0, Valid, ""),
+    (0xf1, Valid, ""),
+    (0xf2, Valid, ""),
+    (0xf3, Valid, ""),
+    (0xf4, Valid, ""),
+    (0xf5, Valid, ""),
+    (0xf6, Valid, ""),
+    (0xf7, Valid, ""),
+    (0xf8, Valid, ""),
+    (0xf9, Valid, ""),
+    (0xfa, Valid, ""),
+    (0xfb, Valid, ""),
+    (0xfc, Valid, ""),
+    (0xfd, Valid, ""),
+    (0xfe, Valid, ""),
+    (0xff, Valid, ""),
+    (0x100, Mapped, "\u0101"),
+    (0x101, Valid, ""),
+    (0x102, Mapped, "\u0103"),
+    (0x103, Valid, ""),
+    (0x104, Mapped, "\u0105"),
+    (0x105, Valid, ""),
+    (0x106, Mapped, "\u0107"),
+    (0x107, Valid, ""),
+    (0x108, Mapped, "\u0109"),
+    (0x109, Valid, ""),
+    (0x10a, Mapped, "\u010b"),
+    (0x10b, Valid, ""),
+    (0x10c, Mapped, "\u010d"),
+    (0x10d, Valid, ""),
+    (0x10e, Mapped, "\u010f"),
+    (0x10f, Valid, ""),
+    (0x110, Mapped, "\u0111"),
+    (0x111, Valid, ""),
+    (0x112, Mapped, "\u0113"),
+    (0x113, Valid, ""),
+    (0x114, Mapped, "\u0115"),
+    (0x115, Valid, ""),
+    (0x116, Mapped, "\u0117"),
+    (0x117, Valid, ""),
+    (0x118, Mapped, "\u0119"),
+    (0x119, Valid, ""),
+    (0x11a, Mapped, "\u011b"),
+    (0x11b, Valid, ""),
+    (0x11c, Mapped, "\u011d"),
+    (0x11d, Valid, ""),
+    (0x11e, Mapped, "\u011f"),
+    (0x11f, Valid, ""),
+    (0x120, Mapped, "\u0121"),
+    (0x121, Valid, ""),
+    (0x122, Mapped, "\u0123"),
+    (0x123, Valid, ""),
+    (0x124, Mapped, "\u0125"),
+    (0x125, Valid, ""),
+    (0x126, Mapped, "\u0127"),
+    (0x127, Valid, ""),
+    (0x128, Mapped, "\u0129"),
+    (0x129, Valid, ""),
+    (0x12a, Mapped, "\u012b"),
+    (0x12b, Valid, ""),
+    (0x12c, Mapped, "\u012d"),
+    (0x12d, Valid, ""),
+    (0x12e, Mapped, "\u012f"),
+    (0x12f, Valid, ""),
+    (0x130, Mapped, "i\u0307"),
+    (0x131, Valid, ""),
+    (0x132, Mapped, "ij"),
+    (0x134, Mapped, "\u0135"),
+    (0x135, Valid, ""),
+    (0x136, Mapped, "\u0137"),
+    (0x137, Valid, ""),
+    (0x139, Mapped, "\u013a"),
+    (0x13a, Valid, ""),
+    (0x13b, Mapped, "\u013c"),
+    (0x13c, Valid, ""),
+    (0x13d, Mapped, "\u013e"),
+    (0x13e, Valid, ""),
+    (0x13f, Mapped, "l\u00b7"),
+    (0x141, Mapped, "\u0142"),
+    (0x142, Valid, ""),
+    (0x143, Mapped, "\u0144"),
+    (0x144, Valid, ""),
+    (0x145, Mapped, "\u0146"),
+    (0x146, Valid, ""),
+    (0x147, Mapped, "\u0148"),
+    (0x148, Valid, ""),
+    (0x149, Mapped, "\u02bcn"),
+    (0x14a, Mapped, "\u014b"),
+    (0x14b, Valid, ""),
+    (0x14c, Mapped, "\u014d"),
+    (0x14d, Valid, ""),
+    (0x14e, Mapped, "\u014f"),
+    (0x14f, Valid, ""),
+    (0x150, Mapped, "\u0151"),
+    (0x151, Valid, ""),
+    (0x152, Mapped, "\u0153"),
+    (0x153, Valid, ""),
+    (0x154, Mapped, "\u0155"),
+    (0x155, Valid, ""),
+    (0x156, Mapped, "\u0157"),
+    (0x157, Valid, ""),
+    (0x158, Mapped, "\u0159"),
+    (0x159, Valid, ""),
+    (0x15a, Mapped, "\u015b"),
+    (0x15b, Valid, ""),
+    (0x15c, Mapped, "\u015d"),
+    (0x15d, Valid, ""),
+    (0x15e, Mapped, "\u015f"),
+    (0x15f, Valid, ""),
+    (0x160, Mapped, "\u0161"),
+    (0x161, Valid, ""),
+    (0x162, Mapped, "\u0163"),
+    (0x163, Valid, ""),
+    (0x164, Mapped, "\u0165"),
+    (0x165, Valid, ""),
+    (0x166, Mapped, "\u0167"),
+    (0x167, Valid, ""),
+    (0x168, Mapped, "\u0169"),
+    (0x169, Valid, ""),
+    (0x16a, Mapped, "\u016b"),
+    (0x16b, Valid, ""),
+    (0x16c, Mapped, "\u016d"),
+    (0x16d, Valid, ""),
+    (0x16e, Mapped, "\u016f"),
+    (0x16f, Valid, ""),
+    (0x170, Mapped, "\u0171"),
+    (0x171, Valid, ""),
+    (0x172, Mapped, "\u0173"),
+    (0x173, Valid, ""),
+    (0x174, Mapped, "\u0175"),
+    (0x175, Valid, ""),
+    (0x176, Mapped, "\u0177"),
+    (0x177, Valid, ""),
+    (0x178, Mapped, "\u00ff"),
+    (0x179, Mapped, "\u017a"),
+    (0x17a, Valid, ""),
+    (0x17b, Mapped, "\u017c"),
+    (0x17c, Valid, ""),
+    (0x17d, Mapped, "\u017e"),
+    (0x17e, Valid, ""),
+    (0x17f, Mapped, "s"),
+    (0x180, Valid, ""),
+    (0x181, Mapped, "\u0253"),
+    (0x182, Mapped, "\u0183"),
+    (0x183, Valid, ""),
+    (0x184, Mapped, "\u0185"),
+    (0x185, Valid, ""),
+    (0x186, Mapped, "\u0254"),
+    (0x187, Mapped, "\u0188"),
+    (0x188, Valid, ""),
+    (0x189, Mapped, "\u0256"),
+    (0x18a, Mapped, "\u0257"),
+    (0x18b, Mapped, "\u018c"),
+    (0x18c, Valid, ""),
+    (0x18e, Mapped, "\u01dd"),
+    (0x18f, Mapped, "\u0259"),
+    (0x190, Mapped, "\u025b"),
+    (0x191, Mapped, "\u0192"),
+    (0x192, Valid, ""),
+    (0x193, Mapped, "\u0260"),
+    (0x194, Mapped, "\u0263"),
+    (0x195, Valid, ""),
+    (0x196, Mapped, "\u0269"),
+    (0x197, Mapped, "\u0268"),
+    (0x198, Mapped, "\u0199"),
+    (0x199, Valid, ""),
+    (0x19c, Mapped, "\u026f"),
+    (0x19d, Mapped, "\u0272"),
+    (0x19e, Valid, ""),
+    (0x19f, Mapped, "\u0275"),
+    (0x1a0, Mapped, "\u01a1"),
+    (0x1a1, Valid, ""),
+    (0x1a2, Mapped, "\u01a3"),
+    (0x1a3, Valid, ""),
+    (0x1a4, Mapped, "\u01a5"),
+    (0x1a5, Valid, ""),
+    (0x1a6, Mapped, "\u0280"),
+    (0x1a7, Mapped, "\u01a8"),
+    (0x1a8, Valid, ""),
+    (0x1a9, Mapped, "\u0283"),
+    (0x1aa, Valid, ""),
+    (0x1ac, Mapped, "\u01ad"),
+    (0x1ad, Valid, ""),
+    (0x1ae, Mapped, "\u0288"),
+    (0x1af, Mapped, "\u01b0"),
+    (0x1b0, Valid, ""),
+    (0x1b1, Mapped, "\u028a"),
+    (0x1b2, Mapped, "\u028b"),
+    (0x1b3, Mapped, "\u01b4"),
+    (0x1b4, Valid, ""),
+    (0x1b5, Mapped, "\u01b6"),
+    (0x1b6, Valid, ""),
+    (0x1b7, Mapped, "\u0292"),
+    (0x1b8, Mapped, "\u01b9"),
+    (0x1b9, Valid, ""),
+    (0x1bc, Mapped, "\u01bd"),
+    (0x1bd, Valid, ""),
+    (0x1c4, Mapped, "d\u017e"),
+    (0x1c7, Mapped, "lj"),
+    (0x1ca, Mapped, "nj"),
+    (0x1cd, Mapped, "\u01ce"),
+    (0x1ce, Valid, ""),
+    (0x1cf, Mapped, "\u01d0"),
+    (0x1d0, Valid, ""),
+    (0x1d1, Mapped, "\u01d2"),
+    (0x1d2, Valid, ""),
+    (0x1d3, Mapped, "\u01d4"),
+    (0x1d4, Valid, ""),
+    (0x1d5, Mapped, "\u01d6"),
+    (0x1d6, Valid, ""),
+    (0x1d7, Mapped, "\u01d8"),
+    (0x1d8, Valid, ""),
+    (0x1d9, Mapped, "\u01da"),
+    (0x1da, Valid, ""),
+    (0x1db, Mapped, "\u01dc"),
+    (0x1dc, Valid, ""),
+    (0x1de, Mapped, "\u01df"),
+    (0x1df, Valid, ""),
+    (0x1e0, Mapped, "\u01e1"),
+    (0x1e1, Valid, ""),
+    (0x1e2, Mapped, "\u01e3"),
+    (0x1e3, Valid, ""),
+    (0x1e4, Mapped, "\u01e5"),
+    (0x1e5, Valid, ""),
+    (0x1e6, Mapped, "\u01e7"),
+    (0x1e7, Valid, ""),
+    (0x1e8, Mapped, "\u01e9"),
+    (0x1e9, Valid, ""),
+    (0x1ea, Mapped, "\u01eb"),
+    (0x1eb, Valid, ""),
+    (0x1ec, Mapped, "\u01ed"),
+    (0x1ed, Valid, ""),
+    (0x1ee, Mapped, "\u01ef"),
+    (0x1ef, Valid, ""),
+    (0x1f1, Mapped, "dz"),
+    (0x1f4, Mapped, "\u01f5"),
+    (0x1f5, Valid, ""),
+    (0x1f6, Mapped, "\u0195"),
+    (0x1f7, Mapped, "\u01bf"),
+    (0x1f8, Mapped, "\u01f9"),
+    (0x1f9, Valid, ""),
+    (0x1fa, Mapped, "\u01fb"),
+    (0x1fb, Valid, ""),
+    (0x1fc, Mapped, "\u01fd"),
+    (0x1fd, Valid, ""),
+    (0x1fe, Mapped, "\u01ff"),
+    (0x1ff, Valid, ""),
+    (0x200, Mapped, "\u0201"),
+    (0x201, Valid, ""),
+    (0x202, Mapped, "\u0203"),
+    (0x203, Valid, ""),
+    (0x204, Mapped, "\u0205"),
+    (0x205, Valid, ""),
+    (0x206, Mapped, "\u0207"),
+    (0x207, Valid, ""),
+    (0x208, Mapped, "\u0209"),
+    (0x209, Valid, ""),
+    (0x20a, Mapped, "\u020b"),
+    (0x20b, Valid, ""),
+    (0x20c, Mapped, "\u020d"),
+    (0x20d, Valid, ""),
+    (0x20e, Mapped, "\u020f"),
+    (0x20f, Valid, ""),
+    (0x210, Mapped, "\u0211"),
+    (0x211, Valid, ""),
+    (0x212, Mapped, "\u0213"),
+    (0x213, Valid, ""),
+    (0x214, Mapped, "\u0215"),
+    (0x215, Valid, ""),
+    (0x216, Mapped, "\u0217"),
+    (0x217, Valid, ""),
+    (0x218, Mapped, "\u0219"),
+    (0x219, Valid, ""),
+    (0x21a, Mapped, "\u021b"),
+    (0x21b, Valid, ""),
+    (0x21c, Mapped, "\u021d"),
+    (0x21d, Valid, ""),
+    (0x21e, Mapped, "\u021f"),
+    (0x21f, Valid, ""),
+    (0x220, Mapped, "\u019e"),
+    (0x221, Valid, ""),
+    (0x222, Mapped, "\u0223"),
+    (0x223, Valid, ""),
+    (0x224, Mapped, "\u0225"),
+    (0x225, Valid, ""),
+    (0x226, Mapped, "\u0227"),
+    (0x227, Valid, ""),
+    (0x228, Mapped, "\u0229"),
+    (0x229, Valid, ""),
+    (0x22a, Mapped, "\u022b"),
+    (0x22b, Valid, ""),
+    (0x22c, Mapped, "\u022d"),
+    (0x22d, Valid, ""),
+    (0x22e, Mapped, "\u022f"),
+    (0x22f, Valid, ""),
+    (0x230, Mapped, "\u0231"),
+    (0x231, Valid, ""),
+    (0x232, Mapped, "\u0233"),
+    (0x233, Valid, ""),
+    (0x23a, Mapped, "\u2c65"),
+    (0x23b, Mapped, "\u023c"),
+    (0x23c, Valid, ""),
+    (0x23d, Mapped, "\u019a"),
+    (0x23e, Mapped, "\u2c66"),
+    (0x23f, Valid, ""),
+    (0x241, Mapped, "\u0242"),
+    (0x242, Valid, ""),
+    (0x243, Mapped, "\u0180"),
+    (0x244, Mapped, "\u0289"),
+    (0x245, Mapped, "\u028c"),
+    (0x246, Mapped, "\u0247"),
+    (0x247, Valid, ""),
+    (0x248, Mapped, "\u0249"),
+    (0x249, Valid, ""),
+    (0x24a, Mapped, "\u024b"),
+    (0x24b, Valid, ""),
+    (0x24c, Mapped, "\u024d"),
+    (0x24d, Valid, ""),
+    (0x24e, Mapped, "\u024f"),
+    (0x24f, Valid, ""),
+    (0x2b0, Mapped, "h"),
+    (0x2b1, Mapped, "\u0266"),
+    (0x2b2, Mapped, "j"),
+    (0x2b3, Mapped, "r"),
+    (0x2b4, Mapped, "\u0279"),
+    (0x2b5, Mapped, "\u027b"),
+    (0x2b6, Mapped, "\u0281"),
+    (0x2b7, Mapped, "w"),
+    (0x2b8, Mapped, "y"),
+    (0x2b9, Valid, ""),
+    (0x2d8, Mapped, " \u0306"),
+    (0x2d9, Mapped, " \u0307"),
+    (0x2da, Mapped, " \u030a"),
+    (0x2db, Mapped, " \u0328"),
+    (0x2dc, Mapped, " \u0303"),
+    (0x2dd, Mapped, " \u030b"),
+    (0x2de, Valid, ""),
+    (0x2e0, Mapped, "\u0263"),
+    (0x2e1, Mapped, "l"),
+    (0x2e2, Mapped, "s"),
+    (0x2e3, Mapped, "x"),
+    (0x2e4, Mapped, "\u0295"),
+    (0x2e5, Valid, ""),
+    (0x340, Mapped, "\u0300"),
+    (0x341, Mapped, "\u0301"),
+    (0x342, Valid, ""),
+    (0x343, Mapped, "\u0313"),
+    (0x344, Mapped, "\u0308\u0301"),
+    (0x345, Mapped, "\u03b9"),
+    (0x346, Valid, ""),
+    (0x34f, Ignored, ""),
+    (0x350, Valid, ""),
+    (0x370, Mapped, "\u0371"),
+    (0x371, Valid, ""),
+    (0x372, Mapped, "\u0373"),
+    (0x373, Valid, ""),
+    (0x374, Mapped, "\u02b9"),
+    (0x375, Valid, ""),
+    (0x376, Mapped, "\u0377"),
+    (0x377, Valid, ""),
+    (0x378, Disallowed, ""),
+    (0x37a, Mapped, " \u03b9"),
+    (0x37b, Valid, ""),
+    (0x37e, Mapped, ";"),
+    (0x37f, Mapped, "\u03f3"),
+    (0x380, Disallowed, ""),
+    (0x384, Mapped, " \u0301"),
+    (0x385, Mapped, " \u0308\u0301"),
+    (0x386, Mapped, "\u03ac"),
+    (0x387, Mapped, "\u00b7"),
+    (0x388, Mapped, "\u03ad"),
+    (0x389, Mapped, "\u03ae"),
+    (0x38a, Mapped, "\u03af"),
+    (0x38b, Disallowed, ""),
+    (0x38c, Mapped, "\u03cc"),
+    (0x38d, Disallowed, ""),
+    (0x38e, Mapped, "\u03cd"),
+    (0x38f, Mapped, "\u03ce"),
+    (0x390, Valid, ""),
+    (0x391, Mapped, "\u03b1"),
+    (0x392, Mapped, "\u03b2"),
+    (0x393, Mapped, "\u03b3"),
+    (0x394, Mapped, "\u03b4"),
+    (0x395, Mapped, "\u03b5"),
+    (0x396, Mapped, "\u03b6"),
+    (0x397, Mapped, "\u03b7"),
+    (0x398, Mapped, "\u03b8"),
+    (0x399, Mapped, "\u03b9"),
+    (0x39a, Mapped, "\u03ba"),
+    (0x39b, Mapped, "\u03bb"),
+    (0x39c, Mapped, "\u03bc"),
+    (0x39d, Mapped, "\u03bd"),
+    (0x39e, Mapped, "\u03be"),
+    (0x39f, Mapped, "\u03bf"),
+    (0x3a0, Mapped, "\u03c0"),
+    (0x3a1, Mapped, "\u03c1"),
+    (0x3a2, Disallowed, ""),
+    (0x3a3, Mapped, "\u03c3"),
+    (0x3a4, Mapped, "\u03c4"),
+    (0x3a5, Mapped, "\u03c5"),
+    (0x3a6, Mapped, "\u03c6"),
+    (0x3a7, Mapped, "\u03c7"),
+    (0x3a8, Mapped, "\u03c8"),
+    (0x3a9, Mapped, "\u03c9"),
+    (0x3aa, Mapped, "\u03ca"),
+    (0x3ab, Mapped, "\u03cb"),
+    (0x3ac, Valid, ""),
+    (0x3c2, Deviation, "\u03c3"),
+    (0x3c3, Valid, ""),
+    (0x3cf, Mapped, "\u03d7"),
+    (0x3d0, Mapped, "\u03b2"),
+    (0x3d1, Mapped, "\u03b8"),
+    (0x3d2, Mapped, "\u03c5"),
+    (0x3d3, Mapped, "\u03cd"),
+    (0x3d4, Mapped, "\u03cb"),
+    (0x3d5, Mapped, "\u03c6"),
+    (0x3d6, Mapped, "\u03c0"),
+    (0x3d7, Valid, ""),
+    (0x3d8, Mapped, "\u03d9"),
+    (0x3d9, Valid, ""),
+    (0x3da, Mapped, "\u03db"),
+    (0x3db, Valid, ""),
+    (0x3dc, Mapped, "\u03dd"),
+    (0x3dd, Valid, ""),
+    (0x3de, Mapped, "\u03df"),
+    (0x3df, Valid, ""),
+    (0x3e0, Mapped, "\u03e1"),
+    (0x3e1, Valid, ""),
+    (0x3e2, Mapped, "\u03e3"),
+    (0x3e3, Valid, ""),
+    (0x3e4, Mapped, "\u03e5"),
+    (0x3e5, Valid, ""),
+    (0x3e6, Mapped, "\u03e7"),
+    (0x3e7, Valid, ""),
+    (0x3e8, Mapped, "\u03e9"),
+    (0x3e9, Valid, ""),
+    (0x3ea, Mapped, "\u03eb"),
+    (0x3eb, Valid, ""),
+    (0x3ec, Mapped, "\u03ed"),
+    (0x3ed, Valid, ""),
+    (0x3ee, Mapped, "\u03ef"),
+    (0x3ef, Valid, ""),
+    (0x3f0, Mapped, "\u03ba"),
+    (0x3f1, Mapped, "\u03c1"),
+    (0x3f2, Mapped, "\u03c3"),
+    (0x3f3, Valid, ""),
+    (0x3f4, Mapped, "\u03b8"),
+    (0x3f5, Mapped, "\u03b5"),
+    (0x3f6, Valid, ""),
+    (0x3f7, Mapped, "\u03f8"),
+    (0x3f8, Valid, ""),
+    (0x3f9, Mapped, "\u03c3"),
+    (0x3fa, Mapped, "\u03fb"),
+    (0x3fb, Valid, ""),
+    (0x3fd, Mapped, "\u037b"),
+    (0x3fe, Mapped, "\u037c"),
+    (0x3ff, Mapped, "\u037d"),
+    (0x400, Mapped, "\u0450"),
+    (0x401, Mapped, "\u0451"),
+    (0x402, Mapped, "\u0452"),
+    (0x403, Mapped, "\u0453"),
+    (0x404, Mapped, "\u0454"),
+    (0x405, Mapped, "\u0455"),
+    (0x406, Mapped, "\u0456"),
+    (0x407, Mapped, "\u0457"),
+    (0x408, Mapped, "\u0458"),
+    (0x409, Mapped, "\u0459"),
+    (0x40a, Mapped, "\u045a"),
+    (0x40b, Mapped, "\u045b"),
+    (0x40c, Mapped, "\u045c"),
+    (0x40d, Mapped, "\u045d"),
+    (0x40e, Mapped, "\u045e"),
+    (0x40f, Mapped, "\u045f"),
+    (0x410, Mapped, "\u0430"),
+    (0x411, Mapped, "\u0431"),
+    (0x412, Mapped, "\u0432"),
+    (0x413, Mapped, "\u0433"),
+    (0x414, Mapped, "\u0434"),
+    (0x415, Mapped, "\u0435"),
+    (0x416, Mapped, "\u0436"),
+    (0x417, Mapped, "\u0437"),
+    (0x418, Mapped, "\u0438"),
+    (0x419, Mapped, "\u0439"),
+    (0x41a, Mapped, "\u043a"),
+    (0x41b, Mapped, "\u043b"),
+    (0x41c, Mapped, "\u043c"),
+    (0x41d, Mapped, "\u043d"),
+    (0x41e, Mapped, "\u043e"),
+    (0x41f, Mapped, "\u043f"),
+    (0x420, Mapped, "\u0440"),
+    (0x421, Mapped, "\u0441"),
+    (0x422, Mapped, "\u0442"),
+    (0x423, Mapped, "\u0443"),
+    (0x424, Mapped, "\u0444"),
+    (0x425, Mapped, "\u0445"),
+    (0x426, Mapped, "\u0446"),
+    (0x427, Mapped, "\u0447"),
+    (0x428, Mapped, "\u0448"),
+    (0x429, Mapped, "\u0449"),
+    (0x42a, Mapped, "\u044a"),
+    (0x42b, Mapped, "\u044b"),
+    (0x42c, Mapped, "\u044c"),
+    (0x42d, Mapped, "\u044d"),
+    (0x42e, Mapped, "\u044e"),
+    (0x42f, Mapped, "\u044f"),
+    (0x430, Valid, ""),
+    (0x460, Mapped, "\u0461"),
+    (0x461, Valid, ""),
+    (0x462, Mapped, "\u0463"),
+    (0x463, Valid, ""),
+    (0x464, Mapped, "\u0465"),
+    (0x465, Valid, ""),
+    (0x466, Mapped, "\u0467"),
+    (0x467, Valid, ""),
+    (0x468, Mapped, "\u0469"),
+    (0x469, Valid, ""),
+    (0x46a, Mapped, "\u046b"),
+    (0x46b, Valid, ""),
+    (0x46c, Mapped, "\u046d"),
+    (0x46d, Valid, ""),
+    (0x46e, Mapped, "\u046f"),
+    (0x46f, Valid, ""),
+    (0x470, Mapped, "\u0471"),
+    (0x471, Valid, ""),
+    (0x472, Mapped, "\u0473"),
+    (0x473, Valid, ""),
+    (0x474, Mapped, "\u0475"),
+    (0x475, Valid, ""),
+    (0x476, Mapped, "\u0477"),
+    (0x477, Valid, ""),
+    (0x478, Mapped, "\u0479"),
+    (0x479, Valid, ""),
+    (0x47a, Mapped, "\u047b"),
+    (0x47b, Valid, ""),
+    (0x47c, Mapped, "\u047d"),
+    (0x47d, Valid, ""),
+    (0x47e, Mapped, "\u047f"),
+    (0x47f, Valid, ""),
+    (0x480, Mapped, "\u0481"),
+    (0x481, Valid, ""),
+    (0x48a, Mapped, "\u048b"),
+    (0x48b, Valid, ""),
+    (0x48c, Mapped, "\u048d"),
+    (0x48d, Valid, ""),
+    (0x48e, Mapped, "\u048f"),
+    (0x48f, Valid, ""),
+    (0x490, Mapped, "\u0491"),
+    (0x491, Valid, ""),
+    (0x492, Mapped, "\u0493"),
+    (0x493, Valid, ""),
+    (0x494, Mapped, "\u0495"),
+    (0x495, Valid, ""),
+    (0x496, Mapped, "\u0497"),
+    (0x497, Valid, ""),
+    (0x498, Mapped, "\u0499"),
+    (0x499, Valid, ""),
+    (0x49a, Mapped, "\u049b"),
+    (0x49b, Valid, ""),
+    (0x49c, Mapped, "\u049d"),
+    (0x49d, Valid, ""),
+    (0x49e, Mapped, "\u049f"),
+    (0x49f, Valid, ""),
+    (0x4a0, Mapped, "\u04a1"),
+    (0x4a1, Valid, ""),
+    (0x4a2, Mapped, "\u04a3"),
+    (0x4a3, Valid, ""),
+    (0x4a4, Mapped, "\u04a5"),
+    (0x4a5, Valid, ""),
+    (0x4a6, Mapped, "\u04a7"),
+    (0x4a7, Valid, ""),
+    (0x4a8, Mapped, "\u04a9"),
+    (0x4a9, Valid, ""),
+    (0x4aa, Mapped, "\u04ab"),
+    (0x4ab, Valid, ""),
+    (0x4ac, Mapped, "\u04ad"),
+    (0x4ad, Valid, ""),
+    (0x4ae, Mapped, "\u04af"),
+    (0x4af, Valid, ""),
+    (0x4b0, Mapped, "\u04b1"),
+    (0x4b1, Valid, ""),
+    (0x4b2, Mapped, "\u04b3"),
+    (0x4b3, Valid, ""),
+    (0x4b4, Mapped, "\u04b5"),
+    (0x4b5, Valid, ""),
+    (0x4b6, Mapped, "\u04b7"),
+    (0x4b7, Valid, ""),
+    (0x4b8, Mapped, "\u04b9"),
+    (0x4b9, Valid, ""),
+    (0x4ba, Mapped, "\u04bb"),
+    (0x4bb, Valid, ""),
+    (0x4bc, Mapped, "\u04bd"),
+    (0x4bd, Valid, ""),
+    (0x4be, Mapped, "\u04bf"),
+    (0x4bf, Valid, ""),
+    (0x4c0, Mapped, "\u04cf"),
+    (0x4c1, Mapped, "\u04c2"),
+    (0x4c2, Valid, ""),
+    (0x4c3, Mapped, "\u04c4"),
+    (0x4c4, Valid, ""),
+    (0x4c5, Mapped, "\u04c6"),
+    (0x4c6, Valid, ""),
+    (0x4c7, Mapped, "\u04c8"),
+    (0x4c8, Valid, ""),
+    (0x4c9, Mapped, "\u04ca"),
+    (0x4ca, Valid, ""),
+    (0x4cb, Mapped, "\u04cc"),
+    (0x4cc, Valid, ""),
+    (0x4cd, Mapped, "\u04ce"),
+    (0x4ce, Valid, ""),
+    (0x4d0, Mapped, "\u04d1"),
+    (0x4d1, Valid, ""),
+    (0x4d2, Mapped, "\u04d3"),
+    (0x4d3, Valid, ""),
+    (0x4d4, Mapped, "\u04d5"),
+    (0x4d5, Valid, ""),
+    (0x4d6, Mapped, "\u04d7"),
+    (0x4d7, Valid, ""),
+    (0x4d8, Mapped, "\u04d9"),
+    (0x4d9, Valid, ""),
+    (0x4da, Mapped, "\u04db"),
+    (0x4db, Valid, ""),
+    (0x4dc, Mapped, "\u04dd"),
+    (0x4dd, Valid, ""),
+    (0x4de, Mapped, "\u04df"),
+    (0x4df, Valid, ""),
+    (0x4e0, Mapped, "\u04e1"),
+    (0x4e1, Valid, ""),
+    (0x4e2, Mapped, "\u04e3"),
+    (0x4e3, Valid, ""),
+    (0x4e4, Mapped, "\u04e5"),
+    (0x4e5, Valid, ""),
+    (0x4e6, Mapped, "\u04e7"),
+    (0x4e7, Valid, ""),
+    (0x4e8, Mapped, "\u04e9"),
+    (0x4e9, Valid, ""),
+    (0x4ea, Mapped, "\u04eb"),
+    (0x4eb, Valid, ""),
+    (0x4ec, Mapped, "\u04ed"),
+    (0x4ed, Valid, ""),
+    (0x4ee, Mapped, "\u04ef"),
+    (0x4ef, Valid, ""),
+    (0x4f0, Mapped, "\u04f1"),
+    (0x4f1, Valid, ""),
+    (0x4f2, Mapped, "\u04f3"),
+    (0x4f3, Valid, ""),
+    (0x4f4, Mapped, "\u04f5"),
+    (0x4f5, Valid, ""),
+    (0x4f6, Mapped, "\u04f7"),
+    (0x4f7, Valid, ""),
+    (0x4f8, Mapped, "\u04f9"),
+    (0x4f9, Valid, ""),
+    (0x4fa, Mapped, "\u04fb"),
+    (0x4fb, Valid, ""),
+    (0x4fc, Mapped, "\u04fd"),
+    (0x4fd, Valid, ""),
+    (0x4fe, Mapped, "\u04ff"),
+    (0x4ff, Valid, ""),
+    (0x500, Mapped, "\u0501"),
+    (0x501, Valid, ""),
+    (0x502, Mapped, "\u0503"),
+    (0x503, Valid, ""),
+    (0x504, Mapped, "\u0505"),
+    (0x505, Valid, ""),
+    (0x506, Mapped, "\u0507"),
+    (0x507, Valid, ""),
+    (0x508, Mapped, "\u0509"),
+    (0x509, Valid, ""),
+    (0x50a, Mapped, "\u050b"),
+    (0x50b, Valid, ""),
+    (0x50c, Mapped, "\u050d"),
+    (0x50d, Valid, ""),
+    (0x50e, Mapped, "\u050f"),
+    (0x50f, Valid, ""),
+    (0x510, Mapped, "\u0511"),
+    (0x511, Valid, ""),
+    (0x512, Mapped, "\u0513"),
+    (0x513, Valid, ""),
+    (0x514, Mapped, "\u0515"),
+    (0x515, Valid, ""),
+    (0x516, Mapped, "\u0517"),
+    (0x517, Valid, ""),
+    (0x518, Mapped, "\u0519"),
+    (0x519, Valid, ""),
+    (0x51a, Mapped, "\u051b"),
+    (0x51b, Valid, ""),
+    (0x51c, Mapped, "\u051d"),
+    (0x51d, Valid, ""),
+    (0x51e, Mapped, "\u051f"),
+    (0x51f, Valid, ""),
+    (0x520, Mapped, "\u0521"),
+    (0x521, Valid, ""),
+    (0x522, Mapped, "\u0523"),
+    (0x523, Valid, ""),
+    (0x524, Mapped, "\u0525"),
+    (0x525, Valid, ""),
+    (0x526, Mapped, "\u0527"),
+    (0x527, Valid, ""),
+    (0x528, Mapped, "\u0529"),
+    (0x529, Valid, ""),
+    (0x52a, Mapped, "\u052b"),
+    (0x52b, Valid, ""),
+    (0x52c, Mapped, "\u052d"),
+    (0x52d, Valid, ""),
+    (0x52e, Mapped, "\u052f"),
+    (0x52f, Valid, ""),
+    (0x530, Disallowed, ""),
+    (0x531, Mapped, "\u0561"),
+    (0x532, Mapped, "\u0562"),
+    (0x533, Mapped, "\u0563"),
+    (0x534, Mapped, "\u0564"),
+    (0x535, Mapped, "\u0565"),
+    (0x536, Mapped, "\u0566"),
+    (0x537, Mapped, "\u0567"),
+    (0x538, Mapped, "\u0568"),
+    (0x539, Mapped, "\u0569"),
+    (0x53a, Mapped, "\u056a"),
+    (0x53b, Mapped, "\u056b"),
+    (0x53c, Mapped, "\u056c"),
+    (0x53d, Mapped, "\u056d"),
+    (0x53e, Mapped, "\u056e"),
+    (0x53f, Mapped, "\u056f"),
+    (0x540, Mapped, "\u0570"),
+    (0x541, Mapped, "\u0571"),
+    (0x542, Mapped, "\u0572"),
+    (0x543, Mapped, "\u0573"),
+    (0x544, Mapped, "\u0574"),
+    (0x545, Mapped, "\u0575"),
+    (0x546, Mapped, "\u0576"),
+    (0x547, Mapped, "\u0577"),
+    (0x548, Mapped, "\u0578"),
+    (0x549, Mapped, "\u0579"),
+    (0x54a, Mapped, "\u057a"),
+    (0x54b, Mapped, "\u057b"),
+    (0x54c, Mapped, "\u057c"),
+    (0x54d, Mapped, "\u057d"),
+    (0x54e, Mapped, "\u057e"),
+    (0x54f, Mapped, "\u057f"),
+    (0x550, Mapped, "\u0580"),
+    (0x551, Mapped, "\u0581"),
+    (0x552, Mapped, "\u0582"),
+    (0x553, Mapped, "\u0583"),
+    (0x554, Mapped, "\u0584"),
+    (0x555, Mapped, "\u0585"),
+    (0x556, Mapped, "\u0586"),
+    (0x557, Disallowed, ""),
+    (0x559, Valid, ""),
+    (0x587, Mapped, "\u0565\u0582"),
+    (0x588, Valid, ""),
+    (0x58b, Disallowed, ""),
+    (0x58d, Valid, ""),
+    (0x590, Disallowed, ""),
+    (0x591, Valid, ""),
+    (0x5c8, Disallowed, ""),
+    (0x5d0, Valid, ""),
+    (0x5eb, Disallowed, ""),
+    (0x5ef, Valid, ""),
+    (0x5f5, Disallowed, ""),
+    (0x606, Valid, ""),
+    (0x61c, Disallowed, ""),
+    (0x61d, Valid, ""),
+    (0x675, Mapped, "\u0627\u0674"),
+    (0x676, Mapped, "\u0648\u0674"),
+    (0x677, Mapped, "\u06c7\u0674"),
+    (0x678, Mapped, "\u064a\u0674"),
+    (0x679, Valid, ""),
+    (0x6dd, Disallowed, ""),
+    (0x6de, Valid, ""),
+    (0x70e, Disallowed, ""),
+    (0x710, Valid, ""),
+    (0x74b, Disallowed, ""),
+    (0x74d, Valid, ""),
+    (0x7b2, Disallowed, ""),
+    (0x7c0, Valid, ""),
+    (0x7fb, Disallowed, ""),
+    (0x7fd, Valid, ""),
+    (0x82e, Disallowed, ""),
+    (0x830, Valid, ""),
+    (0x83f, Disallowed, ""),
+    (0x840, Valid, ""),
+    (0x85c, Disallowed, ""),
+    (0x85e, Valid, ""),
+    (0x85f, Disallowed, ""),
+    (0x860, Valid, ""),
+    (0x86b, Disallowed, ""),
+    (0x870, Valid, ""),
+    (0x890, Disallowed, ""),
+    (0x897, Valid, ""),
+    (0x8e2, Disallowed, ""),
+    (0x8e3, Valid, ""),
+    (0x958, Mapped, "\u0915\u093c"),
+    (0x959, Mapped, "\u0916\u093c"),
+    (0x95a, Mapped, "\u0917\u093c"),
+    (0x95b, Mapped, "\u091c\u093c"),
+    (0x95c, Mapped, "\u0921\u093c"),
+    (0x95d, Mapped, "\u0922\u093c"),
+    (0x95e, Mapped, "\u092b\u093c"),
+    (0x95f, Mapped, "\u092f\u093c"),
+    (0x960, Valid, ""),
+    (0x984, Disallowed, ""),
+    (0x985, Valid, ""),
+    (0x98d, Disallowed, ""),
+    (0x98f, Valid, ""),
+    (0x991, Disallowed, ""),
+    (0x993, Valid, ""),
+    (0x9a9, Disallowed, ""),
+    (0x9aa, Valid, ""),
+    (0x9b1, Disallowed, ""),
+    (0x9b2, Valid, ""),
+    (0x9b3, Disallowed, ""),
+    (0x9b6, Valid, ""),
+    (0x9ba, Disallowed, ""),
+    (0x9bc, Valid, ""),
+    (0x9c5, Disallowed, ""),
+    (0x9c7, Valid, ""),
+    (0x9c9, Disallowed, ""),
+    (0x9cb, Valid, ""),
+    (0x9cf, Disallowed, ""),
+    (0x9d7, Valid, ""),
+    (0x9d8, Disallowed, ""),
+    (0x9dc, Mapped, "\u09a1\u09bc"),
+    (0x9dd, Mapped, "\u09a2\u09bc"),
+    (0x9de, Disallowed, ""),
+    (0x9df, Mapped, "\u09af\u09bc"),
+    (0x9e0, Valid, ""),
+    (0x9e4, Disallowed, ""),
+    (0x9e6, Valid, ""),
+    (0x9ff, Disallowed, ""),
+    (0xa01, Valid, ""),
+    (0xa04, Disallowed, ""),
+    (0xa05, Valid, ""),
+    (0xa0b, Disallowed, ""),
+    (0xa0f, Valid, ""),
+    (0xa11, Disallowed, ""),
+    (0xa13, Valid, ""),
+    (0xa29, Disallowed, ""),
+    (0xa2a, Valid, ""),
+    (0xa31, Disallowed, ""),
+    (0xa32, Valid, ""),
+    (0xa33, Mapped, "\u0a32\u0a3c"),
+    (0xa34, Disallowed, ""),
+    (0xa35, Valid, ""),
+    (0xa36, Mapped, "\u0a38\u0a3c"),
+    (0xa37, Disallowed, ""),
+    (0xa38, Valid, ""),
+    (0xa3a, Disallowed, ""),
+    (0xa3c, Valid, ""),
+    (0xa3d, Disallowed, ""),
+    (0xa3e, Valid, ""),
+    (0xa43, Disallowed, ""),
+    (0xa47, Valid, ""),
+    (0xa49, Disallowed, ""),
+    (0xa4b, Valid, ""),
+    (0xa4e, Disallowed, ""),
+    (0xa51, Valid, ""),
+    (0xa52, Disallowed, ""),
+    (0xa59, Mapped, "\u0a16\u0a3c"),
+    (0xa5a, Mapped, "\u0a17\u0a3c"),
+    (0xa5b, Mapped, "\u0a1c\u0a3c"),
+    (0xa5c, Valid, ""),
+    (0xa5d, Disallowed, ""),
+    (0xa5e, Mapped, "\u0a2b\u0a3c"),
+    (0xa5f, Disallowed, ""),
+    (0xa66, Valid, ""),
+    (0xa77, Disallowed, ""),
+    (0xa81, Valid, ""),
+    (0xa84, Disallowed, ""),
+    (0xa85, Valid, ""),
+    (0xa8e, Disallowed, ""),
+    (0xa8f, Valid, ""),
+    (0xa92, Disallowed, ""),
+    (0xa93, Valid, ""),
+    (0xaa9, Disallowed, ""),
+    (0xaaa, Valid, ""),
+    (0xab1, Disallowed, ""),
+    (0xab2, Valid, ""),
+    (0xab4, Disallowed, ""),
+    (0xab5, Valid, ""),
+    (0xaba, Disallowed, ""),
+    (0xabc, Valid, ""),
+    (0xac6, Disallowed, ""),
+    (0xac7, Valid, ""),
+    (0xaca, Disallowed, ""),
+    (0xacb, Valid, ""),
+    (0xace, Disallowed, ""),
+    (0xad0, Valid, ""),
+    (0xad1, Disallowed, ""),
+    (0xae0, Valid, ""),
+    (0xae4, Disallowed, ""),
+    (0xae6, Valid, ""),
+    (0xaf2, Disallowed, ""),
+    (0xaf9, Valid, ""),
+    (0xb00, Disallowed, ""),
+    (0xb01, Valid, ""),
+    (0xb04, Disallowed, ""),
+    (0xb05, Valid, ""),
+    (0xb0d, Disallowed, ""),
+    (0xb0f, Valid, ""),
+    (0xb11, Disallowed, ""),
+    (0xb13, Valid, ""),
+    (0xb29, Disallowed, ""),
+    (0xb2a, Valid, ""),
+    (0xb31, Disallowed, ""),
+    (0xb32, Valid, ""),
+    (0xb34, Disallowed, ""),
+    (0xb35, Valid, ""),
+    (0xb3a, Disallowed, ""),
+    (0xb3c, Valid, ""),
+    (0xb45, Disallowed, ""),
+    (0xb47, Valid, ""),
+    (0xb49, Disallowed, ""),
+    (0xb4b, Valid, ""),
+    (0xb4e, Disallowed, ""),
+    (0xb55, Valid, ""),
+    (0xb58, Disallowed, ""),
+    (0xb5c, Mapped, "\u0b21\u0b3c"),
+    (0xb5d, Mapped, "\u0b22\u0b3c"),
+    (0xb5e, Disallowed, ""),
+    (0xb5f, Valid, ""),
+    (0xb64, Disallowed, ""),
+    (0xb66, Valid, ""),
+    (0xb78, Disallowed, ""),
+    (0xb82, Valid, ""),
+    (0xb84, Disallowed, ""),
+    (0xb85, Valid, ""),
+    (0xb8b, Disallowed, ""),
+    (0xb8e, Valid, ""),
+    (0xb91, Disallowed, ""),
+    (0xb92, Valid, ""),
+    (0xb96, Disallowed, ""),
+    (0xb99, Valid, ""),
+    (0xb9b, Disallowed, ""),
+    (0xb9c, Valid, ""),
+    (0xb9d, Disallowed, ""),
+    (0xb9e, Valid, ""),
+    (0xba0, Disallowed, ""),
+    (0xba3, Valid, ""),
+    (0xba5, Disallowed, ""),
+    (0xba8, Valid, ""),
+    (0xbab, Disallowed, ""),
+    (0xbae, Valid, ""),
+    (0xbba, Disallowed, ""),
+    (0xbbe, Valid, ""),
+    (0xbc3, Disallowed, ""),
+    (0xbc6, Valid, ""),
+    (0xbc9, Disallowed, ""),
+    (0xbca, Valid, ""),
+    (0xbce, Disallowed, ""),
+    (0xbd0, Valid, ""),
+    (0xbd1, Disallowed, ""),
+    (0xbd7, Valid, ""),
+    (0xbd8, Disallowed, ""),
+    (0xbe6, Valid, ""),
+    (0xbfb, Disallowed, ""),
+    (0xc00, Valid, ""),
+    (0xc0d, Disallowed, ""),
+    (0xc0e, Valid, ""),
+    (0xc11, Disallowed, ""),
+    (0xc12, Valid, ""),
+    (0xc29, Disallowed, ""),
+    (0xc2a, Valid, ""),
+    (0xc3a, Disallowed, ""),
+    (0xc3c, Valid, ""),
+    (0xc45, Disallowed, ""),
+    (0xc46, Valid, ""),
+    (0xc49, Disallowed, ""),
+    (0xc4a, Valid, ""),
+    (0xc4e, Disallowed, ""),
+    (0xc55, Valid, ""),
+    (0xc57, Disallowed, ""),
+    (0xc58, Valid, ""),
+    (0xc5b, Disallowed, ""),
+    (0xc5c, Valid, ""),
+    (0xc5e, Disallowed, ""),
+    (0xc60, Valid, ""),
+    (0xc64, Disallowed, ""),
+    (0xc66, Valid, ""),
+    (0xc70, Disallowed, ""),
+    (0xc77, Valid, ""),
+    (0xc8d, Disallowed, ""),
+    (0xc8e, Valid, ""),
+    (0xc91, Disallowed, ""),
+    (0xc92, Valid, ""),
+    (0xca9, Disallowed, ""),
+    (0xcaa, Valid, ""),
+    (0xcb4, Disallowed, ""),
+    (0xcb5, Valid, ""),
+    (0xcba, Disallowed, ""),
+    (0xcbc, Valid, ""),
+    (0xcc5, Disallowed, ""),
+    (0xcc6, Valid, ""),
+    (0xcc9, Disallowed, ""),
+    (0xcca, Valid, ""),
+    (0xcce, Disallowed, ""),
+    (0xcd5, Valid, ""),
+    (0xcd7, Disallowed, ""),
+    (0xcdc, Valid, ""),
+    (0xcdf, Disallowed, ""),
+    (0xce0, Valid, ""),
+    (0xce4, Disallowed, ""),
+    (0xce6, Valid, ""),
+    (0xcf0, Disallowed, ""),
+    (0xcf1, Valid, ""),
+    (0xcf4, Disallowed, ""),
+    (0xd00, Valid, ""),
+    (0xd0d, Disallowed, ""),
+    (0xd0e, Valid, ""),
+    (0xd11, Disallowed, ""),
+    (0xd12, Valid, ""),
+    (0xd45, Disallowed, ""),
+    (0xd46, Valid, ""),
+    (0xd49, Disallowed, ""),
+    (0xd4a, Valid, ""),
+    (0xd50, Disallowed, ""),
+    (0xd54, Valid, ""),
+    (0xd64, Disallowed, ""),
+    (0xd66, Valid, ""),
+    (0xd80, Disallowed, ""),
+    (0xd81, Valid, ""),
+    (0xd84, Disallowed, ""),
+    (0xd85, Valid, ""),
+    (0xd97, Disallowed, ""),
+    (0xd9a, Valid, ""),
+    (0xdb2, Disallowed, ""),
+    (0xdb3, Valid, ""),
+    (0xdbc, Disallowed, ""),
+    (0xdbd, Valid, ""),
+    (0xdbe, Disallowed, ""),
+    (0xdc0, Valid, ""),
+    (0xdc7, Disallowed, ""),
+    (0xdca, Valid, ""),
+    (0xdcb, Disallowed, ""),
+    (0xdcf, Valid, ""),
+    (0xdd5, Disallowed, ""),
+    (0xdd6, Valid, ""),
+    (0xdd7, Disallowed, ""),
+    (0xdd8, Valid, ""),
+    (0xde0, Disallowed, ""),
+    (0xde6, Valid, ""),
+    (0xdf0, Disallowed, ""),
+    (0xdf2, Valid, ""),
+    (0xdf5, Disallowed, ""),
+    (0xe01, Valid, ""),
+    (0xe33, Mapped, "\u0e4d\u0e32"),
+    (0xe34, Valid, ""),
+    (0xe3b, Disallowed, ""),
+    (0xe3f, Valid, ""),
+    (0xe5c, Disallowed, ""),
+    (0xe81, Valid, ""),
+    (0xe83, Disallowed, ""),
+    (0xe84, Valid, ""),
+    (0xe85, Disallowed, ""),
+    (0xe86, Valid, ""),
+    (0xe8b, Disallowed, ""),
+    (0xe8c, Valid, ""),
+    (0xea4, Disallowed, ""),
+    (0xea5, Valid, ""),
+    (0xea6, Disallowed, ""),
+    (0xea7, Valid, ""),
+    (0xeb3, Mapped, "\u0ecd\u0eb2"),
+    (0xeb4, Valid, ""),
+    (0xebe, Disallowed, ""),
+    (0xec0, Valid, ""),
+    (0xec5, Disallowed, ""),
+    (0xec6, Valid, ""),
+    (0xec7, Disallowed, ""),
+    (0xec8, Valid, ""),
+    (0xecf, Disallowed, ""),
+    (0xed0, Valid, ""),
+    (0xeda, Disallowed, ""),
+    (0xedc, Mapped, "\u0eab\u0e99"),
+    (0xedd, Mapped, "\u0eab\u0ea1"),
+    (0xede, Valid, ""),
+    (0xee0, Disallowed, ""),
+    (0xf00, Valid, ""),
+    (0xf0c, Mapped, "\u0f0b"),
+    (0xf0d, Valid, ""),
+    (0xf43, Mapped, "\u0f42\u0fb7"),
+    (0xf44, Valid, ""),
+    (0xf48, Disallowed, ""),
+    (0xf49, Valid, ""),
+    (0xf4d, Mapped, "\u0f4c\u0fb7"),
+    (0xf4e, Valid, ""),
+    (0xf52, Mapped, "\u0f51\u0fb7"),
+    (0xf53, Valid, ""),
+    (0xf57, Mapped, "\u0f56\u0fb7"),
+    (0xf58, Valid, ""),
+    (0xf5c, Mapped, "\u0f5b\u0fb7"),
+    (0xf5d, Valid, ""),
+    (0xf69, Mapped, "\u0f40\u0fb5"),
+    (0xf6a, Valid, ""),
+    (0xf6d, Disallowed, ""),
+    (0xf71, Valid, ""),
+    (0xf73, Mapped, "\u0f71\u0f72"),
+    (0xf74, Valid, ""),
+    (0xf75, Mapped, "\u0f71\u0f74"),
+    (0xf76, Mapped, "\u0fb2\u0f80"),
+    (0xf77, Mapped, "\u0fb2\u0f71\u0f80"),
+    (0xf78, Mapped, "\u0fb3\u0f80"),
+    (0xf79, Mapped, "\u0fb3\u0f71\u0f80"),
+    (0xf7a, Valid, ""),
+    (0xf81, Mapped, "\u0f71\u0f80"),
+    (0xf82, Valid, ""),
+    (0xf93, Mapped, "\u0f92\u0fb7"),
+    (0xf94, Valid, ""),
+    (0xf98, Disallowed, ""),
+    (0xf99, Valid, ""),
+    (0xf9d, Mapped, "\u0f9c\u0fb7"),
+    (0xf9e, Valid, ""),
+    (0xfa2, Mapped, "\u0fa1\u0fb7"),
+    (0xfa3, Valid, ""),
+    (0xfa7, Mapped, "\u0fa6\u0fb7"),
+    (0xfa8, Valid, ""),
+    (0xfac, Mapped, "\u0fab\u0fb7"),
+    (0xfad, Valid, ""),
+    (0xfb9, Mapped, "\u0f90\u0fb5"),
+    (0xfba, Valid, ""),
+    (0xfbd, Disallowed, ""),
+    (0xfbe, Valid, ""),
+    (0xfcd, Disallowed, ""),
+    (0xfce, Valid, ""),
+    (0xfdb, Disallowed, ""),
+    (0x1000, Valid, ""),
+    (0x10a0, Mapped, "\u2d00"),
+    (0x10a1, Mapped, "\u2d01"),
+    (0x10a2, Mapped, "\u2d02"),
+    (0x10a3, Mapped, "\u2d03"),
+    (0x10a4, Mapped, "\u2d04"),
+    (0x10a5, Mapped, "\u2d05"),
+    (0x10a6, Mapped, "\u2d06"),
+    (0x10a7, Mapped, "\u2d07"),
+    (0x10a8, Mapped, "\u2d08"),
+    (0x10a9, Mapped, "\u2d09"),
+    (0x10aa, Mapped, "\u2d0a"),
+    (0x10ab, Mapped, "\u2d0b"),
+    (0x10ac, Mapped, "\u2d0c"),
+    (0x10ad, Mapped, "\u2d0d"),
+    (0x10ae, Mapped, "\u2d0e"),
+    (0x10af, Mapped, "\u2d0f"),
+    (0x10b0, Mapped, "\u2d10"),
+    (0x10b1, Mapped, "\u2d11"),
+    (0x10b2, Mapped, "\u2d12"),
+    (0x10b3, Mapped, "\u2d13"),
+    (0x10b4, Mapped, "\u2d14"),
+    (0x10b5, Mapped, "\u2d15"),
+    (0x10b6, Mapped, "\u2d16"),
+    (0x10b7, Mapped, "\u2d17"),
+    (0x10b8, Mapped, "\u2d18"),
+    (0x10b9, Mapped, "\u2d19"),
+    (0x10ba, Mapped, "\u2d1a"),
+    (0x10bb, Mapped, "\u2d1b"),
+    (0x10bc, Mapped, "\u2d1c"),
+    (0x10bd, Mapped, "\u2d1d"),
+    (0x10be, Mapped, "\u2d1e"),
+    (0x10bf, Mapped, "\u2d1f"),
+    (0x10c0, Mapped, "\u2d20"),
+    (0x10c1, Mapped, "\u2d21"),
+    (0x10c2, Mapped, "\u2d22"),
+    (0x10c3, Mapped, "\u2d23"),
+    (0x10c4, Mapped, "\u2d24"),
+    (0x10c5, Mapped, "\u2d25"),
+    (0x10c6, Disallowed, ""),
+    (0x10c7, Mapped, "\u2d27"),
+    (0x10c8, Disallowed, ""),
+    (0x10cd, Mapped, "\u2d2d"),
+    (0x10ce, Disallowed, ""),
+    (0x10d0, Valid, ""),
+    (0x10fc, Mapped, "\u10dc"),
+    (0x10fd, Valid, ""),
+    (0x115f, Ignored, ""),
+    (0x1161, Valid, ""),
+    (0x1249, Disallowed, ""),
+    (0x124a, Valid, ""),
+    (0x124e, Disallowed, ""),
+    (0x1250, Valid, ""),
+    (0x1257, Disallowed, ""),
+    (0x1258, Valid, ""),
+    (0x1259, Disallowed, ""),
+    (0x125a, Valid, ""),
+    (0x125e, Disallowed, ""),
+    (0x1260, Valid, ""),
+    (0x1289, Disallowed, ""),
+    (0x128a, Valid, ""),
+    (0x128e, Disallowed, ""),
+    (0x1290, Valid, ""),
+    (0x12b1, Disallowed, ""),
+    (0x12b2, Valid, ""),
+    (0x12b6, Disallowed, ""),
+    (0x12b8, Valid, ""),
+    (0x12bf, Disallowed, ""),
+    (0x12c0, Valid, ""),
+    (0x12c1, Disallowed, ""),
+    (0x12c2, Valid, ""),
+    (0x12c6, Disallowed, ""),
+    (0x12c8, Valid, ""),
+    (0x12d7, Disallowed, ""),
+    (0x12d8, Valid, ""),
+    (0x1311, Disallowed, ""),
+    (0x1312, Valid, ""),
+    (0x1316, Disallowed, ""),
+    (0x1318, Valid, ""),
+    (0x135b, Disallowed, ""),
+    (0x135d, Valid, ""),
+    (0x137d, Disallowed, ""),
+    (0x1380, Valid, ""),
+    (0x139a, Disallowed, ""),
+    (0x13a0, Valid, ""),
+    (0x13f6, Disallowed, ""),
+    (0x13f8, Mapped, "\u13f0"),
+    (0x13f9, Mapped, "\u13f1"),
+    (0x13fa, Mapped, "\u13f2"),
+    (0x13fb, Mapped, "\u13f3"),
+    (0x13fc, Mapped, "\u13f4"),
+    (0x13fd, Mapped, "\u13f5"),
+    (0x13fe, Disallowed, ""),
+    (0x1400, Valid, ""),
+    (0x1680, Disallowed, ""),
+    (0x1681, Valid, ""),
+    (0x169d, Disallowed, ""),
+    (0x16a0, Valid, ""),
+    (0x16f9, Disallowed, ""),
+    (0x1700, Valid, ""),
+    (0x1716, Disallowed, ""),
+    (0x171f, Valid, ""),
+    (0x1737, Disallowed, ""),
+    (0x1740, Valid, ""),
+    (0x1754, Disallowed, ""),
+    (0x1760, Valid, ""),
+    (0x176d, Disallowed, ""),
+    (0x176e, Valid, ""),
+    (0x1771, Disallowed, ""),
+    (0x1772, Valid, ""),
+    (0x1774, Disallowed, ""),
+    (0x1780, Valid, ""),
+    (0x17b4, Ignored, ""),
+    (0x17b6, Valid, ""),
+    (0x17de, Disallowed, ""),
+    (0x17e0, Valid, ""),
+    (0x17ea, Disallowed, ""),
+    (0x17f0, Valid, ""),
+    (0x17fa, Disallowed, ""),
+    (0x1800, Valid, ""),
+    (0x180b, Ignored, ""),
+    (0x1810, Valid, ""),
+    (0x181a, Disallowed, ""),
+    (0x1820, Valid, ""),
+    (0x1879, Disallowed, ""),
+    (0x1880, Valid, ""),
+    (0x18ab, Disallowed, ""),
+    (0x18b0, Valid, ""),
+    (0x18f6, Disallowed, ""),
+    (0x1900, Valid, ""),
+    (0x191f, Disallowed, ""),
+    (0x1920, Valid, ""),
+    (0x192c, Disallowed, ""),
+    (0x1930, Valid, ""),
+    (0x193c, Disallowed, ""),
+    (0x1940, Valid, ""),
+    (0x1941, Disallowed, ""),
+    (0x1944, Valid, ""),
+    (0x196e, Disallowed, ""),
+    (0x1970, Valid, ""),
+    (0x1975, Disallowed, ""),
+    (0x1980, Valid, ""),
+    (0x19ac, Disallowed, ""),
+    (0x19b0, Valid, ""),
+    (0x19ca, Disallowed, ""),
+    (0x19d0, Valid, ""),
+    (0x19db, Disallowed, ""),
+    (0x19de, Valid, ""),
+    (0x1a1c, Disallowed, ""),
+    (0x1a1e, Valid, ""),
+    (0x1a5f, Disallowed, ""),
+    (0x1a60, Valid, ""),
+    (0x1a7d, Disallowed, ""),
+    (0x1a7f, Valid, ""),
+    (0x1a8a, Disallowed, ""),
+    (0x1a90, Valid, ""),
+    (0x1a9a, Disallowed, ""),
+    (0x1aa0, Valid, ""),
+    (0x1aae, Disallowed, ""),
+    (0x1ab0, Valid, ""),
+    (0x1ade, Disallowed, ""),
+    (0x1ae0, Valid, ""),
+    (0x1aec, Disallowed, ""),
+    (0x1b00, Valid, ""),
+    (0x1b4d, Disallowed, ""),
+    (0x1b4e, Valid, ""),
+    (0x1bf4, Disallowed, ""),
+    (0x1bfc, Valid, ""),
+    (0x1c38, Disallowed, ""),
+    (0x1c3b, Valid, ""),
+    (0x1c4a, Disallowed, ""),
+    (0x1c4d, Valid, ""),
+    (0x1c80, Mapped, "\u0432"),
+    (0x1c81, Mapped, "\u0434"),
+    (0x1c82, Mapped, "\u043e"),
+    (0x1c83, Mapped, "\u0441"),
+    (0x1c84, Mapped, "\u0442"),
+    (0x1c86, Mapped, "\u044a"),
+    (0x1c87, Mapped, "\u0463"),
+    (0x1c88, Mapped, "\ua64b"),
+    (0x1c89, Mapped, "\u1c8a"),
+    (0x1c8a, Valid, ""),
+    (0x1c8b, Disallowed, ""),
+    (0x1c90, Mapped, "\u10d0"),
+    (0x1c91, Mapped, "\u10d1"),
+    (0x1c92, Mapped, "\u10d2"),
+    (0x1c93, Mapped, "\u10d3"),
+    (0x1c94, Mapped, "\u10d4"),
+    (0x1c95, Mapped, "\u10d5"),
+    (0x1c96, Mapped, "\u10d6"),
+    (0x1c97, Mapped, "\u10d7"),
+    (0x1c98, Mapped, "\u10d8"),
+    (0x1c99, Mapped, "\u10d9"),
+    (0x1c9a, Mapped, "\u10da"),
+    (0x1c9b, Mapped, "\u10db"),
+    (0x1c9c, Mapped, "\u10dc"),
+    (0x1c9d, Mapped, "\u10dd"),
+    (0x1c9e, Mapped, "\u10de"),
+    (0x1c9f, Mapped, "\u10df"),
+    (0x1ca0, Mapped, "\u10e0"),
+    (0x1ca1, Mapped, "\u10e1"),
+    (0x1ca2, Mapped, "\u10e2"),
+    (0x1ca3, Mapped, "\u10e3"),
+    (0x1ca4, Mapped, "\u10e4"),
+    (0x1ca5, Mapped, "\u10e5"),
+    (0x1ca6, Mapped, "\u10e6"),
+    (0x1ca7, Mapped, "\u10e7"),
+    (0x1ca8, Mapped, "\u10e8"),
+    (0x1ca9, Mapped, "\u10e9"),
+    (0x1caa, Mapped, "\u10ea"),
+    (0x1cab, Mapped, "\u10eb"),
+    (0x1cac, Mapped, "\u10ec"),
+    (0x1cad, Mapped, "\u10ed"),
+    (0x1cae, Mapped, "\u10ee"),
+    (0x1caf, Mapped, "\u10ef"),
+    (0x1cb0, Mapped, "\u10f0"),
+    (0x1cb1, Mapped, "\u10f1"),
+    (0x1cb2, Mapped, "\u10f2"),
+    (0x1cb3, Mapped, "\u10f3"),
+    (0x1cb4, Mapped, "\u10f4"),
+    (0x1cb5, Mapped, "\u10f5"),
+    (0x1cb6, Mapped, "\u10f6"),
+    (0x1cb7, Mapped, "\u10f7"),
+    (0x1cb8, Mapped, "\u10f8"),
+    (0x1cb9, Mapped, "\u10f9"),
+    (0x1cba, Mapped, "\u10fa"),
+    (0x1cbb, Disallowed, ""),
+    (0x1cbd, Mapped, "\u10fd"),
+    (0x1cbe, Mapped, "\u10fe"),
+    (0x1cbf, Mapped, "\u10ff"),
+    (0x1cc0, Valid, ""),
+    (0x1cc8, Disallowed, ""),
+    (0x1cd0, Valid, ""),
+    (0x1cfb, Disallowed, ""),
+    (0x1d00, Valid, ""),
+    (0x1d2c, Mapped, "a"),
+    (0x1d2d, Mapped, "\u00e6"),
+    (0x1d2e, Mapped, "b"),
+    (0x1d2f, Valid, ""),
+    (0x1d30, Mapped, "d"),
+    (0x1d31, Mapped, "e"),
+    (0x1d32, Mapped, "\u01dd"),
+    (0x1d33, Mapped, "g"),
+    (0x1d34, Mapped, "h"),
+    (0x1d35, Mapped, "i"),
+    (0x1d36, Mapped, "j"),
+    (0x1d37, Mapped, "k"),
+    (0x1d38, Mapped, "l"),
+    (0x1d39, Mapped, "m"),
+    (0x1d3a, Mapped, "n"),
+    (0x1d3b, Valid, ""),
+    (0x1d3c, Mapped, "o"),
+    (0x1d3d, Mapped, "\u0223"),
+    (0x1d3e, Mapped, "p"),
+    (0x1d3f, Mapped, "r"),
+    (0x1d40, Mapped, "t"),
+    (0x1d41, Mapped, "u"),
+    (0x1d42, Mapped, "w"),
+    (0x1d43, Mapped, "a"),
+    (0x1d44, Mapped, "\u0250"),
+    (0x1d45, Mapped, "\u0251"),
+    (0x1d46, Mapped, "\u1d02"),
+    (0x1d47, Mapped, "b"),
+    (0x1d48, Mapped, "d"),
+    (0x1d49, Mapped, "e"),
+    (0x1d4a, Mapped, "\u0259"),
+    (0x1d4b, Mapped, "\u025b"),
+    (0x1d4c, Mapped, "\u025c"),
+    (0x1d4d, Mapped, "g"),
+    (0x1d4e, Valid, ""),
+    (0x1d4f, Mapped, "k"),
+    (0x1d50, Mapped, "m"),
+    (0x1d51, Mapped, "\u014b"),
+    (0x1d52, Mapped, "o"),
+    (0x1d53, Mapped, "\u0254"),
+    (0x1d54, Mapped, "\u1d16"),
+    (0x1d55, Mapped, "\u1d17"),
+    (0x1d56, Mapped, "p"),
+    (0x1d57, Mapped, "t"),
+    (0x1d58, Mapped, "u"),
+    (0x1d59, Mapped, "\u1d1d"),
+    (0x1d5a, Mapped, "\u026f"),
+    (0x1d5b, Mapped, "v"),
+    (0x1d5c, Mapped, "\u1d25"),
+    (0x1d5d, Mapped, "\u03b2"),
+    (0x1d5e, Mapped, "\u03b3"),
+    (0x1d5f, Mapped, "\u03b4"),
+    (0x1d60, Mapped, "\u03c6"),
+    (0x1d61, Mapped, "\u03c7"),
+    (0x1d62, Mapped, "i"),
+    (0x1d63, Mapped, "r"),
+    (0x1d64, Mapped, "u"),
+    (0x1d65, Mapped, "v"),
+    (0x1d66, Mapped, "\u03b2"),
+    (0x1d67, Mapped, "\u03b3"),
+    (0x1d68, Mapped, "\u03c1"),
+    (0x1d69, Mapped, "\u03c6"),
+    (0x1d6a, Mapped, "\u03c7"),
+    (0x1d6b, Valid, ""),
+    (0x1d78, Mapped, "\u043d"),
+    (0x1d79, Valid, ""),
+    (0x1d9b, Mapped, "\u0252"),
+    (0x1d9c, Mapped, "c"),
+    (0x1d9d, Mapped, "\u0255"),
+    (0x1d9e, Mapped, "\u00f0"),
+    (0x1d9f, Mapped, "\u025c"),
+    (0x1da0, Mapped, "f"),
+    (0x1da1, Mapped, "\u025f"),
+    (0x1da2, Mapped, "\u0261"),
+    (0x1da3, Mapped, "\u0265"),
+    (0x1da4, Mapped, "\u0268"),
+    (0x1da5, Mapped, "\u0269"),
+    (0x1da6, Mapped, "\u026a"),
+    (0x1da7, Mapped, "\u1d7b"),
+    (0x1da8, Mapped, "\u029d"),
+    (0x1da9, Mapped, "\u026d"),
+    (0x1daa, Mapped, "\u1d85"),
+    (0x1dab, Mapped, "\u029f"),
+    (0x1dac, Mapped, "\u0271"),
+    (0x1dad, Mapped, "\u0270"),
+    (0x1dae, Mapped, "\u0272"),
+    (0x1daf, Mapped, "\u0273"),
+    (0x1db0, Mapped, "\u0274"),
+    (0x1db1, Mapped, "\u0275"),
+    (0x1db2, Mapped, "\u0278"),
+    (0x1db3, Mapped, "\u0282"),
+    (0x1db4, Mapped, "\u0283"),
+    (0x1db5, Mapped, "\u01ab"),
+    (0x1db6, Mapped, "\u0289"),
+    (0x1db7, Mapped, "\u028a"),
+    (0x1db8, Mapped, "\u1d1c"),
+    (0x1db9, Mapped, "\u028b"),
+    (0x1dba, Mapped, "\u028c"),
+    (0x1dbb, Mapped, "z"),
+    (0x1dbc, Mapped, "\u0290"),
+    (0x1dbd, Mapped, "\u0291"),
+    (0x1dbe, Mapped, "\u0292"),
+    (0x1dbf, Mapped, "\u03b8"),
+    (0x1dc0, Valid, ""),
+    (0x1e00, Mapped, "\u1e01"),
+    (0x1e01, Valid, ""),
+    (0x1e02, Mapped, "\u1e03"),
+    (0x1e03, Valid, ""),
+    (0x1e04, Mapped, "\u1e05"),
+    (0x1e05, Valid, ""),
+    (0x1e06, Mapped, "\u1e07"),
+    (0x1e07, Valid, ""),
+    (0x1e08, Mapped, "\u1e09"),
+    (0x1e09, Valid, ""),
+    (0x1e0a, Mapped, "\u1e0b"),
+    (0x1e0b, Valid, ""),
+    (0x1e0c, Mapped, "\u1e0d"),
+    (0x1e0d, Valid, ""),
+    (0x1e0e, Mapped, "\u1e0f"),
+    (0x1e0f, Valid, ""),
+    (0x1e10, Mapped, "\u1e11"),
+    (0x1e11, Valid, ""),
+    (0x1e12, Mapped, "\u1e13"),
+    (0x1e13, Valid, ""),
+    (0x1e14, Mapped, "\u1e15"),
+    (0x1e15, Valid, ""),
+    (0x1e16, Mapped, "\u1e17"),
+    (0x1e17, Valid, ""),
+    (0x1e18, Mapped, "\u1e19"),
+    (0x1e19, Valid, ""),
+    (0x1e1a, Mapped, "\u1e1b"),
+    (0x1e1b, Valid, ""),
+    (0x1e1c, Mapped, "\u1e1d"),
+    (0x1e1d, Valid, ""),
+    (0x1e1e, Mapped, "\u1e1f"),
+    (0x1e1f, Valid, ""),
+    (0x1e20, Mapped, "\u1e21"),
+    (0x1e21, Valid, ""),
+    (0x1e22, Mapped, "\u1e23"),
+    (0x1e23, Valid, ""),
+    (0x1e24, Mapped, "\u1e25"),
+    (0x1e25, Valid, ""),
+    (0x1e26, Mapped, "\u1e27"),
+    (0x1e27, Valid, ""),
+    (0x1e28, Mapped, "\u1e29"),
+    (0x1e29, Valid, ""),
+    (0x1e2a, Mapped, "\u1e2b"),
+    (0x1e2b, Valid, ""),
+    (0x1e2c, Mapped, "\u1e2d"),
+    (0x1e2d, Valid, ""),
+    (0x1e2e, Mapped, "\u1e2f"),
+    (0x1e2f, Valid, ""),
+    (0x1e30, Mapped, "\u1e31"),
+    (0x1e31, Valid, ""),
+    (0x1e32, Mapped, "\u1e33"),
+    (0x1e33, Valid, ""),
+    (0x1e34, Mapped, "\u1e35"),
+    (0x1e35, Valid, ""),
+    (0x1e36, Mapped, "\u1e37"),
+    (0x1e37, Valid, ""),
+    (0x1e38, Mapped, "\u1e39"),
+    (0x1e39, Valid, ""),
+    (0x1e3a, Mapped, "\u1e3b"),
+    (0x1e3b, Valid, ""),
+    (0x1e3c, Mapped, "\u1e3d"),
+    (0x1e3d, Valid, ""),
+    (0x1e3e, Mapped, "\u1e3f"),
+    (0x1e3f, Valid, ""),
+    (0x1e40, Mapped, "\u1e41"),
+    (0x1e41, Valid, ""),
+    (0x1e42, Mapped, "\u1e43"),
+    (0x1e43, Valid, ""),
+    (0x1e44, Mapped, "\u1e45"),
+    (0x1e45, Valid, ""),
+    (0x1e46, Mapped, "\u1e47"),
+    (0x1e47, Valid, ""),
+    (0x1e48, Mapped, "\u1e49"),
+    (0x1e49, Valid, ""),
+    (0x1e4a, Mapped, "\u1e4b"),
+    (0x1e4b, Valid, ""),
+    (0x1e4c, Mapped, "\u1e4d"),
+    (0x1e4d, Valid, ""),
+    (0x1e4e, Mapped, "\u1e4f"),
+    (0x1e4f, Valid, ""),
+    (0x1e50, Mapped, "\u1e51"),
+    (0x1e51, Valid, ""),
+    (0x1e52, Mapped, "\u1e53"),
+    (0x1e53, Valid, ""),
+    (0x1e54, Mapped, "\u1e55"),
+    (0x1e55, Valid, ""),
+    (0x1e56, Mapped, "\u1e57"),
+    (0x1e57, Valid, ""),
+    (0x1e58, Mapped, "\u1e59"),
+    (0x1e59, Valid, ""),
+    (0x1e5a, Mapped, "\u1e5b"),
+    (0x1e5b, Valid, ""),
+    (0x1e5c, Mapped, "\u1e5d"),
+    (0x1e5d, Valid, ""),
+    (0x1e5e, Mapped, "\u1e5f"),
+    (0x1e5f, Valid, ""),
+    (0x1e60, Mapped, "\u1e61"),
+    (0x1e61, Valid, ""),
+    (0x1e62, Mapped, "\u1e63"),
+    (0x1e63, Valid, ""),
+    (0x1e64, Mapped, "\u1e65"),
+    (0x1e65, Valid, ""),
+    (0x1e66, Mapped, "\u1e67"),
+    (0x1e67, Valid, ""),
+    (0x1e68, Mapped, "\u1e69"),
+    (0x1e69, Valid, ""),
+    (0x1e6a, Mapped, "\u1e6b"),
+    (0x1e6b, Valid, ""),
+    (0x1e6c, Mapped, "\u1e6d"),
+    (0x1e6d, Valid, ""),
+    (0x1e6e, Mapped, "\u1e6f"),
+    (0x1e6f, Valid, ""),
+    (0x1e70, Mapped, "\u1e71"),
+    (0x1e71, Valid, ""),
+    (0x1e72, Mapped, "\u1e73"),
+    (0x1e73, Valid, ""),
+    (0x1e74, Mapped, "\u1e75"),
+    (0x1e75, Valid, ""),
+    (0x1e76, Mapped, "\u1e77"),
+    (0x1e77, Valid, ""),
+    (0x1e78, Mapped, "\u1e79"),
+    (0x1e79, Valid, ""),
+    (0x1e7a, Mapped, "\u1e7b"),
+    (0x1e7b, Valid, ""),
+    (0x1e7c, Mapped, "\u1e7d"),
+    (0x1e7d, Valid, ""),
+    (0x1e7e, Mapped, "\u1e7f"),
+    (0x1e7f, Valid, ""),
+    (0x1e80, Mapped, "\u1e81"),
+    (0x1e81, Valid, ""),
+    (0x1e82, Mapped, "\u1e83"),
+    (0x1e83, Valid, ""),
+    (0x1e84, Mapped, "\u1e85"),
+    (0x1e85, Valid, ""),
+    (0x1e86, Mapped, "\u1e87"),
+    (0x1e87, Valid, ""),
+    (0x1e88, Mapped, "\u1e89"),
+    (0x1e89, Valid, ""),
+    (0x1e8a, Mapped, "\u1e8b"),
+    (0x1e8b, Valid, ""),
+    (0x1e8c, Mapped, "\u1e8d"),
+    (0x1e8d, Valid, ""),
+    (0x1e8e, Mapped, "\u1e8f"),
+    (0x1e8f, Valid, ""),
+    (0x1e90, Mapped, "\u1e91"),
+    (0x1e91, Valid, ""),
+    (0x1e92, Mapped, "\u1e93"),
+    (0x1e93, Valid, ""),
+    (0x1e94, Mapped, "\u1e95"),
+    (0x1e95, Valid, ""),
+    (0x1e9a, Mapped, "a\u02be"),
+    (0x1e9b, Mapped, "\u1e61"),
+    (0x1e9c, Valid, ""),
+    (0x1e9e, Mapped, "\u00df"),
+    (0x1e9f, Valid, ""),
+    (0x1ea0, Mapped, "\u1ea1"),
+    (0x1ea1, Valid, ""),
+    (0x1ea2, Mapped, "\u1ea3"),
+    (0x1ea3, Valid, ""),
+    (0x1ea4, Mapped, "\u1ea5"),
+    (0x1ea5, Valid, ""),
+    (0x1ea6, Mapped, "\u1ea7"),
+    (0x1ea7, Valid, ""),
+    (0x1ea8, Mapped, "\u1ea9"),
+    (0x1ea9, Valid, ""),
+    (0x1eaa, Mapped, "\u1eab"),
+    (0x1eab, Valid, ""),
+    (0x1eac, Mapped, "\u1ead"),
+    (0x1ead, Valid, ""),
+    (0x1eae, Mapped, "\u1eaf"),
+    (0x1eaf, Valid, ""),
+    (0x1eb0, Mapped, "\u1eb1"),
+    (0x1eb1, Valid, ""),
+    (0x1eb2, Mapped, "\u1eb3"),
+    (0x1eb3, Valid, ""),
+    (0x1eb4, Mapped, "\u1eb5"),
+    (0x1eb5, Valid, ""),
+    (0x1eb6, Mapped, "\u1eb7"),
+    (0x1eb7, Valid, ""),
+    (0x1eb8, Mapped, "\u1eb9"),
+    (0x1eb9, Valid, ""),
+    (0x1eba, Mapped, "\u1ebb"),
+    (0x1ebb, Valid, ""),
+    (0x1ebc, Mapped, "\u1ebd"),
+    (0x1ebd, Valid, ""),
+    (0x1ebe, Mapped, "\u1ebf"),
+    (0x1ebf, Valid, ""),
+    (0x1ec0, Mapped, "\u1ec1"),
+    (0x1ec1, Valid, ""),
+    (0x1ec2, Mapped, "\u1ec3"),
+    (0x1ec3, Valid, ""),
+    (0x1ec4, Mapped, "\u1ec5"),
+    (0x1ec5, Valid, ""),
+    (0x1ec6, Mapped, "\u1ec7"),
+    (0x1ec7, Valid, ""),
+    (0x1ec8, Mapped, "\u1ec9"),
+    (0x1ec9, Valid, ""),
+    (0x1eca, Mapped, "\u1ecb"),
+    (0x1ecb, Valid, ""),
+    (0x1ecc, Mapped, "\u1ecd"),
+    (0x1ecd, Valid, ""),
+    (0x1ece, Mapped, "\u1ecf"),
+    (0x1ecf, Valid, ""),
+    (0x1ed0, Mapped, "\u1ed1"),
+    (0x1ed1, Valid, ""),
+    (0x1ed2, Mapped, "\u1ed3"),
+    (0x1ed3, Valid, ""),
+    (0x1ed4, Mapped, "\u1ed5"),
+    (0x1ed5, Valid, ""),
+    (0x1ed6, Mapped, "\u1ed7"),
+    (0x1ed7, Valid, ""),
+    (0x1ed8, Mapped, "\u1ed9"),
+    (0x1ed9, Valid, ""),
+    (0x1eda, Mapped, "\u1edb"),
+    (0x1edb, Valid, ""),
+    (0x1edc, Mapped, "\u1edd"),
+    (0x1edd, Valid, ""),
+    (0x1ede, Mapped, "\u1edf"),
+    (0x1edf, Valid, ""),
+    (0x1ee0, Mapped, "\u1ee1"),
+    (0x1ee1, Valid, ""),
+    (0x1ee2, Mapped, "\u1ee3"),
+    (0x1ee3, Valid, ""),
+    (0x1ee4, Mapped, "\u1ee5"),
+    (0x1ee5, Valid, ""),
+    (0x1ee6, Mapped, "\u1ee7"),
+    (0x1ee7, Valid, ""),
+    (0x1ee8, Mapped, "\u1ee9"),
+    (0x1ee9, Valid, ""),
+    (0x1eea, Mapped, "\u1eeb"),
+    (0x1eeb, Valid, ""),
+    (0x1eec, Mapped, "\u1eed"),
+    (0x1eed, Valid, ""),
+    (0x1eee, Mapped, "\u1eef"),
+    (0x1eef, Valid, ""),
+    (0x1ef0, Mapped, "\u1ef1"),
+    (0x1ef1, Valid, ""),
+    (0x1ef2, Mapped, "\u1ef3"),
+    (0x1ef3, Valid, ""),
+    (0x1ef4, Mapped, "\u1ef5"),
+    (0x1ef5, Valid, ""),
+    (0x1ef6, Mapped, "\u1ef7"),
+    (0x1ef7, Valid, ""),
+    (0x1ef8, Mapped, "\u1ef9"),
+    (0x1ef9, Valid, ""),
+    (0x1efa, Mapped, "\u1efb"),
+    (0x1efb, Valid, ""),
+    (0x1efc, Mapped, "\u1efd"),
+    (0x1efd, Valid, ""),
+    (0x1efe, Mapped, "\u1eff"),
+    (0x1eff, Valid, ""),
+    (0x1f08, Mapped, "\u1f00"),
+    (0x1f09, Mapped, "\u1f01"),
+    (0x1f0a, Mapped, "\u1f02"),
+    (0x1f0b, Mapped, "\u1f03"),
+    (0x1f0c, Mapped, "\u1f04"),
+    (0x1f0d, Mapped, "\u1f05"),
+    (0x1f0e, Mapped, "\u1f06"),
+    (0x1f0f, Mapped, "\u1f07"),
+    (0x1f10, Valid, ""),
+    (0x1f16, Disallowed, ""),
+    (0x1f18, Mapped, "\u1f10"),
+    (0x1f19, Mapped, "\u1f11"),
+    (0x1f1a, Mapped, "\u1f12"),
+    (0x1f1b, Mapped, "\u1f13"),
+    (0x1f1c, Mapped, "\u1f14"),
+    (0x1f1d, Mapped, "\u1f15"),
+    (0x1f1e, Disallowed, ""),
+    (0x1f20, Valid, ""),
+    (0x1f28, Mapped, "\u1f20"),
+    (0x1f29, Mapped, "\u1f21"),
+    (0x1f2a, Mapped, "\u1f22"),
+    (0x1f2b, Mapped, "\u1f23"),
+    (0x1f2c, Mapped, "\u1f24"),
+    (0x1f2d, Mapped, "\u1f25"),
+    (0x1f2e, Mapped, "\u1f26"),
+    (0x1f2f, Mapped, "\u1f27"),
+    (0x1f30, Valid, ""),
+    (0x1f38, Mapped, "\u1f30"),
+    (0x1f39, Mapped, "\u1f31"),
+    (0x1f3a, Mapped, "\u1f32"),
+    (0x1f3b, Mapped, "\u1f33"),
+    (0x1f3c, Mapped, "\u1f34"),
+    (0x1f3d, Mapped, "\u1f35"),
+    (0x1f3e, Mapped, "\u1f36"),
+    (0x1f3f, Mapped, "\u1f37"),
+    (0x1f40, Valid, ""),
+    (0x1f46, Disallowed, ""),
+    (0x1f48, Mapped, "\u1f40"),
+    (0x1f49, Mapped, "\u1f41"),
+    (0x1f4a, Mapped, "\u1f42"),
+    (0x1f4b, Mapped, "\u1f43"),
+    (0x1f4c, Mapped, "\u1f44"),
+    (0x1f4d, Mapped, "\u1f45"),
+    (0x1f4e, Disallowed, ""),
+    (0x1f50, Valid, ""),
+    (0x1f58, Disallowed, ""),
+    (0x1f59, Mapped, "\u1f51"),
+    (0x1f5a, Disallowed, ""),
+    (0x1f5b, Mapped, "\u1f53"),
+    (0x1f5c, Disallowed, ""),
+    (0x1f5d, Mapped, "\u1f55"),
+    (0x1f5e, Disallowed, ""),
+    (0x1f5f, Mapped, "\u1f57"),
+    (0x1f60, Valid, ""),
+    (0x1f68, Mapped, "\u1f60"),
+    (0x1f69, Mapped, "\u1f61"),
+    (0x1f6a, Mapped, "\u1f62"),
+    (0x1f6b, Mapped, "\u1f63"),
+    (0x1f6c, Mapped, "\u1f64"),
+    (0x1f6d, Mapped, "\u1f65"),
+    (0x1f6e, Mapped, "\u1f66"),
+    (0x1f6f, Mapped, "\u1f67"),
+    (0x1f70, Valid, ""),
+    (0x1f71, Mapped, "\u03ac"),
+    (0x1f72, Valid, ""),
+    (0x1f73, Mapped, "\u03ad"),
+    (0x1f74, Valid, ""),
+    (0x1f75, Mapped, "\u03ae"),
+    (0x1f76, Valid, ""),
+    (0x1f77, Mapped, "\u03af"),
+    (0x1f78, Valid, ""),
+    (0x1f79, Mapped, "\u03cc"),
+    (0x1f7a, Valid, ""),
+    (0x1f7b, Mapped, "\u03cd"),
+    (0x1f7c, Valid, ""),
+    (0x1f7d, Mapped, "\u03ce"),
+    (0x1f7e, Disallowed, ""),
+    (0x1f80, Mapped, "\u1f00\u03b9"),
+    (0x1f81, Mapped, "\u1f01\u03b9"),
+    (0x1f82, Mapped, "\u1f02\u03b9"),
+    (0x1f83, Mapped, "\u1f03\u03b9"),
+    (0x1f84, Mapped, "\u1f04\u03b9"),
+    (0x1f85, Mapped, "\u1f05\u03b9"),
+    (0x1f86, Mapped, "\u1f06\u03b9"),
+    (0x1f87, Mapped, "\u1f07\u03b9"),
+    (0x1f88, Mapped, "\u1f00\u03b9"),
+    (0x1f89, Mapped, "\u1f01\u03b9"),
+    (0x1f8a, Mapped, "\u1f02\u03b9"),
+    (0x1f8b, Mapped, "\u1f03\u03b9"),
+    (0x1f8c, Mapped, "\u1f04\u03b9"),
+    (0x1f8d, Mapped, "\u1f05\u03b9"),
+    (0x1f8e, Mapped, "\u1f06\u03b9"),
+    (0x1f8f, Mapped, "\u1f07\u03b9"),
+    (0x1f90, Mapped, "\u1f20\u03b9"),
+    (0x1f91, Mapped, "\u1f21\u03b9"),
+    (0x1f92, Mapped, "\u1f22\u03b9"),
+    (0x1f93, Mapped, "\u1f23\u03b9"),
+    (0x1f94, Mapped, "\u1f24\u03b9"),
+    (0x1f95, Mapped, "\u1f25\u03b9"),
+    (0x1f96, Mapped, "\u1f26\u03b9"),
+    (0x1f97, Mapped, "\u1f27\u03b9"),
+    (0x1f98, Mapped, "\u1f20\u03b9"),
+    (0x1f99, Mapped, "\u1f21\u03b9"),
+    (0x1f9a, Mapped, "\u1f22\u03b9"),
+    (0x1f9b, Mapped, "\u1f23\u03b9"),
+    (0x1f9c, Mapped, "\u1f24\u03b9"),
+    (0x1f9d, Mapped, "\u1f25\u03b9"),
+    (0x1f9e, Mapped, "\u1f26\u03b9"),
+    (0x1f9f, Mapped, "\u1f27\u03b9"),
+    (0x1fa0, Mapped, "\u1f60\u03b9"),
+    (0x1fa1, Mapped, "\u1f61\u03b9"),
+    (0x1fa2, Mapped, "\u1f62\u03b9"),
+    (0x1fa3, Mapped, "\u1f63\u03b9"),
+    (0x1fa4, Mapped, "\u1f64\u03b9"),
+    (0x1fa5, Mapped, "\u1f65\u03b9"),
+    (0x1fa6, Mapped, "\u1f66\u03b9"),
+    (0x1fa7, Mapped, "\u1f67\u03b9"),
+    (0x1fa8, Mapped, "\u1f60\u03b9"),
+    (0x1fa9, Mapped, "\u1f61\u03b9"),
+    (0x1faa, Mapped, "\u1f62\u03b9"),
+    (0x1fab, Mapped, "\u1f63\u03b9"),
+    (0x1fac, Mapped, "\u1f64\u03b9"),
+    (0x1fad, Mapped, "\u1f65\u03b9"),
+    (0x1fae, Mapped, "\u1f66\u03b9"),
+    (0x1faf, Mapped, "\u1f67\u03b9"),
+    (0x1fb0, Valid, ""),
+    (0x1fb2, Mapped, "\u1f70\u03b9"),
+    (0x1fb3, Mapped, "\u03b1\u03b9"),
+    (0x1fb4, Mapped, "\u03ac\u03b9"),
+    (0x1fb5, Disallowed, ""),
+    (0x1fb6, Valid, ""),
+    (0x1fb7, Mapped, "\u1fb6\u03b9"),
+    (0x1fb8, Mapped, "\u1fb0"),
+    (0x1fb9, Mapped, "\u1fb1"),
+    (0x1fba, Mapped, "\u1f70"),
+    (0x1fbb, Mapped, "\u03ac"),
+    (0x1fbc, Mapped, "\u03b1\u03b9"),
+    (0x1fbd, Mapped, " \u0313"),
+    (0x1fbe, Mapped, "\u03b9"),
+    (0x1fbf, Mapped, " \u0313"),
+    (0x1fc0, Mapped, " \u0342"),
+    (0x1fc1, Mapped, " \u0308\u0342"),
+    (0x1fc2, Mapped, "\u1f74\u03b9"),
+    (0x1fc3, Mapped, "\u03b7\u03b9"),
+    (0x1fc4, Mapped, "\u03ae\u03b9"),
+    (0x1fc5, Disallowed, ""),
+    (0x1fc6, Valid, ""),
+    (0x1fc7, Mapped, "\u1fc6\u03b9"),
+    (0x1fc8, Mapped, "\u1f72"),
+    (0x1fc9, Mapped, "\u03ad"),
+    (0x1fca, Mapped, "\u1f74"),
+    (0x1fcb, Mapped, "\u03ae"),
+    (0x1fcc, Mapped, "\u03b7\u03b9"),
+    (0x1fcd, Mapped, " \u0313\u0300"),
+    (0x1fce, Mapped, " \u0313\u0301"),
+    (0x1fcf, Mapped, " \u0313\u0342"),
+    (0x1fd0, Valid, ""),
+    (0x1fd3, Mapped, "\u0390"),
+    (0x1fd4, Disallowed, ""),
+    (0x1fd6, Valid, ""),
+    (0x1fd8, Mapped, "\u1fd0"),
+    (0x1fd9, Mapped, "\u1fd1"),
+    (0x1fda, Mapped, "\u1f76"),
+    (0x1fdb, Mapped, "\u03af"),
+    (0x1fdc, Disallowed, ""),
+    (0x1fdd, Mapped, " \u0314\u0300"),
+    (0x1fde, Mapped, " \u0314\u0301"),
+    (0x1fdf, Mapped, " \u0314\u0342"),
+    (0x1fe0, Valid, ""),
+    (0x1fe3, Mapped, "\u03b0"),
+    (0x1fe4, Valid, ""),
+    (0x1fe8, Mapped, "\u1fe0"),
+    (0x1fe9, Mapped, "\u1fe1"),
+    (0x1fea, Mapped, "\u1f7a"),
+    (0x1feb, Mapped, "\u03cd"),
+    (0x1fec, Mapped, "\u1fe5"),
+    (0x1fed, Mapped, " \u0308\u0300"),
+    (0x1fee, Mapped, " \u0308\u0301"),
+    (0x1fef, Mapped, "`"),
+    (0x1ff0, Disallowed, ""),
+    (0x1ff2, Mapped, "\u1f7c\u03b9"),
+    (0x1ff3, Mapped, "\u03c9\u03b9"),
+    (0x1ff4, Mapped, "\u03ce\u03b9"),
+    (0x1ff5, Disallowed, ""),
+    (0x1ff6, Valid, ""),
+    (0x1ff7, Mapped, "\u1ff6\u03b9"),
+    (0x1ff8, Mapped, "\u1f78"),
+    (0x1ff9, Mapped, "\u03cc"),
+    (0x1ffa, Mapped, "\u1f7c"),
+    (0x1ffb, Mapped, "\u03ce"),
+    (0x1ffc, Mapped, "\u03c9\u03b9"),
+    (0x1ffd, Mapped, " \u0301"),
+    (0x1ffe, Mapped, " \u0314"),
+    (0x1fff, Disallowed, ""),
+    (0x2000, Mapped, " "),
+    (0x200b, Ignored, ""),
+    (0x200c, Deviation, ""),
+    (0x200e, Disallowed, ""),
+    (0x2010, Valid, ""),
+    (0x2011, Mapped, "\u2010"),
+    (0x2012, Valid, ""),
+    (0x2017, Mapped, " \u0333"),
+    (0x2018, Valid, ""),
+    (0x2024, Disallowed, ""),
+    (0x2027, Valid, ""),
+    (0x2028, Disallowed, ""),
+    (0x202f, Mapped, " "),
+    (0x2030, Valid, ""),
+    (0x2033, Mapped, "\u2032\u2032"),
+    (0x2034, Mapped, "\u2032\u2032\u2032"),
+    (0x2035, Valid, ""),
+    (0x2036, Mapped, "\u2035\u2035"),
+    (0x2037, Mapped, "\u2035\u2035\u2035"),
+    (0x2038, Valid, ""),
+    (0x203c, Mapped, "!!"),
+    (0x203d, Valid, ""),
+    (0x203e, Mapped, " \u0305"),
+    (0x203f, Valid, ""),
+    (0x2047, Mapped, "??"),
+    (0x2048, Mapped, "?!"),
+    (0x2049, Mapped, "!?"),
+    (0x204a, Valid, ""),
+    (0x2057, Mapped, "\u2032\u2032\u2032\u2032"),
+    (0x2058, Valid, ""),
+    (0x205f, Mapped, " "),
+    (0x2060, Ignored, ""),
+    (0x2065, Disallowed, ""),
+    (0x206a, Ignored, ""),
+    (0x2070, Mapped, "0"),
+    (0x2071, Mapped, "i"),
+    (0x2072, Disallowed, ""),
+    (0x2074, Mapped, "4"),
+    (0x2075, Mapped, "5"),
+    (0x2076, Mapped, "6"),
+    (0x2077, Mapped, "7"),
+    (0x2078, Mapped, "8"),
+    (0x2079, Mapped, "9"),
+    (0x207a, Mapped, "+"),
+    (0x207b, Mapped, "\u2212"),
+    (0x207c, Mapped, "="),
+    (0x207d, Mapped, "("),
+    (0x207e, Mapped, ")"),
+    (0x207f, Mapped, "n"),
+    (0x2080, Mapped, "0"),
+    (0x2081, Mapped, "1"),
+    (0x2082, Mapped, "2"),
+    (0x2083, Mapped, "3"),
+    (0x2084, Mapped, "4"),
+    (0x2085, Mapped, "5"),
+    (0x2086, Mapped, "6"),
+    (0x2087, Mapped, "7"),
+    (0x2088, Mapped, "8"),
+    (0x2089, Mapped, "9"),
+    (0x208a, Mapped, "+"),
+    (0x208b, Mapped, "\u2212"),
+    (0x208c, Mapped, "="),
+    (0x208d, Mapped, "("),
+    (0x208e, Mapped, ")"),
+    (0x208f, Disallowed, ""),
+    (0x2090, Mapped, "a"),
+    (0x2091, Mapped, "e"),
+    (0x2092, Mapped, "o"),
+    (0x2093, Mapped, "x"),
+    (0x2094, Mapped, "\u0259"),
+    (0x2095, Mapped, "h"),
+    (0x2096, Mapped, "k"),
+    (0x2097, Mapped, "l"),
+    (0x2098, Mapped, "m"),
+    (0x2099, Mapped, "n"),
+    (0x209a, Mapped, "p"),
+    (0x209b, Mapped, "s"),
+    (0x209c, Mapped, "t"),
+    (0x209d, Disallowed, ""),
+    (0x20a0, Valid, ""),
+    (0x20a8, Mapped, "rs"),
+    (0x20a9, Valid, ""),
+    (0x20c2, Disallowed, ""),
+    (0x20d0, Valid, ""),
+    (0x20f1, Disallowed, ""),
+    (0x2100, Mapped, "a/c"),
+    (0x2101, Mapped, "a/s"),
+    (0x2102, Mapped, "c"),
+    (0x2103, Mapped, "\u00b0c"),
+    (0x2104, Valid, ""),
+    (0x2105, Mapped, "c/o"),
+    (0x2106, Mapped, "c/u"),
+    (0x2107, Mapped, "\u025b"),
+    (0x2108, Valid, ""),
+    (0x2109, Mapped, "\u00b0f"),
+    (0x210a, Mapped, "g"),
+    (0x210b, Mapped, "h"),
+    (0x210f, Mapped, "\u0127"),
+    (0x2110, Mapped, "i"),
+    (0x2112, Mapped, "l"),
+    (0x2114, Valid, ""),
+    (0x2115, Mapped, "n"),
+    (0x2116, Mapped, "no"),
+    (0x2117, Valid, ""),
+    (0x2119, Mapped, "p"),
+    (0x211a, Mapped, "q"),
+    (0x211b, Mapped, "r"),
+    (0x211e, Valid, ""),
+    (0x2120, Mapped, "sm"),
+    (0x2121, Mapped, "tel"),
+    (0x2122, Mapped, "tm"),
+    (0x2123, Valid, ""),
+    (0x2124, Mapped, "z"),
+    (0x2125, Valid, ""),
+    (0x2126, Mapped, "\u03c9"),
+    (0x2127, Valid, ""),
+    (0x2128, Mapped, "z"),
+    (0x2129, Valid, ""),
+    (0x212a, Mapped, "k"),
+    (0x212b, Mapped, "\u00e5"),
+    (0x212c, Mapped, "b"),
+    (0x212d, Mapped, "c"),
+    (0x212e, Valid, ""),
+    (0x212f, Mapped, "e"),
+    (0x2131, Mapped, "f"),
+    (0x2132, Mapped, "\u214e"),
+    (0x2133, Mapped, "m"),
+    (0x2134, Mapped, "o"),
+    (0x2135, Mapped, "\u05d0"),
+    (0x2136, Mapped, "\u05d1"),
+    (0x2137, Mapped, "\u05d2"),
+    (0x2138, Mapped, "\u05d3"),
+    (0x2139, Mapped, "i"),
+    (0x213a, Valid, ""),
+    (0x213b, Mapped, "fax"),
+    (0x213c, Mapped, "\u03c0"),
+    (0x213d, Mapped, "\u03b3"),
+    (0x213f, Mapped, "\u03c0"),
+    (0x2140, Mapped, "\u2211"),
+    (0x2141, Valid, ""),
+    (0x2145, Mapped, "d"),
+    (0x2147, Mapped, "e"),
+    (0x2148, Mapped, "i"),
+    (0x2149, Mapped, "j"),
+    (0x214a, Valid, ""),
+    (0x2150, Mapped, "1\u20447"),
+    (0x2151, Mapped, "1\u20449"),
+    (0x2152, Mapped, "1\u204410"),
+    (0x2153, Mapped, "1\u20443"),
+    (0x2154, Mapped, "2\u20443"),
+    (0x2155, Mapped, "1\u20445"),
+    (0x2156, Mapped, "2\u20445"),
+    (0x2157, Mapped, "3\u20445"),
+    (0x2158, Mapped, "4\u20445"),
+    (0x2159, Mapped, "1\u20446"),
+    (0x215a, Mapped, "5\u20446"),
+    (0x215b, Mapped, "1\u20448"),
+    (0x215c, Mapped, "3\u20448"),
+    (0x215d, Mapped, "5\u20448"),
+    (0x215e, Mapped, "7\u20448"),
+    (0x215f, Mapped, "1\u2044"),
+    (0x2160, Mapped, "i"),
+    (0x2161, Mapped, "ii"),
+    (0x2162, Mapped, "iii"),
+    (0x2163, Mapped, "iv"),
+    (0x2164, Mapped, "v"),
+    (0x2165, Mapped, "vi"),
+    (0x2166, Mapped, "vii"),
+    (0x2167, Mapped, "viii"),
+    (0x2168, Mapped, "ix"),
+    (0x2169, Mapped, "x"),
+    (0x216a, Mapped, "xi"),
+    (0x216b, Mapped, "xii"),
+    (0x216c, Mapped, "l"),
+    (0x216d, Mapped, "c"),
+    (0x216e, Mapped, "d"),
+    (0x216f, Mapped, "m"),
+    (0x2170, Mapped, "i"),
+    (0x2171, Mapped, "ii"),
+    (0x2172, Mapped, "iii"),
+    (0x2173, Mapped, "iv"),
+    (0x2174, Mapped, "v"),
+    (0x2175, Mapped, "vi"),
+    (0x2176, Mapped, "vii"),
+    (0x2177, Mapped, "viii"),
+    (0x2178, Mapped, "ix"),
+    (0x2179, Mapped, "x"),
+    (0x217a, Mapped, "xi"),
+    (0x217b, Mapped, "xii"),
+    (0x217c, Mapped, "l"),
+    (0x217d, Mapped, "c"),
+    (0x217e, Mapped, "d"),
+    (0x217f, Mapped, "m"),
+    (0x2180, Valid, ""),
+    (0x2183, Mapped, "\u2184"),
+    (0x2184, Valid, ""),
+    (0x2189, Mapped, "0\u20443"),
+    (0x218a, Valid, ""),
+    (0x218c, Disallowed, ""),
+    (0x2190, Valid, ""),
+    (0x222c, Mapped, "\u222b\u222b"),
+    (0x222d, Mapped, "\u222b\u222b\u222b"),
+    (0x222e, Valid, ""),
+    (0x222f, Mapped, "\u222e\u222e"),
+    (0x2230, Mapped, "\u222e\u222e\u222e"),
+    (0x2231, Valid, ""),
+    (0x2329, Mapped, "\u3008"),
+    (0x232a, Mapped, "\u3009"),
+    (0x232b, Valid, ""),
+    (0x242a, Disallowed, ""),
+    (0x2440, Valid, ""),
+    (0x244b, Disallowed, ""),
+    (0x2460, Mapped, "1"),
+    (0x2461, Mapped, "2"),
+    (0x2462, Mapped, "3"),
+    (0x2463, Mapped, "4"),
+    (0x2464, Mapped, "5"),
+    (0x2465, Mapped, "6"),
+    (0x2466, Mapped, "7"),
+    (0x2467, Mapped, "8"),
+    (0x2468, Mapped, "9"),
+    (0x2469, Mapped, "10"),
+    (0x246a, Mapped, "11"),
+    (0x246b, Mapped, "12"),
+    (0x246c, Mapped, "13"),
+    (0x246d, Mapped, "14"),
+    (0x246e, Mapped, "15"),
+    (0x246f, Mapped, "16"),
+    (0x2470, Mapped, "17"),
+    (0x2471, Mapped, "18"),
+    (0x2472, Mapped, "19"),
+    (0x2473, Mapped, "20"),
+    (0x2474, Mapped, "(1)"),
+    (0x2475, Mapped, "(2)"),
+    (0x2476, Mapped, "(3)"),
+    (0x2477, Mapped, "(4)"),
+    (0x2478, Mapped, "(5)"),
+    (0x2479, Mapped, "(6)"),
+    (0x247a, Mapped, "(7)"),
+    (0x247b, Mapped, "(8)"),
+    (0x247c, Mapped, "(9)"),
+    (0x247d, Mapped, "(10)"),
+    (0x247e, Mapped, "(11)"),
+    (0x247f, Mapped, "(12)"),
+    (0x2480, Mapped, "(13)"),
+    (0x2481, Mapped, "(14)"),
+    (0x2482, Mapped, "(15)"),
+    (0x2483, Mapped, "(16)"),
+    (0x2484, Mapped, "(17)"),
+    (0x2485, Mapped, "(18)"),
+    (0x2486, Mapped, "(19)"),
+    (0x2487, Mapped, "(20)"),
+    (0x2488, Disallowed, ""),
+    (0x249c, Mapped, "(a)"),
+    (0x249d, Mapped, "(b)"),
+    (0x249e, Mapped, "(c)"),
+    (0x249f, Mapped, "(d)"),
+    (0x24a0, Mapped, "(e)"),
+    (0x24a1, Mapped, "(f)"),
+    (0x24a2, Mapped, "(g)"),
+    (0x24a3, Mapped, "(h)"),
+    (0x24a4, Mapped, "(i)"),
+    (0x24a5, Mapped, "(j)"),
+    (0x24a6, Mapped, "(k)"),
+    (0x24a7, Mapped, "(l)"),
+    (0x24a8, Mapped, "(m)"),
+    (0x24a9, Mapped, "(n)"),
+    (0x24aa, Mapped, "(o)"),
+    (0x24ab, Mapped, "(p)"),
+    (0x24ac, Mapped, "(q)"),
+    (0x24ad, Mapped, "(r)"),
+    (0x24ae, Mapped, "(s)"),
+    (0x24af, Mapped, "(t)"),
+    (0x24b0, Mapped, "(u)"),
+    (0x24b1, Mapped, "(v)"),
+    (0x24b2, Mapped, "(w)"),
+    (0x24b3, Mapped, "(x)"),
+    (0x24b4, Mapped, "(y)"),
+    (0x24b5, Mapped, "(z)"),
+    (0x24b6, Mapped, "a"),
+    (0x24b7, Mapped, "b"),
+    (0x24b8, Mapped, "c"),
+    (0x24b9, Mapped, "d"),
+    (0x24ba, Mapped, "e"),
+    (0x24bb, Mapped, "f"),
+    (0x24bc, Mapped, "g"),
+    (0x24bd, Mapped, "h"),
+    (0x24be, Mapped, "i"),
+    (0x24bf, Mapped, "j"),
+    (0x24c0, Mapped, "k"),
+    (0x24c1, Mapped, "l"),
+    (0x24c2, Mapped, "m"),
+    (0x24c3, Mapped, "n"),
+    (0x24c4, Mapped, "o"),
+    (0x24c5, Mapped, "p"),
+    (0x24c6, Mapped, "q"),
+    (0x24c7, Mapped, "r"),
+    (0x24c8, Mapped, "s"),
+    (0x24c9, Mapped, "t"),
+    (0x24ca, Mapped, "u"),
+    (0x24cb, Mapped, "v"),
+    (0x24cc, Mapped, "w"),
+    (0x24cd, Mapped, "x"),
+    (0x24ce, Mapped, "y"),
+    (0x24cf, Mapped, "z"),
+    (0x24d0, Mapped, "a"),
+    (0x24d1, Mapped, "b"),
+    (0x24d2, Mapped, "c"),
+    (0x24d3, Mapped, "d"),
+    (0x24d4, Mapped, "e"),
+    (0x24d5, Mapped, "f"),
+    (0x24d6, Mapped, "g"),
+    (0x24d7, Mapped, "h"),
+    (0x24d8, Mapped, "i"),
+    (0x24d9, Mapped, "j"),
+    (0x24da, Mapped, "k"),
+    (0x24db, Mapped, "l"),
+    (0x24dc, Mapped, "m"),
+    (0x24dd, Mapped, "n"),
+    (0x24de, Mapped, "o"),
+    (0x24df, Mapped, "p"),
+    (0x24e0, Mapped, "q"),
+    (0x24e1, Mapped, "r"),
+    (0x24e2, Mapped, "s"),
+    (0x24e3, Mapped, "t"),
+    (0x24e4, Mapped, "u"),
+    (0x24e5, Mapped, "v"),
+    (0x24e6, Mapped, "w"),
+    (0x24e7, Mapped, "x"),
+    (0x24e8, Mapped, "y"),
+    (0x24e9, Mapped, "z"),
+    (0x24ea, Mapped, "0"),
+    (0x24eb, Valid, ""),
+    (0x2a0c, Mapped, "\u222b\u222b\u222b\u222b"),
+    (0x2a0d, Valid, ""),
+    (0x2a74, Mapped, "::="),
+    (0x2a75, Mapped, "=="),
+    (0x2a76, Mapped, "==="),
+    (0x2a77, Valid, ""),
+    (0x2adc, Mapped, "\u2add\u0338"),
+    (0x2add, Valid, ""),
+    (0x2b74, Disallowed, ""),
+    (0x2b76, Valid, ""),
+    (0x2c00, Mapped, "\u2c30"),
+    (0x2c01, Mapped, "\u2c31"),
+    (0x2c02, Mapped, "\u2c32"),
+    (0x2c03, Mapped, "\u2c33"),
+    (0x2c04, Mapped, "\u2c34"),
+    (0x2c05, Mapped, "\u2c35"),
+    (0x2c06, Mapped, "\u2c36"),
+    (0x2c07, Mapped, "\u2c37"),
+    (0x2c08, Mapped, "\u2c38"),
+    (0x2c09, Mapped, "\u2c39"),
+    (0x2c0a, Mapped, "\u2c3a"),
+    (0x2c0b, Mapped, "\u2c3b"),
+    (0x2c0c, Mapped, "\u2c3c"),
+    (0x2c0d, Mapped, "\u2c3d"),
+    (0x2c0e, Mapped, "\u2c3e"),
+    (0x2c0f, Mapped, "\u2c3f"),
+    (0x2c10, Mapped, "\u2c40"),
+    (0x2c11, Mapped, "\u2c41"),
+    (0x2c12, Mapped, "\u2c42"),
+    (0x2c13, Mapped, "\u2c43"),
+    (0x2c14, Mapped, "\u2c44"),
+    (0x2c15, Mapped, "\u2c45"),
+    (0x2c16, Mapped, "\u2c46"),
+    (0x2c17, Mapped, "\u2c47"),
+    (0x2c18, Mapped, "\u2c48"),
+    (0x2c19, Mapped, "\u2c49"),
+    (0x2c1a, Mapped, "\u2c4a"),
+    (0x2c1b, Mapped, "\u2c4b"),
+    (0x2c1c, Mapped, "\u2c4c"),
+    (0x2c1d, Mapped, "\u2c4d"),
+    (0x2c1e, Mapped, "\u2c4e"),
+    (0x2c1f, Mapped, "\u2c4f"),
+    (0x2c20, Mapped, "\u2c50"),
+    (0x2c21, Mapped, "\u2c51"),
+    (0x2c22, Mapped, "\u2c52"),
+    (0x2c23, Mapped, "\u2c53"),
+    (0x2c24, Mapped, "\u2c54"),
+    (0x2c25, Mapped, "\u2c55"),
+    (0x2c26, Mapped, "\u2c56"),
+    (0x2c27, Mapped, "\u2c57"),
+    (0x2c28, Mapped, "\u2c58"),
+    (0x2c29, Mapped, "\u2c59"),
+    (0x2c2a, Mapped, "\u2c5a"),
+    (0x2c2b, Mapped, "\u2c5b"),
+    (0x2c2c, Mapped, "\u2c5c"),
+    (0x2c2d, Mapped, "\u2c5d"),
+    (0x2c2e, Mapped, "\u2c5e"),
+    (0x2c2f, Mapped, "\u2c5f"),
+    (0x2c30, Valid, ""),
+    (0x2c60, Mapped, "\u2c61"),
+    (0x2c61, Valid, ""),
+    (0x2c62, Mapped, "\u026b"),
+    (0x2c63, Mapped, "\u1d7d"),
+    (0x2c64, Mapped, "\u027d"),
+    (0x2c65, Valid, ""),
+    (0x2c67, Mapped, "\u2c68"),
+    (0x2c68, Valid, ""),
+    (0x2c69, Mapped, "\u2c6a"),
+    (0x2c6a, Valid, ""),
+    (0x2c6b, Mapped, "\u2c6c"),
+    (0x2c6c, Valid, ""),
+    (0x2c6d, Mapped, "\u0251"),
+    (0x2c6e, Mapped, "\u0271"),
+    (0x2c6f, Mapped, "\u0250"),
+    (0x2c70, Mapped, "\u0252"),
+    (0x2c71, Valid, ""),
+    (0x2c72, Mapped, "\u2c73"),
+    (0x2c73, Valid, ""),
+    (0x2c75, Mapped, "\u2c76"),
+    (0x2c76, Valid, ""),
+    (0x2c7c, Mapped, "j"),
+    (0x2c7d, Mapped, "v"),
+    (0x2c7e, Mapped, "\u023f"),
+    (0x2c7f, Mapped, "\u0240"),
+    (0x2c80, Mapped, "\u2c81"),
+    (0x2c81, Valid, ""),
+    (0x2c82, Mapped, "\u2c83"),
+    (0x2c83, Valid, ""),
+    (0x2c84, Mapped, "\u2c85"),
+    (0x2c85, Valid, ""),
+    (0x2c86, Mapped, "\u2c87"),
+    (0x2c87, Valid, ""),
+    (0x2c88, Mapped, "\u2c89"),
+    (0x2c89, Valid, ""),
+    (0x2c8a, Mapped, "\u2c8b"),
+    (0x2c8b, Valid, ""),
+    (0x2c8c, Mapped, "\u2c8d"),
+    (0x2c8d, Valid, ""),
+    (0x2c8e, Mapped, "\u2c8f"),
+    (0x2c8f, Valid, ""),
+    (0x2c90, Mapped, "\u2c91"),
+    (0x2c91, Valid, ""),
+    (0x2c92, Mapped, "\u2c93"),
+    (0x2c93, Valid, ""),
+    (0x2c94, Mapped, "\u2c95"),
+    (0x2c95, Valid, ""),
+    (0x2c96, Mapped, "\u2c97"),
+    (0x2c97, Valid, ""),
+    (0x2c98, Mapped, "\u2c99"),
+    (0x2c99, Valid, ""),
+    (0x2c9a, Mapped, "\u2c9b"),
+    (0x2c9b, Valid, ""),
+    (0x2c9c, Mapped, "\u2c9d"),
+    (0x2c9d, Valid, ""),
+    (0x2c9e, Mapped, "\u2c9f"),
+    (0x2c9f, Valid, ""),
+    (0x2ca0, Mapped, "\u2ca1"),
+    (0x2ca1, Valid, ""),
+    (0x2ca2, Mapped, "\u2ca3"),
+    (0x2ca3, Valid, ""),
+    (0x2ca4, Mapped, "\u2ca5"),
+    (0x2ca5, Valid, ""),
+    (0x2ca6, Mapped, "\u2ca7"),
+    (0x2ca7, Valid, ""),
+    (0x2ca8, Mapped, "\u2ca9"),
+    (0x2ca9, Valid, ""),
+    (0x2caa, Mapped, "\u2cab"),
+    (0x2cab, Valid, ""),
+    (0x2cac, Mapped, "\u2cad"),
+    (0x2cad, Valid, ""),
+    (0x2cae, Mapped, "\u2caf"),
+    (0x2caf, Valid, ""),
+    (0x2cb0, Mapped, "\u2cb1"),
+    (0x2cb1, Valid, ""),
+    (0x2cb2, Mapped, "\u2cb3"),
+    (0x2cb3, Valid, ""),
+    (0x2cb4, Mapped, "\u2cb5"),
+    (0x2cb5, Valid, ""),
+    (0x2cb6, Mapped, "\u2cb7"),
+    (0x2cb7, Valid, ""),
+    (0x2cb8, Mapped, "\u2cb9"),
+    (0x2cb9, Valid, ""),
+    (0x2cba, Mapped, "\u2cbb"),
+    (0x2cbb, Valid, ""),
+    (0x2cbc, Mapped, "\u2cbd"),
+    (0x2cbd, Valid, ""),
+    (0x2cbe, Mapped, "\u2cbf"),
+    (0x2cbf, Valid, ""),
+    (0x2cc0, Mapped, "\u2cc1"),
+    (0x2cc1, Valid, ""),
+    (0x2cc2, Mapped, "\u2cc3"),
+    (0x2cc3, Valid, ""),
+    (0x2cc4, Mapped, "\u2cc5"),
+    (0x2cc5, Valid, ""),
+    (0x2cc6, Mapped, "\u2cc7"),
+    (0x2cc7, Valid, ""),
+    (0x2cc8, Mapped, "\u2cc9"),
+    (0x2cc9, Valid, ""),
+    (0x2cca, Mapped, "\u2ccb"),
+    (0x2ccb, Valid, ""),
+    (0x2ccc, Mapped, "\u2ccd"),
+    (0x2ccd, Valid, ""),
+    (0x2cce, Mapped, "\u2ccf"),
+    (0x2ccf, Valid, ""),
+    (0x2cd0, Mapped, "\u2cd1"),
+    (0x2cd1, Valid, ""),
+    (0x2cd2, Mapped, "\u2cd3"),
+    (0x2cd3, Valid, ""),
+    (0x2cd4, Mapped, "\u2cd5"),
+    (0x2cd5, Valid, ""),
+    (0x2cd6, Mapped, "\u2cd7"),
+    (0x2cd7, Valid, ""),
+    (0x2cd8, Mapped, "\u2cd9"),
+    (0x2cd9, Valid, ""),
+    (0x2cda, Mapped, "\u2cdb"),
+    (0x2cdb, Valid, ""),
+    (0x2cdc, Mapped, "\u2cdd"),
+    (0x2cdd, Valid, ""),
+    (0x2cde, Mapped, "\u2cdf"),
+    (0x2cdf, Valid, ""),
+    (0x2ce0, Mapped, "\u2ce1"),
+    (0x2ce1, Valid, ""),
+    (0x2ce2, Mapped, "\u2ce3"),
+    (0x2ce3, Valid, ""),
+    (0x2ceb, Mapped, "\u2cec"),
+    (0x2cec, Valid, ""),
+    (0x2ced, Mapped, "\u2cee"),
+    (0x2cee, Valid, ""),
+    (0x2cf2, Mapped, "\u2cf3"),
+    (0x2cf3, Valid, ""),
+    (0x2cf4, Disallowed, ""),
+    (0x2cf9, Valid, ""),
+    (0x2d26, Disallowed, ""),
+    (0x2d27, Valid, ""),
+    (0x2d28, Disallowed, ""),
+    (0x2d2d, Valid, ""),
+    (0x2d2e, Disallowed, ""),
+    (0x2d30, Valid, ""),
+    (0x2d68, Disallowed, ""),
+    (0x2d6f, Mapped, "\u2d61"),
+    (0x2d70, Valid, ""),
+    (0x2d71, Disallowed, ""),
+    (0x2d7f, Valid, ""),
+    (0x2d97, Disallowed, ""),
+    (0x2da0, Valid, ""),
+    (0x2da7, Disallowed, ""),
+    (0x2da8, Valid, ""),
+    (0x2daf, Disallowed, ""),
+    (0x2db0, Valid, ""),
+    (0x2db7, Disallowed, ""),
+    (0x2db8, Valid, ""),
+    (0x2dbf, Disallowed, ""),
+    (0x2dc0, Valid, ""),
+    (0x2dc7, Disallowed, ""),
+    (0x2dc8, Valid, ""),
+    (0x2dcf, Disallowed, ""),
+    (0x2dd0, Valid, ""),
+    (0x2dd7, Disallowed, ""),
+    (0x2dd8, Valid, ""),
+    (0x2ddf, Disallowed, ""),
+    (0x2de0, Valid, ""),
+    (0x2e5e, Disallowed, ""),
+    (0x2e80, Valid, ""),
+    (0x2e9a, Disallowed, ""),
+    (0x2e9b, Valid, ""),
+    (0x2e9f, Mapped, "\u6bcd"),
+    (0x2ea0, Valid, ""),
+    (0x2ef3, Mapped, "\u9f9f"),
+    (0x2ef4, Disallowed, ""),
+    (0x2f00, Mapped, "\u4e00"),
+    (0x2f01, Mapped, "\u4e28"),
+    (0x2f02, Mapped, "\u4e36"),
+    (0x2f03, Mapped, "\u4e3f"),
+    (0x2f04, Mapped, "\u4e59"),
+    (0x2f05, Mapped, "\u4e85"),
+    (0x2f06, Mapped, "\u4e8c"),
+    (0x2f07, Mapped, "\u4ea0"),
+    (0x2f08, Mapped, "\u4eba"),
+    (0x2f09, Mapped, "\u513f"),
+    (0x2f0a, Mapped, "\u5165"),
+    (0x2f0b, Mapped, "\u516b"),
+    (0x2f0c, Mapped, "\u5182"),
+    (0x2f0d, Mapped, "\u5196"),
+    (0x2f0e, Mapped, "\u51ab"),
+    (0x2f0f, Mapped, "\u51e0"),
+    (0x2f10, Mapped, "\u51f5"),
+    (0x2f11, Mapped, "\u5200"),
+    (0x2f12, Mapped, "\u529b"),
+    (0x2f13, Mapped, "\u52f9"),
+    (0x2f14, Mapped, "\u5315"),
+    (0x2f15, Mapped, "\u531a"),
+    (0x2f16, Mapped, "\u5338"),
+    (0x2f17, Mapped, "\u5341"),
+    (0x2f18, Mapped, "\u535c"),
+    (0x2f19, Mapped, "\u5369"),
+    (0x2f1a, Mapped, "\u5382"),
+    (0x2f1b, Mapped, "\u53b6"),
+    (0x2f1c, Mapped, "\u53c8"),
+    (0x2f1d, Mapped, "\u53e3"),
+    (0x2f1e, Mapped, "\u56d7"),
+    (0x2f1f, Mapped, "\u571f"),
+    (0x2f20, Mapped, "\u58eb"),
+    (0x2f21, Mapped, "\u5902"),
+    (0x2f22, Mapped, "\u590a"),
+    (0x2f23, Mapped, "\u5915"),
+    (0x2f24, Mapped, "\u5927"),
+    (0x2f25, Mapped, "\u5973"),
+    (0x2f26, Mapped, "\u5b50"),
+    (0x2f27, Mapped, "\u5b80"),
+    (0x2f28, Mapped, "\u5bf8"),
+    (0x2f29, Mapped, "\u5c0f"),
+    (0x2f2a, Mapped, "\u5c22"),
+    (0x2f2b, Mapped, "\u5c38"),
+    (0x2f2c, Mapped, "\u5c6e"),
+    (0x2f2d, Mapped, "\u5c71"),
+    (0x2f2e, Mapped, "\u5ddb"),
+    (0x2f2f, Mapped, "\u5de5"),
+    (0x2f30, Mapped, "\u5df1"),
+    (0x2f31, Mapped, "\u5dfe"),
+    (0x2f32, Mapped, "\u5e72"),
+    (0x2f33, Mapped, "\u5e7a"),
+    (0x2f34, Mapped, "\u5e7f"),
+    (0x2f35, Mapped, "\u5ef4"),
+    (0x2f36, Mapped, "\u5efe"),
+    (0x2f37, Mapped, "\u5f0b"),
+    (0x2f38, Mapped, "\u5f13"),
+    (0x2f39, Mapped, "\u5f50"),
+    (0x2f3a, Mapped, "\u5f61"),
+    (0x2f3b, Mapped, "\u5f73"),
+    (0x2f3c, Mapped, "\u5fc3"),
+    (0x2f3d, Mapped, "\u6208"),
+    (0x2f3e, Mapped, "\u6236"),
+    (0x2f3f, Mapped, "\u624b"),
+    (0x2f40, Mapped, "\u652f"),
+    (0x2f41, Mapped, "\u6534"),
+    (0x2f42, Mapped, "\u6587"),
+    (0x2f43, Mapped, "\u6597"),
+    (0x2f44, Mapped, "\u65a4"),
+    (0x2f45, Mapped, "\u65b9"),
+    (0x2f46, Mapped, "\u65e0"),
+    (0x2f47, Mapped, "\u65e5"),
+    (0x2f48, Mapped, "\u66f0"),
+    (0x2f49, Mapped, "\u6708"),
+    (0x2f4a, Mapped, "\u6728"),
+    (0x2f4b, Mapped, "\u6b20"),
+    (0x2f4c, Mapped, "\u6b62"),
+    (0x2f4d, Mapped, "\u6b79"),
+    (0x2f4e, Mapped, "\u6bb3"),
+    (0x2f4f, Mapped, "\u6bcb"),
+    (0x2f50, Mapped, "\u6bd4"),
+    (0x2f51, Mapped, "\u6bdb"),
+    (0x2f52, Mapped, "\u6c0f"),
+    (0x2f53, Mapped, "\u6c14"),
+    (0x2f54, Mapped, "\u6c34"),
+    (0x2f55, Mapped, "\u706b"),
+    (0x2f56, Mapped, "\u722a"),
+    (0x2f57, Mapped, "\u7236"),
+    (0x2f58, Mapped, "\u723b"),
+    (0x2f59, Mapped, "\u723f"),
+    (0x2f5a, Mapped, "\u7247"),
+    (0x2f5b, Mapped, "\u7259"),
+    (0x2f5c, Mapped, "\u725b"),
+    (0x2f5d, Mapped, "\u72ac"),
+    (0x2f5e, Mapped, "\u7384"),
+    (0x2f5f, Mapped, "\u7389"),
+    (0x2f60, Mapped, "\u74dc"),
+    (0x2f61, Mapped, "\u74e6"),
+    (0x2f62, Mapped, "\u7518"),
+    (0x2f63, Mapped, "\u751f"),
+    (0x2f64, Mapped, "\u7528"),
+    (0x2f65, Mapped, "\u7530"),
+    (0x2f66, Mapped, "\u758b"),
+    (0x2f67, Mapped, "\u7592"),
+    (0x2f68, Mapped, "\u7676"),
+    (0x2f69, Mapped, "\u767d"),
+    (0x2f6a, Mapped, "\u76ae"),
+    (0x2f6b, Mapped, "\u76bf"),
+    (0x2f6c, Mapped, "\u76ee"),
+    (0x2f6d, Mapped, "\u77db"),
+    (0x2f6e, Mapped, "\u77e2"),
+    (0x2f6f, Mapped, "\u77f3"),
+    (0x2f70, Mapped, "\u793a"),
+    (0x2f71, Mapped, "\u79b8"),
+    (0x2f72, Mapped, "\u79be"),
+    (0x2f73, Mapped, "\u7a74"),
+    (0x2f74, Mapped, "\u7acb"),
+    (0x2f75, Mapped, "\u7af9"),
+    (0x2f76, Mapped, "\u7c73"),
+    (0x2f77, Mapped, "\u7cf8"),
+    (0x2f78, Mapped, "\u7f36"),
+    (0x2f79, Mapped, "\u7f51"),
+    (0x2f7a, Mapped, "\u7f8a"),
+    (0x2f7b, Mapped, "\u7fbd"),
+    (0x2f7c, Mapped, "\u8001"),
+    (0x2f7d, Mapped, "\u800c"),
+    (0x2f7e, Mapped, "\u8012"),
+    (0x2f7f, Mapped, "\u8033"),
+    (0x2f80, Mapped, "\u807f"),
+    (0x2f81, Mapped, "\u8089"),
+    (0x2f82, Mapped, "\u81e3"),
+    (0x2f83, Mapped, "\u81ea"),
+    (0x2f84, Mapped, "\u81f3"),
+    (0x2f85, Mapped, "\u81fc"),
+    (0x2f86, Mapped, "\u820c"),
+    (0x2f87, Mapped, "\u821b"),
+    (0x2f88, Mapped, "\u821f"),
+    (0x2f89, Mapped, "\u826e"),
+    (0x2f8a, Mapped, "\u8272"),
+    (0x2f8b, Mapped, "\u8278"),
+    (0x2f8c, Mapped, "\u864d"),
+    (0x2f8d, Mapped, "\u866b"),
+    (0x2f8e, Mapped, "\u8840"),
+    (0x2f8f, Mapped, "\u884c"),
+    (0x2f90, Mapped, "\u8863"),
+    (0x2f91, Mapped, "\u897e"),
+    (0x2f92, Mapped, "\u898b"),
+    (0x2f93, Mapped, "\u89d2"),
+    (0x2f94, Mapped, "\u8a00"),
+    (0x2f95, Mapped, "\u8c37"),
+    (0x2f96, Mapped, "\u8c46"),
+    (0x2f97, Mapped, "\u8c55"),
+    (0x2f98, Mapped, "\u8c78"),
+    (0x2f99, Mapped, "\u8c9d"),
+    (0x2f9a, Mapped, "\u8d64"),
+    (0x2f9b, Mapped, "\u8d70"),
+    (0x2f9c, Mapped, "\u8db3"),
+    (0x2f9d, Mapped, "\u8eab"),
+    (0x2f9e, Mapped, "\u8eca"),
+    (0x2f9f, Mapped, "\u8f9b"),
+    (0x2fa0, Mapped, "\u8fb0"),
+    (0x2fa1, Mapped, "\u8fb5"),
+    (0x2fa2, Mapped, "\u9091"),
+    (0x2fa3, Mapped, "\u9149"),
+    (0x2fa4, Mapped, "\u91c6"),
+    (0x2fa5, Mapped, "\u91cc"),
+    (0x2fa6, Mapped, "\u91d1"),
+    (0x2fa7, Mapped, "\u9577"),
+    (0x2fa8, Mapped, "\u9580"),
+    (0x2fa9, Mapped, "\u961c"),
+    (0x2faa, Mapped, "\u96b6"),
+    (0x2fab, Mapped, "\u96b9"),
+    (0x2fac, Mapped, "\u96e8"),
+    (0x2fad, Mapped, "\u9751"),
+    (0x2fae, Mapped, "\u975e"),
+    (0x2faf, Mapped, "\u9762"),
+    (0x2fb0, Mapped, "\u9769"),
+    (0x2fb1, Mapped, "\u97cb"),
+    (0x2fb2, Mapped, "\u97ed"),
+    (0x2fb3, Mapped, "\u97f3"),
+    (0x2fb4, Mapped, "\u9801"),
+    (0x2fb5, Mapped, "\u98a8"),
+    (0x2fb6, Mapped, "\u98db"),
+    (0x2fb7, Mapped, "\u98df"),
+    (0x2fb8, Mapped, "\u9996"),
+    (0x2fb9, Mapped, "\u9999"),
+    (0x2fba, Mapped, "\u99ac"),
+    (0x2fbb, Mapped, "\u9aa8"),
+    (0x2fbc, Mapped, "\u9ad8"),
+    (0x2fbd, Mapped, "\u9adf"),
+    (0x2fbe, Mapped, "\u9b25"),
+    (0x2fbf, Mapped, "\u9b2f"),
+    (0x2fc0, Mapped, "\u9b32"),
+    (0x2fc1, Mapped, "\u9b3c"),
+    (0x2fc2, Mapped, "\u9b5a"),
+    (0x2fc3, Mapped, "\u9ce5"),
+    (0x2fc4, Mapped, "\u9e75"),
+    (0x2fc5, Mapped, "\u9e7f"),
+    (0x2fc6, Mapped, "\u9ea5"),
+    (0x2fc7, Mapped, "\u9ebb"),
+    (0x2fc8, Mapped, "\u9ec3"),
+    (0x2fc9, Mapped, "\u9ecd"),
+    (0x2fca, Mapped, "\u9ed1"),
+    (0x2fcb, Mapped, "\u9ef9"),
+    (0x2fcc, Mapped, "\u9efd"),
+    (0x2fcd, Mapped, "\u9f0e"),
+    (0x2fce, Mapped, "\u9f13"),
+    (0x2fcf, Mapped, "\u9f20"),
+    (0x2fd0, Mapped, "\u9f3b"),
+    (0x2fd1, Mapped, "\u9f4a"),
+    (0x2fd2, Mapped, "\u9f52"),
+    (0x2fd3, Mapped, "\u9f8d"),
+    (0x2fd4, Mapped, "\u9f9c"),
+    (0x2fd5, Mapped, "\u9fa0"),
+    (0x2fd6, Disallowed, ""),
+    (0x3000, Mapped, " "),
+    (0x3001, Valid, ""),
+    (0x3002, Mapped, "."),
+    (0x3003, Valid, ""),
+    (0x3036, Mapped, "\u3012"),
+    (0x3037, Valid, ""),
+    (0x3038, Mapped, "\u5341"),
+    (0x3039, Mapped, "\u5344"),
+    (0x303a, Mapped, "\u5345"),
+    (0x303b, Valid, ""),
+    (0x3040, Disallowed, ""),
+    (0x3041, Valid, ""),
+    (0x3097, Disallowed, ""),
+    (0x3099, Valid, ""),
+    (0x309b, Mapped, " \u3099"),
+    (0x309c, Mapped, " \u309a"),
+    (0x309d, Valid, ""),
+    (0x309f, Mapped, "\u3088\u308a"),
+    (0x30a0, Valid, ""),
+    (0x30ff, Mapped, "\u30b3\u30c8"),
+    (0x3100, Disallowed, ""),
+    (0x3105, Valid, ""),
+    (0x3130, Disallowed, ""),
+    (0x3131, Mapped, "\u1100"),
+    (0x3132, Mapped, "\u1101"),
+    (0x3133, Mapped, "\u11aa"),
+    (0x3134, Mapped, "\u1102"),
+    (0x3135, Mapped, "\u11ac"),
+    (0x3136, Mapped, "\u11ad"),
+    (0x3137, Mapped, "\u1103"),
+    (0x3138, Mapped, "\u1104"),
+    (0x3139, Mapped, "\u1105"),
+    (0x313a, Mapped, "\u11b0"),
+    (0x313b, Mapped, "\u11b1"),
+    (0x313c, Mapped, "\u11b2"),
+    (0x313d, Mapped, "\u11b3"),
+    (0x313e, Mapped, "\u11b4"),
+    (0x313f, Mapped, "\u11b5"),
+    (0x3140, Mapped, "\u111a"),
+    (0x3141, Mapped, "\u1106"),
+    (0x3142, Mapped, "\u1107"),
+    (0x3143, Mapped, "\u1108"),
+    (0x3144, Mapped, "\u1121"),
+    (0x3145, Mapped, "\u1109"),
+    (0x3146, Mapped, "\u110a"),
+    (0x3147, Mapped, "\u110b"),
+    (0x3148, Mapped, "\u110c"),
+    (0x3149, Mapped, "\u110d"),
+    (0x314a, Mapped, "\u110e"),
+    (0x314b, Mapped, "\u110f"),
+    (0x314c, Mapped, "\u1110"),
+    (0x314d, Mapped, "\u1111"),
+    (0x314e, Mapped, "\u1112"),
+    (0x314f, Mapped, "\u1161"),
+    (0x3150, Mapped, "\u1162"),
+    (0x3151, Mapped, "\u1163"),
+    (0x3152, Mapped, "\u1164"),
+    (0x3153, Mapped, "\u1165"),
+    (0x3154, Mapped, "\u1166"),
+    (0x3155, Mapped, "\u1167"),
+    (0x3156, Mapped, "\u1168"),
+    (0x3157, Mapped, "\u1169"),
+    (0x3158, Mapped, "\u116a"),
+    (0x3159, Mapped, "\u116b"),
+    (0x315a, Mapped, "\u116c"),
+    (0x315b, Mapped, "\u116d"),
+    (0x315c, Mapped, "\u116e"),
+    (0x315d, Mapped, "\u116f"),
+    (0x315e, Mapped, "\u1170"),
+    (0x315f, Mapped, "\u1171"),
+    (0x3160, Mapped, "\u1172"),
+    (0x3161, Mapped, "\u1173"),
+    (0x3162, Mapped, "\u1174"),
+    (0x3163, Mapped, "\u1175"),
+    (0x3164, Ignored, ""),
+    (0x3165, Mapped, "\u1114"),
+    (0x3166, Mapped, "\u1115"),
+    (0x3167, Mapped, "\u11c7"),
+    (0x3168, Mapped, "\u11c8"),
+    (0x3169, Mapped, "\u11cc"),
+    (0x316a, Mapped, "\u11ce"),
+    (0x316b, Mapped, "\u11d3"),
+    (0x316c, Mapped, "\u11d7"),
+    (0x316d, Mapped, "\u11d9"),
+    (0x316e, Mapped, "\u111c"),
+    (0x316f, Mapped, "\u11dd"),
+    (0x3170, Mapped, "\u11df"),
+    (0x3171, Mapped, "\u111d"),
+    (0x3172, Mapped, "\u111e"),
+    (0x3173, Mapped, "\u1120"),
+    (0x3174, Mapped, "\u1122"),
+    (0x3175, Mapped, "\u1123"),
+    (0x3176, Mapped, "\u1127"),
+    (0x3177, Mapped, "\u1129"),
+    (0x3178, Mapped, "\u112b"),
+    (0x3179, Mapped, "\u112c"),
+    (0x317a, Mapped, "\u112d"),
+    (0x317b, Mapped, "\u112e"),
+    (0x317c, Mapped, "\u112f"),
+    (0x317d, Mapped, "\u1132"),
+    (0x317e, Mapped, "\u1136"),
+    (0x317f, Mapped, "\u1140"),
+    (0x3180, Mapped, "\u1147"),
+    (0x3181, Mapped, "\u114c"),
+    (0x3182, Mapped, "\u11f1"),
+    (0x3183, Mapped, "\u11f2"),
+    (0x3184, Mapped, "\u1157"),
+    (0x3185, Mapped, "\u1158"),
+    (0x3186, Mapped, "\u1159"),
+    (0x3187, Mapped, "\u1184"),
+    (0x3188, Mapped, "\u1185"),
+    (0x3189, Mapped, "\u1188"),
+    (0x318a, Mapped, "\u1191"),
+    (0x318b, Mapped, "\u1192"),
+    (0x318c, Mapped, "\u1194"),
+    (0x318d, Mapped, "\u119e"),
+    (0x318e, Mapped, "\u11a1"),
+    (0x318f, Disallowed, ""),
+    (0x3190, Valid, ""),
+    (0x3192, Mapped, "\u4e00"),
+    (0x3193, Mapped, "\u4e8c"),
+    (0x3194, Mapped, "\u4e09"),
+    (0x3195, Mapped, "\u56db"),
+    (0x3196, Mapped, "\u4e0a"),
+    (0x3197, Mapped, "\u4e2d"),
+    (0x3198, Mapped, "\u4e0b"),
+    (0x3199, Mapped, "\u7532"),
+    (0x319a, Mapped, "\u4e59"),
+    (0x319b, Mapped, "\u4e19"),
+    (0x319c, Mapped, "\u4e01"),
+    (0x319d, Mapped, "\u5929"),
+    (0x319e, Mapped, "\u5730"),
+    (0x319f, Mapped, "\u4eba"),
+    (0x31a0, Valid, ""),
+    (0x31e6, Disallowed, ""),
+    (0x31f0, Valid, ""),
+    (0x3200, Mapped, "(\u1100)"),
+    (0x3201, Mapped, "(\u1102)"),
+    (0x3202, Mapped, "(\u1103)"),
+    (0x3203, Mapped, "(\u1105)"),
+    (0x3204, Mapped, "(\u1106)"),
+    (0x3205, Mapped, "(\u1107)"),
+    (0x3206, Mapped, "(\u1109)"),
+    (0x3207, Mapped, "(\u110b)"),
+    (0x3208, Mapped, "(\u110c)"),
+    (0x3209, Mapped, "(\u110e)"),
+    (0x320a, Mapped, "(\u110f)"),
+    (0x320b, Mapped, "(\u1110)"),
+    (0x320c, Mapped, "(\u1111)"),
+    (0x320d, Mapped, "(\u1112)"),
+    (0x320e, Mapped, "(\uac00)"),
+    (0x320f, Mapped, "(\ub098)"),
+    (0x3210, Mapped, "(\ub2e4)"),
+    (0x3211, Mapped, "(\ub77c)"),
+    (0x3212, Mapped, "(\ub9c8)"),
+    (0x3213, Mapped, "(\ubc14)"),
+    (0x3214, Mapped, "(\uc0ac)"),
+    (0x3215, Mapped, "(\uc544)"),
+    (0x3216, Mapped, "(\uc790)"),
+    (0x3217, Mapped, "(\ucc28)"),
+    (0x3218, Mapped, "(\uce74)"),
+    (0x3219, Mapped, "(\ud0c0)"),
+    (0x321a, Mapped, "(\ud30c)"),
+    (0x321b, Mapped, "(\ud558)"),
+    (0x321c, Mapped, "(\uc8fc)"),
+    (0x321d, Mapped, "(\uc624\uc804)"),
+    (0x321e, Mapped, "(\uc624\ud6c4)"),
+    (0x321f, Disallowed, ""),
+    (0x3220, Mapped, "(\u4e00)"),
+    (0x3221, Mapped, "(\u4e8c)"),
+    (0x3222, Mapped, "(\u4e09)"),
+    (0x3223, Mapped, "(\u56db)"),
+    (0x3224, Mapped, "(\u4e94)"),
+    (0x3225, Mapped, "(\u516d)"),
+    (0x3226, Mapped, "(\u4e03)"),
+    (0x3227, Mapped, "(\u516b)"),
+    (0x3228, Mapped, "(\u4e5d)"),
+    (0x3229, Mapped, "(\u5341)"),
+    (0x322a, Mapped, "(\u6708)"),
+    (0x322b, Mapped, "(\u706b)"),
+    (0x322c, Mapped, "(\u6c34)"),
+    (0x322d, Mapped, "(\u6728)"),
+    (0x322e, Mapped, "(\u91d1)"),
+    (0x322f, Mapped, "(\u571f)"),
+    (0x3230, Mapped, "(\u65e5)"),
+    (0x3231, Mapped, "(\u682a)"),
+    (0x3232, Mapped, "(\u6709)"),
+    (0x3233, Mapped, "(\u793e)"),
+    (0x3234, Mapped, "(\u540d)"),
+    (0x3235, Mapped, "(\u7279)"),
+    (0x3236, Mapped, "(\u8ca1)"),
+    (0x3237, Mapped, "(\u795d)"),
+    (0x3238, Mapped, "(\u52b4)"),
+    (0x3239, Mapped, "(\u4ee3)"),
+    (0x323a, Mapped, "(\u547c)"),
+    (0x323b, Mapped, "(\u5b66)"),
+    (0x323c, Mapped, "(\u76e3)"),
+    (0x323d, Mapped, "(\u4f01)"),
+    (0x323e, Mapped, "(\u8cc7)"),
+    (0x323f, Mapped, "(\u5354)"),
+    (0x3240, Mapped, "(\u796d)"),
+    (0x3241, Mapped, "(\u4f11)"),
+    (0x3242, Mapped, "(\u81ea)"),
+    (0x3243, Mapped, "(\u81f3)"),
+    (0x3244, Mapped, "\u554f"),
+    (0x3245, Mapped, "\u5e7c"),
+    (0x3246, Mapped, "\u6587"),
+    (0x3247, Mapped, "\u7b8f"),
+    (0x3248, Valid, ""),
+    (0x3250, Mapped, "pte"),
+    (0x3251, Mapped, "21"),
+    (0x3252, Mapped, "22"),
+    (0x3253, Mapped, "23"),
+    (0x3254, Mapped, "24"),
+    (0x3255, Mapped, "25"),
+    (0x3256, Mapped, "26"),
+    (0x3257, Mapped, "27"),
+    (0x3258, Mapped, "28"),
+    (0x3259, Mapped, "29"),
+    (0x325a, Mapped, "30"),
+    (0x325b, Mapped, "31"),
+    (0x325c, Mapped, "32"),
+    (0x325d, Mapped, "33"),
+    (0x325e, Mapped, "34"),
+    (0x325f, Mapped, "35"),
+    (0x3260, Mapped, "\u1100"),
+    (0x3261, Mapped, "\u1102"),
+    (0x3262, Mapped, "\u1103"),
+    (0x3263, Mapped, "\u1105"),
+    (0x3264, Mapped, "\u1106"),
+    (0x3265, Mapped, "\u1107"),
+    (0x3266, Mapped, "\u1109"),
+    (0x3267, Mapped, "\u110b"),
+    (0x3268, Mapped, "\u110c"),
+    (0x3269, Mapped, "\u110e"),
+    (0x326a, Mapped, "\u110f"),
+    (0x326b, Mapped, "\u1110"),
+    (0x326c, Mapped, "\u1111"),
+    (0x326d, Mapped, "\u1112"),
+    (0x326e, Mapped, "\uac00"),
+    (0x326f, Mapped, "\ub098"),
+    (0x3270, Mapped, "\ub2e4"),
+    (0x3271, Mapped, "\ub77c"),
+    (0x3272, Mapped, "\ub9c8"),
+    (0x3273, Mapped, "\ubc14"),
+    (0x3274, Mapped, "\uc0ac"),
+    (0x3275, Mapped, "\uc544"),
+    (0x3276, Mapped, "\uc790"),
+    (0x3277, Mapped, "\ucc28"),
+    (0x3278, Mapped, "\uce74"),
+    (0x3279, Mapped, "\ud0c0"),
+    (0x327a, Mapped, "\ud30c"),
+    (0x327b, Mapped, "\ud558"),
+    (0x327c, Mapped, "\ucc38\uace0"),
+    (0x327d, Mapped, "\uc8fc\uc758"),
+    (0x327e, Mapped, "\uc6b0"),
+    (0x327f, Valid, ""),
+    (0x3280, Mapped, "\u4e00"),
+    (0x3281, Mapped, "\u4e8c"),
+    (0x3282, Mapped, "\u4e09"),
+    (0x3283, Mapped, "\u56db"),
+    (0x3284, Mapped, "\u4e94"),
+    (0x3285, Mapped, "\u516d"),
+    (0x3286, Mapped, "\u4e03"),
+    (0x3287, Mapped, "\u516b"),
+    (0x3288, Mapped, "\u4e5d"),
+    (0x3289, Mapped, "\u5341"),
+    (0x328a, Mapped, "\u6708"),
+    (0x328b, Mapped, "\u706b"),
+    (0x328c, Mapped, "\u6c34"),
+    (0x328d, Mapped, "\u6728"),
+    (0x328e, Mapped, "\u91d1"),
+    (0x328f, Mapped, "\u571f"),
+    (0x3290, Mapped, "\u65e5"),
+    (0x3291, Mapped, "\u682a"),
+    (0x3292, Mapped, "\u6709"),
+    (0x3293, Mapped, "\u793e"),
+    (0x3294, Mapped, "\u540d"),
+    (0x3295, Mapped, "\u7279"),
+    (0x3296, Mapped, "\u8ca1"),
+    (0x3297, Mapped, "\u795d"),
+    (0x3298, Mapped, "\u52b4"),
+    (0x3299, Mapped, "\u79d8"),
+    (0x329a, Mapped, "\u7537"),
+    (0x329b, Mapped, "\u5973"),
+    (0x329c, Mapped, "\u9069"),
+    (0x329d, Mapped, "\u512a"),
+    (0x329e, Mapped, "\u5370"),
+    (0x329f, Mapped, "\u6ce8"),
+    (0x32a0, Mapped, "\u9805"),
+    (0x32a1, Mapped, "\u4f11"),
+    (0x32a2, Mapped, "\u5199"),
+    (0x32a3, Mapped, "\u6b63"),
+    (0x32a4, Mapped, "\u4e0a"),
+    (0x32a5, Mapped, "\u4e2d"),
+    (0x32a6, Mapped, "\u4e0b"),
+    (0x32a7, Mapped, "\u5de6"),
+    (0x32a8, Mapped, "\u53f3"),
+    (0x32a9, Mapped, "\u533b"),
+    (0x32aa, Mapped, "\u5b97"),
+    (0x32ab, Mapped, "\u5b66"),
+    (0x32ac, Mapped, "\u76e3"),
+    (0x32ad, Mapped, "\u4f01"),
+    (0x32ae, Mapped, "\u8cc7"),
+    (0x32af, Mapped, "\u5354"),
+    (0x32b0, Mapped, "\u591c"),
+    (0x32b1, Mapped, "36"),
+    (0x32b2, Mapped, "37"),
+    (0x32b3, Mapped, "38"),
+    (0x32b4, Mapped, "39"),
+    (0x32b5, Mapped, "40"),
+    (0x32b6, Mapped, "41"),
+    (0x32b7, Mapped, "42"),
+    (0x32b8, Mapped, "43"),
+    (0x32b9, Mapped, "44"),
+    (0x32ba, Mapped, "45"),
+    (0x32bb, Mapped, "46"),
+    (0x32bc, Mapped, "47"),
+    (0x32bd, Mapped, "48"),
+    (0x32be, Mapped, "49"),
+    (0x32bf, Mapped, "50"),
+    (0x32c0, Mapped, "1\u6708"),
+    (0x32c1, Mapped, "2\u6708"),
+    (0x32c2, Mapped, "3\u6708"),
+    (0x32c3, Mapped, "4\u6708"),
+    (0x32c4, Mapped, "5\u6708"),
+    (0x32c5, Mapped, "6\u6708"),
+    (0x32c6, Mapped, "7\u6708"),
+    (0x32c7, Mapped, "8\u6708"),
+    (0x32c8, Mapped, "9\u6708"),
+    (0x32c9, Mapped, "10\u6708"),
+    (0x32ca, Mapped, "11\u6708"),
+    (0x32cb, Mapped, "12\u6708"),
+    (0x32cc, Mapped, "hg"),
+    (0x32cd, Mapped, "erg"),
+    (0x32ce, Mapped, "ev"),
+    (0x32cf, Mapped, "ltd"),
+    (0x32d0, Mapped, "\u30a2"),
+    (0x32d1, Mapped, "\u30a4"),
+    (0x32d2, Mapped, "\u30a6"),
+    (0x32d3, Mapped, "\u30a8"),
+    (0x32d4, Mapped, "\u30aa"),
+    (0x32d5, Mapped, "\u30ab"),
+    (0x32d6, Mapped, "\u30ad"),
+    (0x32d7, Mapped, "\u30af"),
+    (0x32d8, Mapped, "\u30b1"),
+    (0x32d9, Mapped, "\u30b3"),
+    (0x32da, Mapped, "\u30b5"),
+    (0x32db, Mapped, "\u30b7"),
+    (0x32dc, Mapped, "\u30b9"),
+    (0x32dd, Mapped, "\u30bb"),
+    (0x32de, Mapped, "\u30bd"),
+    (0x32df, Mapped, "\u30bf"),
+    (0x32e0, Mapped, "\u30c1"),
+    (0x32e1, Mapped, "\u30c4"),
+    (0x32e2, Mapped, "\u30c6"),
+    (0x32e3, Mapped, "\u30c8"),
+    (0x32e4, Mapped, "\u30ca"),
+    (0x32e5, Mapped, "\u30cb"),
+    (0x32e6, Mapped, "\u30cc"),
+    (0x32e7, Mapped, "\u30cd"),
+    (0x32e8, Mapped, "\u30ce"),
+    (0x32e9, Mapped, "\u30cf"),
+    (0x32ea, Mapped, "\u30d2"),
+    (0x32eb, Mapped, "\u30d5"),
+    (0x32ec, Mapped, "\u30d8"),
+    (0x32ed, Mapped, "\u30db"),
+    (0x32ee, Mapped, "\u30de"),
+    (0x32ef, Mapped, "\u30df"),
+    (0x32f0, Mapped, "\u30e0"),
+    (0x32f1, Mapped, "\u30e1"),
+    (0x32f2, Mapped, "\u30e2"),
+    (0x32f3, Mapped, "\u30e4"),
+    (0x32f4, Mapped, "\u30e6"),
+    (0x32f5, Mapped, "\u30e8"),
+    (0x32f6, Mapped, "\u30e9"),
+    (0x32f7, Mapped, "\u30ea"),
+    (0x32f8, Mapped, "\u30eb"),
+    (0x32f9, Mapped, "\u30ec"),
+    (0x32fa, Mapped, "\u30ed"),
+    (0x32fb, Mapped, "\u30ef"),
+    (0x32fc, Mapped, "\u30f0"),
+    (0x32fd, Mapped, "\u30f1"),
+    (0x32fe, Mapped, "\u30f2"),
+    (0x32ff, Mapped, "\u4ee4\u548c"),
+    (0x3300, Mapped, "\u30a2\u30d1\u30fc\u30c8"),
+    (0x3301, Mapped, "\u30a2\u30eb\u30d5\u30a1"),
+    (0x3302, Mapped, "\u30a2\u30f3\u30da\u30a2"),
+    (0x3303, Mapped, "\u30a2\u30fc\u30eb"),
+    (0x3304, Mapped, "\u30a4\u30cb\u30f3\u30b0"),
+    (0x3305, Mapped, "\u30a4\u30f3\u30c1"),
+    (0x3306, Mapped, "\u30a6\u30a9\u30f3"),
+    (0x3307, Mapped, "\u30a8\u30b9\u30af\u30fc\u30c9"),
+    (0x3308, Mapped, "\u30a8\u30fc\u30ab\u30fc"),
+    (0x3309, Mapped, "\u30aa\u30f3\u30b9"),
+    (0x330a, Mapped, "\u30aa\u30fc\u30e0"),
+    (0x330b, Mapped, "\u30ab\u30a4\u30ea"),
+    (0x330c, Mapped, "\u30ab\u30e9\u30c3\u30c8"),
+    (0x330d, Mapped, "\u30ab\u30ed\u30ea\u30fc"),
+    (0x330e, Mapped, "\u30ac\u30ed\u30f3"),
+    (0x330f, Mapped, "\u30ac\u30f3\u30de"),
+    (0x3310, Mapped, "\u30ae\u30ac"),
+    (0x3311, Mapped, "\u30ae\u30cb\u30fc"),
+    (0x3312, Mapped, "\u30ad\u30e5\u30ea\u30fc"),
+    (0x3313, Mapped, "\u30ae\u30eb\u30c0\u30fc"),
+    (0x3314, Mapped, "\u30ad\u30ed"),
+    (0x3315, Mapped, "\u30ad\u30ed\u30b0\u30e9\u30e0"),
+    (0x3316, Mapped, "\u30ad\u30ed\u30e1\u30fc\u30c8\u30eb"),
+    (0x3317, Mapped, "\u30ad\u30ed\u30ef\u30c3\u30c8"),
+    (0x3318, Mapped, "\u30b0\u30e9\u30e0"),
+    (0x3319, Mapped, "\u30b0\u30e9\u30e0\u30c8\u30f3"),
+    (0x331a, Mapped, "\u30af\u30eb\u30bc\u30a4\u30ed"),
+    (0x331b, Mapped, "\u30af\u30ed\u30fc\u30cd"),
+    (0x331c, Mapped, "\u30b1\u30fc\u30b9"),
+    (0x331d, Mapped, "\u30b3\u30eb\u30ca"),
+    (0x331e, Mapped, "\u30b3\u30fc\u30dd"),
+    (0x331f, Mapped, "\u30b5\u30a4\u30af\u30eb"),
+    (0x3320, Mapped, "\u30b5\u30f3\u30c1\u30fc\u30e0"),
+    (0x3321, Mapped, "\u30b7\u30ea\u30f3\u30b0"),
+    (0x3322, Mapped, "\u30bb\u30f3\u30c1"),
+    (0x3323, Mapped, "\u30bb\u30f3\u30c8"),
+    (0x3324, Mapped, "\u30c0\u30fc\u30b9"),
+    (0x3325, Mapped, "\u30c7\u30b7"),
+    (0x3326, Mapped, "\u30c9\u30eb"),
+    (0x3327, Mapped, "\u30c8\u30f3"),
+    (0x3328, Mapped, "\u30ca\u30ce"),
+    (0x3329, Mapped, "\u30ce\u30c3\u30c8"),
+    (0x332a, Mapped, "\u30cf\u30a4\u30c4"),
+    (0x332b, Mapped, "\u30d1\u30fc\u30bb\u30f3\u30c8"),
+    (0x332c, Mapped, "\u30d1\u30fc\u30c4"),
+    (0x332d, Mapped, "\u30d0\u30fc\u30ec\u30eb"),
+    (0x332e, Mapped, "\u30d4\u30a2\u30b9\u30c8\u30eb"),
+    (0x332f, Mapped, "\u30d4\u30af\u30eb"),
+    (0x3330, Mapped, "\u30d4\u30b3"),
+    (0x3331, Mapped, "\u30d3\u30eb"),
+    (0x3332, Mapped, "\u30d5\u30a1\u30e9\u30c3\u30c9"),
+    (0x3333, Mapped, "\u30d5\u30a3\u30fc\u30c8"),
+    (0x3334, Mapped, "\u30d6\u30c3\u30b7\u30a7\u30eb"),
+    (0x3335, Mapped, "\u30d5\u30e9\u30f3"),
+    (0x3336, Mapped, "\u30d8\u30af\u30bf\u30fc\u30eb"),
+    (0x3337, Mapped, "\u30da\u30bd"),
+    (0x3338, Mapped, "\u30da\u30cb\u30d2"),
+    (0x3339, Mapped, "\u30d8\u30eb\u30c4"),
+    (0x333a, Mapped, "\u30da\u30f3\u30b9"),
+    (0x333b, Mapped, "\u30da\u30fc\u30b8"),
+    (0x333c, Mapped, "\u30d9\u30fc\u30bf"),
+    (0x333d, Mapped, "\u30dd\u30a4\u30f3\u30c8"),
+    (0x333e, Mapped, "\u30dc\u30eb\u30c8"),
+    (0x333f, Mapped, "\u30db\u30f3"),
+    (0x3340, Mapped, "\u30dd\u30f3\u30c9"),
+    (0x3341, Mapped, "\u30db\u30fc\u30eb"),
+    (0x3342, Mapped, "\u30db\u30fc\u30f3"),
+    (0x3343, Mapped, "\u30de\u30a4\u30af\u30ed"),
+    (0x3344, Mapped, "\u30de\u30a4\u30eb"),
+    (0x3345, Mapped, "\u30de\u30c3\u30cf"),
+    (0x3346, Mapped, "\u30de\u30eb\u30af"),
+    (0x3347, Mapped, "\u30de\u30f3\u30b7\u30e7\u30f3"),
+    (0x3348, Mapped, "\u30df\u30af\u30ed\u30f3"),
+    (0x3349, Mapped, "\u30df\u30ea"),
+    (0x334a, Mapped, "\u30df\u30ea\u30d0\u30fc\u30eb"),
+    (0x334b, Mapped, "\u30e1\u30ac"),
+    (0x334c, Mapped, "\u30e1\u30ac\u30c8\u30f3"),
+    (0x334d, Mapped, "\u30e1\u30fc\u30c8\u30eb"),
+    (0x334e, Mapped, "\u30e4\u30fc\u30c9"),
+    (0x334f, Mapped, "\u30e4\u30fc\u30eb"),
+    (0x3350, Mapped, "\u30e6\u30a2\u30f3"),
+    (0x3351, Mapped, "\u30ea\u30c3\u30c8\u30eb"),
+    (0x3352, Mapped, "\u30ea\u30e9"),
+    (0x3353, Mapped, "\u30eb\u30d4\u30fc"),
+    (0x3354, Mapped, "\u30eb\u30fc\u30d6\u30eb"),
+    (0x3355, Mapped, "\u30ec\u30e0"),
+    (0x3356, Mapped, "\u30ec\u30f3\u30c8\u30b2\u30f3"),
+    (0x3357, Mapped, "\u30ef\u30c3\u30c8"),
+    (0x3358, Mapped, "0\u70b9"),
+    (0x3359, Mapped, "1\u70b9"),
+    (0x335a, Mapped, "2\u70b9"),
+    (0x335b, Mapped, "3\u70b9"),
+    (0x335c, Mapped, "4\u70b9"),
+    (0x335d, Mapped, "5\u70b9"),
+    (0x335e, Mapped, "6\u70b9"),
+    (0x335f, Mapped, "7\u70b9"),
+    (0x3360, Mapped, "8\u70b9"),
+    (0x3361, Mapped, "9\u70b9"),
+    (0x3362, Mapped, "10\u70b9"),
+    (0x3363, Mapped, "11\u70b9"),
+    (0x3364, Mapped, "12\u70b9"),
+    (0x3365, Mapped, "13\u70b9"),
+    (0x3366, Mapped, "14\u70b9"),
+    (0x3367, Mapped, "15\u70b9"),
+    (0x3368, Mapped, "16\u70b9"),
+    (0x3369, Mapped, "17\u70b9"),
+    (0x336a, Mapped, "18\u70b9"),
+    (0x336b, Mapped, "19\u70b9"),
+    (0x336c, Mapped, "20\u70b9"),
+    (0x336d, Mapped, "21\u70b9"),
+    (0x336e, Mapped, "22\u70b9"),
+    (0x336f, Mapped, "23\u70b9"),
+    (0x3370, Mapped, "24\u70b9"),
+    (0x3371, Mapped, "hpa"),
+    (0x3372, Mapped, "da"),
+    (0x3373, Mapped, "au"),
+    (0x3374, Mapped, "bar"),
+    (0x3375, Mapped, "ov"),
+    (0x3376, Mapped, "pc"),
+    (0x3377, Mapped, "dm"),
+    (0x3378, Mapped, "dm2"),
+    (0x3379, Mapped, "dm3"),
+    (0x337a, Mapped, "iu"),
+    (0x337b, Mapped, "\u5e73\u6210"),
+    (0x337c, Mapped, "\u662d\u548c"),
+    (0x337d, Mapped, "\u5927\u6b63"),
+    (0x337e, Mapped, "\u660e\u6cbb"),
+    (0x337f, Mapped, "\u682a\u5f0f\u4f1a\u793e"),
+    (0x3380, Mapped, "pa"),
+    (0x3381, Mapped, "na"),
+    (0x3382, Mapped, "\u03bca"),
+    (0x3383, Mapped, "ma"),
+    (0x3384, Mapped, "ka"),
+    (0x3385, Mapped, "kb"),
+    (0x3386, Mapped, "mb"),
+    (0x3387, Mapped, "gb"),
+    (0x3388, Mapped, "cal"),
+    (0x3389, Mapped, "kcal"),
+    (0x338a, Mapped, "pf"),
+    (0x338b, Mapped, "nf"),
+    (0x338c, Mapped, "\u03bcf"),
+    (0x338d, Mapped, "\u03bcg"),
+    (0x338e, Mapped, "mg"),
+    (0x338f, Mapped, "kg"),
+    (0x3390, Mapped, "hz"),
+    (0x3391, Mapped, "khz"),
+    (0x3392, Mapped, "mhz"),
+    (0x3393, Mapped, "ghz"),
+    (0x3394, Mapped, "thz"),
+    (0x3395, Mapped, "\u03bcl"),
+    (0x3396, Mapped, "ml"),
+    (0x3397, Mapped, "dl"),
+    (0x3398, Mapped, "kl"),
+    (0x3399, Mapped, "fm"),
+    (0x339a, Mapped, "nm"),
+    (0x339b, Mapped, "\u03bcm"),
+    (0x339c, Mapped, "mm"),
+    (0x339d, Mapped, "cm"),
+    (0x339e, Mapped, "km"),
+    (0x339f, Mapped, "mm2"),
+    (0x33a0, Mapped, "cm2"),
+    (0x33a1, Mapped, "m2"),
+    (0x33a2, Mapped, "km2"),
+    (0x33a3, Mapped, "mm3"),
+    (0x33a4, Mapped, "cm3"),
+    (0x33a5, Mapped, "m3"),
+    (0x33a6, Mapped, "km3"),
+    (0x33a7, Mapped, "m\u2215s"),
+    (0x33a8, Mapped, "m\u2215s2"),
+    (0x33a9, Mapped, "pa"),
+    (0x33aa, Mapped, "kpa"),
+    (0x33ab, Mapped, "mpa"),
+    (0x33ac, Mapped, "gpa"),
+    (0x33ad, Mapped, "rad"),
+    (0x33ae, Mapped, "rad\u2215s"),
+    (0x33af, Mapped, "rad\u2215s2"),
+    (0x33b0, Mapped, "ps"),
+    (0x33b1, Mapped, "ns"),
+    (0x33b2, Mapped, "\u03bcs"),
+    (0x33b3, Mapped, "ms"),
+    (0x33b4, Mapped, "pv"),
+    (0x33b5, Mapped, "nv"),
+    (0x33b6, Mapped, "\u03bcv"),
+    (0x33b7, Mapped, "mv"),
+    (0x33b8, Mapped, "kv"),
+    (0x33b9, Mapped, "mv"),
+    (0x33ba, Mapped, "pw"),
+    (0x33bb, Mapped, "nw"),
+    (0x33bc, Mapped, "\u03bcw"),
+    (0x33bd, Mapped, "mw"),
+    (0x33be, Mapped, "kw"),
+    (0x33bf, Mapped, "mw"),
+    (0x33c0, Mapped, "k\u03c9"),
+    (0x33c1, Mapped, "m\u03c9"),
+    (0x33c2, Disallowed, ""),
+    (0x33c3, Mapped, "bq"),
+    (0x33c4, Mapped, "cc"),
+    (0x33c5, Mapped, "cd"),
+    (0x33c6, Mapped, "c\u2215kg"),
+    (0x33c7, Disallowed, ""),
+    (0x33c8, Mapped, "db"),
+    (0x33c9, Mapped, "gy"),
+    (0x33ca, Mapped, "ha"),
+    (0x33cb, Mapped, "hp"),
+    (0x33cc, Mapped, "in"),
+    (0x33cd, Mapped, "kk"),
+    (0x33ce, Mapped, "km"),
+    (0x33cf, Mapped, "kt"),
+    (0x33d0, Mapped, "lm"),
+    (0x33d1, Mapped, "ln"),
+    (0x33d2, Mapped, "log"),
+    (0x33d3, Mapped, "lx"),
+    (0x33d4, Mapped, "mb"),
+    (0x33d5, Mapped, "mil"),
+    (0x33d6, Mapped, "mol"),
+    (0x33d7, Mapped, "ph"),
+    (0x33d8, Disallowed, ""),
+    (0x33d9, Mapped, "ppm"),
+    (0x33da, Mapped, "pr"),
+    (0x33db, Mapped, "sr"),
+    (0x33dc, Mapped, "sv"),
+    (0x33dd, Mapped, "wb"),
+    (0x33de, Mapped, "v\u2215m"),
+    (0x33df, Mapped, "a\u2215m"),
+    (0x33e0, Mapped, "1\u65e5"),
+    (0x33e1, Mapped, "2\u65e5"),
+    (0x33e2, Mapped, "3\u65e5"),
+    (0x33e3, Mapped, "4\u65e5"),
+    (0x33e4, Mapped, "5\u65e5"),
+    (0x33e5, Mapped, "6\u65e5"),
+    (0x33e6, Mapped, "7\u65e5"),
+    (0x33e7, Mapped, "8\u65e5"),
+    (0x33e8, Mapped, "9\u65e5"),
+    (0x33e9, Mapped, "10\u65e5"),
+    (0x33ea, Mapped, "11\u65e5"),
+    (0x33eb, Mapped, "12\u65e5"),
+    (0x33ec, Mapped, "13\u65e5"),
+    (0x33ed, Mapped, "14\u65e5"),
+    (0x33ee, Mapped, "15\u65e5"),
+    (0x33ef, Mapped, "16\u65e5"),
+    (0x33f0, Mapped, "17\u65e5"),
+    (0x33f1, Mapped, "18\u65e5"),
+    (0x33f2, Mapped, "19\u65e5"),
+    (0x33f3, Mapped, "20\u65e5"),
+    (0x33f4, Mapped, "21\u65e5"),
+    (0x33f5, Mapped, "22\u65e5"),
+    (0x33f6, Mapped, "23\u65e5"),
+    (0x33f7, Mapped, "24\u65e5"),
+    (0x33f8, Mapped, "25\u65e5"),
+    (0x33f9, Mapped, "26\u65e5"),
+    (0x33fa, Mapped, "27\u65e5"),
+    (0x33fb, Mapped, "28\u65e5"),
+    (0x33fc, Mapped, "29\u65e5"),
+    (0x33fd, Mapped, "30\u65e5"),
+    (0x33fe, Mapped, "31\u65e5"),
+    (0x33ff, Mapped, "gal"),
+    (0x3400, Valid, ""),
+    (0xa48d, Disallowed, ""),
+    (0xa490, Valid, ""),
+    (0xa4c7, Disallowed, ""),
+    (0xa4d0, Valid, ""),
+    (0xa62c, Disallowed, ""),
+    (0xa640, Mapped, "\ua641"),
+    (0xa641, Valid, ""),
+    (0xa642, Mapped, "\ua643"),
+    (0xa643, Valid, ""),
+    (0xa644, Mapped, "\ua645"),
+    (0xa645, Valid, ""),
+    (0xa646, Mapped, "\ua647"),
+    (0xa647, Valid, ""),
+    (0xa648, Mapped, "\ua649"),
+    (0xa649, Valid, ""),
+    (0xa64a, Mapped, "\ua64b"),
+    (0xa64b, Valid, ""),
+    (0xa64c, Mapped, "\ua64d"),
+    (0xa64d, Valid, ""),
+    (0xa64e, Mapped, "\ua64f"),
+    (0xa64f, Valid, ""),
+    (0xa650, Mapped, "\ua651"),
+    (0xa651, Valid, ""),
+    (0xa652, Mapped, "\ua653"),
+    (0xa653, Valid, ""),
+    (0xa654, Mapped, "\ua655"),
+    (0xa655, Valid, ""),
+    (0xa656, Mapped, "\ua657"),
+    (0xa657, Valid, ""),
+    (0xa658, Mapped, "\ua659"),
+    (0xa659, Valid, ""),
+    (0xa65a, Mapped, "\ua65b"),
+    (0xa65b, Valid, ""),
+    (0xa65c, Mapped, "\ua65d"),
+    (0xa65d, Valid, ""),
+    (0xa65e, Mapped, "\ua65f"),
+    (0xa65f, Valid, ""),
+    (0xa660, Mapped, "\ua661"),
+    (0xa661, Valid, ""),
+    (0xa662, Mapped, "\ua663"),
+    (0xa663, Valid, ""),
+    (0xa664, Mapped, "\ua665"),
+    (0xa665, Valid, ""),
+    (0xa666, Mapped, "\ua667"),
+    (0xa667, Valid, ""),
+    (0xa668, Mapped, "\ua669"),
+    (0xa669, Valid, ""),
+    (0xa66a, Mapped, "\ua66b"),
+    (0xa66b, Valid, ""),
+    (0xa66c, Mapped, "\ua66d"),
+    (0xa66d, Valid, ""),
+    (0xa680, Mapped, "\ua681"),
+    (0xa681, Valid, ""),
+    (0xa682, Mapped, "\ua683"),
+    (0xa683, Valid, ""),
+    (0xa684, Mapped, "\ua685"),
+    (0xa685, Valid, ""),
+    (0xa686, Mapped, "\ua687"),
+    (0xa687, Valid, ""),
+    (0xa688, Mapped, "\ua689"),
+    (0xa689, Valid, ""),
+    (0xa68a, Mapped, "\ua68b"),
+    (0xa68b, Valid, ""),
+    (0xa68c, Mapped, "\ua68d"),
+    (0xa68d, Valid, ""),
+    (0xa68e, Mapped, "\ua68f"),
+    (0xa68f, Valid, ""),
+    (0xa690, Mapped, "\ua691"),
+    (0xa691, Valid, ""),
+    (0xa692, Mapped, "\ua693"),
+    (0xa693, Valid, ""),
+    (0xa694, Mapped, "\ua695"),
+    (0xa695, Valid, ""),
+    (0xa696, Mapped, "\ua697"),
+    (0xa697, Valid, ""),
+    (0xa698, Mapped, "\ua699"),
+    (0xa699, Valid, ""),
+    (0xa69a, Mapped, "\ua69b"),
+    (0xa69b, Valid, ""),
+    (0xa69c, Mapped, "\u044a"),
+    (0xa69d, Mapped, "\u044c"),
+    (0xa69e, Valid, ""),
+    (0xa6f8, Disallowed, ""),
+    (0xa700, Valid, ""),
+    (0xa722, Mapped, "\ua723"),
+    (0xa723, Valid, ""),
+    (0xa724, Mapped, "\ua725"),
+    (0xa725, Valid, ""),
+    (0xa726, Mapped, "\ua727"),
+    (0xa727, Valid, ""),
+    (0xa728, Mapped, "\ua729"),
+    (0xa729, Valid, ""),
+    (0xa72a, Mapped, "\ua72b"),
+    (0xa72b, Valid, ""),
+    (0xa72c, Mapped, "\ua72d"),
+    (0xa72d, Valid, ""),
+    (0xa72e, Mapped, "\ua72f"),
+    (0xa72f, Valid, ""),
+    (0xa732, Mapped, "\ua733"),
+    (0xa733, Valid, ""),
+    (0xa734, Mapped, "\ua735"),
+    (0xa735, Valid, ""),
+    (0xa736, Mapped, "\ua737"),
+    (0xa737, Valid, ""),
+    (0xa738, Mapped, "\ua739"),
+    (0xa739, Valid, ""),
+    (0xa73a, Mapped, "\ua73b"),
+    (0xa73b, Valid, ""),
+    (0xa73c, Mapped, "\ua73d"),
+    (0xa73d, Valid, ""),
+    (0xa73e, Mapped, "\ua73f"),
+    (0xa73f, Valid, ""),
+    (0xa740, Mapped, "\ua741"),
+    (0xa741, Valid, ""),
+    (0xa742, Mapped, "\ua743"),
+    (0xa743, Valid, ""),
+    (0xa744, Mapped, "\ua745"),
+    (0xa745, Valid, ""),
+    (0xa746, Mapped, "\ua747"),
+    (0xa747, Valid, ""),
+    (0xa748, Mapped, "\ua749"),
+    (0xa749, Valid, ""),
+    (0xa74a, Mapped, "\ua74b"),
+    (0xa74b, Valid, ""),
+    (0xa74c, Mapped, "\ua74d"),
+    (0xa74d, Valid, ""),
+    (0xa74e, Mapped, "\ua74f"),
+    (0xa74f, Valid, ""),
+    (0xa750, Mapped, "\ua751"),
+    (0xa751, Valid, ""),
+    (0xa752, Mapped, "\ua753"),
+    (0xa753, Valid, ""),
+    (0xa754, Mapped, "\ua755"),
+    (0xa755, Valid, ""),
+    (0xa756, Mapped, "\ua757"),
+    (0xa757, Valid, ""),
+    (0xa758, Mapped, "\ua759"),
+    (0xa759, Valid, ""),
+    (0xa75a, Mapped, "\ua75b"),
+    (0xa75b, Valid, ""),
+    (0xa75c, Mapped, "\ua75d"),
+    (0xa75d, Valid, ""),
+    (0xa75e, Mapped, "\ua75f"),
+    (0xa75f, Valid, ""),
+    (0xa760, Mapped, "\ua761"),
+    (0xa761, Valid, ""),
+    (0xa762, Mapped, "\ua763"),
+    (0xa763, Valid, ""),
+    (0xa764, Mapped, "\ua765"),
+    (0xa765, Valid, ""),
+    (0xa766, Mapped, "\ua767"),
+    (0xa767, Valid, ""),
+    (0xa768, Mapped, "\ua769"),
+    (0xa769, Valid, ""),
+    (0xa76a, Mapped, "\ua76b"),
+    (0xa76b, Valid, ""),
+    (0xa76c, Mapped, "\ua76d"),
+    (0xa76d, Valid, ""),
+    (0xa76e, Mapped, "\ua76f"),
+    (0xa76f, Valid, ""),
+    (0xa770, Mapped, "\ua76f"),
+    (0xa771, Valid, ""),
+    (0xa779, Mapped, "\ua77a"),
+    (0xa77a, Valid, ""),
+    (0xa77b, Mapped, "\ua77c"),
+    (0xa77c, Valid, ""),
+    (0xa77d, Mapped, "\u1d79"),
+    (0xa77e, Mapped, "\ua77f"),
+    (0xa77f, Valid, ""),
+    (0xa780, Mapped, "\ua781"),
+    (0xa781, Valid, ""),
+    (0xa782, Mapped, "\ua783"),
+    (0xa783, Valid, ""),
+    (0xa784, Mapped, "\ua785"),
+    (0xa785, Valid, ""),
+    (0xa786, Mapped, "\ua787"),
+    (0xa787, Valid, ""),
+    (0xa78b, Mapped, "\ua78c"),
+    (0xa78c, Valid, ""),
+    (0xa78d, Mapped, "\u0265"),
+    (0xa78e, Valid, ""),
+    (0xa790, Mapped, "\ua791"),
+    (0xa791, Valid, ""),
+    (0xa792, Mapped, "\ua793"),
+    (0xa793, Valid, ""),
+    (0xa796, Mapped, "\ua797"),
+    (0xa797, Valid, ""),
+    (0xa798, Mapped, "\ua799"),
+    (0xa799, Valid, ""),
+    (0xa79a, Mapped, "\ua79b"),
+    (0xa79b, Valid, ""),
+    (0xa79c, Mapped, "\ua79d"),
+    (0xa79d, Valid, ""),
+    (0xa79e, Mapped, "\ua79f"),
+    (0xa79f, Valid, ""),
+    (0xa7a0, Mapped, "\ua7a1"),
+    (0xa7a1, Valid, ""),
+    (0xa7a2, Mapped, "\ua7a3"),
+    (0xa7a3, Valid, ""),
+    (0xa7a4, Mapped, "\ua7a5"),
+    (0xa7a5, Valid, ""),
+    (0xa7a6, Mapped, "\ua7a7"),
+    (0xa7a7, Valid, ""),
+    (0xa7a8, Mapped, "\ua7a9"),
+    (0xa7a9, Valid, ""),
+    (0xa7aa, Mapped, "\u0266"),
+    (0xa7ab, Mapped, "\u025c"),
+    (0xa7ac, Mapped, "\u0261"),
+    (0xa7ad, Mapped, "\u026c"),
+    (0xa7ae, Mapped, "\u026a"),
+    (0xa7af, Valid, ""),
+    (0xa7b0, Mapped, "\u029e"),
+    (0xa7b1, Mapped, "\u0287"),
+    (0xa7b2, Mapped, "\u029d"),
+    (0xa7b3, Mapped, "\uab53"),
+    (0xa7b4, Mapped, "\ua7b5"),
+    (0xa7b5, Valid, ""),
+    (0xa7b6, Mapped, "\ua7b7"),
+    (0xa7b7, Valid, ""),
+    (0xa7b8, Mapped, "\ua7b9"),
+    (0xa7b9, Valid, ""),
+    (0xa7ba, Mapped, "\ua7bb"),
+    (0xa7bb, Valid, ""),
+    (0xa7bc, Mapped, "\ua7bd"),
+    (0xa7bd, Valid, ""),
+    (0xa7be, Mapped, "\ua7bf"),
+    (0xa7bf, Valid, ""),
+    (0xa7c0, Mapped, "\ua7c1"),
+    (0xa7c1, Valid, ""),
+    (0xa7c2, Mapped, "\ua7c3"),
+    (0xa7c3, Valid, ""),
+    (0xa7c4, Mapped, "\ua794"),
+    (0xa7c5, Mapped, "\u0282"),
+    (0xa7c6, Mapped, "\u1d8e"),
+    (0xa7c7, Mapped, "\ua7c8"),
+    (0xa7c8, Valid, ""),
+    (0xa7c9, Mapped, "\ua7ca"),
+    (0xa7ca, Valid, ""),
+    (0xa7cb, Mapped, "\u0264"),
+    (0xa7cc, Mapped, "\ua7cd"),
+    (0xa7cd, Valid, ""),
+    (0xa7ce, Mapped, "\ua7cf"),
+    (0xa7cf, Valid, ""),
+    (0xa7d0, Mapped, "\ua7d1"),
+    (0xa7d1, Valid, ""),
+    (0xa7d2, Mapped, "\ua7d3"),
+    (0xa7d3, Valid, ""),
+    (0xa7d4, Mapped, "\ua7d5"),
+    (0xa7d5, Valid, ""),
+    (0xa7d6, Mapped, "\ua7d7"),
+    (0xa7d7, Valid, ""),
+    (0xa7d8, Mapped, "\ua7d9"),
+    (0xa7d9, Valid, ""),
+    (0xa7da, Mapped, "\ua7db"),
+    (0xa7db, Valid, ""),
+    (0xa7dc, Mapped, "\u019b"),
+    (0xa7dd, Disallowed, ""),
+    (0xa7f1, Mapped, "s"),
+    (0xa7f2, Mapped, "c"),
+    (0xa7f3, Mapped, "f"),
+    (0xa7f4, Mapped, "q"),
+    (0xa7f5, Mapped, "\ua7f6"),
+    (0xa7f6, Valid, ""),
+    (0xa7f8, Mapped, "\u0127"),
+    (0xa7f9, Mapped, "\u0153"),
+    (0xa7fa, Valid, ""),
+    (0xa82d, Disallowed, ""),
+    (0xa830, Valid, ""),
+    (0xa83a, Disallowed, ""),
+    (0xa840, Valid, ""),
+    (0xa878, Disallowed, ""),
+    (0xa880, Valid, ""),
+    (0xa8c6, Disallowed, ""),
+    (0xa8ce, Valid, ""),
+    (0xa8da, Disallowed, ""),
+    (0xa8e0, Valid, ""),
+    (0xa954, Disallowed, ""),
+    (0xa95f, Valid, ""),
+    (0xa97d, Disallowed, ""),
+    (0xa980, Valid, ""),
+    (0xa9ce, Disallowed, ""),
+    (0xa9cf, Valid, ""),
+    (0xa9da, Disallowed, ""),
+    (0xa9de, Valid, ""),
+    (0xa9ff, Disallowed, ""),
+    (0xaa00, Valid, ""),
+    (0xaa37, Disallowed, ""),
+    (0xaa40, Valid, ""),
+    (0xaa4e, Disallowed, ""),
+    (0xaa50, Valid, ""),
+    (0xaa5a, Disallowed, ""),
+    (0xaa5c, Valid, ""),
+    (0xaac3, Disallowed, ""),
+    (0xaadb, Valid, ""),
+    (0xaaf7, Disallowed, ""),
+    (0xab01, Valid, ""),
+    (0xab07, Disallowed, ""),
+    (0xab09, Valid, ""),
+    (0xab0f, Disallowed, ""),
+    (0xab11, Valid, ""),
+    (0xab17, Disallowed, ""),
+    (0xab20, Valid, ""),
+    (0xab27, Disallowed, ""),
+    (0xab28, Valid, ""),
+    (0xab2f, Disallowed, ""),
+    (0xab30, Valid, ""),
+    (0xab5c, Mapped, "\ua727"),
+    (0xab5d, Mapped, "\uab37"),
+    (0xab5e, Mapped, "\u026b"),
+    (0xab5f, Mapped, "\uab52"),
+    (0xab60, Valid, ""),
+    (0xab69, Mapped, "\u028d"),
+    (0xab6a, Valid, ""),
+    (0xab6c, Disallowed, ""),
+    (0xab70, Mapped, "\u13a0"),
+    (0xab71, Mapped, "\u13a1"),
+    (0xab72, Mapped, "\u13a2"),
+    (0xab73, Mapped, "\u13a3"),
+    (0xab74, Mapped, "\u13a4"),
+    (0xab75, Mapped, "\u13a5"),
+    (0xab76, Mapped, "\u13a6"),
+    (0xab77, Mapped, "\u13a7"),
+    (0xab78, Mapped, "\u13a8"),
+    (0xab79, Mapped, "\u13a9"),
+    (0xab7a, Mapped, "\u13aa"),
+    (0xab7b, Mapped, "\u13ab"),
+    (0xab7c, Mapped, "\u13ac"),
+    (0xab7d, Mapped, "\u13ad"),
+    (0xab7e, Mapped, "\u13ae"),
+    (0xab7f, Mapped, "\u13af"),
+    (0xab80, Mapped, "\u13b0"),
+    (0xab81, Mapped, "\u13b1"),
+    (0xab82, Mapped, "\u13b2"),
+    (0xab83, Mapped, "\u13b3"),
+    (0xab84, Mapped, "\u13b4"),
+    (0xab85, Mapped, "\u13b5"),
+    (0xab86, Mapped, "\u13b6"),
+    (0xab87, Mapped, "\u13b7"),
+    (0xab88, Mapped, "\u13b8"),
+    (0xab89, Mapped, "\u13b9"),
+    (0xab8a, Mapped, "\u13ba"),
+    (0xab8b, Mapped, "\u13bb"),
+    (0xab8c, Mapped, "\u13bc"),
+    (0xab8d, Mapped, "\u13bd"),
+    (0xab8e, Mapped, "\u13be"),
+    (0xab8f, Mapped, "\u13bf"),
+    (0xab90, Mapped, "\u13c0"),
+    (0xab91, Mapped, "\u13c1"),
+    (0xab92, Mapped, "\u13c2"),
+    (0xab93, Mapped, "\u13c3"),
+    (0xab94, Mapped, "\u13c4"),
+    (0xab95, Mapped, "\u13c5"),
+    (0xab96, Mapped, "\u13c6"),
+    (0xab97, Mapped, "\u13c7"),
+    (0xab98, Mapped, "\u13c8"),
+    (0xab99, Mapped, "\u13c9"),
+    (0xab9a, Mapped, "\u13ca"),
+    (0xab9b, Mapped, "\u13cb"),
+    (0xab9c, Mapped, "\u13cc"),
+    (0xab9d, Mapped, "\u13cd"),
+    (0xab9e, Mapped, "\u13ce"),
+    (0xab9f, Mapped, "\u13cf"),
+    (0xaba0, Mapped, "\u13d0"),
+    (0xaba1, Mapped, "\u13d1"),
+    (0xaba2, Mapped, "\u13d2"),
+    (0xaba3, Mapped, "\u13d3"),
+    (0xaba4, Mapped, "\u13d4"),
+    (0xaba5, Mapped, "\u13d5"),
+    (0xaba6, Mapped, "\u13d6"),
+    (0xaba7, Mapped, "\u13d7"),
+    (0xaba8, Mapped, "\u13d8"),
+    (0xaba9, Mapped, "\u13d9"),
+    (0xabaa, Mapped, "\u13da"),
+    (0xabab, Mapped, "\u13db"),
+    (0xabac, Mapped, "\u13dc"),
+    (0xabad, Mapped, "\u13dd"),
+    (0xabae, Mapped, "\u13de"),
+    (0xabaf, Mapped, "\u13df"),
+    (0xabb0, Mapped, "\u13e0"),
+    (0xabb1, Mapped, "\u13e1"),
+    (0xabb2, Mapped, "\u13e2"),
+    (0xabb3, Mapped, "\u13e3"),
+    (0xabb4, Mapped, "\u13e4"),
+    (0xabb5, Mapped, "\u13e5"),
+    (0xabb6, Mapped, "\u13e6"),
+    (0xabb7, Mapped, "\u13e7"),
+    (0xabb8, Mapped, "\u13e8"),
+    (0xabb9, Mapped, "\u13e9"),
+    (0xabba, Mapped, "\u13ea"),
+    (0xabbb, Mapped, "\u13eb"),
+    (0xabbc, Mapped, "\u13ec"),
+    (0xabbd, Mapped, "\u13ed"),
+    (0xabbe, Mapped, "\u13ee"),
+    (0xabbf, Mapped, "\u13ef"),
+    (0xabc0, Valid, ""),
+    (0xabee, Disallowed, ""),
+    (0xabf0, Valid, ""),
+    (0xabfa, Disallowed, ""),
+    (0xac00, Valid, ""),
+    (0xd7a4, Disallowed, ""),
+    (0xd7b0, Valid, ""),
+    (0xd7c7, Disallowed, ""),
+    (0xd7cb, Valid, ""),
+    (0xd7fc, Disallowed, ""),
+    (0xf900, Mapped, "\u8c48"),
+    (0xf901, Mapped, "\u66f4"),
+    (0xf902, Mapped, "\u8eca"),
+    (0xf903, Mapped, "\u8cc8"),
+    (0xf904, Mapped, "\u6ed1"),
+    (0xf905, Mapped, "\u4e32"),
+    (0xf906, Mapped, "\u53e5"),
+    (0xf907, Mapped, "\u9f9c"),
+    (0xf909, Mapped, "\u5951"),
+    (0xf90a, Mapped, "\u91d1"),
+    (0xf90b, Mapped, "\u5587"),
+    (0xf90c, Mapped, "\u5948"),
+    (0xf90d, Mapped, "\u61f6"),
+    (0xf90e, Mapped, "\u7669"),
+    (0xf90f, Mapped, "\u7f85"),
+    (0xf910, Mapped, "\u863f"),
+    (0xf911, Mapped, "\u87ba"),
+    (0xf912, Mapped, "\u88f8"),
+    (0xf913, Mapped, "\u908f"),
+    (0xf914, Mapped, "\u6a02"),
+    (0xf915, Mapped, "\u6d1b"),
+    (0xf916, Mapped, "\u70d9"),
+    (0xf917, Mapped, "\u73de"),
+    (0xf918, Mapped, "\u843d"),
+    (0xf919, Mapped, "\u916a"),
+    (0xf91a, Mapped, "\u99f1"),
+    (0xf91b, Mapped, "\u4e82"),
+    (0xf91c, Mapped, "\u5375"),
+    (0xf91d, Mapped, "\u6b04"),
+    (0xf91e, Mapped, "\u721b"),
+    (0xf91f, Mapped, "\u862d"),
+    (0xf920, Mapped, "\u9e1e"),
+    (0xf921, Mapped, "\u5d50"),
+    (0xf922, Mapped, "\u6feb"),
+    (0xf923, Mapped, "\u85cd"),
+    (0xf924, Mapped, "\u8964"),
+    (0xf925, Mapped, "\u62c9"),
+    (0xf926, Mapped, "\u81d8"),
+    (0xf927, Mapped, "\u881f"),
+    (0xf928, Mapped, "\u5eca"),
+    (0xf929, Mapped, "\u6717"),
+    (0xf92a, Mapped, "\u6d6a"),
+    (0xf92b, Mapped, "\u72fc"),
+    (0xf92c, Mapped, "\u90ce"),
+    (0xf92d, Mapped, "\u4f86"),
+    (0xf92e, Mapped, "\u51b7"),
+    (0xf92f, Mapped, "\u52de"),
+    (0xf930, Mapped, "\u64c4"),
+    (0xf931, Mapped, "\u6ad3"),
+    (0xf932, Mapped, "\u7210"),
+    (0xf933, Mapped, "\u76e7"),
+    (0xf934, Mapped, "\u8001"),
+    (0xf935, Mapped, "\u8606"),
+    (0xf936, Mapped, "\u865c"),
+    (0xf937, Mapped, "\u8def"),
+    (0xf938, Mapped, "\u9732"),
+    (0xf939, Mapped, "\u9b6f"),
+    (0xf93a, Mapped, "\u9dfa"),
+    (0xf93b, Mapped, "\u788c"),
+    (0xf93c, Mapped, "\u797f"),
+    (0xf93d, Mapped, "\u7da0"),
+    (0xf93e, Mapped, "\u83c9"),
+    (0xf93f, Mapped, "\u9304"),
+    (0xf940, Mapped, "\u9e7f"),
+    (0xf941, Mapped, "\u8ad6"),
+    (0xf942, Mapped, "\u58df"),
+    (0xf943, Mapped, "\u5f04"),
+    (0xf944, Mapped, "\u7c60"),
+    (0xf945, Mapped, "\u807e"),
+    (0xf946, Mapped, "\u7262"),
+    (0xf947, Mapped, "\u78ca"),
+    (0xf948, Mapped, "\u8cc2"),
+    (0xf949, Mapped, "\u96f7"),
+    (0xf94a, Mapped, "\u58d8"),
+    (0xf94b, Mapped, "\u5c62"),
+    (0xf94c, Mapped, "\u6a13"),
+    (0xf94d, Mapped, "\u6dda"),
+    (0xf94e, Mapped, "\u6f0f"),
+    (0xf94f, Mapped, "\u7d2f"),
+    (0xf950, Mapped, "\u7e37"),
+    (0xf951, Mapped, "\u964b"),
+    (0xf952, Mapped, "\u52d2"),
+    (0xf953, Mapped, "\u808b"),
+    (0xf954, Mapped, "\u51dc"),
+    (0xf955, Mapped, "\u51cc"),
+    (0xf956, Mapped, "\u7a1c"),
+    (0xf957, Mapped, "\u7dbe"),
+    (0xf958, Mapped, "\u83f1"),
+    (0xf959, Mapped, "\u9675"),
+    (0xf95a, Mapped, "\u8b80"),
+    (0xf95b, Mapped, "\u62cf"),
+    (0xf95c, Mapped, "\u6a02"),
+    (0xf95d, Mapped, "\u8afe"),
+    (0xf95e, Mapped, "\u4e39"),
+    (0xf95f, Mapped, "\u5be7"),
+    (0xf960, Mapped, "\u6012"),
+    (0xf961, Mapped, "\u7387"),
+    (0xf962, Mapped, "\u7570"),
+    (0xf963, Mapped, "\u5317"),
+    (0xf964, Mapped, "\u78fb"),
+    (0xf965, Mapped, "\u4fbf"),
+    (0xf966, Mapped, "\u5fa9"),
+    (0xf967, Mapped, "\u4e0d"),
+    (0xf968, Mapped, "\u6ccc"),
+    (0xf969, Mapped, "\u6578"),
+    (0xf96a, Mapped, "\u7d22"),
+    (0xf96b, Mapped, "\u53c3"),
+    (0xf96c, Mapped, "\u585e"),
+    (0xf96d, Mapped, "\u7701"),
+    (0xf96e, Mapped, "\u8449"),
+    (0xf96f, Mapped, "\u8aaa"),
+    (0xf970, Mapped, "\u6bba"),
+    (0xf971, Mapped, "\u8fb0"),
+    (0xf972, Mapped, "\u6c88"),
+    (0xf973, Mapped, "\u62fe"),
+    (0xf974, Mapped, "\u82e5"),
+    (0xf975, Mapped, "\u63a0"),
+    (0xf976, Mapped, "\u7565"),
+    (0xf977, Mapped, "\u4eae"),
+    (0xf978, Mapped, "\u5169"),
+    (0xf979, Mapped, "\u51c9"),
+    (0xf97a, Mapped, "\u6881"),
+    (0xf97b, Mapped, "\u7ce7"),
+    (0xf97c, Mapped, "\u826f"),
+    (0xf97d, Mapped, "\u8ad2"),
+    (0xf97e, Mapped, "\u91cf"),
+    (0xf97f, Mapped, "\u52f5"),
+    (0xf980, Mapped, "\u5442"),
+    (0xf981, Mapped, "\u5973"),
+    (0xf982, Mapped, "\u5eec"),
+    (0xf983, Mapped, "\u65c5"),
+    (0xf984, Mapped, "\u6ffe"),
+    (0xf985, Mapped, "\u792a"),
+    (0xf986, Mapped, "\u95ad"),
+    (0xf987, Mapped, "\u9a6a"),
+    (0xf988, Mapped, "\u9e97"),
+    (0xf989, Mapped, "\u9ece"),
+    (0xf98a, Mapped, "\u529b"),
+    (0xf98b, Mapped, "\u66c6"),
+    (0xf98c, Mapped, "\u6b77"),
+    (0xf98d, Mapped, "\u8f62"),
+    (0xf98e, Mapped, "\u5e74"),
+    (0xf98f, Mapped, "\u6190"),
+    (0xf990, Mapped, "\u6200"),
+    (0xf991, Mapped, "\u649a"),
+    (0xf992, Mapped, "\u6f23"),
+    (0xf993, Mapped, "\u7149"),
+    (0xf994, Mapped, "\u7489"),
+    (0xf995, Mapped, "\u79ca"),
+    (0xf996, Mapped, "\u7df4"),
+    (0xf997, Mapped, "\u806f"),
+    (0xf998, Mapped, "\u8f26"),
+    (0xf999, Mapped, "\u84ee"),
+    (0xf99a, Mapped, "\u9023"),
+    (0xf99b, Mapped, "\u934a"),
+    (0xf99c, Mapped, "\u5217"),
+    (0xf99d, Mapped, "\u52a3"),
+    (0xf99e, Mapped, "\u54bd"),
+    (0xf99f, Mapped, "\u70c8"),
+    (0xf9a0, Mapped, "\u88c2"),
+    (0xf9a1, Mapped, "\u8aaa"),
+    (0xf9a2, Mapped, "\u5ec9"),
+    (0xf9a3, Mapped, "\u5ff5"),
+    (0xf9a4, Mapped, "\u637b"),
+    (0xf9a5, Mapped, "\u6bae"),
+    (0xf9a6, Mapped, "\u7c3e"),
+    (0xf9a7, Mapped, "\u7375"),
+    (0xf9a8, Mapped, "\u4ee4"),
+    (0xf9a9, Mapped, "\u56f9"),
+    (0xf9aa, Mapped, "\u5be7"),
+    (0xf9ab, Mapped, "\u5dba"),
+    (0xf9ac, Mapped, "\u601c"),
+    (0xf9ad, Mapped, "\u73b2"),
+    (0xf9ae, Mapped, "\u7469"),
+    (0xf9af, Mapped, "\u7f9a"),
+    (0xf9b0, Mapped, "\u8046"),
+    (0xf9b1, Mapped, "\u9234"),
+    (0xf9b2, Mapped, "\u96f6"),
+    (0xf9b3, Mapped, "\u9748"),
+    (0xf9b4, Mapped, "\u9818"),
+    (0xf9b5, Mapped, "\u4f8b"),
+    (0xf9b6, Mapped, "\u79ae"),
+    (0xf9b7, Mapped, "\u91b4"),
+    (0xf9b8, Mapped, "\u96b8"),
+    (0xf9b9, Mapped, "\u60e1"),
+    (0xf9ba, Mapped, "\u4e86"),
+    (0xf9bb, Mapped, "\u50da"),
+    (0xf9bc, Mapped, "\u5bee"),
+    (0xf9bd, Mapped, "\u5c3f"),
+    (0xf9be, Mapped, "\u6599"),
+    (0xf9bf, Mapped, "\u6a02"),
+    (0xf9c0, Mapped, "\u71ce"),
+    (0xf9c1, Mapped, "\u7642"),
+    (0xf9c2, Mapped, "\u84fc"),
+    (0xf9c3, Mapped, "\u907c"),
+    (0xf9c4, Mapped, "\u9f8d"),
+    (0xf9c5, Mapped, "\u6688"),
+    (0xf9c6, Mapped, "\u962e"),
+    (0xf9c7, Mapped, "\u5289"),
+    (0xf9c8, Mapped, "\u677b"),
+    (0xf9c9, Mapped, "\u67f3"),
+    (0xf9ca, Mapped, "\u6d41"),
+    (0xf9cb, Mapped, "\u6e9c"),
+    (0xf9cc, Mapped, "\u7409"),
+    (0xf9cd, Mapped, "\u7559"),
+    (0xf9ce, Mapped, "\u786b"),
+    (0xf9cf, Mapped, "\u7d10"),
+    (0xf9d0, Mapped, "\u985e"),
+    (0xf9d1, Mapped, "\u516d"),
+    (0xf9d2, Mapped, "\u622e"),
+    (0xf9d3, Mapped, "\u9678"),
+    (0xf9d4, Mapped, "\u502b"),
+    (0xf9d5, Mapped, "\u5d19"),
+    (0xf9d6, Mapped, "\u6dea"),
+    (0xf9d7, Mapped, "\u8f2a"),
+    (0xf9d8, Mapped, "\u5f8b"),
+    (0xf9d9, Mapped, "\u6144"),
+    (0xf9da, Mapped, "\u6817"),
+    (0xf9db, Mapped, "\u7387"),
+    (0xf9dc, Mapped, "\u9686"),
+    (0xf9dd, Mapped, "\u5229"),
+    (0xf9de, Mapped, "\u540f"),
+    (0xf9df, Mapped, "\u5c65"),
+    (0xf9e0, Mapped, "\u6613"),
+    (0xf9e1, Mapped, "\u674e"),
+    (0xf9e2, Mapped, "\u68a8"),
+    (0xf9e3, Mapped, "\u6ce5"),
+    (0xf9e4, Mapped, "\u7406"),
+    (0xf9e5, Mapped, "\u75e2"),
+    (0xf9e6, Mapped, "\u7f79"),
+    (0xf9e7, Mapped, "\u88cf"),
+    (0xf9e8, Mapped, "\u88e1"),
+    (0xf9e9, Mapped, "\u91cc"),
+    (0xf9ea, Mapped, "\u96e2"),
+    (0xf9eb, Mapped, "\u533f"),
+    (0xf9ec, Mapped, "\u6eba"),
+    (0xf9ed, Mapped, "\u541d"),
+    (0xf9ee, Mapped, "\u71d0"),
+    (0xf9ef, Mapped, "\u7498"),
+    (0xf9f0, Mapped, "\u85fa"),
+    (0xf9f1, Mapped, "\u96a3"),
+    (0xf9f2, Mapped, "\u9c57"),
+    (0xf9f3, Mapped, "\u9e9f"),
+    (0xf9f4, Mapped, "\u6797"),
+    (0xf9f5, Mapped, "\u6dcb"),
+    (0xf9f6, Mapped, "\u81e8"),
+    (0xf9f7, Mapped, "\u7acb"),
+    (0xf9f8, Mapped, "\u7b20"),
+    (0xf9f9, Mapped, "\u7c92"),
+    (0xf9fa, Mapped, "\u72c0"),
+    (0xf9fb, Mapped, "\u7099"),
+    (0xf9fc, Mapped, "\u8b58"),
+    (0xf9fd, Mapped, "\u4ec0"),
+    (0xf9fe, Mapped, "\u8336"),
+    (0xf9ff, Mapped, "\u523a"),
+    (0xfa00, Mapped, "\u5207"),
+    (0xfa01, Mapped, "\u5ea6"),
+    (0xfa02, Mapped, "\u62d3"),
+    (0xfa03, Mapped, "\u7cd6"),
+    (0xfa04, Mapped, "\u5b85"),
+    (0xfa05, Mapped, "\u6d1e"),
+    (0xfa06, Mapped, "\u66b4"),
+    (0xfa07, Mapped, "\u8f3b"),
+    (0xfa08, Mapped, "\u884c"),
+    (0xfa09, Mapped, "\u964d"),
+    (0xfa0a, Mapped, "\u898b"),
+    (0xfa0b, Mapped, "\u5ed3"),
+    (0xfa0c, Mapped, "\u5140"),
+    (0xfa0d, Mapped, "\u55c0"),
+    (0xfa0e, Valid, ""),
+    (0xfa10, Mapped, "\u585a"),
+    (0xfa11, Valid, ""),
+    (0xfa12, Mapped, "\u6674"),
+    (0xfa13, Valid, ""),
+    (0xfa15, Mapped, "\u51de"),
+    (0xfa16, Mapped, "\u732a"),
+    (0xfa17, Mapped, "\u76ca"),
+    (0xfa18, Mapped, "\u793c"),
+    (0xfa19, Mapped, "\u795e"),
+    (0xfa1a, Mapped, "\u7965"),
+    (0xfa1b, Mapped, "\u798f"),
+    (0xfa1c, Mapped, "\u9756"),
+    (0xfa1d, Mapped, "\u7cbe"),
+    (0xfa1e, Mapped, "\u7fbd"),
+    (0xfa1f, Valid, ""),
+    (0xfa20, Mapped, "\u8612"),
+    (0xfa21, Valid, ""),
+    (0xfa22, Mapped, "\u8af8"),
+    (0xfa23, Valid, ""),
+    (0xfa25, Mapped, "\u9038"),
+    (0xfa26, Mapped, "\u90fd"),
+    (0xfa27, Valid, ""),
+    (0xfa2a, Mapped, "\u98ef"),
+    (0xfa2b, Mapped, "\u98fc"),
+    (0xfa2c, Mapped, "\u9928"),
+    (0xfa2d, Mapped, "\u9db4"),
+    (0xfa2e, Mapped, "\u90de"),
+    (0xfa2f, Mapped, "\u96b7"),
+    (0xfa30, Mapped, "\u4fae"),
+    (0xfa31, Mapped, "\u50e7"),
+    (0xfa32, Mapped, "\u514d"),
+    (0xfa33, Mapped, "\u52c9"),
+    (0xfa34, Mapped, "\u52e4"),
+    (0xfa35, Mapped, "\u5351"),
+    (0xfa36, Mapped, "\u559d"),
+    (0xfa37, Mapped, "\u5606"),
+    (0xfa38, Mapped, "\u5668"),
+    (0xfa39, Mapped, "\u5840"),
+    (0xfa3a, Mapped, "\u58a8"),
+    (0xfa3b, Mapped, "\u5c64"),
+    (0xfa3c, Mapped, "\u5c6e"),
+    (0xfa3d, Mapped, "\u6094"),
+    (0xfa3e, Mapped, "\u6168"),
+    (0xfa3f, Mapped, "\u618e"),
+    (0xfa40, Mapped, "\u61f2"),
+    (0xfa41, Mapped, "\u654f"),
+    (0xfa42, Mapped, "\u65e2"),
+    (0xfa43, Mapped, "\u6691"),
+    (0xfa44, Mapped, "\u6885"),
+    (0xfa45, Mapped, "\u6d77"),
+    (0xfa46, Mapped, "\u6e1a"),
+    (0xfa47, Mapped, "\u6f22"),
+    (0xfa48, Mapped, "\u716e"),
+    (0xfa49, Mapped, "\u722b"),
+    (0xfa4a, Mapped, "\u7422"),
+    (0xfa4b, Mapped, "\u7891"),
+    (0xfa4c, Mapped, "\u793e"),
+    (0xfa4d, Mapped, "\u7949"),
+    (0xfa4e, Mapped, "\u7948"),
+    (0xfa4f, Mapped, "\u7950"),
+    (0xfa50, Mapped, "\u7956"),
+    (0xfa51, Mapped, "\u795d"),
+    (0xfa52, Mapped, "\u798d"),
+    (0xfa53, Mapped, "\u798e"),
+    (0xfa54, Mapped, "\u7a40"),
+    (0xfa55, Mapped, "\u7a81"),
+    (0xfa56, Mapped, "\u7bc0"),
+    (0xfa57, Mapped, "\u7df4"),
+    (0xfa58, Mapped, "\u7e09"),
+    (0xfa59, Mapped, "\u7e41"),
+    (0xfa5a, Mapped, "\u7f72"),
+    (0xfa5b, Mapped, "\u8005"),
+    (0xfa5c, Mapped, "\u81ed"),
+    (0xfa5d, Mapped, "\u8279"),
+    (0xfa5f, Mapped, "\u8457"),
+    (0xfa60, Mapped, "\u8910"),
+    (0xfa61, Mapped, "\u8996"),
+    (0xfa62, Mapped, "\u8b01"),
+    (0xfa63, Mapped, "\u8b39"),
+    (0xfa64, Mapped, "\u8cd3"),
+    (0xfa65, Mapped, "\u8d08"),
+    (0xfa66, Mapped, "\u8fb6"),
+    (0xfa67, Mapped, "\u9038"),
+    (0xfa68, Mapped, "\u96e3"),
+    (0xfa69, Mapped, "\u97ff"),
+    (0xfa6a, Mapped, "\u983b"),
+    (0xfa6b, Mapped, "\u6075"),
+    (0xfa6c, Mapped, "\U000242ee"),
+    (0xfa6d, Mapped, "\u8218"),
+    (0xfa6e, Disallowed, ""),
+    (0xfa70, Mapped, "\u4e26"),
+    (0xfa71, Mapped, "\u51b5"),
+    (0xfa72, Mapped, "\u5168"),
+    (0xfa73, Mapped, "\u4f80"),
+    (0xfa74, Mapped, "\u5145"),
+    (0xfa75, Mapped, "\u5180"),
+    (0xfa76, Mapped, "\u52c7"),
+    (0xfa77, Mapped, "\u52fa"),
+    (0xfa78, Mapped, "\u559d"),
+    (0xfa79, Mapped, "\u5555"),
+    (0xfa7a, Mapped, "\u5599"),
+    (0xfa7b, Mapped, "\u55e2"),
+    (0xfa7c, Mapped, "\u585a"),
+    (0xfa7d, Mapped, "\u58b3"),
+    (0xfa7e, Mapped, "\u5944"),
+    (0xfa7f, Mapped, "\u5954"),
+    (0xfa80, Mapped, "\u5a62"),
+    (0xfa81, Mapped, "\u5b28"),
+    (0xfa82, Mapped, "\u5ed2"),
+    (0xfa83, Mapped, "\u5ed9"),
+    (0xfa84, Mapped, "\u5f69"),
+    (0xfa85, Mapped, "\u5fad"),
+    (0xfa86, Mapped, "\u60d8"),
+    (0xfa87, Mapped, "\u614e"),
+    (0xfa88, Mapped, "\u6108"),
+    (0xfa89, Mapped, "\u618e"),
+    (0xfa8a, Mapped, "\u6160"),
+    (0xfa8b, Mapped, "\u61f2"),
+    (0xfa8c, Mapped, "\u6234"),
+    (0xfa8d, Mapped, "\u63c4"),
+    (0xfa8e, Mapped, "\u641c"),
+    (0xfa8f, Mapped, "\u6452"),
+    (0xfa90, Mapped, "\u6556"),
+    (0xfa91, Mapped, "\u6674"),
+    (0xfa92, Mapped, "\u6717"),
+    (0xfa93, Mapped, "\u671b"),
+    (0xfa94, Mapped, "\u6756"),
+    (0xfa95, Mapped, "\u6b79"),
+    (0xfa96, Mapped, "\u6bba"),
+    (0xfa97, Mapped, "\u6d41"),
+    (0xfa98, Mapped, "\u6edb"),
+    (0xfa99, Mapped, "\u6ecb"),
+    (0xfa9a, Mapped, "\u6f22"),
+    (0xfa9b, Mapped, "\u701e"),
+    (0xfa9c, Mapped, "\u716e"),
+    (0xfa9d, Mapped, "\u77a7"),
+    (0xfa9e, Mapped, "\u7235"),
+    (0xfa9f, Mapped, "\u72af"),
+    (0xfaa0, Mapped, "\u732a"),
+    (0xfaa1, Mapped, "\u7471"),
+    (0xfaa2, Mapped, "\u7506"),
+    (0xfaa3, Mapped, "\u753b"),
+    (0xfaa4, Mapped, "\u761d"),
+    (0xfaa5, Mapped, "\u761f"),
+    (0xfaa6, Mapped, "\u76ca"),
+    (0xfaa7, Mapped, "\u76db"),
+    (0xfaa8, Mapped, "\u76f4"),
+    (0xfaa9, Mapped, "\u774a"),
+    (0xfaaa, Mapped, "\u7740"),
+    (0xfaab, Mapped, "\u78cc"),
+    (0xfaac, Mapped, "\u7ab1"),
+    (0xfaad, Mapped, "\u7bc0"),
+    (0xfaae, Mapped, "\u7c7b"),
+    (0xfaaf, Mapped, "\u7d5b"),
+    (0xfab0, Mapped, "\u7df4"),
+    (0xfab1, Mapped, "\u7f3e"),
+    (0xfab2, Mapped, "\u8005"),
+    (0xfab3, Mapped, "\u8352"),
+    (0xfab4, Mapped, "\u83ef"),
+    (0xfab5, Mapped, "\u8779"),
+    (0xfab6, Mapped, "\u8941"),
+    (0xfab7, Mapped, "\u8986"),
+    (0xfab8, Mapped, "\u8996"),
+    (0xfab9, Mapped, "\u8abf"),
+    (0xfaba, Mapped, "\u8af8"),
+    (0xfabb, Mapped, "\u8acb"),
+    (0xfabc, Mapped, "\u8b01"),
+    (0xfabd, Mapped, "\u8afe"),
+    (0xfabe, Mapped, "\u8aed"),
+    (0xfabf, Mapped, "\u8b39"),
+    (0xfac0, Mapped, "\u8b8a"),
+    (0xfac1, Mapped, "\u8d08"),
+    (0xfac2, Mapped, "\u8f38"),
+    (0xfac3, Mapped, "\u9072"),
+    (0xfac4, Mapped, "\u9199"),
+    (0xfac5, Mapped, "\u9276"),
+    (0xfac6, Mapped, "\u967c"),
+    (0xfac7, Mapped, "\u96e3"),
+    (0xfac8, Mapped, "\u9756"),
+    (0xfac9, Mapped, "\u97db"),
+    (0xfaca, Mapped, "\u97ff"),
+    (0xfacb, Mapped, "\u980b"),
+    (0xfacc, Mapped, "\u983b"),
+    (0xfacd, Mapped, "\u9b12"),
+    (0xface, Mapped, "\u9f9c"),
+    (0xfacf, Mapped, "\U0002284a"),
+    (0xfad0, Mapped, "\U00022844"),
+    (0xfad1, Mapped, "\U000233d5"),
+    (0xfad2, Mapped, "\u3b9d"),
+    (0xfad3, Mapped, "\u4018"),
+    (0xfad4, Mapped, "\u4039"),
+    (0xfad5, Mapped, "\U00025249"),
+    (0xfad6, Mapped, "\U00025cd0"),
+    (0xfad7, Mapped, "\U00027ed3"),
+    (0xfad8, Mapped, "\u9f43"),
+    (0xfad9, Mapped, "\u9f8e"),
+    (0xfada, Disallowed, ""),
+    (0xfb00, Mapped, "ff"),
+    (0xfb01, Mapped, "fi"),
+    (0xfb02, Mapped, "fl"),
+    (0xfb03, Mapped, "ffi"),
+    (0xfb04, Mapped, "ffl"),
+    (0xfb05, Mapped, "st"),
+    (0xfb07, Disallowed, ""),
+    (0xfb13, Mapped, "\u0574\u0576"),
+    (0xfb14, Mapped, "\u0574\u0565"),
+    (0xfb15, Mapped, "\u0574\u056b"),
+    (0xfb16, Mapped, "\u057e\u0576"),
+    (0xfb17, Mapped, "\u0574\u056d"),
+    (0xfb18, Disallowed, ""),
+    (0xfb1d, Mapped, "\u05d9\u05b4"),
+    (0xfb1e, Valid, ""),
+    (0xfb1f, Mapped, "\u05f2\u05b7"),
+    (0xfb20, Mapped, "\u05e2"),
+    (0xfb21, Mapped, "\u05d0"),
+    (0xfb22, Mapped, "\u05d3"),
+    (0xfb23, Mapped, "\u05d4"),
+    (0xfb24, Mapped, "\u05db"),
+    (0xfb25, Mapped, "\u05dc"),
+    (0xfb26, Mapped, "\u05dd"),
+    (0xfb27, Mapped, "\u05e8"),
+    (0xfb28, Mapped, "\u05ea"),
+    (0xfb29, Mapped, "+"),
+    (0xfb2a, Mapped, "\u05e9\u05c1"),
+    (0xfb2b, Mapped, "\u05e9\u05c2"),
+    (0xfb2c, Mapped, "\u05e9\u05bc\u05c1"),
+    (0xfb2d, Mapped, "\u05e9\u05bc\u05c2"),
+    (0xfb2e, Mapped, "\u05d0\u05b7"),
+    (0xfb2f, Mapped, "\u05d0\u05b8"),
+    (0xfb30, Mapped, "\u05d0\u05bc"),
+    (0xfb31, Mapped, "\u05d1\u05bc"),
+    (0xfb32, Mapped, "\u05d2\u05bc"),
+    (0xfb33, Mapped, "\u05d3\u05bc"),
+    (0xfb34, Mapped, "\u05d4\u05bc"),
+    (0xfb35, Mapped, "\u05d5\u05bc"),
+    (0xfb36, Mapped, "\u05d6\u05bc"),
+    (0xfb37, Disallowed, ""),
+    (0xfb38, Mapped, "\u05d8\u05bc"),
+    (0xfb39, Mapped, "\u05d9\u05bc"),
+    (0xfb3a, Mapped, "\u05da\u05bc"),
+    (0xfb3b, Mapped, "\u05db\u05bc"),
+    (0xfb3c, Mapped, "\u05dc\u05bc"),
+    (0xfb3d, Disallowed, ""),
+    (0xfb3e, Mapped, "\u05de\u05bc"),
+    (0xfb3f, Disallowed, ""),
+    (0xfb40, Mapped, "\u05e0\u05bc"),
+    (0xfb41, Mapped, "\u05e1\u05bc"),
+    (0xfb42, Disallowed, ""),
+    (0xfb43, Mapped, "\u05e3\u05bc"),
+    (0xfb44, Mapped, "\u05e4\u05bc"),
+    (0xfb45, Disallowed, ""),
+    (0xfb46, Mapped, "\u05e6\u05bc"),
+    (0xfb47, Mapped, "\u05e7\u05bc"),
+    (0xfb48, Mapped, "\u05e8\u05bc"),
+    (0xfb49, Mapped, "\u05e9\u05bc"),
+    (0xfb4a, Mapped, "\u05ea\u05bc"),
+    (0xfb4b, Mapped, "\u05d5\u05b9"),
+    (0xfb4c, Mapped, "\u05d1\u05bf"),
+    (0xfb4d, Mapped, "\u05db\u05bf"),
+    (0xfb4e, Mapped, "\u05e4\u05bf"),
+    (0xfb4f, Mapped, "\u05d0\u05dc"),
+    (0xfb50, Mapped, "\u0671"),
+    (0xfb52, Mapped, "\u067b"),
+    (0xfb56, Mapped, "\u067e"),
+    (0xfb5a, Mapped, "\u0680"),
+    (0xfb5e, Mapped, "\u067a"),
+    (0xfb62, Mapped, "\u067f"),
+    (0xfb66, Mapped, "\u0679"),
+    (0xfb6a, Mapped, "\u06a4"),
+    (0xfb6e, Mapped, "\u06a6"),
+    (0xfb72, Mapped, "\u0684"),
+    (0xfb76, Mapped, "\u0683"),
+    (0xfb7a, Mapped, "\u0686"),
+    (0xfb7e, Mapped, "\u0687"),
+    (0xfb82, Mapped, "\u068d"),
+    (0xfb84, Mapped, "\u068c"),
+    (0xfb86, Mapped, "\u068e"),
+    (0xfb88, Mapped, "\u0688"),
+    (0xfb8a, Mapped, "\u0698"),
+    (0xfb8c, Mapped, "\u0691"),
+    (0xfb8e, Mapped, "\u06a9"),
+    (0xfb92, Mapped, "\u06af"),
+    (0xfb96, Mapped, "\u06b3"),
+    (0xfb9a, Mapped, "\u06b1"),
+    (0xfb9e, Mapped, "\u06ba"),
+    (0xfba0, Mapped, "\u06bb"),
+    (0xfba4, Mapped, "\u06c0"),
+    (0xfba6, Mapped, "\u06c1"),
+    (0xfbaa, Mapped, "\u06be"),
+    (0xfbae, Mapped, "\u06d2"),
+    (0xfbb0, Mapped, "\u06d3"),
+    (0xfbb2, Valid, ""),
+    (0xfbd3, Mapped, "\u06ad"),
+    (0xfbd7, Mapped, "\u06c7"),
+    (0xfbd9, Mapped, "\u06c6"),
+    (0xfbdb, Mapped, "\u06c8"),
+    (0xfbdd, Mapped, "\u06c7\u0674"),
+    (0xfbde, Mapped, "\u06cb"),
+    (0xfbe0, Mapped, "\u06c5"),
+    (0xfbe2, Mapped, "\u06c9"),
+    (0xfbe4, Mapped, "\u06d0"),
+    (0xfbe8, Mapped, "\u0649"),
+    (0xfbea, Mapped, "\u0626\u0627"),
+    (0xfbec, Mapped, "\u0626\u06d5"),
+    (0xfbee, Mapped, "\u0626\u0648"),
+    (0xfbf0, Mapped, "\u0626\u06c7"),
+    (0xfbf2, Mapped, "\u0626\u06c6"),
+    (0xfbf4, Mapped, "\u0626\u06c8"),
+    (0xfbf6, Mapped, "\u0626\u06d0"),
+    (0xfbf9, Mapped, "\u0626\u0649"),
+    (0xfbfc, Mapped, "\u06cc"),
+    (0xfc00, Mapped, "\u0626\u062c"),
+    (0xfc01, Mapped, "\u0626\u062d"),
+    (0xfc02, Mapped, "\u0626\u0645"),
+    (0xfc03, Mapped, "\u0626\u0649"),
+    (0xfc04, Mapped, "\u0626\u064a"),
+    (0xfc05, Mapped, "\u0628\u062c"),
+    (0xfc06, Mapped, "\u0628\u062d"),
+    (0xfc07, Mapped, "\u0628\u062e"),
+    (0xfc08, Mapped, "\u0628\u0645"),
+    (0xfc09, Mapped, "\u0628\u0649"),
+    (0xfc0a, Mapped, "\u0628\u064a"),
+    (0xfc0b, Mapped, "\u062a\u062c"),
+    (0xfc0c, Mapped, "\u062a\u062d"),
+    (0xfc0d, Mapped, "\u062a\u062e"),
+    (0xfc0e, Mapped, "\u062a\u0645"),
+    (0xfc0f, Mapped, "\u062a\u0649"),
+    (0xfc10, Mapped, "\u062a\u064a"),
+    (0xfc11, Mapped, "\u062b\u062c"),
+    (0xfc12, Mapped, "\u062b\u0645"),
+    (0xfc13, Mapped, "\u062b\u0649"),
+    (0xfc14, Mapped, "\u062b\u064a"),
+    (0xfc15, Mapped, "\u062c\u062d"),
+    (0xfc16, Mapped, "\u062c\u0645"),
+    (0xfc17, Mapped, "\u062d\u062c"),
+    (0xfc18, Mapped, "\u062d\u0645"),
+    (0xfc19, Mapped, "\u062e\u062c"),
+    (0xfc1a, Mapped, "\u062e\u062d"),
+    (0xfc1b, Mapped, "\u062e\u0645"),
+    (0xfc1c, Mapped, "\u0633\u062c"),
+    (0xfc1d, Mapped, "\u0633\u062d"),
+    (0xfc1e, Mapped, "\u0633\u062e"),
+    (0xfc1f, Mapped, "\u0633\u0645"),
+    (0xfc20, Mapped, "\u0635\u062d"),
+    (0xfc21, Mapped, "\u0635\u0645"),
+    (0xfc22, Mapped, "\u0636\u062c"),
+    (0xfc23, Mapped, "\u0636\u062d"),
+    (0xfc24, Mapped, "\u0636\u062e"),
+    (0xfc25, Mapped, "\u0636\u0645"),
+    (0xfc26, Mapped, "\u0637\u062d"),
+    (0xfc27, Mapped, "\u0637\u0645"),
+    (0xfc28, Mapped, "\u0638\u0645"),
+    (0xfc29, Mapped, "\u0639\u062c"),
+    (0xfc2a, Mapped, "\u0639\u0645"),
+    (0xfc2b, Mapped, "\u063a\u062c"),
+    (0xfc2c, Mapped, "\u063a\u0645"),
+    (0xfc2d, Mapped, "\u0641\u062c"),
+    (0xfc2e, Mapped, "\u0641\u062d"),
+    (0xfc2f, Mapped, "\u0641\u062e"),
+    (0xfc30, Mapped, "\u0641\u0645"),
+    (0xfc31, Mapped, "\u0641\u0649"),
+    (0xfc32, Mapped, "\u0641\u064a"),
+    (0xfc33, Mapped, "\u0642\u062d"),
+    (0xfc34, Mapped, "\u0642\u0645"),
+    (0xfc35, Mapped, "\u0642\u0649"),
+    (0xfc36, Mapped, "\u0642\u064a"),
+    (0xfc37, Mapped, "\u0643\u0627"),
+    (0xfc38, Mapped, "\u0643\u062c"),
+    (0xfc39, Mapped, "\u0643\u062d"),
+    (0xfc3a, Mapped, "\u0643\u062e"),
+    (0xfc3b, Mapped, "\u0643\u0644"),
+    (0xfc3c, Mapped, "\u0643\u0645"),
+    (0xfc3d, Mapped, "\u0643\u0649"),
+    (0xfc3e, Mapped, "\u0643\u064a"),
+    (0xfc3f, Mapped, "\u0644\u062c"),
+    (0xfc40, Mapped, "\u0644\u062d"),
+    (0xfc41, Mapped, "\u0644\u062e"),
+    (0xfc42, Mapped, "\u0644\u0645"),
+    (0xfc43, Mapped, "\u0644\u0649"),
+    (0xfc44, Mapped, "\u0644\u064a"),
+    (0xfc45, Mapped, "\u0645\u062c"),
+    (0xfc46, Mapped, "\u0645\u062d"),
+    (0xfc47, Mapped, "\u0645\u062e"),
+    (0xfc48, Mapped, "\u0645\u0645"),
+    (0xfc49, Mapped, "\u0645\u0649"),
+    (0xfc4a, Mapped, "\u0645\u064a"),
+    (0xfc4b, Mapped, "\u0646\u062c"),
+    (0xfc4c, Mapped, "\u0646\u062d"),
+    (0xfc4d, Mapped, "\u0646\u062e"),
+    (0xfc4e, Mapped, "\u0646\u0645"),
+    (0xfc4f, Mapped, "\u0646\u0649"),
+    (0xfc50, Mapped, "\u0646\u064a"),
+    (0xfc51, Mapped, "\u0647\u062c"),
+    (0xfc52, Mapped, "\u0647\u0645"),
+    (0xfc53, Mapped, "\u0647\u0649"),
+    (0xfc54, Mapped, "\u0647\u064a"),
+    (0xfc55, Mapped, "\u064a\u062c"),
+    (0xfc56, Mapped, "\u064a\u062d"),
+    (0xfc57, Mapped, "\u064a\u062e"),
+    (0xfc58, Mapped, "\u064a\u0645"),
+    (0xfc59, Mapped, "\u064a\u0649"),
+    (0xfc5a, Mapped, "\u064a\u064a"),
+    (0xfc5b, Mapped, "\u0630\u0670"),
+    (0xfc5c, Mapped, "\u0631\u0670"),
+    (0xfc5d, Mapped, "\u0649\u0670"),
+    (0xfc5e, Mapped, " \u064c\u0651"),
+    (0xfc5f, Mapped, " \u064d\u0651"),
+    (0xfc60, Mapped, " \u064e\u0651"),
+    (0xfc61, Mapped, " \u064f\u0651"),
+    (0xfc62, Mapped, " \u0650\u0651"),
+    (0xfc63, Mapped, " \u0651\u0670"),
+    (0xfc64, Mapped, "\u0626\u0631"),
+    (0xfc65, Mapped, "\u0626\u0632"),
+    (0xfc66, Mapped, "\u0626\u0645"),
+    (0xfc67, Mapped, "\u0626\u0646"),
+    (0xfc68, Mapped, "\u0626\u0649"),
+    (0xfc69, Mapped, "\u0626\u064a"),
+    (0xfc6a, Mapped, "\u0628\u0631"),
+    (0xfc6b, Mapped, "\u0628\u0632"),
+    (0xfc6c, Mapped, "\u0628\u0645"),
+    (0xfc6d, Mapped, "\u0628\u0646"),
+    (0xfc6e, Mapped, "\u0628\u0649"),
+    (0xfc6f, Mapped, "\u0628\u064a"),
+    (0xfc70, Mapped, "\u062a\u0631"),
+    (0xfc71, Mapped, "\u062a\u0632"),
+    (0xfc72, Mapped, "\u062a\u0645"),
+    (0xfc73, Mapped, "\u062a\u0646"),
+    (0xfc74, Mapped, "\u062a\u0649"),
+    (0xfc75, Mapped, "\u062a\u064a"),
+    (0xfc76, Mapped, "\u062b\u0631"),
+    (0xfc77, Mapped, "\u062b\u0632"),
+    (0xfc78, Mapped, "\u062b\u0645"),
+    (0xfc79, Mapped, "\u062b\u0646"),
+    (0xfc7a, Mapped, "\u062b\u0649"),
+    (0xfc7b, Mapped, "\u062b\u064a"),
+    (0xfc7c, Mapped, "\u0641\u0649"),
+    (0xfc7d, Mapped, "\u0641\u064a"),
+    (0xfc7e, Mapped, "\u0642\u0649"),
+    (0xfc7f, Mapped, "\u0642\u064a"),
+    (0xfc80, Mapped, "\u0643\u0627"),
+    (0xfc81, Mapped, "\u0643\u0644"),
+    (0xfc82, Mapped, "\u0643\u0645"),
+    (0xfc83, Mapped, "\u0643\u0649"),
+    (0xfc84, Mapped, "\u0643\u064a"),
+    (0xfc85, Mapped, "\u0644\u0645"),
+    (0xfc86, Mapped, "\u0644\u0649"),
+    (0xfc87, Mapped, "\u0644\u064a"),
+    (0xfc88, Mapped, "\u0645\u0627"),
+    (0xfc89, Mapped, "\u0645\u0645"),
+    (0xfc8a, Mapped, "\u0646\u0631"),
+    (0xfc8b, Mapped, "\u0646\u0632"),
+    (0xfc8c, Mapped, "\u0646\u0645"),
+    (0xfc8d, Mapped, "\u0646\u0646"),
+    (0xfc8e, Mapped, "\u0646\u0649"),
+    (0xfc8f, Mapped, "\u0646\u064a"),
+    (0xfc90, Mapped, "\u0649\u0670"),
+    (0xfc91, Mapped, "\u064a\u0631"),
+    (0xfc92, Mapped, "\u064a\u0632"),
+    (0xfc93, Mapped, "\u064a\u0645"),
+    (0xfc94, Mapped, "\u064a\u0646"),
+    (0xfc95, Mapped, "\u064a\u0649"),
+    (0xfc96, Mapped, "\u064a\u064a"),
+    (0xfc97, Mapped, "\u0626\u062c"),
+    (0xfc98, Mapped, "\u0626\u062d"),
+    (0xfc99, Mapped, "\u0626\u062e"),
+    (0xfc9a, Mapped, "\u0626\u0645"),
+    (0xfc9b, Mapped, "\u0626\u0647"),
+    (0xfc9c, Mapped, "\u0628\u062c"),
+    (0xfc9d, Mapped, "\u0628\u062d"),
+    (0xfc9e, Mapped, "\u0628\u062e"),
+    (0xfc9f, Mapped, "\u0628\u0645"),
+    (0xfca0, Mapped, "\u0628\u0647"),
+    (0xfca1, Mapped, "\u062a\u062c"),
+    (0xfca2, Mapped, "\u062a\u062d"),
+    (0xfca3, Mapped, "\u062a\u062e"),
+    (0xfca4, Mapped, "\u062a\u0645"),
+    (0xfca5, Mapped, "\u062a\u0647"),
+    (0xfca6, Mapped, "\u062b\u0645"),
+    (0xfca7, Mapped, "\u062c\u062d"),
+    (0xfca8, Mapped, "\u062c\u0645"),
+    (0xfca9, Mapped, "\u062d\u062c"),
+    (0xfcaa, Mapped, "\u062d\u0645"),
+    (0xfcab, Mapped, "\u062e\u062c"),
+    (0xfcac, Mapped, "\u062e\u0645"),
+    (0xfcad, Mapped, "\u0633\u062c"),
+    (0xfcae, Mapped, "\u0633\u062d"),
+    (0xfcaf, Mapped, "\u0633\u062e"),
+    (0xfcb0, Mapped, "\u0633\u0645"),
+    (0xfcb1, Mapped, "\u0635\u062d"),
+    (0xfcb2, Mapped, "\u0635\u062e"),
+    (0xfcb3, Mapped, "\u0635\u0645"),
+    (0xfcb4, Mapped, "\u0636\u062c"),
+    (0xfcb5, Mapped, "\u0636\u062d"),
+    (0xfcb6, Mapped, "\u0636\u062e"),
+    (0xfcb7, Mapped, "\u0636\u0645"),
+    (0xfcb8, Mapped, "\u0637\u062d"),
+    (0xfcb9, Mapped, "\u0638\u0645"),
+    (0xfcba, Mapped, "\u0639\u062c"),
+    (0xfcbb, Mapped, "\u0639\u0645"),
+    (0xfcbc, Mapped, "\u063a\u062c"),
+    (0xfcbd, Mapped, "\u063a\u0645"),
+    (0xfcbe, Mapped, "\u0641\u062c"),
+    (0xfcbf, Mapped, "\u0641\u062d"),
+    (0xfcc0, Mapped, "\u0641\u062e"),
+    (0xfcc1, Mapped, "\u0641\u0645"),
+    (0xfcc2, Mapped, "\u0642\u062d"),
+    (0xfcc3, Mapped, "\u0642\u0645"),
+    (0xfcc4, Mapped, "\u0643\u062c"),
+    (0xfcc5, Mapped, "\u0643\u062d"),
+    (0xfcc6, Mapped, "\u0643\u062e"),
+    (0xfcc7, Mapped, "\u0643\u0644"),
+    (0xfcc8, Mapped, "\u0643\u0645"),
+    (0xfcc9, Mapped, "\u0644\u062c"),
+    (0xfcca, Mapped, "\u0644\u062d"),
+    (0xfccb, Mapped, "\u0644\u062e"),
+    (0xfccc, Mapped, "\u0644\u0645"),
+    (0xfccd, Mapped, "\u0644\u0647"),
+    (0xfcce, Mapped, "\u0645\u062c"),
+    (0xfccf, Mapped, "\u0645\u062d"),
+    (0xfcd0, Mapped, "\u0645\u062e"),
+    (0xfcd1, Mapped, "\u0645\u0645"),
+    (0xfcd2, Mapped, "\u0646\u062c"),
+    (0xfcd3, Mapped, "\u0646\u062d"),
+    (0xfcd4, Mapped, "\u0646\u062e"),
+    (0xfcd5, Mapped, "\u0646\u0645"),
+    (0xfcd6, Mapped, "\u0646\u0647"),
+    (0xfcd7, Mapped, "\u0647\u062c"),
+    (0xfcd8, Mapped, "\u0647\u0645"),
+    (0xfcd9, Mapped, "\u0647\u0670"),
+    (0xfcda, Mapped, "\u064a\u062c"),
+    (0xfcdb, Mapped, "\u064a\u062d"),
+    (0xfcdc, Mapped, "\u064a\u062e"),
+    (0xfcdd, Mapped, "\u064a\u0645"),
+    (0xfcde, Mapped, "\u064a\u0647"),
+    (0xfcdf, Mapped, "\u0626\u0645"),
+    (0xfce0, Mapped, "\u0626\u0647"),
+    (0xfce1, Mapped, "\u0628\u0645"),
+    (0xfce2, Mapped, "\u0628\u0647"),
+    (0xfce3, Mapped, "\u062a\u0645"),
+    (0xfce4, Mapped, "\u062a\u0647"),
+    (0xfce5, Mapped, "\u062b\u0645"),
+    (0xfce6, Mapped, "\u062b\u0647"),
+    (0xfce7, Mapped, "\u0633\u0645"),
+    (0xfce8, Mapped, "\u0633\u0647"),
+    (0xfce9, Mapped, "\u0634\u0645"),
+    (0xfcea, Mapped, "\u0634\u0647"),
+    (0xfceb, Mapped, "\u0643\u0644"),
+    (0xfcec, Mapped, "\u0643\u0645"),
+    (0xfced, Mapped, "\u0644\u0645"),
+    (0xfcee, Mapped, "\u0646\u0645"),
+    (0xfcef, Mapped, "\u0646\u0647"),
+    (0xfcf0, Mapped, "\u064a\u0645"),
+    (0xfcf1, Mapped, "\u064a\u0647"),
+    (0xfcf2, Mapped, "\u0640\u064e\u0651"),
+    (0xfcf3, Mapped, "\u0640\u064f\u0651"),
+    (0xfcf4, Mapped, "\u0640\u0650\u0651"),
+    (0xfcf5, Mapped, "\u0637\u0649"),
+    (0xfcf6, Mapped, "\u0637\u064a"),
+    (0xfcf7, Mapped, "\u0639\u0649"),
+    (0xfcf8, Mapped, "\u0639\u064a"),
+    (0xfcf9, Mapped, "\u063a\u0649"),
+    (0xfcfa, Mapped, "\u063a\u064a"),
+    (0xfcfb, Mapped, "\u0633\u0649"),
+    (0xfcfc, Mapped, "\u0633\u064a"),
+    (0xfcfd, Mapped, "\u0634\u0649"),
+    (0xfcfe, Mapped, "\u0634\u064a"),
+    (0xfcff, Mapped, "\u062d\u0649"),
+    (0xfd00, Mapped, "\u062d\u064a"),
+    (0xfd01, Mapped, "\u062c\u0649"),
+    (0xfd02, Mapped, "\u062c\u064a"),
+    (0xfd03, Mapped, "\u062e\u0649"),
+    (0xfd04, Mapped, "\u062e\u064a"),
+    (0xfd05, Mapped, "\u0635\u0649"),
+    (0xfd06, Mapped, "\u0635\u064a"),
+    (0xfd07, Mapped, "\u0636\u0649"),
+    (0xfd08, Mapped, "\u0636\u064a"),
+    (0xfd09, Mapped, "\u0634\u062c"),
+    (0xfd0a, Mapped, "\u0634\u062d"),
+    (0xfd0b, Mapped, "\u0634\u062e"),
+    (0xfd0c, Mapped, "\u0634\u0645"),
+    (0xfd0d, Mapped, "\u0634\u0631"),
+    (0xfd0e, Mapped, "\u0633\u0631"),
+    (0xfd0f, Mapped, "\u0635\u0631"),
+    (0xfd10, Mapped, "\u0636\u0631"),
+    (0xfd11, Mapped, "\u0637\u0649"),
+    (0xfd12, Mapped, "\u0637\u064a"),
+    (0xfd13, Mapped, "\u0639\u0649"),
+    (0xfd14, Mapped, "\u0639\u064a"),
+    (0xfd15, Mapped, "\u063a\u0649"),
+    (0xfd16, Mapped, "\u063a\u064a"),
+    (0xfd17, Mapped, "\u0633\u0649"),
+    (0xfd18, Mapped, "\u0633\u064a"),
+    (0xfd19, Mapped, "\u0634\u0649"),
+    (0xfd1a, Mapped, "\u0634\u064a"),
+    (0xfd1b, Mapped, "\u062d\u0649"),
+    (0xfd1c, Mapped, "\u062d\u064a"),
+    (0xfd1d, Mapped, "\u062c\u0649"),
+    (0xfd1e, Mapped, "\u062c\u064a"),
+    (0xfd1f, Mapped, "\u062e\u0649"),
+    (0xfd20, Mapped, "\u062e\u064a"),
+    (0xfd21, Mapped, "\u0635\u0649"),
+    (0xfd22, Mapped, "\u0635\u064a"),
+    (0xfd23, Mapped, "\u0636\u0649"),
+    (0xfd24, Mapped, "\u0636\u064a"),
+    (0xfd25, Mapped, "\u0634\u062c"),
+    (0xfd26, Mapped, "\u0634\u062d"),
+    (0xfd27, Mapped, "\u0634\u062e"),
+    (0xfd28, Mapped, "\u0634\u0645"),
+    (0xfd29, Mapped, "\u0634\u0631"),
+    (0xfd2a, Mapped, "\u0633\u0631"),
+    (0xfd2b, Mapped, "\u0635\u0631"),
+    (0xfd2c, Mapped, "\u0636\u0631"),
+    (0xfd2d, Mapped, "\u0634\u062c"),
+    (0xfd2e, Mapped, "\u0634\u062d"),
+    (0xfd2f, Mapped, "\u0634\u062e"),
+    (0xfd30, Mapped, "\u0634\u0645"),
+    (0xfd31, Mapped, "\u0633\u0647"),
+    (0xfd32, Mapped, "\u0634\u0647"),
+    (0xfd33, Mapped, "\u0637\u0645"),
+    (0xfd34, Mapped, "\u0633\u062c"),
+    (0xfd35, Mapped, "\u0633\u062d"),
+    (0xfd36, Mapped, "\u0633\u062e"),
+    (0xfd37, Mapped, "\u0634\u062c"),
+    (0xfd38, Mapped, "\u0634\u062d"),
+    (0xfd39, Mapped, "\u0634\u062e"),
+    (0xfd3a, Mapped, "\u0637\u0645"),
+    (0xfd3b, Mapped, "\u0638\u0645"),
+    (0xfd3c, Mapped, "\u0627\u064b"),
+    (0xfd3e, Valid, ""),
+    (0xfd50, Mapped, "\u062a\u062c\u0645"),
+    (0xfd51, Mapped, "\u062a\u062d\u062c"),
+    (0xfd53, Mapped, "\u062a\u062d\u0645"),
+    (0xfd54, Mapped, "\u062a\u062e\u0645"),
+    (0xfd55, Mapped, "\u062a\u0645\u062c"),
+    (0xfd56, Mapped, "\u062a\u0645\u062d"),
+    (0xfd57, Mapped, "\u062a\u0645\u062e"),
+    (0xfd58, Mapped, "\u062c\u0645\u062d"),
+    (0xfd5a, Mapped, "\u062d\u0645\u064a"),
+    (0xfd5b, Mapped, "\u062d\u0645\u0649"),
+    (0xfd5c, Mapped, "\u0633\u062d\u062c"),
+    (0xfd5d, Mapped, "\u0633\u062c\u062d"),
+    (0xfd5e, Mapped, "\u0633\u062c\u0649"),
+    (0xfd5f, Mapped, "\u0633\u0645\u062d"),
+    (0xfd61, Mapped, "\u0633\u0645\u062c"),
+    (0xfd62, Mapped, "\u0633\u0645\u0645"),
+    (0xfd64, Mapped, "\u0635\u062d\u062d"),
+    (0xfd66, Mapped, "\u0635\u0645\u0645"),
+    (0xfd67, Mapped, "\u0634\u062d\u0645"),
+    (0xfd69, Mapped, "\u0634\u062c\u064a"),
+    (0xfd6a, Mapped, "\u0634\u0645\u062e"),
+    (0xfd6c, Mapped, "\u0634\u0645\u0645"),
+    (0xfd6e, Mapped, "\u0636\u062d\u0649"),
+    (0xfd6f, Mapped, "\u0636\u062e\u0645"),
+    (0xfd71, Mapped, "\u0637\u0645\u062d"),
+    (0xfd73, Mapped, "\u0637\u0645\u0645"),
+    (0xfd74, Mapped, "\u0637\u0645\u064a"),
+    (0xfd75, Mapped, "\u0639\u062c\u0645"),
+    (0xfd76, Mapped, "\u0639\u0645\u0645"),
+    (0xfd78, Mapped, "\u0639\u0645\u0649"),
+    (0xfd79, Mapped, "\u063a\u0645\u0645"),
+    (0xfd7a, Mapped, "\u063a\u0645\u064a"),
+    (0xfd7b, Mapped, "\u063a\u0645\u0649"),
+    (0xfd7c, Mapped, "\u0641\u062e\u0645"),
+    (0xfd7e, Mapped, "\u0642\u0645\u062d"),
+    (0xfd7f, Mapped, "\u0642\u0645\u0645"),
+    (0xfd80, Mapped, "\u0644\u062d\u0645"),
+    (0xfd81, Mapped, "\u0644\u062d\u064a"),
+    (0xfd82, Mapped, "\u0644\u062d\u0649"),
+    (0xfd83, Mapped, "\u0644\u062c\u062c"),
+    (0xfd85, Mapped, "\u0644\u062e\u0645"),
+    (0xfd87, Mapped, "\u0644\u0645\u062d"),
+    (0xfd89, Mapped, "\u0645\u062d\u062c"),
+    (0xfd8a, Mapped, "\u0645\u062d\u0645"),
+    (0xfd8b, Mapped, "\u0645\u062d\u064a"),
+    (0xfd8c, Mapped, "\u0645\u062c\u062d"),
+    (0xfd8d, Mapped, "\u0645\u062c\u0645"),
+    (0xfd8e, Mapped, "\u0645\u062e\u062c"),
+    (0xfd8f, Mapped, "\u0645\u062e\u0645"),
+    (0xfd90, Valid, ""),
+    (0xfd92, Mapped, "\u0645\u062c\u062e"),
+    (0xfd93, Mapped, "\u0647\u0645\u062c"),
+    (0xfd94, Mapped, "\u0647\u0645\u0645"),
+    (0xfd95, Mapped, "\u0646\u062d\u0645"),
+    (0xfd96, Mapped, "\u0646\u062d\u0649"),
+    (0xfd97, Mapped, "\u0646\u062c\u0645"),
+    (0xfd99, Mapped, "\u0646\u062c\u0649"),
+    (0xfd9a, Mapped, "\u0646\u0645\u064a"),
+    (0xfd9b, Mapped, "\u0646\u0645\u0649"),
+    (0xfd9c, Mapped, "\u064a\u0645\u0645"),
+    (0xfd9e, Mapped, "\u0628\u062e\u064a"),
+    (0xfd9f, Mapped, "\u062a\u062c\u064a"),
+    (0xfda0, Mapped, "\u062a\u062c\u0649"),
+    (0xfda1, Mapped, "\u062a\u062e\u064a"),
+    (0xfda2, Mapped, "\u062a\u062e\u0649"),
+    (0xfda3, Mapped, "\u062a\u0645\u064a"),
+    (0xfda4, Mapped, "\u062a\u0645\u0649"),
+    (0xfda5, Mapped, "\u062c\u0645\u064a"),
+    (0xfda6, Mapped, "\u062c\u062d\u0649"),
+    (0xfda7, Mapped, "\u062c\u0645\u0649"),
+    (0xfda8, Mapped, "\u0633\u062e\u0649"),
+    (0xfda9, Mapped, "\u0635\u062d\u064a"),
+    (0xfdaa, Mapped, "\u0634\u062d\u064a"),
+    (0xfdab, Mapped, "\u0636\u062d\u064a"),
+    (0xfdac, Mapped, "\u0644\u062c\u064a"),
+    (0xfdad, Mapped, "\u0644\u0645\u064a"),
+    (0xfdae, Mapped, "\u064a\u062d\u064a"),
+    (0xfdaf, Mapped, "\u064a\u062c\u064a"),
+    (0xfdb0, Mapped, "\u064a\u0645\u064a"),
+    (0xfdb1, Mapped, "\u0645\u0645\u064a"),
+    (0xfdb2, Mapped, "\u0642\u0645\u064a"),
+    (0xfdb3, Mapped, "\u0646\u062d\u064a"),
+    (0xfdb4, Mapped, "\u0642\u0645\u062d"),
+    (0xfdb5, Mapped, "\u0644\u062d\u0645"),
+    (0xfdb6, Mapped, "\u0639\u0645\u064a"),
+    (0xfdb7, Mapped, "\u0643\u0645\u064a"),
+    (0xfdb8, Mapped, "\u0646\u062c\u062d"),
+    (0xfdb9, Mapped, "\u0645\u062e\u064a"),
+    (0xfdba, Mapped, "\u0644\u062c\u0645"),
+    (0xfdbb, Mapped, "\u0643\u0645\u0645"),
+    (0xfdbc, Mapped, "\u0644\u062c\u0645"),
+    (0xfdbd, Mapped, "\u0646\u062c\u062d"),
+    (0xfdbe, Mapped, "\u062c\u062d\u064a"),
+    (0xfdbf, Mapped, "\u062d\u062c\u064a"),
+    (0xfdc0, Mapped, "\u0645\u062c\u064a"),
+    (0xfdc1, Mapped, "\u0641\u0645\u064a"),
+    (0xfdc2, Mapped, "\u0628\u062d\u064a"),
+    (0xfdc3, Mapped, "\u0643\u0645\u0645"),
+    (0xfdc4, Mapped, "\u0639\u062c\u0645"),
+    (0xfdc5, Mapped, "\u0635\u0645\u0645"),
+    (0xfdc6, Mapped, "\u0633\u062e\u064a"),
+    (0xfdc7, Mapped, "\u0646\u062c\u064a"),
+    (0xfdc8, Valid, ""),
+    (0xfdd0, Disallowed, ""),
+    (0xfdf0, Mapped, "\u0635\u0644\u06d2"),
+    (0xfdf1, Mapped, "\u0642\u0644\u06d2"),
+    (0xfdf2, Mapped, "\u0627\u0644\u0644\u0647"),
+    (0xfdf3, Mapped, "\u0627\u0643\u0628\u0631"),
+    (0xfdf4, Mapped, "\u0645\u062d\u0645\u062f"),
+    (0xfdf5, Mapped, "\u0635\u0644\u0639\u0645"),
+    (0xfdf6, Mapped, "\u0631\u0633\u0648\u0644"),
+    (0xfdf7, Mapped, "\u0639\u0644\u064a\u0647"),
+    (0xfdf8, Mapped, "\u0648\u0633\u0644\u0645"),
+    (0xfdf9, Mapped, "\u0635\u0644\u0649"),
+    (0xfdfa, Mapped, "\u0635\u0644\u0649 \u0627\u0644\u0644\u0647 \u0639\u0644\u064a\u0647 \u0648\u0633\u0644\u0645"),
+    (0xfdfb, Mapped, "\u062c\u0644 \u062c\u0644\u0627\u0644\u0647"),
+    (0xfdfc, Mapped, "\u0631\u06cc\u0627\u0644"),
+    (0xfdfd, Valid, ""),
+    (0xfe00, Ignored, ""),
+    (0xfe10, Mapped, ","),
+    (0xfe11, Mapped, "\u3001"),
+    (0xfe12, Disallowed, ""),
+    (0xfe13, Mapped, ":"),
+    (0xfe14, Mapped, ";"),
+    (0xfe15, Mapped, "!"),
+    (0xfe16, Mapped, "?"),
+    (0xfe17, Mapped, "\u3016"),
+    (0xfe18, Mapped, "\u3017"),
+    (0xfe19, Disallowed, ""),
+    (0xfe20, Valid, ""),
+    (0xfe30, Disallowed, ""),
+    (0xfe31, Mapped, "\u2014"),
+    (0xfe32, Mapped, "\u2013"),
+    (0xfe33, Mapped, "_"),
+    (0xfe35, Mapped, "("),
+    (0xfe36, Mapped, ")"),
+    (0xfe37, Mapped, "{"),
+    (0xfe38, Mapped, "}"),
+    (0xfe39, Mapped, "\u3014"),
+    (0xfe3a, Mapped, "\u3015"),
+    (0xfe3b, Mapped, "\u3010"),
+    (0xfe3c, Mapped, "\u3011"),
+    (0xfe3d, Mapped, "\u300a"),
+    (0xfe3e, Mapped, "\u300b"),
+    (0xfe3f, Mapped, "\u3008"),
+    (0xfe40, Mapped, "\u3009"),
+    (0xfe41, Mapped, "\u300c"),
+    (0xfe42, Mapped, "\u300d"),
+    (0xfe43, Mapped, "\u300e"),
+    (0xfe44, Mapped, "\u300f"),
+    (0xfe45, Valid, ""),
+    (0xfe47, Mapped, "["),
+    (0xfe48, Mapped, "]"),
+    (0xfe49, Mapped, " \u0305"),
+    (0xfe4d, Mapped, "_"),
+    (0xfe50, Mapped, ","),
+    (0xfe51, Mapped, "\u3001"),
+    (0xfe52, Disallowed, ""),
+    (0xfe54, Mapped, ";"),
+    (0xfe55, Mapped, ":"),
+    (0xfe56, Mapped, "?"),
+    (0xfe57, Mapped, "!"),
+    (0xfe58, Mapped, "\u2014"),
+    (0xfe59, Mapped, "("),
+    (0xfe5a, Mapped, ")"),
+    (0xfe5b, Mapped, "{"),
+    (0xfe5c, Mapped, "}"),
+    (0xfe5d, Mapped, "\u3014"),
+    (0xfe5e, Mapped, "\u3015"),
+    (0xfe5f, Mapped, "#"),
+    (0xfe60, Mapped, "&"),
+    (0xfe61, Mapped, "*"),
+    (0xfe62, Mapped, "+"),
+    (0xfe63, Mapped, "-"),
+    (0xfe64, Mapped, "<"),
+    (0xfe65, Mapped, ">"),
+    (0xfe66, Mapped, "="),
+    (0xfe67, Disallowed, ""),
+    (0xfe68, Mapped, "\u005c"),
+    (0xfe69, Mapped, "$"),
+    (0xfe6a, Mapped, "%"),
+    (0xfe6b, Mapped, "@"),
+    (0xfe6c, Disallowed, ""),
+    (0xfe70, Mapped, " \u064b"),
+    (0xfe71, Mapped, "\u0640\u064b"),
+    (0xfe72, Mapped, " \u064c"),
+    (0xfe73, Valid, ""),
+    (0xfe74, Mapped, " \u064d"),
+    (0xfe75, Disallowed, ""),
+    (0xfe76, Mapped, " \u064e"),
+    (0xfe77, Mapped, "\u0640\u064e"),
+    (0xfe78, Mapped, " \u064f"),
+    (0xfe79, Mapped, "\u0640\u064f"),
+    (0xfe7a, Mapped, " \u0650"),
+    (0xfe7b, Mapped, "\u0640\u0650"),
+    (0xfe7c, Mapped, " \u0651"),
+    (0xfe7d, Mapped, "\u0640\u0651"),
+    (0xfe7e, Mapped, " \u0652"),
+    (0xfe7f, Mapped, "\u0640\u0652"),
+    (0xfe80, Mapped, "\u0621"),
+    (0xfe81, Mapped, "\u0622"),
+    (0xfe83, Mapped, "\u0623"),
+    (0xfe85, Mapped, "\u0624"),
+    (0xfe87, Mapped, "\u0625"),
+    (0xfe89, Mapped, "\u0626"),
+    (0xfe8d, Mapped, "\u0627"),
+    (0xfe8f, Mapped, "\u0628"),
+    (0xfe93, Mapped, "\u0629"),
+    (0xfe95, Mapped, "\u062a"),
+    (0xfe99, Mapped, "\u062b"),
+    (0xfe9d, Mapped, "\u062c"),
+    (0xfea1, Mapped, "\u062d"),
+    (0xfea5, Mapped, "\u062e"),
+    (0xfea9, Mapped, "\u062f"),
+    (0xfeab, Mapped, "\u0630"),
+    (0xfead, Mapped, "\u0631"),
+    (0xfeaf, Mapped, "\u0632"),
+    (0xfeb1, Mapped, "\u0633"),
+    (0xfeb5, Mapped, "\u0634"),
+    (0xfeb9, Mapped, "\u0635"),
+    (0xfebd, Mapped, "\u0636"),
+    (0xfec1, Mapped, "\u0637"),
+    (0xfec5, Mapped, "\u0638"),
+    (0xfec9, Mapped, "\u0639"),
+    (0xfecd, Mapped, "\u063a"),
+    (0xfed1, Mapped, "\u0641"),
+    (0xfed5, Mapped, "\u0642"),
+    (0xfed9, Mapped, "\u0643"),
+    (0xfedd, Mapped, "\u0644"),
+    (0xfee1, Mapped, "\u0645"),
+    (0xfee5, Mapped, "\u0646"),
+    (0xfee9, Mapped, "\u0647"),
+    (0xfeed, Mapped, "\u0648"),
+    (0xfeef, Mapped, "\u0649"),
+    (0xfef1, Mapped, "\u064a"),
+    (0xfef5, Mapped, "\u0644\u0622"),
+    (0xfef7, Mapped, "\u0644\u0623"),
+    (0xfef9, Mapped, "\u0644\u0625"),
+    (0xfefb, Mapped, "\u0644\u0627"),
+    (0xfefd, Disallowed, ""),
+    (0xfeff, Ignored, ""),
+    (0xff00, Disallowed, ""),
+    (0xff01, Mapped, "!"),
+    (0xff02, Mapped, "\u0022"),
+    (0xff03, Mapped, "#"),
+    (0xff04, Mapped, "$"),
+    (0xff05, Mapped, "%"),
+    (0xff06, Mapped, "&"),
+    (0xff07, Mapped, "'"),
+    (0xff08, Mapped, "("),
+    (0xff09, Mapped, ")"),
+    (0xff0a, Mapped, "*"),
+    (0xff0b, Mapped, "+"),
+    (0xff0c, Mapped, ","),
+    (0xff0d, Mapped, "-"),
+    (0xff0e, Mapped, "."),
+    (0xff0f, Mapped, "/"),
+    (0xff10, Mapped, "0"),
+    (0xff11, Mapped, "1"),
+    (0xff12, Mapped, "2"),
+    (0xff13, Mapped, "3"),
+    (0xff14, Mapped, "4"),
+    (0xff15, Mapped, "5"),
+    (0xff16, Mapped, "6"),
+    (0xff17, Mapped, "7"),
+    (0xff18, Mapped, "8"),
+    (0xff19, Mapped, "9"),
+    (0xff1a, Mapped, ":"),
+    (0xff1b, Mapped, ";"),
+    (0xff1c, Mapped, "<"),
+    (0xff1d, Mapped, "="),
+    (0xff1e, Mapped, ">"),
+    (0xff1f, Mapped, "?"),
+    (0xff20, Mapped, "@"),
+    (0xff21, Mapped, "a"),
+    (0xff22, Mapped, "b"),
+    (0xff23, Mapped, "c"),
+    (0xff24, Mapped, "d"),
+    (0xff25, Mapped, "e"),
+    (0xff26, Mapped, "f"),
+    (0xff27, Mapped, "g"),
+    (0xff28, Mapped, "h"),
+    (0xff29, Mapped, "i"),
+    (0xff2a, Mapped, "j"),
+    (0xff2b, Mapped, "k"),
+    (0xff2c, Mapped, "l"),
+    (0xff2d, Mapped, "m"),
+    (0xff2e, Mapped, "n"),
+    (0xff2f, Mapped, "o"),
+    (0xff30, Mapped, "p"),
+    (0xff31, Mapped, "q"),
+    (0xff32, Mapped, "r"),
+    (0xff33, Mapped, "s"),
+    (0xff34, Mapped, "t"),
+    (0xff35, Mapped, "u"),
+    (0xff36, Mapped, "v"),
+    (0xff37, Mapped, "w"),
+    (0xff38, Mapped, "x"),
+    (0xff39, Mapped, "y"),
+    (0xff3a, Mapped, "z"),
+    (0xff3b, Mapped, "["),
+    (0xff3c, Mapped, "\u005c"),
+    (0xff3d, Mapped, "]"),
+    (0xff3e, Mapped, "^"),
+    (0xff3f, Mapped, "_"),
+    (0xff40, Mapped, "`"),
+    (0xff41, Mapped, "a"),
+    (0xff42, Mapped, "b"),
+    (0xff43, Mapped, "c"),
+    (0xff44, Mapped, "d"),
+    (0xff45, Mapped, "e"),
+    (0xff46, Mapped, "f"),
+    (0xff47, Mapped, "g"),
+    (0xff48, Mapped, "h"),
+    (0xff49, Mapped, "i"),
+    (0xff4a, Mapped, "j"),
+    (0xff4b, Mapped, "k"),
+    (0xff4c, Mapped, "l"),
+    (0xff4d, Mapped, "m"),
+    (0xff4e, Mapped, "n"),
+    (0xff4f, Mapped, "o"),
+    (0xff50, Mapped, "p"),
+    (0xff51, Mapped, "q"),
+    (0xff52, Mapped, "r"),
+    (0xff53, Mapped, "s"),
+    (0xff54, Mapped, "t"),
+    (0xff55, Mapped, "u"),
+    (0xff56, Mapped, "v"),
+    (0xff57, Mapped, "w"),
+    (0xff58, Mapped, "x"),
+    (0xff59, Mapped, "y"),
+    (0xff5a, Mapped, "z"),
+    (0xff5b, Mapped, "{"),
+    (0xff5c, Mapped, "|"),
+    (0xff5d, Mapped, "}"),
+    (0xff5e, Mapped, "~"),
+    (0xff5f, Mapped, "\u2985"),
+    (0xff60, Mapped, "\u2986"),
+    (0xff61, Mapped, "."),
+    (0xff62, Mapped, "\u300c"),
+    (0xff63, Mapped, "\u300d"),
+    (0xff64, Mapped, "\u3001"),
+    (0xff65, Mapped, "\u30fb"),
+    (0xff66, Mapped, "\u30f2"),
+    (0xff67, Mapped, "\u30a1"),
+    (0xff68, Mapped, "\u30a3"),
+    (0xff69, Mapped, "\u30a5"),
+    (0xff6a, Mapped, "\u30a7"),
+    (0xff6b, Mapped, "\u30a9"),
+    (0xff6c, Mapped, "\u30e3"),
+    (0xff6d, Mapped, "\u30e5"),
+    (0xff6e, Mapped, "\u30e7"),
+    (0xff6f, Mapped, "\u30c3"),
+    (0xff70, Mapped, "\u30fc"),
+    (0xff71, Mapped, "\u30a2"),
+    (0xff72, Mapped, "\u30a4"),
+    (0xff73, Mapped, "\u30a6"),
+    (0xff74, Mapped, "\u30a8"),
+    (0xff75, Mapped, "\u30aa"),
+    (0xff76, Mapped, "\u30ab"),
+    (0xff77, Mapped, "\u30ad"),
+    (0xff78, Mapped, "\u30af"),
+    (0xff79, Mapped, "\u30b1"),
+    (0xff7a, Mapped, "\u30b3"),
+    (0xff7b, Mapped, "\u30b5"),
+    (0xff7c, Mapped, "\u30b7"),
+    (0xff7d, Mapped, "\u30b9"),
+    (0xff7e, Mapped, "\u30bb"),
+    (0xff7f, Mapped, "\u30bd"),
+    (0xff80, Mapped, "\u30bf"),
+    (0xff81, Mapped, "\u30c1"),
+    (0xff82, Mapped, "\u30c4"),
+    (0xff83, Mapped, "\u30c6"),
+    (0xff84, Mapped, "\u30c8"),
+    (0xff85, Mapped, "\u30ca"),
+    (0xff86, Mapped, "\u30cb"),
+    (0xff87, Mapped, "\u30cc"),
+    (0xff88, Mapped, "\u30cd"),
+    (0xff89, Mapped, "\u30ce"),
+    (0xff8a, Mapped, "\u30cf"),
+    (0xff8b, Mapped, "\u30d2"),
+    (0xff8c, Mapped, "\u30d5"),
+    (0xff8d, Mapped, "\u30d8"),
+    (0xff8e, Mapped, "\u30db"),
+    (0xff8f, Mapped, "\u30de"),
+    (0xff90, Mapped, "\u30df"),
+    (0xff91, Mapped, "\u30e0"),
+    (0xff92, Mapped, "\u30e1"),
+    (0xff93, Mapped, "\u30e2"),
+    (0xff94, Mapped, "\u30e4"),
+    (0xff95, Mapped, "\u30e6"),
+    (0xff96, Mapped, "\u30e8"),
+    (0xff97, Mapped, "\u30e9"),
+    (0xff98, Mapped, "\u30ea"),
+    (0xff99, Mapped, "\u30eb"),
+    (0xff9a, Mapped, "\u30ec"),
+    (0xff9b, Mapped, "\u30ed"),
+    (0xff9c, Mapped, "\u30ef"),
+    (0xff9d, Mapped, "\u30f3"),
+    (0xff9e, Mapped, "\u3099"),
+    (0xff9f, Mapped, "\u309a"),
+    (0xffa0, Ignored, ""),
+    (0xffa1, Mapped, "\u1100"),
+    (0xffa2, Mapped, "\u1101"),
+    (0xffa3, Mapped, "\u11aa"),
+    (0xffa4, Mapped, "\u1102"),
+    (0xffa5, Mapped, "\u11ac"),
+    (0xffa6, Mapped, "\u11ad"),
+    (0xffa7, Mapped, "\u1103"),
+    (0xffa8, Mapped, "\u1104"),
+    (0xffa9, Mapped, "\u1105"),
+    (0xffaa, Mapped, "\u11b0"),
+    (0xffab, Mapped, "\u11b1"),
+    (0xffac, Mapped, "\u11b2"),
+    (0xffad, Mapped, "\u11b3"),
+    (0xffae, Mapped, "\u11b4"),
+    (0xffaf, Mapped, "\u11b5"),
+    (0xffb0, Mapped, "\u111a"),
+    (0xffb1, Mapped, "\u1106"),
+    (0xffb2, Mapped, "\u1107"),
+    (0xffb3, Mapped, "\u1108"),
+    (0xffb4, Mapped, "\u1121"),
+    (0xffb5, Mapped, "\u1109"),
+    (0xffb6, Mapped, "\u110a"),
+    (0xffb7, Mapped, "\u110b"),
+    (0xffb8, Mapped, "\u110c"),
+    (0xffb9, Mapped, "\u110d"),
+    (0xffba, Mapped, "\u110e"),
+    (0xffbb, Mapped, "\u110f"),
+    (0xffbc, Mapped, "\u1110"),
+    (0xffbd, Mapped, "\u1111"),
+    (0xffbe, Mapped, "\u1112"),
+    (0xffbf, Disallowed, ""),
+    (0xffc2, Mapped, "\u1161"),
+    (0xffc3, Mapped, "\u1162"),
+    (0xffc4, Mapped, "\u1163"),
+    (0xffc5, Mapped, "\u1164"),
+    (0xffc6, Mapped, "\u1165"),
+    (0xffc7, Mapped, "\u1166"),
+    (0xffc8, Disallowed, ""),
+    (0xffca, Mapped, "\u1167"),
+    (0xffcb, Mapped, "\u1168"),
+    (0xffcc, Mapped, "\u1169"),
+    (0xffcd, Mapped, "\u116a"),
+    (0xffce, Mapped, "\u116b"),
+    (0xffcf, Mapped, "\u116c"),
+    (0xffd0, Disallowed, ""),
+    (0xffd2, Mapped, "\u116d"),
+    (0xffd3, Mapped, "\u116e"),
+    (0xffd4, Mapped, "\u116f"),
+    (0xffd5, Mapped, "\u1170"),
+    (0xffd6, Mapped, "\u1171"),
+    (0xffd7, Mapped, "\u1172"),
+    (0xffd8, Disallowed, ""),
+    (0xffda, Mapped, "\u1173"),
+    (0xffdb, Mapped, "\u1174"),
+    (0xffdc, Mapped, "\u1175"),
+    (0xffdd, Disallowed, ""),
+    (0xffe0, Mapped, "\u00a2"),
+    (0xffe1, Mapped, "\u00a3"),
+    (0xffe2, Mapped, "\u00ac"),
+    (0xffe3, Mapped, " \u0304"),
+    (0xffe4, Mapped, "\u00a6"),
+    (0xffe5, Mapped, "\u00a5"),
+    (0xffe6, Mapped, "\u20a9"),
+    (0xffe7, Disallowed, ""),
+    (0xffe8, Mapped, "\u2502"),
+    (0xffe9, Mapped, "\u2190"),
+    (0xffea, Mapped, "\u2191"),
+    (0xffeb, Mapped, "\u2192"),
+    (0xffec, Mapped, "\u2193"),
+    (0xffed, Mapped, "\u25a0"),
+    (0xffee, Mapped, "\u25cb"),
+    (0xffef, Disallowed, ""),
+    (0x10000, Valid, ""),
+    (0x1000c, Disallowed, ""),
+    (0x1000d, Valid, ""),
+    (0x10027, Disallowed, ""),
+    (0x10028, Valid, ""),
+    (0x1003b, Disallowed, ""),
+    (0x1003c, Valid, ""),
+    (0x1003e, Disallowed, ""),
+    (0x1003f, Valid, ""),
+    (0x1004e, Disallowed, ""),
+    (0x10050, Valid, ""),
+    (0x1005e, Disallowed, ""),
+    (0x10080, Valid, ""),
+    (0x100fb, Disallowed, ""),
+    (0x10100, Valid, ""),
+    (0x10103, Disallowed, ""),
+    (0x10107, Valid, ""),
+    (0x10134, Disallowed, ""),
+    (0x10137, Valid, ""),
+    (0x1018f, Disallowed, ""),
+    (0x10190, Valid, ""),
+    (0x1019d, Disallowed, ""),
+    (0x101a0, Valid, ""),
+    (0x101a1, Disallowed, ""),
+    (0x101d0, Valid, ""),
+    (0x101fe, Disallowed, ""),
+    (0x10280, Valid, ""),
+    (0x1029d, Disallowed, ""),
+    (0x102a0, Valid, ""),
+    (0x102d1, Disallowed, ""),
+    (0x102e0, Valid, ""),
+    (0x102fc, Disallowed, ""),
+    (0x10300, Valid, ""),
+    (0x10324, Disallowed, ""),
+    (0x1032d, Valid, ""),
+    (0x1034b, Disallowed, ""),
+    (0x10350, Valid, ""),
+    (0x1037b, Disallowed, ""),
+    (0x10380, Valid, ""),
+    (0x1039e, Disallowed, ""),
+    (0x1039f, Valid, ""),
+    (0x103c4, Disallowed, ""),
+    (0x103c8, Valid, ""),
+    (0x103d6, Disallowed, ""),
+    (0x10400, Mapped, "\U00010428"),
+    (0x10401, Mapped, "\U00010429"),
+    (0x10402, Mapped, "\U0001042a"),
+    (0x10403, Mapped, "\U0001042b"),
+    (0x10404, Mapped, "\U0001042c"),
+    (0x10405, Mapped, "\U0001042d"),
+    (0x10406, Mapped, "\U0001042e"),
+    (0x10407, Mapped, "\U0001042f"),
+    (0x10408, Mapped, "\U00010430"),
+    (0x10409, Mapped, "\U00010431"),
+    (0x1040a, Mapped, "\U00010432"),
+    (0x1040b, Mapped, "\U00010433"),
+    (0x1040c, Mapped, "\U00010434"),
+    (0x1040d, Mapped, "\U00010435"),
+    (0x1040e, Mapped, "\U00010436"),
+    (0x1040f, Mapped, "\U00010437"),
+    (0x10410, Mapped, "\U00010438"),
+    (0x10411, Mapped, "\U00010439"),
+    (0x10412, Mapped, "\U0001043a"),
+    (0x10413, Mapped, "\U0001043b"),
+    (0x10414, Mapped, "\U0001043c"),
+    (0x10415, Mapped, "\U0001043d"),
+    (0x10416, Mapped, "\U0001043e"),
+    (0x10417, Mapped, "\U0001043f"),
+    (0x10418, Mapped, "\U00010440"),
+    (0x10419, Mapped, "\U00010441"),
+    (0x1041a, Mapped, "\U00010442"),
+    (0x1041b, Mapped, "\U00010443"),
+    (0x1041c, Mapped, "\U00010444"),
+    (0x1041d, Mapped, "\U00010445"),
+    (0x1041e, Mapped, "\U00010446"),
+    (0x1041f, Mapped, "\U00010447"),
+    (0x10420, Mapped, "\U00010448"),
+    (0x10421, Mapped, "\U00010449"),
+    (0x10422, Mapped, "\U0001044a"),
+    (0x10423, Mapped, "\U0001044b"),
+    (0x10424, Mapped, "\U0001044c"),
+    (0x10425, Mapped, "\U0001044d"),
+    (0x10426, Mapped, "\U0001044e"),
+    (0x10427, Mapped, "\U0001044f"),
+    (0x10428, Valid, ""),
+    (0x1049e, Disallowed, ""),
+    (0x104a0, Valid, ""),
+    (0x104aa, Disallowed, ""),
+    (0x104b0, Mapped, "\U000104d8"),
+    (0x104b1, Mapped, "\U000104d9"),
+    (0x104b2, Mapped, "\U000104da"),
+    (0x104b3, Mapped, "\U000104db"),
+    (0x104b4, Mapped, "\U000104dc"),
+    (0x104b5, Mapped, "\U000104dd"),
+    (0x104b6, Mapped, "\U000104de"),
+    (0x104b7, Mapped, "\U000104df"),
+    (0x104b8, Mapped, "\U000104e0"),
+    (0x104b9, Mapped, "\U000104e1"),
+    (0x104ba, Mapped, "\U000104e2"),
+    (0x104bb, Mapped, "\U000104e3"),
+    (0x104bc, Mapped, "\U000104e4"),
+    (0x104bd, Mapped, "\U000104e5"),
+    (0x104be, Mapped, "\U000104e6"),
+    (0x104bf, Mapped, "\U000104e7"),
+    (0x104c0, Mapped, "\U000104e8"),
+    (0x104c1, Mapped, "\U000104e9"),
+    (0x104c2, Mapped, "\U000104ea"),
+    (0x104c3, Mapped, "\U000104eb"),
+    (0x104c4, Mapped, "\U000104ec"),
+    (0x104c5, Mapped, "\U000104ed"),
+    (0x104c6, Mapped, "\U000104ee"),
+    (0x104c7, Mapped, "\U000104ef"),
+    (0x104c8, Mapped, "\U000104f0"),
+    (0x104c9, Mapped, "\U000104f1"),
+    (0x104ca, Mapped, "\U000104f2"),
+    (0x104cb, Mapped, "\U000104f3"),
+    (0x104cc, Mapped, "\U000104f4"),
+    (0x104cd, Mapped, "\U000104f5"),
+    (0x104ce, Mapped, "\U000104f6"),
+    (0x104cf, Mapped, "\U000104f7"),
+    (0x104d0, Mapped, "\U000104f8"),
+    (0x104d1, Mapped, "\U000104f9"),
+    (0x104d2, Mapped, "\U000104fa"),
+    (0x104d3, Mapped, "\U000104fb"),
+    (0x104d4, Disallowed, ""),
+    (0x104d8, Valid, ""),
+    (0x104fc, Disallowed, ""),
+    (0x10500, Valid, ""),
+    (0x10528, Disallowed, ""),
+    (0x10530, Valid, ""),
+    (0x10564, Disallowed, ""),
+    (0x1056f, Valid, ""),
+    (0x10570, Mapped, "\U00010597"),
+    (0x10571, Mapped, "\U00010598"),
+    (0x10572, Mapped, "\U00010599"),
+    (0x10573, Mapped, "\U0001059a"),
+    (0x10574, Mapped, "\U0001059b"),
+    (0x10575, Mapped, "\U0001059c"),
+    (0x10576, Mapped, "\U0001059d"),
+    (0x10577, Mapped, "\U0001059e"),
+    (0x10578, Mapped, "\U0001059f"),
+    (0x10579, Mapped, "\U000105a0"),
+    (0x1057a, Mapped, "\U000105a1"),
+    (0x1057b, Disallowed, ""),
+    (0x1057c, Mapped, "\U000105a3"),
+    (0x1057d, Mapped, "\U000105a4"),
+    (0x1057e, Mapped, "\U000105a5"),
+    (0x1057f, Mapped, "\U000105a6"),
+    (0x10580, Mapped, "\U000105a7"),
+    (0x10581, Mapped, "\U000105a8"),
+    (0x10582, Mapped, "\U000105a9"),
+    (0x10583, Mapped, "\U000105aa"),
+    (0x10584, Mapped, "\U000105ab"),
+    (0x10585, Mapped, "\U000105ac"),
+    (0x10586, Mapped, "\U000105ad"),
+    (0x10587, Mapped, "\U000105ae"),
+    (0x10588, Mapped, "\U000105af"),
+    (0x10589, Mapped, "\U000105b0"),
+    (0x1058a, Mapped, "\U000105b1"),
+    (0x1058b, Disallowed, ""),
+    (0x1058c, Mapped, "\U000105b3"),
+    (0x1058d, Mapped, "\U000105b4"),
+    (0x1058e, Mapped, "\U000105b5"),
+    (0x1058f, Mapped, "\U000105b6"),
+    (0x10590, Mapped, "\U000105b7"),
+    (0x10591, Mapped, "\U000105b8"),
+    (0x10592, Mapped, "\U000105b9"),
+    (0x10593, Disallowed, ""),
+    (0x10594, Mapped, "\U000105bb"),
+    (0x10595, Mapped, "\U000105bc"),
+    (0x10596, Disallowed, ""),
+    (0x10597, Valid, ""),
+    (0x105a2, Disallowed, ""),
+    (0x105a3, Valid, ""),
+    (0x105b2, Disallowed, ""),
+    (0x105b3, Valid, ""),
+    (0x105ba, Disallowed, ""),
+    (0x105bb, Valid, ""),
+    (0x105bd, Disallowed, ""),
+    (0x105c0, Valid, ""),
+    (0x105f4, Disallowed, ""),
+    (0x10600, Valid, ""),
+    (0x10737, Disallowed, ""),
+    (0x10740, Valid, ""),
+    (0x10756, Disallowed, ""),
+    (0x10760, Valid, ""),
+    (0x10768, Disallowed, ""),
+    (0x10780, Valid, ""),
+    (0x10781, Mapped, "\u02d0"),
+    (0x10782, Mapped, "\u02d1"),
+    (0x10783, Mapped, "\u00e6"),
+    (0x10784, Mapped, "\u0299"),
+    (0x10785, Mapped, "\u0253"),
+    (0x10786, Disallowed, ""),
+    (0x10787, Mapped, "\u02a3"),
+    (0x10788, Mapped, "\uab66"),
+    (0x10789, Mapped, "\u02a5"),
+    (0x1078a, Mapped, "\u02a4"),
+    (0x1078b, Mapped, "\u0256"),
+    (0x1078c, Mapped, "\u0257"),
+    (0x1078d, Mapped, "\u1d91"),
+    (0x1078e, Mapped, "\u0258"),
+    (0x1078f, Mapped, "\u025e"),
+    (0x10790, Mapped, "\u02a9"),
+    (0x10791, Mapped, "\u0264"),
+    (0x10792, Mapped, "\u0262"),
+    (0x10793, Mapped, "\u0260"),
+    (0x10794, Mapped, "\u029b"),
+    (0x10795, Mapped, "\u0127"),
+    (0x10796, Mapped, "\u029c"),
+    (0x10797, Mapped, "\u0267"),
+    (0x10798, Mapped, "\u0284"),
+    (0x10799, Mapped, "\u02aa"),
+    (0x1079a, Mapped, "\u02ab"),
+    (0x1079b, Mapped, "\u026c"),
+    (0x1079c, Mapped, "\U0001df04"),
+    (0x1079d, Mapped, "\ua78e"),
+    (0x1079e, Mapped, "\u026e"),
+    (0x1079f, Mapped, "\U0001df05"),
+    (0x107a0, Mapped, "\u028e"),
+    (0x107a1, Mapped, "\U0001df06"),
+    (0x107a2, Mapped, "\u00f8"),
+    (0x107a3, Mapped, "\u0276"),
+    (0x107a4, Mapped, "\u0277"),
+    (0x107a5, Mapped, "q"),
+    (0x107a6, Mapped, "\u027a"),
+    (0x107a7, Mapped, "\U0001df08"),
+    (0x107a8, Mapped, "\u027d"),
+    (0x107a9, Mapped, "\u027e"),
+    (0x107aa, Mapped, "\u0280"),
+    (0x107ab, Mapped, "\u02a8"),
+    (0x107ac, Mapped, "\u02a6"),
+    (0x107ad, Mapped, "\uab67"),
+    (0x107ae, Mapped, "\u02a7"),
+    (0x107af, Mapped, "\u0288"),
+    (0x107b0, Mapped, "\u2c71"),
+    (0x107b1, Disallowed, ""),
+    (0x107b2, Mapped, "\u028f"),
+    (0x107b3, Mapped, "\u02a1"),
+    (0x107b4, Mapped, "\u02a2"),
+    (0x107b5, Mapped, "\u0298"),
+    (0x107b6, Mapped, "\u01c0"),
+    (0x107b7, Mapped, "\u01c1"),
+    (0x107b8, Mapped, "\u01c2"),
+    (0x107b9, Mapped, "\U0001df0a"),
+    (0x107ba, Mapped, "\U0001df1e"),
+    (0x107bb, Disallowed, ""),
+    (0x10800, Valid, ""),
+    (0x10806, Disallowed, ""),
+    (0x10808, Valid, ""),
+    (0x10809, Disallowed, ""),
+    (0x1080a, Valid, ""),
+    (0x10836, Disallowed, ""),
+    (0x10837, Valid, ""),
+    (0x10839, Disallowed, ""),
+    (0x1083c, Valid, ""),
+    (0x1083d, Disallowed, ""),
+    (0x1083f, Valid, ""),
+    (0x10856, Disallowed, ""),
+    (0x10857, Valid, ""),
+    (0x1089f, Disallowed, ""),
+    (0x108a7, Valid, ""),
+    (0x108b0, Disallowed, ""),
+    (0x108e0, Valid, ""),
+    (0x108f3, Disallowed, ""),
+    (0x108f4, Valid, ""),
+    (0x108f6, Disallowed, ""),
+    (0x108fb, Valid, ""),
+    (0x1091c, Disallowed, ""),
+    (0x1091f, Valid, ""),
+    (0x1093a, Disallowed, ""),
+    (0x1093f, Valid, ""),
+    (0x1095a, Disallowed, ""),
+    (0x10980, Valid, ""),
+    (0x109b8, Disallowed, ""),
+    (0x109bc, Valid, ""),
+    (0x109d0, Disallowed, ""),
+    (0x109d2, Valid, ""),
+    (0x10a04, Disallowed, ""),
+    (0x10a05, Valid, ""),
+    (0x10a07, Disallowed, ""),
+    (0x10a0c, Valid, ""),
+    (0x10a14, Disallowed, ""),
+    (0x10a15, Valid, ""),
+    (0x10a18, Disallowed, ""),
+    (0x10a19, Valid, ""),
+    (0x10a36, Disallowed, ""),
+    (0x10a38, Valid, ""),
+    (0x10a3b, Disallowed, ""),
+    (0x10a3f, Valid, ""),
+    (0x10a49, Disallowed, ""),
+    (0x10a50, Valid, ""),
+    (0x10a59, Disallowed, ""),
+    (0x10a60, Valid, ""),
+    (0x10aa0, Disallowed, ""),
+    (0x10ac0, Valid, ""),
+    (0x10ae7, Disallowed, ""),
+    (0x10aeb, Valid, ""),
+    (0x10af7, Disallowed, ""),
+    (0x10b00, Valid, ""),
+    (0x10b36, Disallowed, ""),
+    (0x10b39, Valid, ""),
+    (0x10b56, Disallowed, ""),
+    (0x10b58, Valid, ""),
+    (0x10b73, Disallowed, ""),
+    (0x10b78, Valid, ""),
+    (0x10b92, Disallowed, ""),
+    (0x10b99, Valid, ""),
+    (0x10b9d, Disallowed, ""),
+    (0x10ba9, Valid, ""),
+    (0x10bb0, Disallowed, ""),
+    (0x10c00, Valid, ""),
+    (0x10c49, Disallowed, ""),
+    (0x10c80, Mapped, "\U00010cc0"),
+    (0x10c81, Mapped, "\U00010cc1"),
+    (0x10c82, Mapped, "\U00010cc2"),
+    (0x10c83, Mapped, "\U00010cc3"),
+    (0x10c84, Mapped, "\U00010cc4"),
+    (0x10c85, Mapped, "\U00010cc5"),
+    (0x10c86, Mapped, "\U00010cc6"),
+    (0x10c87, Mapped, "\U00010cc7"),
+    (0x10c88, Mapped, "\U00010cc8"),
+    (0x10c89, Mapped, "\U00010cc9"),
+    (0x10c8a, Mapped, "\U00010cca"),
+    (0x10c8b, Mapped, "\U00010ccb"),
+    (0x10c8c, Mapped, "\U00010ccc"),
+    (0x10c8d, Mapped, "\U00010ccd"),
+    (0x10c8e, Mapped, "\U00010cce"),
+    (0x10c8f, Mapped, "\U00010ccf"),
+    (0x10c90, Mapped, "\U00010cd0"),
+    (0x10c91, Mapped, "\U00010cd1"),
+    (0x10c92, Mapped, "\U00010cd2"),
+    (0x10c93, Mapped, "\U00010cd3"),
+    (0x10c94, Mapped, "\U00010cd4"),
+    (0x10c95, Mapped, "\U00010cd5"),
+    (0x10c96, Mapped, "\U00010cd6"),
+    (0x10c97, Mapped, "\U00010cd7"),
+    (0x10c98, Mapped, "\U00010cd8"),
+    (0x10c99, Mapped, "\U00010cd9"),
+    (0x10c9a, Mapped, "\U00010cda"),
+    (0x10c9b, Mapped, "\U00010cdb"),
+    (0x10c9c, Mapped, "\U00010cdc"),
+    (0x10c9d, Mapped, "\U00010cdd"),
+    (0x10c9e, Mapped, "\U00010cde"),
+    (0x10c9f, Mapped, "\U00010cdf"),
+    (0x10ca0, Mapped, "\U00010ce0"),
+    (0x10ca1, Mapped, "\U00010ce1"),
+    (0x10ca2, Mapped, "\U00010ce2"),
+    (0x10ca3, Mapped, "\U00010ce3"),
+    (0x10ca4, Mapped, "\U00010ce4"),
+    (0x10ca5, Mapped, "\U00010ce5"),
+    (0x10ca6, Mapped, "\U00010ce6"),
+    (0x10ca7, Mapped, "\U00010ce7"),
+    (0x10ca8, Mapped, "\U00010ce8"),
+    (0x10ca9, Mapped, "\U00010ce9"),
+    (0x10caa, Mapped, "\U00010cea"),
+    (0x10cab, Mapped, "\U00010ceb"),
+    (0x10cac, Mapped, "\U00010cec"),
+    (0x10cad, Mapped, "\U00010ced"),
+    (0x10cae, Mapped, "\U00010cee"),
+    (0x10caf, Mapped, "\U00010cef"),
+    (0x10cb0, Mapped, "\U00010cf0"),
+    (0x10cb1, Mapped, "\U00010cf1"),
+    (0x10cb2, Mapped, "\U00010cf2"),
+    (0x10cb3, Disallowed, ""),
+    (0x10cc0, Valid, ""),
+    (0x10cf3, Disallowed, ""),
+    (0x10cfa, Valid, ""),
+    (0x10d28, Disallowed, ""),
+    (0x10d30, Valid, ""),
+    (0x10d3a, Disallowed, ""),
+    (0x10d40, Valid, ""),
+    (0x10d50, Mapped, "\U00010d70"),
+    (0x10d51, Mapped, "\U00010d71"),
+    (0x10d52, Mapped, "\U00010d72"),
+    (0x10d53, Mapped, "\U00010d73"),
+    (0x10d54, Mapped, "\U00010d74"),
+    (0x10d55, Mapped, "\U00010d75"),
+    (0x10d56, Mapped, "\U00010d76"),
+    (0x10d57, Mapped, "\U00010d77"),
+    (0x10d58, Mapped, "\U00010d78"),
+    (0x10d59, Mapped, "\U00010d79"),
+    (0x10d5a, Mapped, "\U00010d7a"),
+    (0x10d5b, Mapped, "\U00010d7b"),
+    (0x10d5c, Mapped, "\U00010d7c"),
+    (0x10d5d, Mapped, "\U00010d7d"),
+    (0x10d5e, Mapped, "\U00010d7e"),
+    (0x10d5f, Mapped, "\U00010d7f"),
+    (0x10d60, Mapped, "\U00010d80"),
+    (0x10d61, Mapped, "\U00010d81"),
+    (0x10d62, Mapped, "\U00010d82"),
+    (0x10d63, Mapped, "\U00010d83"),
+    (0x10d64, Mapped, "\U00010d84"),
+    (0x10d65, Mapped, "\U00010d85"),
+    (0x10d66, Disallowed, ""),
+    (0x10d69, Valid, ""),
+    (0x10d86, Disallowed, ""),
+    (0x10d8e, Valid, ""),
+    (0x10d90, Disallowed, ""),
+    (0x10e60, Valid, ""),
+    (0x10e7f, Disallowed, ""),
+    (0x10e80, Valid, ""),
+    (0x10eaa, Disallowed, ""),
+    (0x10eab, Valid, ""),
+    (0x10eae, Disallowed, ""),
+    (0x10eb0, Valid, ""),
+    (0x10eb2, Disallowed, ""),
+    (0x10ec2, Valid, ""),
+    (0x10ec8, Disallowed, ""),
+    (0x10ed0, Valid, ""),
+    (0x10ed9, Disallowed, ""),
+    (0x10efa, Valid, ""),
+    (0x10f28, Disallowed, ""),
+    (0x10f30, Valid, ""),
+    (0x10f5a, Disallowed, ""),
+    (0x10f70, Valid, ""),
+    (0x10f8a, Disallowed, ""),
+    (0x10fb0, Valid, ""),
+    (0x10fcc, Disallowed, ""),
+    (0x10fe0, Valid, ""),
+    (0x10ff7, Disallowed, ""),
+    (0x11000, Valid, ""),
+    (0x1104e, Disallowed, ""),
+    (0x11052, Valid, ""),
+    (0x11076, Disallowed, ""),
+    (0x1107f, Valid, ""),
+    (0x110bd, Disallowed, ""),
+    (0x110be, Valid, ""),
+    (0x110c3, Disallowed, ""),
+    (0x110d0, Valid, ""),
+    (0x110e9, Disallowed, ""),
+    (0x110f0, Valid, ""),
+    (0x110fa, Disallowed, ""),
+    (0x11100, Valid, ""),
+    (0x11135, Disallowed, ""),
+    (0x11136, Valid, ""),
+    (0x11148, Disallowed, ""),
+    (0x11150, Valid, ""),
+    (0x11177, Disallowed, ""),
+    (0x11180, Valid, ""),
+    (0x111e0, Disallowed, ""),
+    (0x111e1, Valid, ""),
+    (0x111f5, Disallowed, ""),
+    (0x11200, Valid, ""),
+    (0x11212, Disallowed, ""),
+    (0x11213, Valid, ""),
+    (0x11242, Disallowed, ""),
+    (0x11280, Valid, ""),
+    (0x11287, Disallowed, ""),
+    (0x11288, Valid, ""),
+    (0x11289, Disallowed, ""),
+    (0x1128a, Valid, ""),
+    (0x1128e, Disallowed, ""),
+    (0x1128f, Valid, ""),
+    (0x1129e, Disallowed, ""),
+    (0x1129f, Valid, ""),
+    (0x112aa, Disallowed, ""),
+    (0x112b0, Valid, ""),
+    (0x112eb, Disallowed, ""),
+    (0x112f0, Valid, ""),
+    (0x112fa, Disallowed, ""),
+    (0x11300, Valid, ""),
+    (0x11304, Disallowed, ""),
+    (0x11305, Valid, ""),
+    (0x1130d, Disallowed, ""),
+    (0x1130f, Valid, ""),
+    (0x11311, Disallowed, ""),
+    (0x11313, Valid, ""),
+    (0x11329, Disallowed, ""),
+    (0x1132a, Valid, ""),
+    (0x11331, Disallowed, ""),
+    (0x11332, Valid, ""),
+    (0x11334, Disallowed, ""),
+    (0x11335, Valid, ""),
+    (0x1133a, Disallowed, ""),
+    (0x1133b, Valid, ""),
+    (0x11345, Disallowed, ""),
+    (0x11347, Valid, ""),
+    (0x11349, Disallowed, ""),
+    (0x1134b, Valid, ""),
+    (0x1134e, Disallowed, ""),
+    (0x11350, Valid, ""),
+    (0x11351, Disallowed, ""),
+    (0x11357, Valid, ""),
+    (0x11358, Disallowed, ""),
+    (0x1135d, Valid, ""),
+    (0x11364, Disallowed, ""),
+    (0x11366, Valid, ""),
+    (0x1136d, Disallowed, ""),
+    (0x11370, Valid, ""),
+    (0x11375, Disallowed, ""),
+    (0x11380, Valid, ""),
+    (0x1138a, Disallowed, ""),
+    (0x1138b, Valid, ""),
+    (0x1138c, Disallowed, ""),
+    (0x1138e, Valid, ""),
+    (0x1138f, Disallowed, ""),
+    (0x11390, Valid, ""),
+    (0x113b6, Disallowed, ""),
+    (0x113b7, Valid, ""),
+    (0x113c1, Disallowed, ""),
+    (0x113c2, Valid, ""),
+    (0x113c3, Disallowed, ""),
+    (0x113c5, Valid, ""),
+    (0x113c6, Disallowed, ""),
+    (0x113c7, Valid, ""),
+    (0x113cb, Disallowed, ""),
+    (0x113cc, Valid, ""),
+    (0x113d6, Disallowed, ""),
+    (0x113d7, Valid, ""),
+    (0x113d9, Disallowed, ""),
+    (0x113e1, Valid, ""),
+    (0x113e3, Disallowed, ""),
+    (0x11400, Valid, ""),
+    (0x1145c, Disallowed, ""),
+    (0x1145d, Valid, ""),
+    (0x11462, Disallowed, ""),
+    (0x11480, Valid, ""),
+    (0x114c8, Disallowed, ""),
+    (0x114d0, Valid, ""),
+    (0x114da, Disallowed, ""),
+    (0x11580, Valid, ""),
+    (0x115b6, Disallowed, ""),
+    (0x115b8, Valid, ""),
+    (0x115de, Disallowed, ""),
+    (0x11600, Valid, ""),
+    (0x11645, Disallowed, ""),
+    (0x11650, Valid, ""),
+    (0x1165a, Disallowed, ""),
+    (0x11660, Valid, ""),
+    (0x1166d, Disallowed, ""),
+    (0x11680, Valid, ""),
+    (0x116ba, Disallowed, ""),
+    (0x116c0, Valid, ""),
+    (0x116ca, Disallowed, ""),
+    (0x116d0, Valid, ""),
+    (0x116e4, Disallowed, ""),
+    (0x11700, Valid, ""),
+    (0x1171b, Disallowed, ""),
+    (0x1171d, Valid, ""),
+    (0x1172c, Disallowed, ""),
+    (0x11730, Valid, ""),
+    (0x11747, Disallowed, ""),
+    (0x11800, Valid, ""),
+    (0x1183c, Disallowed, ""),
+    (0x118a0, Mapped, "\U000118c0"),
+    (0x118a1, Mapped, "\U000118c1"),
+    (0x118a2, Mapped, "\U000118c2"),
+    (0x118a3, Mapped, "\U000118c3"),
+    (0x118a4, Mapped, "\U000118c4"),
+    (0x118a5, Mapped, "\U000118c5"),
+    (0x118a6, Mapped, "\U000118c6"),
+    (0x118a7, Mapped, "\U000118c7"),
+    (0x118a8, Mapped, "\U000118c8"),
+    (0x118a9, Mapped, "\U000118c9"),
+    (0x118aa, Mapped, "\U000118ca"),
+    (0x118ab, Mapped, "\U000118cb"),
+    (0x118ac, Mapped, "\U000118cc"),
+    (0x118ad, Mapped, "\U000118cd"),
+    (0x118ae, Mapped, "\U000118ce"),
+    (0x118af, Mapped, "\U000118cf"),
+    (0x118b0, Mapped, "\U000118d0"),
+    (0x118b1, Mapped, "\U000118d1"),
+    (0x118b2, Mapped, "\U000118d2"),
+    (0x118b3, Mapped, "\U000118d3"),
+    (0x118b4, Mapped, "\U000118d4"),
+    (0x118b5, Mapped, "\U000118d5"),
+    (0x118b6, Mapped, "\U000118d6"),
+    (0x118b7, Mapped, "\U000118d7"),
+    (0x118b8, Mapped, "\U000118d8"),
+    (0x118b9, Mapped, "\U000118d9"),
+    (0x118ba, Mapped, "\U000118da"),
+    (0x118bb, Mapped, "\U000118db"),
+    (0x118bc, Mapped, "\U000118dc"),
+    (0x118bd, Mapped, "\U000118dd"),
+    (0x118be, Mapped, "\U000118de"),
+    (0x118bf, Mapped, "\U000118df"),
+    (0x118c0, Valid, ""),
+    (0x118f3, Disallowed, ""),
+    (0x118ff, Valid, ""),
+    (0x11907, Disallowed, ""),
+    (0x11909, Valid, ""),
+    (0x1190a, Disallowed, ""),
+    (0x1190c, Valid, ""),
+    (0x11914, Disallowed, ""),
+    (0x11915, Valid, ""),
+    (0x11917, Disallowed, ""),
+    (0x11918, Valid, ""),
+    (0x11936, Disallowed, ""),
+    (0x11937, Valid, ""),
+    (0x11939, Disallowed, ""),
+    (0x1193b, Valid, ""),
+    (0x11947, Disallowed, ""),
+    (0x11950, Valid, ""),
+    (0x1195a, Disallowed, ""),
+    (0x119a0, Valid, ""),
+    (0x119a8, Disallowed, ""),
+    (0x119aa, Valid, ""),
+    (0x119d8, Disallowed, ""),
+    (0x119da, Valid, ""),
+    (0x119e5, Disallowed, ""),
+    (0x11a00, Valid, ""),
+    (0x11a48, Disallowed, ""),
+    (0x11a50, Valid, ""),
+    (0x11aa3, Disallowed, ""),
+    (0x11ab0, Valid, ""),
+    (0x11af9, Disallowed, ""),
+    (0x11b00, Valid, ""),
+    (0x11b0a, Disallowed, ""),
+    (0x11b60, Valid, ""),
+    (0x11b68, Disallowed, ""),
+    (0x11bc0, Valid, ""),
+    (0x11be2, Disallowed, ""),
+    (0x11bf0, Valid, ""),
+    (0x11bfa, Disallowed, ""),
+    (0x11c00, Valid, ""),
+    (0x11c09, Disallowed, ""),
+    (0x11c0a, Valid, ""),
+    (0x11c37, Disallowed, ""),
+    (0x11c38, Valid, ""),
+    (0x11c46, Disallowed, ""),
+    (0x11c50, Valid, ""),
+    (0x11c6d, Disallowed, ""),
+    (0x11c70, Valid, ""),
+    (0x11c90, Disallowed, ""),
+    (0x11c92, Valid, ""),
+    (0x11ca8, Disallowed, ""),
+    (0x11ca9, Valid, ""),
+    (0x11cb7, Disallowed, ""),
+    (0x11d00, Valid, ""),
+    (0x11d07, Disallowed, ""),
+    (0x11d08, Valid, ""),
+    (0x11d0a, Disallowed, ""),
+    (0x11d0b, Valid, ""),
+    (0x11d37, Disallowed, ""),
+    (0x11d3a, Valid, ""),
+    (0x11d3b, Disallowed, ""),
+    (0x11d3c, Valid, ""),
+    (0x11d3e, Disallowed, ""),
+    (0x11d3f, Valid, ""),
+    (0x11d48, Disallowed, ""),
+    (0x11d50, Valid, ""),
+    (0x11d5a, Disallowed, ""),
+    (0x11d60, Valid, ""),
+    (0x11d66, Disallowed, ""),
+    (0x11d67, Valid, ""),
+    (0x11d69, Disallowed, ""),
+    (0x11d6a, Valid, ""),
+    (0x11d8f, Disallowed, ""),
+    (0x11d90, Valid, ""),
+    (0x11d92, Disallowed, ""),
+    (0x11d93, Valid, ""),
+    (0x11d99, Disallowed, ""),
+    (0x11da0, Valid, ""),
+    (0x11daa, Disallowed, ""),
+    (0x11db0, Valid, ""),
+    (0x11ddc, Disallowed, ""),
+    (0x11de0, Valid, ""),
+    (0x11dea, Disallowed, ""),
+    (0x11ee0, Valid, ""),
+    (0x11ef9, Disallowed, ""),
+    (0x11f00, Valid, ""),
+    (0x11f11, Disallowed, ""),
+    (0x11f12, Valid, ""),
+    (0x11f3b, Disallowed, ""),
+    (0x11f3e, Valid, ""),
+    (0x11f5b, Disallowed, ""),
+    (0x11fb0, Valid, ""),
+    (0x11fb1, Disallowed, ""),
+    (0x11fc0, Valid, ""),
+    (0x11ff2, Disallowed, ""),
+    (0x11fff, Valid, ""),
+    (0x1239a, Disallowed, ""),
+    (0x12400, Valid, ""),
+    (0x1246f, Disallowed, ""),
+    (0x12470, Valid, ""),
+    (0x12475, Disallowed, ""),
+    (0x12480, Valid, ""),
+    (0x12544, Disallowed, ""),
+    (0x12f90, Valid, ""),
+    (0x12ff3, Disallowed, ""),
+    (0x13000, Valid, ""),
+    (0x13430, Disallowed, ""),
+    (0x13440, Valid, ""),
+    (0x13456, Disallowed, ""),
+    (0x13460, Valid, ""),
+    (0x143fb, Disallowed, ""),
+    (0x14400, Valid, ""),
+    (0x14647, Disallowed, ""),
+    (0x16100, Valid, ""),
+    (0x1613a, Disallowed, ""),
+    (0x16800, Valid, ""),
+    (0x16a39, Disallowed, ""),
+    (0x16a40, Valid, ""),
+    (0x16a5f, Disallowed, ""),
+    (0x16a60, Valid, ""),
+    (0x16a6a, Disallowed, ""),
+    (0x16a6e, Valid, ""),
+    (0x16abf, Disallowed, ""),
+    (0x16ac0, Valid, ""),
+    (0x16aca, Disallowed, ""),
+    (0x16ad0, Valid, ""),
+    (0x16aee, Disallowed, ""),
+    (0x16af0, Valid, ""),
+    (0x16af6, Disallowed, ""),
+    (0x16b00, Valid, ""),
+    (0x16b46, Disallowed, ""),
+    (0x16b50, Valid, ""),
+    (0x16b5a, Disallowed, ""),
+    (0x16b5b, Valid, ""),
+    (0x16b62, Disallowed, ""),
+    (0x16b63, Valid, ""),
+    (0x16b78, Disallowed, ""),
+    (0x16b7d, Valid, ""),
+    (0x16b90, Disallowed, ""),
+    (0x16d40, Valid, ""),
+    (0x16d7a, Disallowed, ""),
+    (0x16e40, Mapped, "\U00016e60"),
+    (0x16e41, Mapped, "\U00016e61"),
+    (0x16e42, Mapped, "\U00016e62"),
+    (0x16e43, Mapped, "\U00016e63"),
+    (0x16e44, Mapped, "\U00016e64"),
+    (0x16e45, Mapped, "\U00016e65"),
+    (0x16e46, Mapped, "\U00016e66"),
+    (0x16e47, Mapped, "\U00016e67"),
+    (0x16e48, Mapped, "\U00016e68"),
+    (0x16e49, Mapped, "\U00016e69"),
+    (0x16e4a, Mapped, "\U00016e6a"),
+    (0x16e4b, Mapped, "\U00016e6b"),
+    (0x16e4c, Mapped, "\U00016e6c"),
+    (0x16e4d, Mapped, "\U00016e6d"),
+    (0x16e4e, Mapped, "\U00016e6e"),
+    (0x16e4f, Mapped, "\U00016e6f"),
+    (0x16e50, Mapped, "\U00016e70"),
+    (0x16e51, Mapped, "\U00016e71"),
+    (0x16e52, Mapped, "\U00016e72"),
+    (0x16e53, Mapped, "\U00016e73"),
+    (0x16e54, Mapped, "\U00016e74"),
+    (0x16e55, Mapped, "\U00016e75"),
+    (0x16e56, Mapped, "\U00016e76"),
+    (0x16e57, Mapped, "\U00016e77"),
+    (0x16e58, Mapped, "\U00016e78"),
+    (0x16e59, Mapped, "\U00016e79"),
+    (0x16e5a, Mapped, "\U00016e7a"),
+    (0x16e5b, Mapped, "\U00016e7b"),
+    (0x16e5c, Mapped, "\U00016e7c"),
+    (0x16e5d, Mapped, "\U00016e7d"),
+    (0x16e5e, Mapped, "\U00016e7e"),
+    (0x16e5f, Mapped, "\U00016e7f"),
+    (0x16e60, Valid, ""),
+    (0x16e9b, Disallowed, ""),
+    (0x16ea0, Mapped, "\U00016ebb"),
+    (0x16ea1, Mapped, "\U00016ebc"),
+    (0x16ea2, Mapped, "\U00016ebd"),
+    (0x16ea3, Mapped, "\U00016ebe"),
+    (0x16ea4, Mapped, "\U00016ebf"),
+    (0x16ea5, Mapped, "\U00016ec0"),
+    (0x16ea6, Mapped, "\U00016ec1"),
+    (0x16ea7, Mapped, "\U00016ec2"),
+    (0x16ea8, Mapped, "\U00016ec3"),
+    (0x16ea9, Mapped, "\U00016ec4"),
+    (0x16eaa, Mapped, "\U00016ec5"),
+    (0x16eab, Mapped, "\U00016ec6"),
+    (0x16eac, Mapped, "\U00016ec7"),
+    (0x16ead, Mapped, "\U00016ec8"),
+    (0x16eae, Mapped, "\U00016ec9"),
+    (0x16eaf, Mapped, "\U00016eca"),
+    (0x16eb0, Mapped, "\U00016ecb"),
+    (0x16eb1, Mapped, "\U00016ecc"),
+    (0x16eb2, Mapped, "\U00016ecd"),
+    (0x16eb3, Mapped, "\U00016ece"),
+    (0x16eb4, Mapped, "\U00016ecf"),
+    (0x16eb5, Mapped, "\U00016ed0"),
+    (0x16eb6, Mapped, "\U00016ed1"),
+    (0x16eb7, Mapped, "\U00016ed2"),
+    (0x16eb8, Mapped, "\U00016ed3"),
+    (0x16eb9, Disallowed, ""),
+    (0x16ebb, Valid, ""),
+    (0x16ed4, Disallowed, ""),
+    (0x16f00, Valid, ""),
+    (0x16f4b, Disallowed, ""),
+    (0x16f4f, Valid, ""),
+    (0x16f88, Disallowed, ""),
+    (0x16f8f, Valid, ""),
+    (0x16fa0, Disallowed, ""),
+    (0x16fe0, Valid, ""),
+    (0x16fe5, Disallowed, ""),
+    (0x16ff0, Valid, ""),
+    (0x16ff7, Disallowed, ""),
+    (0x17000, Valid, ""),
+    (0x18cd6, Disallowed, ""),
+    (0x18cff, Valid, ""),
+    (0x18d1f, Disallowed, ""),
+    (0x18d80, Valid, ""),
+    (0x18df3, Disallowed, ""),
+    (0x1aff0, Valid, ""),
+    (0x1aff4, Disallowed, ""),
+    (0x1aff5, Valid, ""),
+    (0x1affc, Disallowed, ""),
+    (0x1affd, Valid, ""),
+    (0x1afff, Disallowed, ""),
+    (0x1b000, Valid, ""),
+    (0x1b123, Disallowed, ""),
+    (0x1b132, Valid, ""),
+    (0x1b133, Disallowed, ""),
+    (0x1b150, Valid, ""),
+    (0x1b153, Disallowed, ""),
+    (0x1b155, Valid, ""),
+    (0x1b156, Disallowed, ""),
+    (0x1b164, Valid, ""),
+    (0x1b168, Disallowed, ""),
+    (0x1b170, Valid, ""),
+    (0x1b2fc, Disallowed, ""),
+    (0x1bc00, Valid, ""),
+    (0x1bc6b, Disallowed, ""),
+    (0x1bc70, Valid, ""),
+    (0x1bc7d, Disallowed, ""),
+    (0x1bc80, Valid, ""),
+    (0x1bc89, Disallowed, ""),
+    (0x1bc90, Valid, ""),
+    (0x1bc9a, Disallowed, ""),
+    (0x1bc9c, Valid, ""),
+    (0x1bca0, Ignored, ""),
+    (0x1bca4, Disallowed, ""),
+    (0x1cc00, Valid, ""),
+    (0x1ccd6, Mapped, "a"),
+    (0x1ccd7, Mapped, "b"),
+    (0x1ccd8, Mapped, "c"),
+    (0x1ccd9, Mapped, "d"),
+    (0x1ccda, Mapped, "e"),
+    (0x1ccdb, Mapped, "f"),
+    (0x1ccdc, Mapped, "g"),
+    (0x1ccdd, Mapped, "h"),
+    (0x1ccde, Mapped, "i"),
+    (0x1ccdf, Mapped, "j"),
+    (0x1cce0, Mapped, "k"),
+    (0x1cce1, Mapped, "l"),
+    (0x1cce2, Mapped, "m"),
+    (0x1cce3, Mapped, "n"),
+    (0x1cce4, Mapped, "o"),
+    (0x1cce5, Mapped, "p"),
+    (0x1cce6, Mapped, "q"),
+    (0x1cce7, Mapped, "r"),
+    (0x1cce8, Mapped, "s"),
+    (0x1cce9, Mapped, "t"),
+    (0x1ccea, Mapped, "u"),
+    (0x1cceb, Mapped, "v"),
+    (0x1ccec, Mapped, "w"),
+    (0x1cced, Mapped, "x"),
+    (0x1ccee, Mapped, "y"),
+    (0x1ccef, Mapped, "z"),
+    (0x1ccf0, Mapped, "0"),
+    (0x1ccf1, Mapped, "1"),
+    (0x1ccf2, Mapped, "2"),
+    (0x1ccf3, Mapped, "3"),
+    (0x1ccf4, Mapped, "4"),
+    (0x1ccf5, Mapped, "5"),
+    (0x1ccf6, Mapped, "6"),
+    (0x1ccf7, Mapped, "7"),
+    (0x1ccf8, Mapped, "8"),
+    (0x1ccf9, Mapped, "9"),
+    (0x1ccfa, Valid, ""),
+    (0x1ccfd, Disallowed, ""),
+    (0x1cd00, Valid, ""),
+    (0x1ceb4, Disallowed, ""),
+    (0x1ceba, Valid, ""),
+    (0x1ced1, Disallowed, ""),
+    (0x1cee0, Valid, ""),
+    (0x1cef1, Disallowed, ""),
+    (0x1cf00, Valid, ""),
+    (0x1cf2e, Disallowed, ""),
+    (0x1cf30, Valid, ""),
+    (0x1cf47, Disallowed, ""),
+    (0x1cf50, Valid, ""),
+    (0x1cfc4, Disallowed, ""),
+    (0x1d000, Valid, ""),
+    (0x1d0f6, Disallowed, ""),
+    (0x1d100, Valid, ""),
+    (0x1d127, Disallowed, ""),
+    (0x1d129, Valid, ""),
+    (0x1d15e, Mapped, "\U0001d157\U0001d165"),
+    (0x1d15f, Mapped, "\U0001d158\U0001d165"),
+    (0x1d160, Mapped, "\U0001d158\U0001d165\U0001d16e"),
+    (0x1d161, Mapped, "\U0001d158\U0001d165\U0001d16f"),
+    (0x1d162, Mapped, "\U0001d158\U0001d165\U0001d170"),
+    (0x1d163, Mapped, "\U0001d158\U0001d165\U0001d171"),
+    (0x1d164, Mapped, "\U0001d158\U0001d165\U0001d172"),
+    (0x1d165, Valid, ""),
+    (0x1d173, Ignored, ""),
+    (0x1d17b, Valid, ""),
+    (0x1d1bb, Mapped, "\U0001d1b9\U0001d165"),
+    (0x1d1bc, Mapped, "\U0001d1ba\U0001d165"),
+    (0x1d1bd, Mapped, "\U0001d1b9\U0001d165\U0001d16e"),
+    (0x1d1be, Mapped, "\U0001d1ba\U0001d165\U0001d16e"),
+    (0x1d1bf, Mapped, "\U0001d1b9\U0001d165\U0001d16f"),
+    (0x1d1c0, Mapped, "\U0001d1ba\U0001d165\U0001d16f"),
+    (0x1d1c1, Valid, ""),
+    (0x1d1eb, Disallowed, ""),
+    (0x1d200, Valid, ""),
+    (0x1d246, Disallowed, ""),
+    (0x1d2c0, Valid, ""),
+    (0x1d2d4, Disallowed, ""),
+    (0x1d2e0, Valid, ""),
+    (0x1d2f4, Disallowed, ""),
+    (0x1d300, Valid, ""),
+    (0x1d357, Disallowed, ""),
+    (0x1d360, Valid, ""),
+    (0x1d379, Disallowed, ""),
+    (0x1d400, Mapped, "a"),
+    (0x1d401, Mapped, "b"),
+    (0x1d402, Mapped, "c"),
+    (0x1d403, Mapped, "d"),
+    (0x1d404, Mapped, "e"),
+    (0x1d405, Mapped, "f"),
+    (0x1d406, Mapped, "g"),
+    (0x1d407, Mapped, "h"),
+    (0x1d408, Mapped, "i"),
+    (0x1d409, Mapped, "j"),
+    (0x1d40a, Mapped, "k"),
+    (0x1d40b, Mapped, "l"),
+    (0x1d40c, Mapped, "m"),
+    (0x1d40d, Mapped, "n"),
+    (0x1d40e, Mapped, "o"),
+    (0x1d40f, Mapped, "p"),
+    (0x1d410, Mapped, "q"),
+    (0x1d411, Mapped, "r"),
+    (0x1d412, Mapped, "s"),
+    (0x1d413, Mapped, "t"),
+    (0x1d414, Mapped, "u"),
+    (0x1d415, Mapped, "v"),
+    (0x1d416, Mapped, "w"),
+    (0x1d417, Mapped, "x"),
+    (0x1d418, Mapped, "y"),
+    (0x1d419, Mapped, "z"),
+    (0x1d41a, Mapped, "a"),
+    (0x1d41b, Mapped, "b"),
+    (0x1d41c, Mapped, "c"),
+    (0x1d41d, Mapped, "d"),
+    (0x1d41e, Mapped, "e"),
+    (0x1d41f, Mapped, "f"),
+    (0x1d420, Mapped, "g"),
+    (0x1d421, Mapped, "h"),
+    (0x1d422, Mapped, "i"),
+    (0x1d423, Mapped, "j"),
+    (0x1d424, Mapped, "k"),
+    (0x1d425, Mapped, "l"),
+    (0x1d426, Mapped, "m"),
+    (0x1d427, Mapped, "n"),
+    (0x1d428, Mapped, "o"),
+    (0x1d429, Mapped, "p"),
+    (0x1d42a, Mapped, "q"),
+    (0x1d42b, Mapped, "r"),
+    (0x1d42c, Mapped, "s"),
+    (0x1d42d, Mapped, "t"),
+    (0x1d42e, Mapped, "u"),
+    (0x1d42f, Mapped, "v"),
+    (0x1d430, Mapped, "w"),
+    (0x1d431, Mapped, "x"),
+    (0x1d432, Mapped, "y"),
+    (0x1d433, Mapped, "z"),
+    (0x1d434, Mapped, "a"),
+    (0x1d435, Mapped, "b"),
+    (0x1d436, Mapped, "c"),
+    (0x1d437, Mapped, "d"),
+    (0x1d438, Mapped, "e"),
+    (0x1d439, Mapped, "f"),
+    (0x1d43a, Mapped, "g"),
+    (0x1d43b, Mapped, "h"),
+    (0x1d43c, Mapped, "i"),
+    (0x1d43d, Mapped, "j"),
+    (0x1d43e, Mapped, "k"),
+    (0x1d43f, Mapped, "l"),
+    (0x1d440, Mapped, "m"),
+    (0x1d441, Mapped, "n"),
+    (0x1d442, Mapped, "o"),
+    (0x1d443, Mapped, "p"),
+    (0x1d444, Mapped, "q"),
+    (0x1d445, Mapped, "r"),
+    (0x1d446, Mapped, "s"),
+    (0x1d447, Mapped, "t"),
+    (0x1d448, Mapped, "u"),
+    (0x1d449, Mapped, "v"),
+    (0x1d44a, Mapped, "w"),
+    (0x1d44b, Mapped, "x"),
+    (0x1d44c, Mapped, "y"),
+    (0x1d44d, Mapped, "z"),
+    (0x1d44e, Mapped, "a"),
+    (0x1d44f, Mapped, "b"),
+    (0x1d450, Mapped, "c"),
+    (0x1d451, Mapped, "d"),
+    (0x1d452, Mapped, "e"),
+    (0x1d453, Mapped, "f"),
+    (0x1d454, Mapped, "g"),
+    (0x1d455, Disallowed, ""),
+    (0x1d456, Mapped, "i"),
+    (0x1d457, Mapped, "j"),
+    (0x1d458, Mapped, "k"),
+    (0x1d459, Mapped, "l"),
+    (0x1d45a, Mapped, "m"),
+    (0x1d45b, Mapped, "n"),
+    (0x1d45c, Mapped, "o"),
+    (0x1d45d, Mapped, "p"),
+    (0x1d45e, Mapped, "q"),
+    (0x1d45f, Mapped, "r"),
+    (0x1d460, Mapped, "s"),
+    (0x1d461, Mapped, "t"),
+    (0x1d462, Mapped, "u"),
+    (0x1d463, Mapped, "v"),
+    (0x1d464, Mapped, "w"),
+    (0x1d465, Mapped, "x"),
+    (0x1d466, Mapped, "y"),
+    (0x1d467, Mapped, "z"),
+    (0x1d468, Mapped, "a"),
+    (0x1d469, Mapped, "b"),
+    (0x1d46a, Mapped, "c"),
+    (0x1d46b, Mapped, "d"),
+    (0x1d46c, Mapped, "e"),
+    (0x1d46d, Mapped, "f"),
+    (0x1d46e, Mapped, "g"),
+    (0x1d46f, Mapped, "h"),
+    (0x1d470, Mapped, "i"),
+    (0x1d471, Mapped, "j"),
+    (0x1d472, Mapped, "k"),
+    (0x1d473, Mapped, "l"),
+    (0x1d474, Mapped, "m"),
+    (0x1d475, Mapped, "n"),
+    (0x1d476, Mapped, "o"),
+    (0x1d477, Mapped, "p"),
+    (0x1d478, Mapped, "q"),
+    (0x1d479, Mapped, "r"),
+    (0x1d47a, Mapped, "s"),
+    (0x1d47b, Mapped, "t"),
+    (0x1d47c, Mapped, "u"),
+    (0x1d47d, Mapped, "v"),
+    (0x1d47e, Mapped, "w"),
+    (0x1d47f, Mapped, "x"),
+    (0x1d480, Mapped, "y"),
+    (0x1d481, Mapped, "z"),
+    (0x1d482, Mapped, "a"),
+    (0x1d483, Mapped, "b"),
+    (0x1d484, Mapped, "c"),
+    (0x1d485, Mapped, "d"),
+    (0x1d486, Mapped, "e"),
+    (0x1d487, Mapped, "f"),
+    (0x1d488, Mapped, "g"),
+    (0x1d489, Mapped, "h"),
+    (0x1d48a, Mapped, "i"),
+    (0x1d48b, Mapped, "j"),
+    (0x1d48c, Mapped, "k"),
+    (0x1d48d, Mapped, "l"),
+    (0x1d48e, Mapped, "m"),
+    (0x1d48f, Mapped, "n"),
+    (0x1d490, Mapped, "o"),
+    (0x1d491, Mapped, "p"),
+    (0x1d492, Mapped, "q"),
+    (0x1d493, Mapped, "r"),
+    (0x1d494, Mapped, "s"),
+    (0x1d495, Mapped, "t"),
+    (0x1d496, Mapped, "u"),
+    (0x1d497, Mapped, "v"),
+    (0x1d498, Mapped, "w"),
+    (0x1d499, Mapped, "x"),
+    (0x1d49a, Mapped, "y"),
+    (0x1d49b, Mapped, "z"),
+    (0x1d49c, Mapped, "a"),
+    (0x1d49d, Disallowed, ""),
+    (0x1d49e, Mapped, "c"),
+    (0x1d49f, Mapped, "d"),
+    (0x1d4a0, Disallowed, ""),
+    (0x1d4a2, Mapped, "g"),
+    (0x1d4a3, Disallowed, ""),
+    (0x1d4a5, Mapped, "j"),
+    (0x1d4a6, Mapped, "k"),
+    (0x1d4a7, Disallowed, ""),
+    (0x1d4a9, Mapped, "n"),
+    (0x1d4aa, Mapped, "o"),
+    (0x1d4ab, Mapped, "p"),
+    (0x1d4ac, Mapped, "q"),
+    (0x1d4ad, Disallowed, ""),
+    (0x1d4ae, Mapped, "s"),
+    (0x1d4af, Mapped, "t"),
+    (0x1d4b0, Mapped, "u"),
+    (0x1d4b1, Mapped, "v"),
+    (0x1d4b2, Mapped, "w"),
+    (0x1d4b3, Mapped, "x"),
+    (0x1d4b4, Mapped, "y"),
+    (0x1d4b5, Mapped, "z"),
+    (0x1d4b6, Mapped, "a"),
+    (0x1d4b7, Mapped, "b"),
+    (0x1d4b8, Mapped, "c"),
+    (0x1d4b9, Mapped, "d"),
+    (0x1d4ba, Disallowed, ""),
+    (0x1d4bb, Mapped, "f"),
+    (0x1d4bc, Disallowed, ""),
+    (0x1d4bd, Mapped, "h"),
+    (0x1d4be, Mapped, "i"),
+    (0x1d4bf, Mapped, "j"),
+    (0x1d4c0, Mapped, "k"),
+    (0x1d4c1, Mapped, "l"),
+    (0x1d4c2, Mapped, "m"),
+    (0x1d4c3, Mapped, "n"),
+    (0x1d4c4, Disallowed, ""),
+    (0x1d4c5, Mapped, "p"),
+    (0x1d4c6, Mapped, "q"),
+    (0x1d4c7, Mapped, "r"),
+    (0x1d4c8, Mapped, "s"),
+    (0x1d4c9, Mapped, "t"),
+    (0x1d4ca, Mapped, "u"),
+    (0x1d4cb, Mapped, "v"),
+    (0x1d4cc, Mapped, "w"),
+    (0x1d4cd, Mapped, "x"),
+    (0x1d4ce, Mapped, "y"),
+    (0x1d4cf, Mapped, "z"),
+    (0x1d4d0, Mapped, "a"),
+    (0x1d4d1, Mapped, "b"),
+    (0x1d4d2, Mapped, "c"),
+    (0x1d4d3, Mapped, "d"),
+    (0x1d4d4, Mapped, "e"),
+    (0x1d4d5, Mapped, "f"),
+    (0x1d4d6, Mapped, "g"),
+    (0x1d4d7, Mapped, "h"),
+    (0x1d4d8, Mapped, "i"),
+    (0x1d4d9, Mapped, "j"),
+    (0x1d4da, Mapped, "k"),
+    (0x1d4db, Mapped, "l"),
+    (0x1d4dc, Mapped, "m"),
+    (0x1d4dd, Mapped, "n"),
+    (0x1d4de, Mapped, "o"),
+    (0x1d4df, Mapped, "p"),
+    (0x1d4e0, Mapped, "q"),
+    (0x1d4e1, Mapped, "r"),
+    (0x1d4e2, Mapped, "s"),
+    (0x1d4e3, Mapped, "t"),
+    (0x1d4e4, Mapped, "u"),
+    (0x1d4e5, Mapped, "v"),
+    (0x1d4e6, Mapped, "w"),
+    (0x1d4e7, Mapped, "x"),
+    (0x1d4e8, Mapped, "y"),
+    (0x1d4e9, Mapped, "z"),
+    (0x1d4ea, Mapped, "a"),
+    (0x1d4eb, Mapped, "b"),
+    (0x1d4ec, Mapped, "c"),
+    (0x1d4ed, Mapped, "d"),
+    (0x1d4ee, Mapped, "e"),
+    (0x1d4ef, Mapped, "f"),
+    (0x1d4f0, Mapped, "g"),
+    (0x1d4f1, Mapped, "h"),
+    (0x1d4f2, Mapped, "i"),
+    (0x1d4f3, Mapped, "j"),
+    (0x1d4f4, Mapped, "k"),
+    (0x1d4f5, Mapped, "l"),
+    (0x1d4f6, Mapped, "m"),
+    (0x1d4f7, Mapped, "n"),
+    (0x1d4f8, Mapped, "o"),
+    (0x1d4f9, Mapped, "p"),
+    (0x1d4fa, Mapped, "q"),
+    (0x1d4fb, Mapped, "r"),
+    (0x1d4fc, Mapped, "s"),
+    (0x1d4fd, Mapped, "t"),
+    (0x1d4fe, Mapped, "u"),
+    (0x1d4ff, Mapped, "v"),
+    (0x1d500, Mapped, "w"),
+    (0x1d501, Mapped, "x"),
+    (0x1d502, Mapped, "y"),
+    (0x1d503, Mapped, "z"),
+    (0x1d504, Mapped, "a"),
+    (0x1d505, Mapped, "b"),
+    (0x1d506, Disallowed, ""),
+    (0x1d507, Mapped, "d"),
+    (0x1d508, Mapped, "e"),
+    (0x1d509, Mapped, "f"),
+    (0x1d50a, Mapped, "g"),
+    (0x1d50b, Disallowed, ""),
+    (0x1d50d, Mapped, "j"),
+    (0x1d50e, Mapped, "k"),
+    (0x1d50f, Mapped, "l"),
+    (0x1d510, Mapped, "m"),
+    (0x1d511, Mapped, "n"),
+    (0x1d512, Mapped, "o"),
+    (0x1d513, Mapped, "p"),
+    (0x1d514, Mapped, "q"),
+    (0x1d515, Disallowed, ""),
+    (0x1d516, Mapped, "s"),
+    (0x1d517, Mapped, "t"),
+    (0x1d518, Mapped, "u"),
+    (0x1d519, Mapped, "v"),
+    (0x1d51a, Mapped, "w"),
+    (0x1d51b, Mapped, "x"),
+    (0x1d51c, Mapped, "y"),
+    (0x1d51d, Disallowed, ""),
+    (0x1d51e, Mapped, "a"),
+    (0x1d51f, Mapped, "b"),
+    (0x1d520, Mapped, "c"),
+    (0x1d521, Mapped, "d"),
+    (0x1d522, Mapped, "e"),
+    (0x1d523, Mapped, "f"),
+    (0x1d524, Mapped, "g"),
+    (0x1d525, Mapped, "h"),
+    (0x1d526, Mapped, "i"),
+    (0x1d527, Mapped, "j"),
+    (0x1d528, Mapped, "k"),
+    (0x1d529, Mapped, "l"),
+    (0x1d52a, Mapped, "m"),
+    (0x1d52b, Mapped, "n"),
+    (0x1d52c, Mapped, "o"),
+    (0x1d52d, Mapped, "p"),
+    (0x1d52e, Mapped, "q"),
+    (0x1d52f, Mapped, "r"),
+    (0x1d530, Mapped, "s"),
+    (0x1d531, Mapped, "t"),
+    (0x1d532, Mapped, "u"),
+    (0x1d533, Mapped, "v"),
+    (0x1d534, Mapped, "w"),
+    (0x1d535, Mapped, "x"),
+    (0x1d536, Mapped, "y"),
+    (0x1d537, Mapped, "z"),
+    (0x1d538, Mapped, "a"),
+    (0x1d539, Mapped, "b"),
+    (0x1d53a, Disallowed, ""),
+    (0x1d53b, Mapped, "d"),
+    (0x1d53c, Mapped, "e"),
+    (0x1d53d, Mapped, "f"),
+    (0x1d53e, Mapped, "g"),
+    (0x1d53f, Disallowed, ""),
+    (0x1d540, Mapped, "i"),
+    (0x1d541, Mapped, "j"),
+    (0x1d542, Mapped, "k"),
+    (0x1d543, Mapped, "l"),
+    (0x1d544, Mapped, "m"),
+    (0x1d545, Disallowed, ""),
+    (0x1d546, Mapped, "o"),
+    (0x1d547, Disallowed, ""),
+    (0x1d54a, Mapped, "s"),
+    (0x1d54b, Mapped, "t"),
+    (0x1d54c, Mapped, "u"),
+    (0x1d54d, Mapped, "v"),
+    (0x1d54e, Mapped, "w"),
+    (0x1d54f, Mapped, "x"),
+    (0x1d550, Mapped, "y"),
+    (0x1d551, Disallowed, ""),
+    (0x1d552, Mapped, "a"),
+    (0x1d553, Mapped, "b"),
+    (0x1d554, Mapped, "c"),
+    (0x1d555, Mapped, "d"),
+    (0x1d556, Mapped, "e"),
+    (0x1d557, Mapped, "f"),
+    (0x1d558, Mapped, "g"),
+    (0x1d559, Mapped, "h"),
+    (0x1d55a, Mapped, "i"),
+    (0x1d55b, Mapped, "j"),
+    (0x1d55c, Mapped, "k"),
+    (0x1d55d, Mapped, "l"),
+    (0x1d55e, Mapped, "m"),
+    (0x1d55f, Mapped, "n"),
+    (0x1d560, Mapped, "o"),
+    (0x1d561, Mapped, "p"),
+    (0x1d562, Mapped, "q"),
+    (0x1d563, Mapped, "r"),
+    (0x1d564, Mapped, "s"),
+    (0x1d565, Mapped, "t"),
+    (0x1d566, Mapped, "u"),
+    (0x1d567, Mapped, "v"),
+    (0x1d568, Mapped, "w"),
+    (0x1d569, Mapped, "x"),
+    (0x1d56a, Mapped, "y"),
+    (0x1d56b, Mapped, "z"),
+    (0x1d56c, Mapped, "a"),
+    (0x1d56d, Mapped, "b"),
+    (0x1d56e, Mapped, "c"),
+    (0x1d56f, Mapped, "d"),
+    (0x1d570, Mapped, "e"),
+    (0x1d571, Mapped, "f"),
+    (0x1d572, Mapped, "g"),
+    (0x1d573, Mapped, "h"),
+    (0x1d574, Mapped, "i"),
+    (0x1d575, Mapped, "j"),
+    (0x1d576, Mapped, "k"),
+    (0x1d577, Mapped, "l"),
+    (0x1d578, Mapped, "m"),
+    (0x1d579, Mapped, "n"),
+    (0x1d57a, Mapped, "o"),
+    (0x1d57b, Mapped, "p"),
+    (0x1d57c, Mapped, "q"),
+    (0x1d57d, Mapped, "r"),
+    (0x1d57e, Mapped, "s"),
+    (0x1d57f, Mapped, "t"),
+    (0x1d580, Mapped, "u"),
+    (0x1d581, Mapped, "v"),
+    (0x1d582, Mapped, "w"),
+    (0x1d583, Mapped, "x"),
+    (0x1d584, Mapped, "y"),
+    (0x1d585, Mapped, "z"),
+    (0x1d586, Mapped, "a"),
+    (0x1d587, Mapped, "b"),
+    (0x1d588, Mapped, "c"),
+    (0x1d589, Mapped, "d"),
+    (0x1d58a, Mapped, "e"),
+    (0x1d58b, Mapped, "f"),
+    (0x1d58c, Mapped, "g"),
+    (0x1d58d, Mapped, "h"),
+    (0x1d58e, Mapped, "i"),
+    (0x1d58f, Mapped, "j"),
+    (0x1d590, Mapped, "k"),
+    (0x1d591, Mapped, "l"),
+    (0x1d592, Mapped, "m"),
+    (0x1d593, Mapped, "n"),
+    (0x1d594, Mapped, "o"),
+    (0x1d595, Mapped, "p"),
+    (0x1d596, Mapped, "q"),
+    (0x1d597, Mapped, "r"),
+    (0x1d598, Mapped, "s"),
+    (0x1d599, Mapped, "t"),
+    (0x1d59a, Mapped, "u"),
+    (0x1d59b, Mapped, "v"),
+    (0x1d59c, Mapped, "w"),
+    (0x1d59d, Mapped, "x"),
+    (0x1d59e, Mapped, "y"),
+    (0x1d59f, Mapped, "z"),
+    (0x1d5a0, Mapped, "a"),
+    (0x1d5a1, Mapped, "b"),
+    (0x1d5a2, Mapped, "c"),
+    (0x1d5a3, Mapped, "d"),
+    (0x1d5a4, Mapped, "e"),
+    (0x1d5a5, Mapped, "f"),
+    (0x1d5a6, Mapped, "g"),
+    (0x1d5a7, Mapped, "h"),
+    (0x1d5a8, Mapped, "i"),
+    (0x1d5a9, Mapped, "j"),
+    (0x1d5aa, Mapped, "k"),
+    (0x1d5ab, Mapped, "l"),
+    (0x1d5ac, Mapped, "m"),
+    (0x1d5ad, Mapped, "n"),
+    (0x1d5ae, Mapped, "o"),
+    (0x1d5af, Mapped, "p"),
+    (0x1d5b0, Mapped, "q"),
+    (0x1d5b1, Mapped, "r"),
+    (0x1d5b2, Mapped, "s"),
+    (0x1d5b3, Mapped, "t"),
+    (0x1d5b4, Mapped, "u"),
+    (0x1d5b5, Mapped, "v"),
+    (0x1d5b6, Mapped, "w"),
+    (0x1d5b7, Mapped, "x"),
+    (0x1d5b8, Mapped, "y"),
+    (0x1d5b9, Mapped, "z"),
+    (0x1d5ba, Mapped, "a"),
+    (0x1d5bb, Mapped, "b"),
+    (0x1d5bc, Mapped, "c"),
+    (0x1d5bd, Mapped, "d"),
+    (0x1d5be, Mapped, "e"),
+    (0x1d5bf, Mapped, "f"),
+    (0x1d5c0, Mapped, "g"),
+    (0x1d5c1, Mapped, "h"),
+    (0x1d5c2, Mapped, "i"),
+    (0x1d5c3, Mapped, "j"),
+    (0x1d5c4, Mapped, "k"),
+    (0x1d5c5, Mapped, "l"),
+    (0x1d5c6, Mapped, "m"),
+    (0x1d5c7, Mapped, "n"),
+    (0x1d5c8, Mapped, "o"),
+    (0x1d5c9, Mapped, "p"),
+    (0x1d5ca, Mapped, "q"),
+    (0x1d5cb, Mapped, "r"),
+    (0x1d5cc, Mapped, "s"),
+    (0x1d5cd, Mapped, "t"),
+    (0x1d5ce, Mapped, "u"),
+    (0x1d5cf, Mapped, "v"),
+    (0x1d5d0, Mapped, "w"),
+    (0x1d5d1, Mapped, "x"),
+    (0x1d5d2, Mapped, "y"),
+    (0x1d5d3, Mapped, "z"),
+    (0x1d5d4, Mapped, "a"),
+    (0x1d5d5, Mapped, "b"),
+    (0x1d5d6, Mapped, "c"),
+    (0x1d5d7, Mapped, "d"),
+    (0x1d5d8, Mapped, "e"),
+    (0x1d5d9, Mapped, "f"),
+    (0x1d5da, Mapped, "g"),
+    (0x1d5db, Mapped, "h"),
+    (0x1d5dc, Mapped, "i"),
+    (0x1d5dd, Mapped, "j"),
+    (0x1d5de, Mapped, "k"),
+    (0x1d5df, Mapped, "l"),
+    (0x1d5e0, Mapped, "m"),
+    (0x1d5e1, Mapped, "n"),
+    (0x1d5e2, Mapped, "o"),
+    (0x1d5e3, Mapped, "p"),
+    (0x1d5e4, Mapped, "q"),
+    (0x1d5e5, Mapped, "r"),
+    (0x1d5e6, Mapped, "s"),
+    (0x1d5e7, Mapped, "t"),
+    (0x1d5e8, Mapped, "u"),
+    (0x1d5e9, Mapped, "v"),
+    (0x1d5ea, Mapped, "w"),
+    (0x1d5eb, Mapped, "x"),
+    (0x1d5ec, Mapped, "y"),
+    (0x1d5ed, Mapped, "z"),
+    (0x1d5ee, Mapped, "a"),
+    (0x1d5ef, Mapped, "b"),
+    (0x1d5f0, Mapped, "c"),
+    (0x1d5f1, Mapped, "d"),
+    (0x1d5f2, Mapped, "e"),
+    (0x1d5f3, Mapped, "f"),
+    (0x1d5f4, Mapped, "g"),
+    (0x1d5f5, Mapped, "h"),
+    (0x1d5f6, Mapped, "i"),
+    (0x1d5f7, Mapped, "j"),
+    (0x1d5f8, Mapped, "k"),
+    (0x1d5f9, Mapped, "l"),
+    (0x1d5fa, Mapped, "m"),
+    (0x1d5fb, Mapped, "n"),
+    (0x1d5fc, Mapped, "o"),
+    (0x1d5fd, Mapped, "p"),
+    (0x1d5fe, Mapped, "q"),
+    (0x1d5ff, Mapped, "r"),
+    (0x1d600, Mapped, "s"),
+    (0x1d601, Mapped, "t"),
+    (0x1d602, Mapped, "u"),
+    (0x1d603, Mapped, "v"),
+    (0x1d604, Mapped, "w"),
+    (0x1d605, Mapped, "x"),
+    (0x1d606, Mapped, "y"),
+    (0x1d607, Mapped, "z"),
+    (0x1d608, Mapped, "a"),
+    (0x1d609, Mapped, "b"),
+    (0x1d60a, Mapped, "c"),
+    (0x1d60b, Mapped, "d"),
+    (0x1d60c, Mapped, "e"),
+    (0x1d60d, Mapped, "f"),
+    (0x1d60e, Mapped, "g"),
+    (0x1d60f, Mapped, "h"),
+    (0x1d610, Mapped, "i"),
+    (0x1d611, Mapped, "j"),
+    (0x1d612, Mapped, "k"),
+    (0x1d613, Mapped, "l"),
+    (0x1d614, Mapped, "m"),
+    (0x1d615, Mapped, "n"),
+    (0x1d616, Mapped, "o"),
+    (0x1d617, Mapped, "p"),
+    (0x1d618, Mapped, "q"),
+    (0x1d619, Mapped, "r"),
+    (0x1d61a, Mapped, "s"),
+    (0x1d61b, Mapped, "t"),
+    (0x1d61c, Mapped, "u"),
+    (0x1d61d, Mapped, "v"),
+    (0x1d61e, Mapped, "w"),
+    (0x1d61f, Mapped, "x"),
+    (0x1d620, Mapped, "y"),
+    (0x1d621, Mapped, "z"),
+    (0x1d622, Mapped, "a"),
+    (0x1d623, Mapped, "b"),
+    (0x1d624, Mapped, "c"),
+    (0x1d625, Mapped, "d"),
+    (0x1d626, Mapped, "e"),
+    (0x1d627, Mapped, "f"),
+    (0x1d628, Mapped, "g"),
+    (0x1d629, Mapped, "h"),
+    (0x1d62a, Mapped, "i"),
+    (0x1d62b, Mapped, "j"),
+    (0x1d62c, Mapped, "k"),
+    (0x1d62d, Mapped, "l"),
+    (0x1d62e, Mapped, "m"),
+    (0x1d62f, Mapped, "n"),
+    (0x1d630, Mapped, "o"),
+    (0x1d631, Mapped, "p"),
+    (0x1d632, Mapped, "q"),
+    (0x1d633, Mapped, "r"),
+    (0x1d634, Mapped, "s"),
+    (0x1d635, Mapped, "t"),
+    (0x1d636, Mapped, "u"),
+    (0x1d637, Mapped, "v"),
+    (0x1d638, Mapped, "w"),
+    (0x1d639, Mapped, "x"),
+    (0x1d63a, Mapped, "y"),
+    (0x1d63b, Mapped, "z"),
+    (0x1d63c, Mapped, "a"),
+    (0x1d63d, Mapped, "b"),
+    (0x1d63e, Mapped, "c"),
+    (0x1d63f, Mapped, "d"),
+    (0x1d640, Mapped, "e"),
+    (0x1d641, Mapped, "f"),
+    (0x1d642, Mapped, "g"),
+    (0x1d643, Mapped, "h"),
+    (0x1d644, Mapped, "i"),
+    (0x1d645, Mapped, "j"),
+    (0x1d646, Mapped, "k"),
+    (0x1d647, Mapped, "l"),
+    (0x1d648, Mapped, "m"),
+    (0x1d649, Mapped, "n"),
+    (0x1d64a, Mapped, "o"),
+    (0x1d64b, Mapped, "p"),
+    (0x1d64c, Mapped, "q"),
+    (0x1d64d, Mapped, "r"),
+    (0x1d64e, Mapped, "s"),
+    (0x1d64f, Mapped, "t"),
+    (0x1d650, Mapped, "u"),
+    (0x1d651, Mapped, "v"),
+    (0x1d652, Mapped, "w"),
+    (0x1d653, Mapped, "x"),
+    (0x1d654, Mapped, "y"),
+    (0x1d655, Mapped, "z"),
+    (0x1d656, Mapped, "a"),
+    (0x1d657, Mapped, "b"),
+    (0x1d658, Mapped, "c"),
+    (0x1d659, Mapped, "d"),
+    (0x1d65a, Mapped, "e"),
+    (0x1d65b, Mapped, "f"),
+    (0x1d65c, Mapped, "g"),
+    (0x1d65d, Mapped, "h"),
+    (0x1d65e, Mapped, "i"),
+    (0x1d65f, Mapped, "j"),
+    (0x1d660, Mapped, "k"),
+    (0x1d661, Mapped, "l"),
+    (0x1d662, Mapped, "m"),
+    (0x1d663, Mapped, "n"),
+    (0x1d664, Mapped, "o"),
+    (0x1d665, Mapped, "p"),
+    (0x1d666, Mapped, "q"),
+    (0x1d667, Mapped, "r"),
+    (0x1d668, Mapped, "s"),
+    (0x1d669, Mapped, "t"),
+    (0x1d66a, Mapped, "u"),
+    (0x1d66b, Mapped, "v"),
+    (0x1d66c, Mapped, "w"),
+    (0x1d66d, Mapped, "x"),
+    (0x1d66e, Mapped, "y"),
+    (0x1d66f, Mapped, "z"),
+    (0x1d670, Mapped, "a"),
+    (0x1d671, Mapped, "b"),
+    (0x1d672, Mapped, "c"),
+    (0x1d673, Mapped, "d"),
+    (0x1d674, Mapped, "e"),
+    (0x1d675, Mapped, "f"),
+    (0x1d676, Mapped, "g"),
+    (0x1d677, Mapped, "h"),
+    (0x1d678, Mapped, "i"),
+    (0x1d679, Mapped, "j"),
+    (0x1d67a, Mapped, "k"),
+    (0x1d67b, Mapped, "l"),
+    (0x1d67c, Mapped, "m"),
+    (0x1d67d, Mapped, "n"),
+    (0x1d67e, Mapped, "o"),
+    (0x1d67f, Mapped, "p"),
+    (0x1d680, Mapped, "q"),
+    (0x1d681, Mapped, "r"),
+    (0x1d682, Mapped, "s"),
+    (0x1d683, Mapped, "t"),
+    (0x1d684, Mapped, "u"),
+    (0x1d685, Mapped, "v"),
+    (0x1d686, Mapped, "w"),
+    (0x1d687, Mapped, "x"),
+    (0x1d688, Mapped, "y"),
+    (0x1d689, Mapped, "z"),
+    (0x1d68a, Mapped, "a"),
+    (0x1d68b, Mapped, "b"),
+    (0x1d68c, Mapped, "c"),
+    (0x1d68d, Mapped, "d"),
+    (0x1d68e, Mapped, "e"),
+    (0x1d68f, Mapped, "f"),
+    (0x1d690, Mapped, "g"),
+    (0x1d691, Mapped, "h"),
+    (0x1d692, Mapped, "i"),
+    (0x1d693, Mapped, "j"),
+    (0x1d694, Mapped, "k"),
+    (0x1d695, Mapped, "l"),
+    (0x1d696, Mapped, "m"),
+    (0x1d697, Mapped, "n"),
+    (0x1d698, Mapped, "o"),
+    (0x1d699, Mapped, "p"),
+    (0x1d69a, Mapped, "q"),
+    (0x1d69b, Mapped, "r"),
+    (0x1d69c, Mapped, "s"),
+    (0x1d69d, Mapped, "t"),
+    (0x1d69e, Mapped, "u"),
+    (0x1d69f, Mapped, "v"),
+    (0x1d6a0, Mapped, "w"),
+    (0x1d6a1, Mapped, "x"),
+    (0x1d6a2, Mapped, "y"),
+    (0x1d6a3, Mapped, "z"),
+    (0x1d6a4, Mapped, "\u0131"),
+    (0x1d6a5, Mapped, "\u0237"),
+    (0x1d6a6, Disallowed, ""),
+    (0x1d6a8, Mapped, "\u03b1"),
+    (0x1d6a9, Mapped, "\u03b2"),
+    (0x1d6aa, Mapped, "\u03b3"),
+    (0x1d6ab, Mapped, "\u03b4"),
+    (0x1d6ac, Mapped, "\u03b5"),
+    (0x1d6ad, Mapped, "\u03b6"),
+    (0x1d6ae, Mapped, "\u03b7"),
+    (0x1d6af, Mapped, "\u03b8"),
+    (0x1d6b0, Mapped, "\u03b9"),
+    (0x1d6b1, Mapped, "\u03ba"),
+    (0x1d6b2, Mapped, "\u03bb"),
+    (0x1d6b3, Mapped, "\u03bc"),
+    (0x1d6b4, Mapped, "\u03bd"),
+    (0x1d6b5, Mapped, "\u03be"),
+    (0x1d6b6, Mapped, "\u03bf"),
+    (0x1d6b7, Mapped, "\u03c0"),
+    (0x1d6b8, Mapped, "\u03c1"),
+    (0x1d6b9, Mapped, "\u03b8"),
+    (0x1d6ba, Mapped, "\u03c3"),
+    (0x1d6bb, Mapped, "\u03c4"),
+    (0x1d6bc, Mapped, "\u03c5"),
+    (0x1d6bd, Mapped, "\u03c6"),
+    (0x1d6be, Mapped, "\u03c7"),
+    (0x1d6bf, Mapped, "\u03c8"),
+    (0x1d6c0, Mapped, "\u03c9"),
+    (0x1d6c1, Mapped, "\u2207"),
+    (0x1d6c2, Mapped, "\u03b1"),
+    (0x1d6c3, Mapped, "\u03b2"),
+    (0x1d6c4, Mapped, "\u03b3"),
+    (0x1d6c5, Mapped, "\u03b4"),
+    (0x1d6c6, Mapped, "\u03b5"),
+    (0x1d6c7, Mapped, "\u03b6"),
+    (0x1d6c8, Mapped, "\u03b7"),
+    (0x1d6c9, Mapped, "\u03b8"),
+    (0x1d6ca, Mapped, "\u03b9"),
+    (0x1d6cb, Mapped, "\u03ba"),
+    (0x1d6cc, Mapped, "\u03bb"),
+    (0x1d6cd, Mapped, "\u03bc"),
+    (0x1d6ce, Mapped, "\u03bd"),
+    (0x1d6cf, Mapped, "\u03be"),
+    (0x1d6d0, Mapped, "\u03bf"),
+    (0x1d6d1, Mapped, "\u03c0"),
+    (0x1d6d2, Mapped, "\u03c1"),
+    (0x1d6d3, Mapped, "\u03c3"),
+    (0x1d6d5, Mapped, "\u03c4"),
+    (0x1d6d6, Mapped, "\u03c5"),
+    (0x1d6d7, Mapped, "\u03c6"),
+    (0x1d6d8, Mapped, "\u03c7"),
+    (0x1d6d9, Mapped, "\u03c8"),
+    (0x1d6da, Mapped, "\u03c9"),
+    (0x1d6db, Mapped, "\u2202"),
+    (0x1d6dc, Mapped, "\u03b5"),
+    (0x1d6dd, Mapped, "\u03b8"),
+    (0x1d6de, Mapped, "\u03ba"),
+    (0x1d6df, Mapped, "\u03c6"),
+    (0x1d6e0, Mapped, "\u03c1"),
+    (0x1d6e1, Mapped, "\u03c0"),
+    (0x1d6e2, Mapped, "\u03b1"),
+    (0x1d6e3, Mapped, "\u03b2"),
+    (0x1d6e4, Mapped, "\u03b3"),
+    (0x1d6e5, Mapped, "\u03b4"),
+    (0x1d6e6, Mapped, "\u03b5"),
+    (0x1d6e7, Mapped, "\u03b6"),
+    (0x1d6e8, Mapped, "\u03b7"),
+    (0x1d6e9, Mapped, "\u03b8"),
+    (0x1d6ea, Mapped, "\u03b9"),
+    (0x1d6eb, Mapped, "\u03ba"),
+    (0x1d6ec, Mapped, "\u03bb"),
+    (0x1d6ed, Mapped, "\u03bc"),
+    (0x1d6ee, Mapped, "\u03bd"),
+    (0x1d6ef, Mapped, "\u03be"),
+    (0x1d6f0, Mapped, "\u03bf"),
+    (0x1d6f1, Mapped, "\u03c0"),
+    (0x1d6f2, Mapped, "\u03c1"),
+    (0x1d6f3, Mapped, "\u03b8"),
+    (0x1d6f4, Mapped, "\u03c3"),
+    (0x1d6f5, Mapped, "\u03c4"),
+    (0x1d6f6, Mapped, "\u03c5"),
+    (0x1d6f7, Mapped, "\u03c6"),
+    (0x1d6f8, Mapped, "\u03c7"),
+    (0x1d6f9, Mapped, "\u03c8"),
+    (0x1d6fa, Mapped, "\u03c9"),
+    (0x1d6fb, Mapped, "\u2207"),
+    (0x1d6fc, Mapped, "\u03b1"),
+    (0x1d6fd, Mapped, "\u03b2"),
+    (0x1d6fe, Mapped, "\u03b3"),
+    (0x1d6ff, Mapped, "\u03b4"),
+    (0x1d700, Mapped, "\u03b5"),
+    (0x1d701, Mapped, "\u03b6"),
+    (0x1d702, Mapped, "\u03b7"),
+    (0x1d703, Mapped, "\u03b8"),
+    (0x1d704, Mapped, "\u03b9"),
+    (0x1d705, Mapped, "\u03ba"),
+    (0x1d706, Mapped, "\u03bb"),
+    (0x1d707, Mapped, "\u03bc"),
+    (0x1d708, Mapped, "\u03bd"),
+    (0x1d709, Mapped, "\u03be"),
+    (0x1d70a, Mapped, "\u03bf"),
+    (0x1d70b, Mapped, "\u03c0"),
+    (0x1d70c, Mapped, "\u03c1"),
+    (0x1d70d, Mapped, "\u03c3"),
+    (0x1d70f, Mapped, "\u03c4"),
+    (0x1d710, Mapped, "\u03c5"),
+    (0x1d711, Mapped, "\u03c6"),
+    (0x1d712, Mapped, "\u03c7"),
+    (0x1d713, Mapped, "\u03c8"),
+    (0x1d714, Mapped, "\u03c9"),
+    (0x1d715, Mapped, "\u2202"),
+    (0x1d716, Mapped, "\u03b5"),
+    (0x1d717, Mapped, "\u03b8"),
+    (0x1d718, Mapped, "\u03ba"),
+    (0x1d719, Mapped, "\u03c6"),
+    (0x1d71a, Mapped, "\u03c1"),
+    (0x1d71b, Mapped, "\u03c0"),
+    (0x1d71c, Mapped, "\u03b1"),
+    (0x1d71d, Mapped, "\u03b2"),
+    (0x1d71e, Mapped, "\u03b3"),
+    (0x1d71f, Mapped, "\u03b4"),
+    (0x1d720, Mapped, "\u03b5"),
+    (0x1d721, Mapped, "\u03b6"),
+    (0x1d722, Mapped, "\u03b7"),
+    (0x1d723, Mapped, "\u03b8"),
+    (0x1d724, Mapped, "\u03b9"),
+    (0x1d725, Mapped, "\u03ba"),
+    (0x1d726, Mapped, "\u03bb"),
+    (0x1d727, Mapped, "\u03bc"),
+    (0x1d728, Mapped, "\u03bd"),
+    (0x1d729, Mapped, "\u03be"),
+    (0x1d72a, Mapped, "\u03bf"),
+    (0x1d72b, Mapped, "\u03c0"),
+    (0x1d72c, Mapped, "\u03c1"),
+    (0x1d72d, Mapped, "\u03b8"),
+    (0x1d72e, Mapped, "\u03c3"),
+    (0x1d72f, Mapped, "\u03c4"),
+    (0x1d730, Mapped, "\u03c5"),
+    (0x1d731, Mapped, "\u03c6"),
+    (0x1d732, Mapped, "\u03c7"),
+    (0x1d733, Mapped, "\u03c8"),
+    (0x1d734, Mapped, "\u03c9"),
+    (0x1d735, Mapped, "\u2207"),
+    (0x1d736, Mapped, "\u03b1"),
+    (0x1d737, Mapped, "\u03b2"),
+    (0x1d738, Mapped, "\u03b3"),
+    (0x1d739, Mapped, "\u03b4"),
+    (0x1d73a, Mapped, "\u03b5"),
+    (0x1d73b, Mapped, "\u03b6"),
+    (0x1d73c, Mapped, "\u03b7"),
+    (0x1d73d, Mapped, "\u03b8"),
+    (0x1d73e, Mapped, "\u03b9"),
+    (0x1d73f, Mapped, "\u03ba"),
+    (0x1d740, Mapped, "\u03bb"),
+    (0x1d741, Mapped, "\u03bc"),
+    (0x1d742, Mapped, "\u03bd"),
+    (0x1d743, Mapped, "\u03be"),
+    (0x1d744, Mapped, "\u03bf"),
+    (0x1d745, Mapped, "\u03c0"),
+    (0x1d746, Mapped, "\u03c1"),
+    (0x1d747, Mapped, "\u03c3"),
+    (0x1d749, Mapped, "\u03c4"),
+    (0x1d74a, Mapped, "\u03c5"),
+    (0x1d74b, Mapped, "\u03c6"),
+    (0x1d74c, Mapped, "\u03c7"),
+    (0x1d74d, Mapped, "\u03c8"),
+    (0x1d74e, Mapped, "\u03c9"),
+    (0x1d74f, Mapped, "\u2202"),
+    (0x1d750, Mapped, "\u03b5"),
+    (0x1d751, Mapped, "\u03b8"),
+    (0x1d752, Mapped, "\u03ba"),
+    (0x1d753, Mapped, "\u03c6"),
+    (0x1d754, Mapped, "\u03c1"),
+    (0x1d755, Mapped, "\u03c0"),
+    (0x1d756, Mapped, "\u03b1"),
+    (0x1d757, Mapped, "\u03b2"),
+    (0x1d758, Mapped, "\u03b3"),
+    (0x1d759, Mapped, "\u03b4"),
+    (0x1d75a, Mapped, "\u03b5"),
+    (0x1d75b, Mapped, "\u03b6"),
+    (0x1d75c, Mapped, "\u03b7"),
+    (0x1d75d, Mapped, "\u03b8"),
+    (0x1d75e, Mapped, "\u03b9"),
+    (0x1d75f, Mapped, "\u03ba"),
+    (0x1d760, Mapped, "\u03bb"),
+    (0x1d761, Mapped, "\u03bc"),
+    (0x1d762, Mapped, "\u03bd"),
+    (0x1d763, Mapped, "\u03be"),
+    (0x1d764, Mapped, "\u03bf"),
+    (0x1d765, Mapped, "\u03c0"),
+    (0x1d766, Mapped, "\u03c1"),
+    (0x1d767, Mapped, "\u03b8"),
+    (0x1d768, Mapped, "\u03c3"),
+    (0x1d769, Mapped, "\u03c4"),
+    (0x1d76a, Mapped, "\u03c5"),
+    (0x1d76b, Mapped, "\u03c6"),
+    (0x1d76c, Mapped, "\u03c7"),
+    (0x1d76d, Mapped, "\u03c8"),
+    (0x1d76e, Mapped, "\u03c9"),
+    (0x1d76f, Mapped, "\u2207"),
+    (0x1d770, Mapped, "\u03b1"),
+    (0x1d771, Mapped, "\u03b2"),
+    (0x1d772, Mapped, "\u03b3"),
+    (0x1d773, Mapped, "\u03b4"),
+    (0x1d774, Mapped, "\u03b5"),
+    (0x1d775, Mapped, "\u03b6"),
+    (0x1d776, Mapped, "\u03b7"),
+    (0x1d777, Mapped, "\u03b8"),
+    (0x1d778, Mapped, "\u03b9"),
+    (0x1d779, Mapped, "\u03ba"),
+    (0x1d77a, Mapped, "\u03bb"),
+    (0x1d77b, Mapped, "\u03bc"),
+    (0x1d77c, Mapped, "\u03bd"),
+    (0x1d77d, Mapped, "\u03be"),
+    (0x1d77e, Mapped, "\u03bf"),
+    (0x1d77f, Mapped, "\u03c0"),
+    (0x1d780, Mapped, "\u03c1"),
+    (0x1d781, Mapped, "\u03c3"),
+    (0x1d783, Mapped, "\u03c4"),
+    (0x1d784, Mapped, "\u03c5"),
+    (0x1d785, Mapped, "\u03c6"),
+    (0x1d786, Mapped, "\u03c7"),
+    (0x1d787, Mapped, "\u03c8"),
+    (0x1d788, Mapped, "\u03c9"),
+    (0x1d789, Mapped, "\u2202"),
+    (0x1d78a, Mapped, "\u03b5"),
+    (0x1d78b, Mapped, "\u03b8"),
+    (0x1d78c, Mapped, "\u03ba"),
+    (0x1d78d, Mapped, "\u03c6"),
+    (0x1d78e, Mapped, "\u03c1"),
+    (0x1d78f, Mapped, "\u03c0"),
+    (0x1d790, Mapped, "\u03b1"),
+    (0x1d791, Mapped, "\u03b2"),
+    (0x1d792, Mapped, "\u03b3"),
+    (0x1d793, Mapped, "\u03b4"),
+    (0x1d794, Mapped, "\u03b5"),
+    (0x1d795, Mapped, "\u03b6"),
+    (0x1d796, Mapped, "\u03b7"),
+    (0x1d797, Mapped, "\u03b8"),
+    (0x1d798, Mapped, "\u03b9"),
+    (0x1d799, Mapped, "\u03ba"),
+    (0x1d79a, Mapped, "\u03bb"),
+    (0x1d79b, Mapped, "\u03bc"),
+    (0x1d79c, Mapped, "\u03bd"),
+    (0x1d79d, Mapped, "\u03be"),
+    (0x1d79e, Mapped, "\u03bf"),
+    (0x1d79f, Mapped, "\u03c0"),
+    (0x1d7a0, Mapped, "\u03c1"),
+    (0x1d7a1, Mapped, "\u03b8"),
+    (0x1d7a2, Mapped, "\u03c3"),
+    (0x1d7a3, Mapped, "\u03c4"),
+    (0x1d7a4, Mapped, "\u03c5"),
+    (0x1d7a5, Mapped, "\u03c6"),
+    (0x1d7a6, Mapped, "\u03c7"),
+    (0x1d7a7, Mapped, "\u03c8"),
+    (0x1d7a8, Mapped, "\u03c9"),
+    (0x1d7a9, Mapped, "\u2207"),
+    (0x1d7aa, Mapped, "\u03b1"),
+    (0x1d7ab, Mapped, "\u03b2"),
+    (0x1d7ac, Mapped, "\u03b3"),
+    (0x1d7ad, Mapped, "\u03b4"),
+    (0x1d7ae, Mapped, "\u03b5"),
+    (0x1d7af, Mapped, "\u03b6"),
+    (0x1d7b0, Mapped, "\u03b7"),
+    (0x1d7b1, Mapped, "\u03b8"),
+    (0x1d7b2, Mapped, "\u03b9"),
+    (0x1d7b3, Mapped, "\u03ba"),
+    (0x1d7b4, Mapped, "\u03bb"),
+    (0x1d7b5, Mapped, "\u03bc"),
+    (0x1d7b6, Mapped, "\u03bd"),
+    (0x1d7b7, Mapped, "\u03be"),
+    (0x1d7b8, Mapped, "\u03bf"),
+    (0x1d7b9, Mapped, "\u03c0"),
+    (0x1d7ba, Mapped, "\u03c1"),
+    (0x1d7bb, Mapped, "\u03c3"),
+    (0x1d7bd, Mapped, "\u03c4"),
+    (0x1d7be, Mapped, "\u03c5"),
+    (0x1d7bf, Mapped, "\u03c6"),
+    (0x1d7c0, Mapped, "\u03c7"),
+    (0x1d7c1, Mapped, "\u03c8"),
+    (0x1d7c2, Mapped, "\u03c9"),
+    (0x1d7c3, Mapped, "\u2202"),
+    (0x1d7c4, Mapped, "\u03b5"),
+    (0x1d7c5, Mapped, "\u03b8"),
+    (0x1d7c6, Mapped, "\u03ba"),
+    (0x1d7c7, Mapped, "\u03c6"),
+    (0x1d7c8, Mapped, "\u03c1"),
+    (0x1d7c9, Mapped, "\u03c0"),
+    (0x1d7ca, Mapped, "\u03dd"),
+    (0x1d7cc, Disallowed, ""),
+    (0x1d7ce, Mapped, "0"),
+    (0x1d7cf, Mapped, "1"),
+    (0x1d7d0, Mapped, "2"),
+    (0x1d7d1, Mapped, "3"),
+    (0x1d7d2, Mapped, "4"),
+    (0x1d7d3, Mapped, "5"),
+    (0x1d7d4, Mapped, "6"),
+    (0x1d7d5, Mapped, "7"),
+    (0x1d7d6, Mapped, "8"),
+    (0x1d7d7, Mapped, "9"),
+    (0x1d7d8, Mapped, "0"),
+    (0x1d7d9, Mapped, "1"),
+    (0x1d7da, Mapped, "2"),
+    (0x1d7db, Mapped, "3"),
+    (0x1d7dc, Mapped, "4"),
+    (0x1d7dd, Mapped, "5"),
+    (0x1d7de, Mapped, "6"),
+    (0x1d7df, Mapped, "7"),
+    (0x1d7e0, Mapped, "8"),
+    (0x1d7e1, Mapped, "9"),
+    (0x1d7e2, Mapped, "0"),
+    (0x1d7e3, Mapped, "1"),
+    (0x1d7e4, Mapped, "2"),
+    (0x1d7e5, Mapped, "3"),
+    (0x1d7e6, Mapped, "4"),
+    (0x1d7e7, Mapped, "5"),
+    (0x1d7e8, Mapped, "6"),
+    (0x1d7e9, Mapped, "7"),
+    (0x1d7ea, Mapped, "8"),
+    (0x1d7eb, Mapped, "9"),
+    (0x1d7ec, Mapped, "0"),
+    (0x1d7ed, Mapped, "1"),
+    (0x1d7ee, Mapped, "2"),
+    (0x1d7ef, Mapped, "3"),
+    (0x1d7f0, Mapped, "4"),
+    (0x1d7f1, Mapped, "5"),
+    (0x1d7f2, Mapped, "6"),
+    (0x1d7f3, Mapped, "7"),
+    (0x1d7f4, Mapped, "8"),
+    (0x1d7f5, Mapped, "9"),
+    (0x1d7f6, Mapped, "0"),
+    (0x1d7f7, Mapped, "1"),
+    (0x1d7f8, Mapped, "2"),
+    (0x1d7f9, Mapped, "3"),
+    (0x1d7fa, Mapped, "4"),
+    (0x1d7fb, Mapped, "5"),
+    (0x1d7fc, Mapped, "6"),
+    (0x1d7fd, Mapped, "7"),
+    (0x1d7fe, Mapped, "8"),
+    (0x1d7ff, Mapped, "9"),
+    (0x1d800, Valid, ""),
+    (0x1da8c, Disallowed, ""),
+    (0x1da9b, Valid, ""),
+    (0x1daa0, Disallowed, ""),
+    (0x1daa1, Valid, ""),
+    (0x1dab0, Disallowed, ""),
+    (0x1df00, Valid, ""),
+    (0x1df1f, Disallowed, ""),
+    (0x1df25, Valid, ""),
+    (0x1df2b, Disallowed, ""),
+    (0x1e000, Valid, ""),
+    (0x1e007, Disallowed, ""),
+    (0x1e008, Valid, ""),
+    (0x1e019, Disallowed, ""),
+    (0x1e01b, Valid, ""),
+    (0x1e022, Disallowed, ""),
+    (0x1e023, Valid, ""),
+    (0x1e025, Disallowed, ""),
+    (0x1e026, Valid, ""),
+    (0x1e02b, Disallowed, ""),
+    (0x1e030, Mapped, "\u0430"),
+    (0x1e031, Mapped, "\u0431"),
+    (0x1e032, Mapped, "\u0432"),
+    (0x1e033, Mapped, "\u0433"),
+    (0x1e034, Mapped, "\u0434"),
+    (0x1e035, Mapped, "\u0435"),
+    (0x1e036, Mapped, "\u0436"),
+    (0x1e037, Mapped, "\u0437"),
+    (0x1e038, Mapped, "\u0438"),
+    (0x1e039, Mapped, "\u043a"),
+    (0x1e03a, Mapped, "\u043b"),
+    (0x1e03b, Mapped, "\u043c"),
+    (0x1e03c, Mapped, "\u043e"),
+    (0x1e03d, Mapped, "\u043f"),
+    (0x1e03e, Mapped, "\u0440"),
+    (0x1e03f, Mapped, "\u0441"),
+    (0x1e040, Mapped, "\u0442"),
+    (0x1e041, Mapped, "\u0443"),
+    (0x1e042, Mapped, "\u0444"),
+    (0x1e043, Mapped, "\u0445"),
+    (0x1e044, Mapped, "\u0446"),
+    (0x1e045, Mapped, "\u0447"),
+    (0x1e046, Mapped, "\u0448"),
+    (0x1e047, Mapped, "\u044b"),
+    (0x1e048, Mapped, "\u044d"),
+    (0x1e049, Mapped, "\u044e"),
+    (0x1e04a, Mapped, "\ua689"),
+    (0x1e04b, Mapped, "\u04d9"),
+    (0x1e04c, Mapped, "\u0456"),
+    (0x1e04d, Mapped, "\u0458"),
+    (0x1e04e, Mapped, "\u04e9"),
+    (0x1e04f, Mapped, "\u04af"),
+    (0x1e050, Mapped, "\u04cf"),
+    (0x1e051, Mapped, "\u0430"),
+    (0x1e052, Mapped, "\u0431"),
+    (0x1e053, Mapped, "\u0432"),
+    (0x1e054, Mapped, "\u0433"),
+    (0x1e055, Mapped, "\u0434"),
+    (0x1e056, Mapped, "\u0435"),
+    (0x1e057, Mapped, "\u0436"),
+    (0x1e058, Mapped, "\u0437"),
+    (0x1e059, Mapped, "\u0438"),
+    (0x1e05a, Mapped, "\u043a"),
+    (0x1e05b, Mapped, "\u043b"),
+    (0x1e05c, Mapped, "\u043e"),
+    (0x1e05d, Mapped, "\u043f"),
+    (0x1e05e, Mapped, "\u0441"),
+    (0x1e05f, Mapped, "\u0443"),
+    (0x1e060, Mapped, "\u0444"),
+    (0x1e061, Mapped, "\u0445"),
+    (0x1e062, Mapped, "\u0446"),
+    (0x1e063, Mapped, "\u0447"),
+    (0x1e064, Mapped, "\u0448"),
+    (0x1e065, Mapped, "\u044a"),
+    (0x1e066, Mapped, "\u044b"),
+    (0x1e067, Mapped, "\u0491"),
+    (0x1e068, Mapped, "\u0456"),
+    (0x1e069, Mapped, "\u0455"),
+    (0x1e06a, Mapped, "\u045f"),
+    (0x1e06b, Mapped, "\u04ab"),
+    (0x1e06c, Mapped, "\ua651"),
+    (0x1e06d, Mapped, "\u04b1"),
+    (0x1e06e, Disallowed, ""),
+    (0x1e08f, Valid, ""),
+    (0x1e090, Disallowed, ""),
+    (0x1e100, Valid, ""),
+    (0x1e12d, Disallowed, ""),
+    (0x1e130, Valid, ""),
+    (0x1e13e, Disallowed, ""),
+    (0x1e140, Valid, ""),
+    (0x1e14a, Disallowed, ""),
+    (0x1e14e, Valid, ""),
+    (0x1e150, Disallowed, ""),
+    (0x1e290, Valid, ""),
+    (0x1e2af, Disallowed, ""),
+    (0x1e2c0, Valid, ""),
+    (0x1e2fa, Disallowed, ""),
+    (0x1e2ff, Valid, ""),
+    (0x1e300, Disallowed, ""),
+    (0x1e4d0, Valid, ""),
+    (0x1e4fa, Disallowed, ""),
+    (0x1e5d0, Valid, ""),
+    (0x1e5fb, Disallowed, ""),
+    (0x1e5ff, Valid, ""),
+    (0x1e600, Disallowed, ""),
+    (0x1e6c0, Valid, ""),
+    (0x1e6df, Disallowed, ""),
+    (0x1e6e0, Valid, ""),
+    (0x1e6f6, Disallowed, ""),
+    (0x1e6fe, Valid, ""),
+    (0x1e700, Disallowed, ""),
+    (0x1e7e0, Valid, ""),
+    (0x1e7e7, Disallowed, ""),
+    (0x1e7e8, Valid, ""),
+    (0x1e7ec, Disallowed, ""),
+    (0x1e7ed, Valid, ""),
+    (0x1e7ef, Disallowed, ""),
+    (0x1e7f0, Valid, ""),
+    (0x1e7ff, Disallowed, ""),
+    (0x1e800, Valid, ""),
+    (0x1e8c5, Disallowed, ""),
+    (0x1e8c7, Valid, ""),
+    (0x1e8d7, Disallowed, ""),
+    (0x1e900, Mapped, "\U0001e922"),
+    (0x1e901, Mapped, "\U0001e923"),
+    (0x1e902, Mapped, "\U0001e924"),
+    (0x1e903, Mapped, "\U0001e925"),
+    (0x1e904, Mapped, "\U0001e926"),
+    (0x1e905, Mapped, "\U0001e927"),
+    (0x1e906, Mapped, "\U0001e928"),
+    (0x1e907, Mapped, "\U0001e929"),
+    (0x1e908, Mapped, "\U0001e92a"),
+    (0x1e909, Mapped, "\U0001e92b"),
+    (0x1e90a, Mapped, "\U0001e92c"),
+    (0x1e90b, Mapped, "\U0001e92d"),
+    (0x1e90c, Mapped, "\U0001e92e"),
+    (0x1e90d, Mapped, "\U0001e92f"),
+    (0x1e90e, Mapped, "\U0001e930"),
+    (0x1e90f, Mapped, "\U0001e931"),
+    (0x1e910, Mapped, "\U0001e932"),
+    (0x1e911, Mapped, "\U0001e933"),
+    (0x1e912, Mapped, "\U0001e934"),
+    (0x1e913, Mapped, "\U0001e935"),
+    (0x1e914, Mapped, "\U0001e936"),
+    (0x1e915, Mapped, "\U0001e937"),
+    (0x1e916, Mapped, "\U0001e938"),
+    (0x1e917, Mapped, "\U0001e939"),
+    (0x1e918, Mapped, "\U0001e93a"),
+    (0x1e919, Mapped, "\U0001e93b"),
+    (0x1e91a, Mapped, "\U0001e93c"),
+    (0x1e91b, Mapped, "\U0001e93d"),
+    (0x1e91c, Mapped, "\U0001e93e"),
+    (0x1e91d, Mapped, "\U0001e93f"),
+    (0x1e91e, Mapped, "\U0001e940"),
+    (0x1e91f, Mapped, "\U0001e941"),
+    (0x1e920, Mapped, "\U0001e942"),
+    (0x1e921, Mapped, "\U0001e943"),
+    (0x1e922, Valid, ""),
+    (0x1e94c, Disallowed, ""),
+    (0x1e950, Valid, ""),
+    (0x1e95a, Disallowed, ""),
+    (0x1e95e, Valid, ""),
+    (0x1e960, Disallowed, ""),
+    (0x1ec71, Valid, ""),
+    (0x1ecb5, Disallowed, ""),
+    (0x1ed01, Valid, ""),
+    (0x1ed3e, Disallowed, ""),
+    (0x1ee00, Mapped, "\u0627"),
+    (0x1ee01, Mapped, "\u0628"),
+    (0x1ee02, Mapped, "\u062c"),
+    (0x1ee03, Mapped, "\u062f"),
+    (0x1ee04, Disallowed, ""),
+    (0x1ee05, Mapped, "\u0648"),
+    (0x1ee06, Mapped, "\u0632"),
+    (0x1ee07, Mapped, "\u062d"),
+    (0x1ee08, Mapped, "\u0637"),
+    (0x1ee09, Mapped, "\u064a"),
+    (0x1ee0a, Mapped, "\u0643"),
+    (0x1ee0b, Mapped, "\u0644"),
+    (0x1ee0c, Mapped, "\u0645"),
+    (0x1ee0d, Mapped, "\u0646"),
+    (0x1ee0e, Mapped, "\u0633"),
+    (0x1ee0f, Mapped, "\u0639"),
+    (0x1ee10, Mapped, "\u0641"),
+    (0x1ee11, Mapped, "\u0635"),
+    (0x1ee12, Mapped, "\u0642"),
+    (0x1ee13, Mapped, "\u0631"),
+    (0x1ee14, Mapped, "\u0634"),
+    (0x1ee15, Mapped, "\u062a"),
+    (0x1ee16, Mapped, "\u062b"),
+    (0x1ee17, Mapped, "\u062e"),
+    (0x1ee18, Mapped, "\u0630"),
+    (0x1ee19, Mapped, "\u0636"),
+    (0x1ee1a, Mapped, "\u0638"),
+    (0x1ee1b, Mapped, "\u063a"),
+    (0x1ee1c, Mapped, "\u066e"),
+    (0x1ee1d, Mapped, "\u06ba"),
+    (0x1ee1e, Mapped, "\u06a1"),
+    (0x1ee1f, Mapped, "\u066f"),
+    (0x1ee20, Disallowed, ""),
+    (0x1ee21, Mapped, "\u0628"),
+    (0x1ee22, Mapped, "\u062c"),
+    (0x1ee23, Disallowed, ""),
+    (0x1ee24, Mapped, "\u0647"),
+    (0x1ee25, Disallowed, ""),
+    (0x1ee27, Mapped, "\u062d"),
+    (0x1ee28, Disallowed, ""),
+    (0x1ee29, Mapped, "\u064a"),
+    (0x1ee2a, Mapped, "\u0643"),
+    (0x1ee2b, Mapped, "\u0644"),
+    (0x1ee2c, Mapped, "\u0645"),
+    (0x1ee2d, Mapped, "\u0646"),
+    (0x1ee2e, Mapped, "\u0633"),
+    (0x1ee2f, Mapped, "\u0639"),
+    (0x1ee30, Mapped, "\u0641"),
+    (0x1ee31, Mapped, "\u0635"),
+    (0x1ee32, Mapped, "\u0642"),
+    (0x1ee33, Disallowed, ""),
+    (0x1ee34, Mapped, "\u0634"),
+    (0x1ee35, Mapped, "\u062a"),
+    (0x1ee36, Mapped, "\u062b"),
+    (0x1ee37, Mapped, "\u062e"),
+    (0x1ee38, Disallowed, ""),
+    (0x1ee39, Mapped, "\u0636"),
+    (0x1ee3a, Disallowed, ""),
+    (0x1ee3b, Mapped, "\u063a"),
+    (0x1ee3c, Disallowed, ""),
+    (0x1ee42, Mapped, "\u062c"),
+    (0x1ee43, Disallowed, ""),
+    (0x1ee47, Mapped, "\u062d"),
+    (0x1ee48, Disallowed, ""),
+    (0x1ee49, Mapped, "\u064a"),
+    (0x1ee4a, Disallowed, ""),
+    (0x1ee4b, Mapped, "\u0644"),
+    (0x1ee4c, Disallowed, ""),
+    (0x1ee4d, Mapped, "\u0646"),
+    (0x1ee4e, Mapped, "\u0633"),
+    (0x1ee4f, Mapped, "\u0639"),
+    (0x1ee50, Disallowed, ""),
+    (0x1ee51, Mapped, "\u0635"),
+    (0x1ee52, Mapped, "\u0642"),
+    (0x1ee53, Disallowed, ""),
+    (0x1ee54, Mapped, "\u0634"),
+    (0x1ee55, Disallowed, ""),
+    (0x1ee57, Mapped, "\u062e"),
+    (0x1ee58, Disallowed, ""),
+    (0x1ee59, Mapped, "\u0636"),
+    (0x1ee5a, Disallowed, ""),
+    (0x1ee5b, Mapped, "\u063a"),
+    (0x1ee5c, Disallowed, ""),
+    (0x1ee5d, Mapped, "\u06ba"),
+    (0x1ee5e, Disallowed, ""),
+    (0x1ee5f, Mapped, "\u066f"),
+    (0x1ee60, Disallowed, ""),
+    (0x1ee61, Mapped, "\u0628"),
+    (0x1ee62, Mapped, "\u062c"),
+    (0x1ee63, Disallowed, ""),
+    (0x1ee64, Mapped, "\u0647"),
+    (0x1ee65, Disallowed, ""),
+    (0x1ee67, Mapped, "\u062d"),
+    (0x1ee68, Mapped, "\u0637"),
+    (0x1ee69, Mapped, "\u064a"),
+    (0x1ee6a, Mapped, "\u0643"),
+    (0x1ee6b, Disallowed, ""),
+    (0x1ee6c, Mapped, "\u0645"),
+    (0x1ee6d, Mapped, "\u0646"),
+    (0x1ee6e, Mapped, "\u0633"),
+    (0x1ee6f, Mapped, "\u0639"),
+    (0x1ee70, Mapped, "\u0641"),
+    (0x1ee71, Mapped, "\u0635"),
+    (0x1ee72, Mapped, "\u0642"),
+    (0x1ee73, Disallowed, ""),
+    (0x1ee74, Mapped, "\u0634"),
+    (0x1ee75, Mapped, "\u062a"),
+    (0x1ee76, Mapped, "\u062b"),
+    (0x1ee77, Mapped, "\u062e"),
+    (0x1ee78, Disallowed, ""),
+    (0x1ee79, Mapped, "\u0636"),
+    (0x1ee7a, Mapped, "\u0638"),
+    (0x1ee7b, Mapped, "\u063a"),
+    (0x1ee7c, Mapped, "\u066e"),
+    (0x1ee7d, Disallowed, ""),
+    (0x1ee7e, Mapped, "\u06a1"),
+    (0x1ee7f, Disallowed, ""),
+    (0x1ee80, Mapped, "\u0627"),
+    (0x1ee81, Mapped, "\u0628"),
+    (0x1ee82, Mapped, "\u062c"),
+    (0x1ee83, Mapped, "\u062f"),
+    (0x1ee84, Mapped, "\u0647"),
+    (0x1ee85, Mapped, "\u0648"),
+    (0x1ee86, Mapped, "\u0632"),
+    (0x1ee87, Mapped, "\u062d"),
+    (0x1ee88, Mapped, "\u0637"),
+    (0x1ee89, Mapped, "\u064a"),
+    (0x1ee8a, Disallowed, ""),
+    (0x1ee8b, Mapped, "\u0644"),
+    (0x1ee8c, Mapped, "\u0645"),
+    (0x1ee8d, Mapped, "\u0646"),
+    (0x1ee8e, Mapped, "\u0633"),
+    (0x1ee8f, Mapped, "\u0639"),
+    (0x1ee90, Mapped, "\u0641"),
+    (0x1ee91, Mapped, "\u0635"),
+    (0x1ee92, Mapped, "\u0642"),
+    (0x1ee93, Mapped, "\u0631"),
+    (0x1ee94, Mapped, "\u0634"),
+    (0x1ee95, Mapped, "\u062a"),
+    (0x1ee96, Mapped, "\u062b"),
+    (0x1ee97, Mapped, "\u062e"),
+    (0x1ee98, Mapped, "\u0630"),
+    (0x1ee99, Mapped, "\u0636"),
+    (0x1ee9a, Mapped, "\u0638"),
+    (0x1ee9b, Mapped, "\u063a"),
+    (0x1ee9c, Disallowed, ""),
+    (0x1eea1, Mapped, "\u0628"),
+    (0x1eea2, Mapped, "\u062c"),
+    (0x1eea3, Mapped, "\u062f"),
+    (0x1eea4, Disallowed, ""),
+    (0x1eea5, Mapped, "\u0648"),
+    (0x1eea6, Mapped, "\u0632"),
+    (0x1eea7, Mapped, "\u062d"),
+    (0x1eea8, Mapped, "\u0637"),
+    (0x1eea9, Mapped, "\u064a"),
+    (0x1eeaa, Disallowed, ""),
+    (0x1eeab, Mapped, "\u0644"),
+    (0x1eeac, Mapped, "\u0645"),
+    (0x1eead, Mapped, "\u0646"),
+    (0x1eeae, Mapped, "\u0633"),
+    (0x1eeaf, Mapped, "\u0639"),
+    (0x1eeb0, Mapped, "\u0641"),
+    (0x1eeb1, Mapped, "\u0635"),
+    (0x1eeb2, Mapped, "\u0642"),
+    (0x1eeb3, Mapped, "\u0631"),
+    (0x1eeb4, Mapped, "\u0634"),
+    (0x1eeb5, Mapped, "\u062a"),
+    (0x1eeb6, Mapped, "\u062b"),
+    (0x1eeb7, Mapped, "\u062e"),
+    (0x1eeb8, Mapped, "\u0630"),
+    (0x1eeb9, Mapped, "\u0636"),
+    (0x1eeba, Mapped, "\u0638"),
+    (0x1eebb, Mapped, "\u063a"),
+    (0x1eebc, Disallowed, ""),
+    (0x1eef0, Valid, ""),
+    (0x1eef2, Disallowed, ""),
+    (0x1f000, Valid, ""),
+    (0x1f02c, Disallowed, ""),
+    (0x1f030, Valid, ""),
+    (0x1f094, Disallowed, ""),
+    (0x1f0a0, Valid, ""),
+    (0x1f0af, Disallowed, ""),
+    (0x1f0b1, Valid, ""),
+    (0x1f0c0, Disallowed, ""),
+    (0x1f0c1, Valid, ""),
+    (0x1f0d0, Disallowed, ""),
+    (0x1f0d1, Valid, ""),
+    (0x1f0f6, Disallowed, ""),
+    (0x1f101, Mapped, "0,"),
+    (0x1f102, Mapped, "1,"),
+    (0x1f103, Mapped, "2,"),
+    (0x1f104, Mapped, "3,"),
+    (0x1f105, Mapped, "4,"),
+    (0x1f106, Mapped, "5,"),
+    (0x1f107, Mapped, "6,"),
+    (0x1f108, Mapped, "7,"),
+    (0x1f109, Mapped, "8,"),
+    (0x1f10a, Mapped, "9,"),
+    (0x1f10b, Valid, ""),
+    (0x1f110, Mapped, "(a)"),
+    (0x1f111, Mapped, "(b)"),
+    (0x1f112, Mapped, "(c)"),
+    (0x1f113, Mapped, "(d)"),
+    (0x1f114, Mapped, "(e)"),
+    (0x1f115, Mapped, "(f)"),
+    (0x1f116, Mapped, "(g)"),
+    (0x1f117, Mapped, "(h)"),
+    (0x1f118, Mapped, "(i)"),
+    (0x1f119, Mapped, "(j)"),
+    (0x1f11a, Mapped, "(k)"),
+    (0x1f11b, Mapped, "(l)"),
+    (0x1f11c, Mapped, "(m)"),
+    (0x1f11d, Mapped, "(n)"),
+    (0x1f11e, Mapped, "(o)"),
+    (0x1f11f, Mapped, "(p)"),
+    (0x1f120, Mapped, "(q)"),
+    (0x1f121, Mapped, "(r)"),
+    (0x1f122, Mapped, "(s)"),
+    (0x1f123, Mapped, "(t)"),
+    (0x1f124, Mapped, "(u)"),
+    (0x1f125, Mapped, "(v)"),
+    (0x1f126, Mapped, "(w)"),
+    (0x1f127, Mapped, "(x)"),
+    (0x1f128, Mapped, "(y)"),
+    (0x1f129, Mapped, "(z)"),
+    (0x1f12a, Mapped, "\u3014s\u3015"),
+    (0x1f12b, Mapped, "c"),
+    (0x1f12c, Mapped, "r"),
+    (0x1f12d, Mapped, "cd"),
+    (0x1f12e, Mapped, "wz"),
+    (0x1f12f, Valid, ""),
+    (0x1f130, Mapped, "a"),
+    (0x1f131, Mapped, "b"),
+    (0x1f132, Mapped, "c"),
+    (0x1f133, Mapped, "d"),
+    (0x1f134, Mapped, "e"),
+    (0x1f135, Mapped, "f"),
+    (0x1f136, Mapped, "g"),
+    (0x1f137, Mapped, "h"),
+    (0x1f138, Mapped, "i"),
+    (0x1f139, Mapped, "j"),
+    (0x1f13a, Mapped, "k"),
+    (0x1f13b, Mapped, "l"),
+    (0x1f13c, Mapped, "m"),
+    (0x1f13d, Mapped, "n"),
+    (0x1f13e, Mapped, "o"),
+    (0x1f13f, Mapped, "p"),
+    (0x1f140, Mapped, "q"),
+    (0x1f141, Mapped, "r"),
+    (0x1f142, Mapped, "s"),
+    (0x1f143, Mapped, "t"),
+    (0x1f144, Mapped, "u"),
+    (0x1f145, Mapped, "v"),
+    (0x1f146, Mapped, "w"),
+    (0x1f147, Mapped, "x"),
+    (0x1f148, Mapped, "y"),
+    (0x1f149, Mapped, "z"),
+    (0x1f14a, Mapped, "hv"),
+    (0x1f14b, Mapped, "mv"),
+    (0x1f14c, Mapped, "sd"),
+    (0x1f14d, Mapped, "ss"),
+    (0x1f14e, Mapped, "ppv"),
+    (0x1f14f, Mapped, "wc"),
+    (0x1f150, Valid, ""),
+    (0x1f16a, Mapped, "mc"),
+    (0x1f16b, Mapped, "md"),
+    (0x1f16c, Mapped, "mr"),
+    (0x1f16d, Valid, ""),
+    (0x1f190, Mapped, "dj"),
+    (0x1f191, Valid, ""),
+    (0x1f1ae, Disallowed, ""),
+    (0x1f1e6, Valid, ""),
+    (0x1f200, Mapped, "\u307b\u304b"),
+    (0x1f201, Mapped, "\u30b3\u30b3"),
+    (0x1f202, Mapped, "\u30b5"),
+    (0x1f203, Disallowed, ""),
+    (0x1f210, Mapped, "\u624b"),
+    (0x1f211, Mapped, "\u5b57"),
+    (0x1f212, Mapped, "\u53cc"),
+    (0x1f213, Mapped, "\u30c7"),
+    (0x1f214, Mapped, "\u4e8c"),
+    (0x1f215, Mapped, "\u591a"),
+    (0x1f216, Mapped, "\u89e3"),
+    (0x1f217, Mapped, "\u5929"),
+    (0x1f218, Mapped, "\u4ea4"),
+    (0x1f219, Mapped, "\u6620"),
+    (0x1f21a, Mapped, "\u7121"),
+    (0x1f21b, Mapped, "\u6599"),
+    (0x1f21c, Mapped, "\u524d"),
+    (0x1f21d, Mapped, "\u5f8c"),
+    (0x1f21e, Mapped, "\u518d"),
+    (0x1f21f, Mapped, "\u65b0"),
+    (0x1f220, Mapped, "\u521d"),
+    (0x1f221, Mapped, "\u7d42"),
+    (0x1f222, Mapped, "\u751f"),
+    (0x1f223, Mapped, "\u8ca9"),
+    (0x1f224, Mapped, "\u58f0"),
+    (0x1f225, Mapped, "\u5439"),
+    (0x1f226, Mapped, "\u6f14"),
+    (0x1f227, Mapped, "\u6295"),
+    (0x1f228, Mapped, "\u6355"),
+    (0x1f229, Mapped, "\u4e00"),
+    (0x1f22a, Mapped, "\u4e09"),
+    (0x1f22b, Mapped, "\u904a"),
+    (0x1f22c, Mapped, "\u5de6"),
+    (0x1f22d, Mapped, "\u4e2d"),
+    (0x1f22e, Mapped, "\u53f3"),
+    (0x1f22f, Mapped, "\u6307"),
+    (0x1f230, Mapped, "\u8d70"),
+    (0x1f231, Mapped, "\u6253"),
+    (0x1f232, Mapped, "\u7981"),
+    (0x1f233, Mapped, "\u7a7a"),
+    (0x1f234, Mapped, "\u5408"),
+    (0x1f235, Mapped, "\u6e80"),
+    (0x1f236, Mapped, "\u6709"),
+    (0x1f237, Mapped, "\u6708"),
+    (0x1f238, Mapped, "\u7533"),
+    (0x1f239, Mapped, "\u5272"),
+    (0x1f23a, Mapped, "\u55b6"),
+    (0x1f23b, Mapped, "\u914d"),
+    (0x1f23c, Disallowed, ""),
+    (0x1f240, Mapped, "\u3014\u672c\u3015"),
+    (0x1f241, Mapped, "\u3014\u4e09\u3015"),
+    (0x1f242, Mapped, "\u3014\u4e8c\u3015"),
+    (0x1f243, Mapped, "\u3014\u5b89\u3015"),
+    (0x1f244, Mapped, "\u3014\u70b9\u3015"),
+    (0x1f245, Mapped, "\u3014\u6253\u3015"),
+    (0x1f246, Mapped, "\u3014\u76d7\u3015"),
+    (0x1f247, Mapped, "\u3014\u52dd\u3015"),
+    (0x1f248, Mapped, "\u3014\u6557\u3015"),
+    (0x1f249, Disallowed, ""),
+    (0x1f250, Mapped, "\u5f97"),
+    (0x1f251, Mapped, "\u53ef"),
+    (0x1f252, Disallowed, ""),
+    (0x1f260, Valid, ""),
+    (0x1f266, Disallowed, ""),
+    (0x1f300, Valid, ""),
+    (0x1f6d9, Disallowed, ""),
+    (0x1f6dc, Valid, ""),
+    (0x1f6ed, Disallowed, ""),
+    (0x1f6f0, Valid, ""),
+    (0x1f6fd, Disallowed, ""),
+    (0x1f700, Valid, ""),
+    (0x1f7da, Disallowed, ""),
+    (0x1f7e0, Valid, ""),
+    (0x1f7ec, Disallowed, ""),
+    (0x1f7f0, Valid, ""),
+    (0x1f7f1, Disallowed, ""),
+    (0x1f800, Valid, ""),
+    (0x1f80c, Disallowed, ""),
+    (0x1f810, Valid, ""),
+    (0x1f848, Disallowed, ""),
+    (0x1f850, Valid, ""),
+    (0x1f85a, Disallowed, ""),
+    (0x1f860, Valid, ""),
+    (0x1f888, Disallowed, ""),
+    (0x1f890, Valid, ""),
+    (0x1f8ae, Disallowed, ""),
+    (0x1f8b0, Valid, ""),
+    (0x1f8bc, Disallowed, ""),
+    (0x1f8c0, Valid, ""),
+    (0x1f8c2, Disallowed, ""),
+    (0x1f8d0, Valid, ""),
+    (0x1f8d9, Disallowed, ""),
+    (0x1f900, Valid, ""),
+    (0x1fa58, Disallowed, ""),
+    (0x1fa60, Valid, ""),
+    (0x1fa6e, Disallowed, ""),
+    (0x1fa70, Valid, ""),
+    (0x1fa7d, Disallowed, ""),
+    (0x1fa80, Valid, ""),
+    (0x1fa8b, Disallowed, ""),
+    (0x1fa8e, Valid, ""),
+    (0x1fac7, Disallowed, ""),
+    (0x1fac8, Valid, ""),
+    (0x1fac9, Disallowed, ""),
+    (0x1facd, Valid, ""),
+    (0x1fadd, Disallowed, ""),
+    (0x1fadf, Valid, ""),
+    (0x1faeb, Disallowed, ""),
+    (0x1faef, Valid, ""),
+    (0x1faf9, Disallowed, ""),
+    (0x1fb00, Valid, ""),
+    (0x1fb93, Disallowed, ""),
+    (0x1fb94, Valid, ""),
+    (0x1fbf0, Mapped, "0"),
+    (0x1fbf1, Mapped, "1"),
+    (0x1fbf2, Mapped, "2"),
+    (0x1fbf3, Mapped, "3"),
+    (0x1fbf4, Mapped, "4"),
+    (0x1fbf5, Mapped, "5"),
+    (0x1fbf6, Mapped, "6"),
+    (0x1fbf7, Mapped, "7"),
+    (0x1fbf8, Mapped, "8"),
+    (0x1fbf9, Mapped, "9"),
+    (0x1fbfa, Valid, ""),
+    (0x1fbfb, Disallowed, ""),
+    (0x20000, Valid, ""),
+    (0x2a6e0, Disallowed, ""),
+    (0x2a700, Valid, ""),
+    (0x2b81e, Disallowed, ""),
+    (0x2b820, Valid, ""),
+    (0x2ceae, Disallowed, ""),
+    (0x2ceb0, Valid, ""),
+    (0x2ebe1, Disallowed, ""),
+    (0x2ebf0, Valid, ""),
+    (0x2ee5e, Disallowed, ""),
+    (0x2f800, Mapped, "\u4e3d"),
+    (0x2f801, Mapped, "\u4e38"),
+    (0x2f802, Mapped, "\u4e41"),
+    (0x2f803, Mapped, "\U00020122"),
+    (0x2f804, Mapped, "\u4f60"),
+    (0x2f805, Mapped, "\u4fae"),
+    (0x2f806, Mapped, "\u4fbb"),
+    (0x2f807, Mapped, "\u5002"),
+    (0x2f808, Mapped, "\u507a"),
+    (0x2f809, Mapped, "\u5099"),
+    (0x2f80a, Mapped, "\u50e7"),
+    (0x2f80b, Mapped, "\u50cf"),
+    (0x2f80c, Mapped, "\u349e"),
+    (0x2f80d, Mapped, "\U0002063a"),
+    (0x2f80e, Mapped, "\u514d"),
+    (0x2f80f, Mapped, "\u5154"),
+    (0x2f810, Mapped, "\u5164"),
+    (0x2f811, Mapped, "\u5177"),
+    (0x2f812, Mapped, "\U0002051c"),
+    (0x2f813, Mapped, "\u34b9"),
+    (0x2f814, Mapped, "\u5167"),
+    (0x2f815, Mapped, "\u518d"),
+    (0x2f816, Mapped, "\U0002054b"),
+    (0x2f817, Mapped, "\u5197"),
+    (0x2f818, Mapped, "\u51a4"),
+    (0x2f819, Mapped, "\u4ecc"),
+    (0x2f81a, Mapped, "\u51ac"),
+    (0x2f81b, Mapped, "\u51b5"),
+    (0x2f81c, Mapped, "\U000291df"),
+    (0x2f81d, Mapped, "\u51f5"),
+    (0x2f81e, Mapped, "\u5203"),
+    (0x2f81f, Mapped, "\u34df"),
+    (0x2f820, Mapped, "\u523b"),
+    (0x2f821, Mapped, "\u5246"),
+    (0x2f822, Mapped, "\u5272"),
+    (0x2f823, Mapped, "\u5277"),
+    (0x2f824, Mapped, "\u3515"),
+    (0x2f825, Mapped, "\u52c7"),
+    (0x2f826, Mapped, "\u52c9"),
+    (0x2f827, Mapped, "\u52e4"),
+    (0x2f828, Mapped, "\u52fa"),
+    (0x2f829, Mapped, "\u5305"),
+    (0x2f82a, Mapped, "\u5306"),
+    (0x2f82b, Mapped, "\u5317"),
+    (0x2f82c, Mapped, "\u5349"),
+    (0x2f82d, Mapped, "\u5351"),
+    (0x2f82e, Mapped, "\u535a"),
+    (0x2f82f, Mapped, "\u5373"),
+    (0x2f830, Mapped, "\u537d"),
+    (0x2f831, Mapped, "\u537f"),
+    (0x2f834, Mapped, "\U00020a2c"),
+    (0x2f835, Mapped, "\u7070"),
+    (0x2f836, Mapped, "\u53ca"),
+    (0x2f837, Mapped, "\u53df"),
+    (0x2f838, Mapped, "\U00020b63"),
+    (0x2f839, Mapped, "\u53eb"),
+    (0x2f83a, Mapped, "\u53f1"),
+    (0x2f83b, Mapped, "\u5406"),
+    (0x2f83c, Mapped, "\u549e"),
+    (0x2f83d, Mapped, "\u5438"),
+    (0x2f83e, Mapped, "\u5448"),
+    (0x2f83f, Mapped, "\u5468"),
+    (0x2f840, Mapped, "\u54a2"),
+    (0x2f841, Mapped, "\u54f6"),
+    (0x2f842, Mapped, "\u5510"),
+    (0x2f843, Mapped, "\u5553"),
+    (0x2f844, Mapped, "\u5563"),
+    (0x2f845, Mapped, "\u5584"),
+    (0x2f847, Mapped, "\u5599"),
+    (0x2f848, Mapped, "\u55ab"),
+    (0x2f849, Mapped, "\u55b3"),
+    (0x2f84a, Mapped, "\u55c2"),
+    (0x2f84b, Mapped, "\u5716"),
+    (0x2f84c, Mapped, "\u5606"),
+    (0x2f84d, Mapped, "\u5717"),
+    (0x2f84e, Mapped, "\u5651"),
+    (0x2f84f, Mapped, "\u5674"),
+    (0x2f850, Mapped, "\u5207"),
+    (0x2f851, Mapped, "\u58ee"),
+    (0x2f852, Mapped, "\u57ce"),
+    (0x2f853, Mapped, "\u57f4"),
+    (0x2f854, Mapped, "\u580d"),
+    (0x2f855, Mapped, "\u578b"),
+    (0x2f856, Mapped, "\u5832"),
+    (0x2f857, Mapped, "\u5831"),
+    (0x2f858, Mapped, "\u58ac"),
+    (0x2f859, Mapped, "\U000214e4"),
+    (0x2f85a, Mapped, "\u58f2"),
+    (0x2f85b, Mapped, "\u58f7"),
+    (0x2f85c, Mapped, "\u5906"),
+    (0x2f85d, Mapped, "\u591a"),
+    (0x2f85e, Mapped, "\u5922"),
+    (0x2f85f, Mapped, "\u5962"),
+    (0x2f860, Mapped, "\U000216a8"),
+    (0x2f861, Mapped, "\U000216ea"),
+    (0x2f862, Mapped, "\u59ec"),
+    (0x2f863, Mapped, "\u5a1b"),
+    (0x2f864, Mapped, "\u5a27"),
+    (0x2f865, Mapped, "\u59d8"),
+    (0x2f866, Mapped, "\u5a66"),
+    (0x2f867, Mapped, "\u36ee"),
+    (0x2f868, Mapped, "\u36fc"),
+    (0x2f869, Mapped, "\u5b08"),
+    (0x2f86a, Mapped, "\u5b3e"),
+    (0x2f86c, Mapped, "\U000219c8"),
+    (0x2f86d, Mapped, "\u5bc3"),
+    (0x2f86e, Mapped, "\u5bd8"),
+    (0x2f86f, Mapped, "\u5be7"),
+    (0x2f870, Mapped, "\u5bf3"),
+    (0x2f871, Mapped, "\U00021b18"),
+    (0x2f872, Mapped, "\u5bff"),
+    (0x2f873, Mapped, "\u5c06"),
+    (0x2f874, Mapped, "\u5f53"),
+    (0x2f875, Mapped, "\u5c22"),
+    (0x2f876, Mapped, "\u3781"),
+    (0x2f877, Mapped, "\u5c60"),
+    (0x2f878, Mapped, "\u5c6e"),
+    (0x2f879, Mapped, "\u5cc0"),
+    (0x2f87a, Mapped, "\u5c8d"),
+    (0x2f87b, Mapped, "\U00021de4"),
+    (0x2f87c, Mapped, "\u5d43"),
+    (0x2f87d, Mapped, "\U00021de6"),
+    (0x2f87e, Mapped, "\u5d6e"),
+    (0x2f87f, Mapped, "\u5d6b"),
+    (0x2f880, Mapped, "\u5d7c"),
+    (0x2f881, Mapped, "\u5de1"),
+    (0x2f882, Mapped, "\u5de2"),
+    (0x2f883, Mapped, "\u382f"),
+    (0x2f884, Mapped, "\u5dfd"),
+    (0x2f885, Mapped, "\u5e28"),
+    (0x2f886, Mapped, "\u5e3d"),
+    (0x2f887, Mapped, "\u5e69"),
+    (0x2f888, Mapped, "\u3862"),
+    (0x2f889, Mapped, "\U00022183"),
+    (0x2f88a, Mapped, "\u387c"),
+    (0x2f88b, Mapped, "\u5eb0"),
+    (0x2f88c, Mapped, "\u5eb3"),
+    (0x2f88d, Mapped, "\u5eb6"),
+    (0x2f88e, Mapped, "\u5eca"),
+    (0x2f88f, Mapped, "\U0002a392"),
+    (0x2f890, Mapped, "\u5efe"),
+    (0x2f891, Mapped, "\U00022331"),
+    (0x2f893, Mapped, "\u8201"),
+    (0x2f894, Mapped, "\u5f22"),
+    (0x2f896, Mapped, "\u38c7"),
+    (0x2f897, Mapped, "\U000232b8"),
+    (0x2f898, Mapped, "\U000261da"),
+    (0x2f899, Mapped, "\u5f62"),
+    (0x2f89a, Mapped, "\u5f6b"),
+    (0x2f89b, Mapped, "\u38e3"),
+    (0x2f89c, Mapped, "\u5f9a"),
+    (0x2f89d, Mapped, "\u5fcd"),
+    (0x2f89e, Mapped, "\u5fd7"),
+    (0x2f89f, Mapped, "\u5ff9"),
+    (0x2f8a0, Mapped, "\u6081"),
+    (0x2f8a1, Mapped, "\u393a"),
+    (0x2f8a2, Mapped, "\u391c"),
+    (0x2f8a3, Mapped, "\u6094"),
+    (0x2f8a4, Mapped, "\U000226d4"),
+    (0x2f8a5, Mapped, "\u60c7"),
+    (0x2f8a6, Mapped, "\u6148"),
+    (0x2f8a7, Mapped, "\u614c"),
+    (0x2f8a8, Mapped, "\u614e"),
+    (0x2f8a9, Mapped, "\u614c"),
+    (0x2f8aa, Mapped, "\u617a"),
+    (0x2f8ab, Mapped, "\u618e"),
+    (0x2f8ac, Mapped, "\u61b2"),
+    (0x2f8ad, Mapped, "\u61a4"),
+    (0x2f8ae, Mapped, "\u61af"),
+    (0x2f8af, Mapped, "\u61de"),
+    (0x2f8b0, Mapped, "\u61f2"),
+    (0x2f8b1, Mapped, "\u61f6"),
+    (0x2f8b2, Mapped, "\u6210"),
+    (0x2f8b3, Mapped, "\u621b"),
+    (0x2f8b4, Mapped, "\u625d"),
+    (0x2f8b5, Mapped, "\u62b1"),
+    (0x2f8b6, Mapped, "\u62d4"),
+    (0x2f8b7, Mapped, "\u6350"),
+    (0x2f8b8, Mapped, "\U00022b0c"),
+    (0x2f8b9, Mapped, "\u633d"),
+    (0x2f8ba, Mapped, "\u62fc"),
+    (0x2f8bb, Mapped, "\u6368"),
+    (0x2f8bc, Mapped, "\u6383"),
+    (0x2f8bd, Mapped, "\u63e4"),
+    (0x2f8be, Mapped, "\U00022bf1"),
+    (0x2f8bf, Mapped, "\u6422"),
+    (0x2f8c0, Mapped, "\u63c5"),
+    (0x2f8c1, Mapped, "\u63a9"),
+    (0x2f8c2, Mapped, "\u3a2e"),
+    (0x2f8c3, Mapped, "\u6469"),
+    (0x2f8c4, Mapped, "\u647e"),
+    (0x2f8c5, Mapped, "\u649d"),
+    (0x2f8c6, Mapped, "\u6477"),
+    (0x2f8c7, Mapped, "\u3a6c"),
+    (0x2f8c8, Mapped, "\u654f"),
+    (0x2f8c9, Mapped, "\u656c"),
+    (0x2f8ca, Mapped, "\U0002300a"),
+    (0x2f8cb, Mapped, "\u65e3"),
+    (0x2f8cc, Mapped, "\u66f8"),
+    (0x2f8cd, Mapped, "\u6649"),
+    (0x2f8ce, Mapped, "\u3b19"),
+    (0x2f8cf, Mapped, "\u6691"),
+    (0x2f8d0, Mapped, "\u3b08"),
+    (0x2f8d1, Mapped, "\u3ae4"),
+    (0x2f8d2, Mapped, "\u5192"),
+    (0x2f8d3, Mapped, "\u5195"),
+    (0x2f8d4, Mapped, "\u6700"),
+    (0x2f8d5, Mapped, "\u669c"),
+    (0x2f8d6, Mapped, "\u80ad"),
+    (0x2f8d7, Mapped, "\u43d9"),
+    (0x2f8d8, Mapped, "\u6717"),
+    (0x2f8d9, Mapped, "\u671b"),
+    (0x2f8da, Mapped, "\u6721"),
+    (0x2f8db, Mapped, "\u675e"),
+    (0x2f8dc, Mapped, "\u6753"),
+    (0x2f8dd, Mapped, "\U000233c3"),
+    (0x2f8de, Mapped, "\u3b49"),
+    (0x2f8df, Mapped, "\u67fa"),
+    (0x2f8e0, Mapped, "\u6785"),
+    (0x2f8e1, Mapped, "\u6852"),
+    (0x2f8e2, Mapped, "\u6885"),
+    (0x2f8e3, Mapped, "\U0002346d"),
+    (0x2f8e4, Mapped, "\u688e"),
+    (0x2f8e5, Mapped, "\u681f"),
+    (0x2f8e6, Mapped, "\u6914"),
+    (0x2f8e7, Mapped, "\u3b9d"),
+    (0x2f8e8, Mapped, "\u6942"),
+    (0x2f8e9, Mapped, "\u69a3"),
+    (0x2f8ea, Mapped, "\u69ea"),
+    (0x2f8eb, Mapped, "\u6aa8"),
+    (0x2f8ec, Mapped, "\U000236a3"),
+    (0x2f8ed, Mapped, "\u6adb"),
+    (0x2f8ee, Mapped, "\u3c18"),
+    (0x2f8ef, Mapped, "\u6b21"),
+    (0x2f8f0, Mapped, "\U000238a7"),
+    (0x2f8f1, Mapped, "\u6b54"),
+    (0x2f8f2, Mapped, "\u3c4e"),
+    (0x2f8f3, Mapped, "\u6b72"),
+    (0x2f8f4, Mapped, "\u6b9f"),
+    (0x2f8f5, Mapped, "\u6bba"),
+    (0x2f8f6, Mapped, "\u6bbb"),
+    (0x2f8f7, Mapped, "\U00023a8d"),
+    (0x2f8f8, Mapped, "\U00021d0b"),
+    (0x2f8f9, Mapped, "\U00023afa"),
+    (0x2f8fa, Mapped, "\u6c4e"),
+    (0x2f8fb, Mapped, "\U00023cbc"),
+    (0x2f8fc, Mapped, "\u6cbf"),
+    (0x2f8fd, Mapped, "\u6ccd"),
+    (0x2f8fe, Mapped, "\u6c67"),
+    (0x2f8ff, Mapped, "\u6d16"),
+    (0x2f900, Mapped, "\u6d3e"),
+    (0x2f901, Mapped, "\u6d77"),
+    (0x2f902, Mapped, "\u6d41"),
+    (0x2f903, Mapped, "\u6d69"),
+    (0x2f904, Mapped, "\u6d78"),
+    (0x2f905, Mapped, "\u6d85"),
+    (0x2f906, Mapped, "\U00023d1e"),
+    (0x2f907, Mapped, "\u6d34"),
+    (0x2f908, Mapped, "\u6e2f"),
+    (0x2f909, Mapped, "\u6e6e"),
+    (0x2f90a, Mapped, "\u3d33"),
+    (0x2f90b, Mapped, "\u6ecb"),
+    (0x2f90c, Mapped, "\u6ec7"),
+    (0x2f90d, Mapped, "\U00023ed1"),
+    (0x2f90e, Mapped, "\u6df9"),
+    (0x2f90f, Mapped, "\u6f6e"),
+    (0x2f910, Mapped, "\U00023f5e"),
+    (0x2f911, Mapped, "\U00023f8e"),
+    (0x2f912, Mapped, "\u6fc6"),
+    (0x2f913, Mapped, "\u7039"),
+    (0x2f914, Mapped, "\u701e"),
+    (0x2f915, Mapped, "\u701b"),
+    (0x2f916, Mapped, "\u3d96"),
+    (0x2f917, Mapped, "\u704a"),
+    (0x2f918, Mapped, "\u707d"),
+    (0x2f919, Mapped, "\u7077"),
+    (0x2f91a, Mapped, "\u70ad"),
+    (0x2f91b, Mapped, "\U00020525"),
+    (0x2f91c, Mapped, "\u7145"),
+    (0x2f91d, Mapped, "\U00024263"),
+    (0x2f91e, Mapped, "\u719c"),
+    (0x2f91f, Mapped, "\U000243ab"),
+    (0x2f920, Mapped, "\u7228"),
+    (0x2f921, Mapped, "\u7235"),
+    (0x2f922, Mapped, "\u7250"),
+    (0x2f923, Mapped, "\U00024608"),
+    (0x2f924, Mapped, "\u7280"),
+    (0x2f925, Mapped, "\u7295"),
+    (0x2f926, Mapped, "\U00024735"),
+    (0x2f927, Mapped, "\U00024814"),
+    (0x2f928, Mapped, "\u737a"),
+    (0x2f929, Mapped, "\u738b"),
+    (0x2f92a, Mapped, "\u3eac"),
+    (0x2f92b, Mapped, "\u73a5"),
+    (0x2f92c, Mapped, "\u3eb8"),
+    (0x2f92e, Mapped, "\u7447"),
+    (0x2f92f, Mapped, "\u745c"),
+    (0x2f930, Mapped, "\u7471"),
+    (0x2f931, Mapped, "\u7485"),
+    (0x2f932, Mapped, "\u74ca"),
+    (0x2f933, Mapped, "\u3f1b"),
+    (0x2f934, Mapped, "\u7524"),
+    (0x2f935, Mapped, "\U00024c36"),
+    (0x2f936, Mapped, "\u753e"),
+    (0x2f937, Mapped, "\U00024c92"),
+    (0x2f938, Mapped, "\u7570"),
+    (0x2f939, Mapped, "\U0002219f"),
+    (0x2f93a, Mapped, "\u7610"),
+    (0x2f93b, Mapped, "\U00024fa1"),
+    (0x2f93c, Mapped, "\U00024fb8"),
+    (0x2f93d, Mapped, "\U00025044"),
+    (0x2f93e, Mapped, "\u3ffc"),
+    (0x2f93f, Mapped, "\u4008"),
+    (0x2f940, Mapped, "\u76f4"),
+    (0x2f941, Mapped, "\U000250f3"),
+    (0x2f942, Mapped, "\U000250f2"),
+    (0x2f943, Mapped, "\U00025119"),
+    (0x2f944, Mapped, "\U00025133"),
+    (0x2f945, Mapped, "\u771e"),
+    (0x2f946, Mapped, "\u771f"),
+    (0x2f948, Mapped, "\u774a"),
+    (0x2f949, Mapped, "\u4039"),
+    (0x2f94a, Mapped, "\u778b"),
+    (0x2f94b, Mapped, "\u4046"),
+    (0x2f94c, Mapped, "\u4096"),
+    (0x2f94d, Mapped, "\U0002541d"),
+    (0x2f94e, Mapped, "\u784e"),
+    (0x2f94f, Mapped, "\u788c"),
+    (0x2f950, Mapped, "\u78cc"),
+    (0x2f951, Mapped, "\u40e3"),
+    (0x2f952, Mapped, "\U00025626"),
+    (0x2f953, Mapped, "\u7956"),
+    (0x2f954, Mapped, "\U0002569a"),
+    (0x2f955, Mapped, "\U000256c5"),
+    (0x2f956, Mapped, "\u798f"),
+    (0x2f957, Mapped, "\u79eb"),
+    (0x2f958, Mapped, "\u412f"),
+    (0x2f959, Mapped, "\u7a40"),
+    (0x2f95a, Mapped, "\u7a4a"),
+    (0x2f95b, Mapped, "\u7a4f"),
+    (0x2f95c, Mapped, "\U0002597c"),
+    (0x2f95d, Mapped, "\U00025aa7"),
+    (0x2f95f, Mapped, "\u7aee"),
+    (0x2f960, Mapped, "\u4202"),
+    (0x2f961, Mapped, "\U00025bab"),
+    (0x2f962, Mapped, "\u7bc6"),
+    (0x2f963, Mapped, "\u7bc9"),
+    (0x2f964, Mapped, "\u4227"),
+    (0x2f965, Mapped, "\U00025c80"),
+    (0x2f966, Mapped, "\u7cd2"),
+    (0x2f967, Mapped, "\u42a0"),
+    (0x2f968, Mapped, "\u7ce8"),
+    (0x2f969, Mapped, "\u7ce3"),
+    (0x2f96a, Mapped, "\u7d00"),
+    (0x2f96b, Mapped, "\U00025f86"),
+    (0x2f96c, Mapped, "\u7d63"),
+    (0x2f96d, Mapped, "\u4301"),
+    (0x2f96e, Mapped, "\u7dc7"),
+    (0x2f96f, Mapped, "\u7e02"),
+    (0x2f970, Mapped, "\u7e45"),
+    (0x2f971, Mapped, "\u4334"),
+    (0x2f972, Mapped, "\U00026228"),
+    (0x2f973, Mapped, "\U00026247"),
+    (0x2f974, Mapped, "\u4359"),
+    (0x2f975, Mapped, "\U000262d9"),
+    (0x2f976, Mapped, "\u7f7a"),
+    (0x2f977, Mapped, "\U0002633e"),
+    (0x2f978, Mapped, "\u7f95"),
+    (0x2f979, Mapped, "\u7ffa"),
+    (0x2f97a, Mapped, "\u8005"),
+    (0x2f97b, Mapped, "\U000264da"),
+    (0x2f97c, Mapped, "\U00026523"),
+    (0x2f97d, Mapped, "\u8060"),
+    (0x2f97e, Mapped, "\U000265a8"),
+    (0x2f97f, Mapped, "\u8070"),
+    (0x2f980, Mapped, "\U0002335f"),
+    (0x2f981, Mapped, "\u43d5"),
+    (0x2f982, Mapped, "\u80b2"),
+    (0x2f983, Mapped, "\u8103"),
+    (0x2f984, Mapped, "\u440b"),
+    (0x2f985, Mapped, "\u813e"),
+    (0x2f986, Mapped, "\u5ab5"),
+    (0x2f987, Mapped, "\U000267a7"),
+    (0x2f988, Mapped, "\U000267b5"),
+    (0x2f989, Mapped, "\U00023393"),
+    (0x2f98a, Mapped, "\U0002339c"),
+    (0x2f98b, Mapped, "\u8201"),
+    (0x2f98c, Mapped, "\u8204"),
+    (0x2f98d, Mapped, "\u8f9e"),
+    (0x2f98e, Mapped, "\u446b"),
+    (0x2f98f, Mapped, "\u8291"),
+    (0x2f990, Mapped, "\u828b"),
+    (0x2f991, Mapped, "\u829d"),
+    (0x2f992, Mapped, "\u52b3"),
+    (0x2f993, Mapped, "\u82b1"),
+    (0x2f994, Mapped, "\u82b3"),
+    (0x2f995, Mapped, "\u82bd"),
+    (0x2f996, Mapped, "\u82e6"),
+    (0x2f997, Mapped, "\U00026b3c"),
+    (0x2f998, Mapped, "\u82e5"),
+    (0x2f999, Mapped, "\u831d"),
+    (0x2f99a, Mapped, "\u8363"),
+    (0x2f99b, Mapped, "\u83ad"),
+    (0x2f99c, Mapped, "\u8323"),
+    (0x2f99d, Mapped, "\u83bd"),
+    (0x2f99e, Mapped, "\u83e7"),
+    (0x2f99f, Mapped, "\u8457"),
+    (0x2f9a0, Mapped, "\u8353"),
+    (0x2f9a1, Mapped, "\u83ca"),
+    (0x2f9a2, Mapped, "\u83cc"),
+    (0x2f9a3, Mapped, "\u83dc"),
+    (0x2f9a4, Mapped, "\U00026c36"),
+    (0x2f9a5, Mapped, "\U00026d6b"),
+    (0x2f9a6, Mapped, "\U00026cd5"),
+    (0x2f9a7, Mapped, "\u452b"),
+    (0x2f9a8, Mapped, "\u84f1"),
+    (0x2f9a9, Mapped, "\u84f3"),
+    (0x2f9aa, Mapped, "\u8516"),
+    (0x2f9ab, Mapped, "\U000273ca"),
+    (0x2f9ac, Mapped, "\u8564"),
+    (0x2f9ad, Mapped, "\U00026f2c"),
+    (0x2f9ae, Mapped, "\u455d"),
+    (0x2f9af, Mapped, "\u4561"),
+    (0x2f9b0, Mapped, "\U00026fb1"),
+    (0x2f9b1, Mapped, "\U000270d2"),
+    (0x2f9b2, Mapped, "\u456b"),
+    (0x2f9b3, Mapped, "\u8650"),
+    (0x2f9b4, Mapped, "\u865c"),
+    (0x2f9b5, Mapped, "\u8667"),
+    (0x2f9b6, Mapped, "\u8669"),
+    (0x2f9b7, Mapped, "\u86a9"),
+    (0x2f9b8, Mapped, "\u8688"),
+    (0x2f9b9, Mapped, "\u870e"),
+    (0x2f9ba, Mapped, "\u86e2"),
+    (0x2f9bb, Mapped, "\u8779"),
+    (0x2f9bc, Mapped, "\u8728"),
+    (0x2f9bd, Mapped, "\u876b"),
+    (0x2f9be, Mapped, "\u8786"),
+    (0x2f9bf, Mapped, "\u45d7"),
+    (0x2f9c0, Mapped, "\u87e1"),
+    (0x2f9c1, Mapped, "\u8801"),
+    (0x2f9c2, Mapped, "\u45f9"),
+    (0x2f9c3, Mapped, "\u8860"),
+    (0x2f9c4, Mapped, "\u8863"),
+    (0x2f9c5, Mapped, "\U00027667"),
+    (0x2f9c6, Mapped, "\u88d7"),
+    (0x2f9c7, Mapped, "\u88de"),
+    (0x2f9c8, Mapped, "\u4635"),
+    (0x2f9c9, Mapped, "\u88fa"),
+    (0x2f9ca, Mapped, "\u34bb"),
+    (0x2f9cb, Mapped, "\U000278ae"),
+    (0x2f9cc, Mapped, "\U00027966"),
+    (0x2f9cd, Mapped, "\u46be"),
+    (0x2f9ce, Mapped, "\u46c7"),
+    (0x2f9cf, Mapped, "\u8aa0"),
+    (0x2f9d0, Mapped, "\u8aed"),
+    (0x2f9d1, Mapped, "\u8b8a"),
+    (0x2f9d2, Mapped, "\u8c55"),
+    (0x2f9d3, Mapped, "\U00027ca8"),
+    (0x2f9d4, Mapped, "\u8cab"),
+    (0x2f9d5, Mapped, "\u8cc1"),
+    (0x2f9d6, Mapped, "\u8d1b"),
+    (0x2f9d7, Mapped, "\u8d77"),
+    (0x2f9d8, Mapped, "\U00027f2f"),
+    (0x2f9d9, Mapped, "\U00020804"),
+    (0x2f9da, Mapped, "\u8dcb"),
+    (0x2f9db, Mapped, "\u8dbc"),
+    (0x2f9dc, Mapped, "\u8df0"),
+    (0x2f9dd, Mapped, "\U000208de"),
+    (0x2f9de, Mapped, "\u8ed4"),
+    (0x2f9df, Mapped, "\u8f38"),
+    (0x2f9e0, Mapped, "\U000285d2"),
+    (0x2f9e1, Mapped, "\U000285ed"),
+    (0x2f9e2, Mapped, "\u9094"),
+    (0x2f9e3, Mapped, "\u90f1"),
+    (0x2f9e4, Mapped, "\u9111"),
+    (0x2f9e5, Mapped, "\U0002872e"),
+    (0x2f9e6, Mapped, "\u911b"),
+    (0x2f9e7, Mapped, "\u9238"),
+    (0x2f9e8, Mapped, "\u92d7"),
+    (0x2f9e9, Mapped, "\u92d8"),
+    (0x2f9ea, Mapped, "\u927c"),
+    (0x2f9eb, Mapped, "\u93f9"),
+    (0x2f9ec, Mapped, "\u9415"),
+    (0x2f9ed, Mapped, "\U00028bfa"),
+    (0x2f9ee, Mapped, "\u958b"),
+    (0x2f9ef, Mapped, "\u4995"),
+    (0x2f9f0, Mapped, "\u95b7"),
+    (0x2f9f1, Mapped, "\U00028d77"),
+    (0x2f9f2, Mapped, "\u49e6"),
+    (0x2f9f3, Mapped, "\u96c3"),
+    (0x2f9f4, Mapped, "\u5db2"),
+    (0x2f9f5, Mapped, "\u9723"),
+    (0x2f9f6, Mapped, "\U00029145"),
+    (0x2f9f7, Mapped, "\U0002921a"),
+    (0x2f9f8, Mapped, "\u4a6e"),
+    (0x2f9f9, Mapped, "\u4a76"),
+    (0x2f9fa, Mapped, "\u97e0"),
+    (0x2f9fb, Mapped, "\U0002940a"),
+    (0x2f9fc, Mapped, "\u4ab2"),
+    (0x2f9fd, Mapped, "\U00029496"),
+    (0x2f9fe, Mapped, "\u980b"),
+    (0x2fa00, Mapped, "\u9829"),
+    (0x2fa01, Mapped, "\U000295b6"),
+    (0x2fa02, Mapped, "\u98e2"),
+    (0x2fa03, Mapped, "\u4b33"),
+    (0x2fa04, Mapped, "\u9929"),
+    (0x2fa05, Mapped, "\u99a7"),
+    (0x2fa06, Mapped, "\u99c2"),
+    (0x2fa07, Mapped, "\u99fe"),
+    (0x2fa08, Mapped, "\u4bce"),
+    (0x2fa09, Mapped, "\U00029b30"),
+    (0x2fa0a, Mapped, "\u9b12"),
+    (0x2fa0b, Mapped, "\u9c40"),
+    (0x2fa0c, Mapped, "\u9cfd"),
+    (0x2fa0d, Mapped, "\u4cce"),
+    (0x2fa0e, Mapped, "\u4ced"),
+    (0x2fa0f, Mapped, "\u9d67"),
+    (0x2fa10, Mapped, "\U0002a0ce"),
+    (0x2fa11, Mapped, "\u4cf8"),
+    (0x2fa12, Mapped, "\U0002a105"),
+    (0x2fa13, Mapped, "\U0002a20e"),
+    (0x2fa14, Mapped, "\U0002a291"),
+    (0x2fa15, Mapped, "\u9ebb"),
+    (0x2fa16, Mapped, "\u4d56"),
+    (0x2fa17, Mapped, "\u9ef9"),
+    (0x2fa18, Mapped, "\u9efe"),
+    (0x2fa19, Mapped, "\u9f05"),
+    (0x2fa1a, Mapped, "\u9f0f"),
+    (0x2fa1b, Mapped, "\u9f16"),
+    (0x2fa1c, Mapped, "\u9f3b"),
+    (0x2fa1d, Mapped, "\U0002a600"),
+    (0x2fa1e, Disallowed, ""),
+    (0x30000, Valid, ""),
+    (0x3134b, Disallowed, ""),
+    (0x31350, Valid, ""),
+    (0x3347a, Disallowed, ""),
+    (0xe0100, Ignored, ""),
+    (0xe01f0, Disallowed, ""),
+];
@@ -0,0 +1,154 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Log records for SMTP sessions and the sinks they are sent to.
+
+use std::ascii::AsciiExt;
+use std::fmt::{Show, Formatter, FormatError};
+use std::io::net::ip::IpAddr;
+
+/// The importance of a log record, from the least to the most important.
+#[deriving(PartialEq, Eq, PartialOrd, Ord, Clone, Show)]
+pub enum SmtpLogLevel {
+    /// Protocol transcripts and other details.
+    LogDebug,
+    /// Normal events, ie. a client connecting.
+    LogInfo,
+    /// Things that went wrong because of a client, ie. an overlong line.
+    LogWarning,
+    /// Things that went wrong on our side.
+    LogError
+}
+
+/// What a log record is about.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub enum SmtpLogKind {
+    /// A line received from the client, part of the protocol transcript.
+    LogClientLine,
+    /// A line sent to the client, part of the protocol transcript.
+    LogServerLine,
+    /// Anything else.
+    LogEvent
+}
+
+/// A log record.
+pub struct SmtpLogRecord<'a> {
+    /// The importance of the record.
+    pub level: SmtpLogLevel,
+    /// What the record is about.
+    pub kind: SmtpLogKind,
+    /// The session the record belongs to. Records which don't belong to a session, ie. the
+    /// server starting, have none.
+    pub session_id: Option<u64>,
+    /// The IP of the client of the session.
+    pub peer_ip: Option<IpAddr>,
+    /// The message, ie. the line itself for transcripts.
+    pub message: &'a str
+}
+
+impl<'a> Show for SmtpLogRecord<'a> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FormatError> {
+        let level = match self.level {
+            LogDebug => "debug",
+            LogInfo => "info",
+            LogWarning => "warning",
+            LogError => "error"
+        };
+        try!(write!(f, "rsmtp: {}:", level));
+        match self.session_id {
+            Some(id) => try!(write!(f, " #{}", id)),
+            None => {}
+        }
+        match self.peer_ip {
+            Some(ip) => try!(write!(f, " {}:", ip)),
+            None => {}
+        }
+        match self.kind {
+            LogClientLine => write!(f, " imsg: {}", self.message),
+            LogServerLine => write!(f, " omsg: {}", self.message),
+            LogEvent => write!(f, " {}", self.message)
+        }
+    }
+}
+
+#[test]
+fn test_record_fmt() {
+    use std::io::net::ip::Ipv4Addr;
+
+    let mut record = SmtpLogRecord {
+        level: LogInfo,
+        kind: LogEvent,
+        session_id: None,
+        peer_ip: None,
+        message: "listening on port 25"
+    };
+    assert_eq!("rsmtp: info: listening on port 25", record.to_string().as_slice());
+
+    record.level = LogDebug;
+    record.kind = LogClientLine;
+    record.session_id = Some(12);
+    record.peer_ip = Some(Ipv4Addr(127, 0, 0, 1));
+    record.message = "NOOP";
+    assert_eq!("rsmtp: debug: #12 127.0.0.1: imsg: NOOP", record.to_string().as_slice());
+}
+
+/// Somewhere to send log records, ie. a file or a logging service.
+///
+/// A sink is shared by all the sessions of a server, which may run in different threads, so
+/// it must synchronize writes itself if needed.
+pub trait SmtpLogSink {
+    /// Handle a log record.
+    fn log(&self, record: &SmtpLogRecord);
+}
+
+/// Prints log records to the console.
+pub struct StdoutLogSink;
+
+impl SmtpLogSink for StdoutLogSink {
+    fn log(&self, record: &SmtpLogRecord) {
+        println!("{}", record);
+    }
+}
+
+/// Drops all log records.
+pub struct NullLogSink;
+
+impl SmtpLogSink for NullLogSink {
+    #[allow(unused_variable)]
+    fn log(&self, record: &SmtpLogRecord) {
+    }
+}
+
+/// Hide the credentials of an `AUTH` command line, keeping the mechanism, ie. `AUTH PLAIN xxx`
+/// becomes `AUTH PLAIN <redacted>`. Other lines are returned as is.
+pub fn redact_auth(line: &str) -> String {
+    if line.len() < 5 || !line.is_char_boundary(5) || !line.slice_to(5).eq_ignore_ascii_case("AUTH ") {
+        return line.into_string();
+    }
+    let mut words = line.slice_from(5).trim_left().splitn(1, ' ');
+    let mechanism = words.next().unwrap_or("");
+    match words.next() {
+        Some(_) => format!("AUTH {} <redacted>", mechanism),
+        None => format!("AUTH {}", mechanism)
+    }
+}
+
+#[test]
+fn test_redact_auth() {
+    assert_eq!("NOOP", redact_auth("NOOP").as_slice());
+    assert_eq!("AUTHOR", redact_auth("AUTHOR").as_slice());
+    assert_eq!("AUTH LOGIN", redact_auth("AUTH LOGIN").as_slice());
+    assert_eq!("AUTH PLAIN <redacted>", redact_auth("AUTH PLAIN AHJ1c3QAc2VjcmV0").as_slice());
+    assert_eq!("AUTH PLAIN <redacted>", redact_auth("auth PLAIN AHJ1c3QAc2VjcmV0").as_slice());
+}
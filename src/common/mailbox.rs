@@ -16,9 +16,11 @@
 
 use std::string::String;
 use super::utils;
+use super::utils::ParseError;
+use super::idna;
 use std::io::net::ip;
-use std::from_str::FromStr;
-use std::ascii::OwnedAsciiExt;
+use std::ascii::{AsciiExt, OwnedAsciiExt};
+use std::fmt;
 
 /// Maximum length of the local part.
 static MAX_MAILBOX_LOCAL_PART_LEN: uint = 64;
@@ -40,7 +42,9 @@ fn test_static_vars() {
 }
 
 /// Represents the local part of an email address, aka the username.
-#[deriving(PartialEq, Eq, Clone, Show)]
+///
+/// When formatted with `{}`, a local part is shown as it should be sent in SMTP.
+#[deriving(PartialEq, Eq, Clone)]
 pub struct MailboxLocalPart {
     /// This is a version of the local part for use in the SMTP protocol.
     ///
@@ -54,6 +58,40 @@ pub struct MailboxLocalPart {
 }
 
 impl MailboxLocalPart {
+    /// Creates a local part from a string if it is a valid dot-string or quoted-string, as it
+    /// would be sent in SMTP, ie. `rust.is` or `"rust is"`. Otherwise, returns a
+    /// `MailboxParseError`.
+    pub fn parse(s: &str) -> Result<MailboxLocalPart, MailboxParseError> {
+        let (local_part, len) = try!(MailboxLocalPart::parse_start(s));
+        if len != s.len() {
+            Err(LocalPartUnrecognized(ParseError::new(len, "end of local part")))
+        } else {
+            Ok(local_part)
+        }
+    }
+
+    /// Parses the local part found at the beginning of a string and returns it along with its
+    /// length in the string.
+    fn parse_start(s: &str) -> Result<(MailboxLocalPart, uint), MailboxParseError> {
+        match utils::parse_dot_string(s) {
+            Ok((dot_string, _)) => {
+                if dot_string.len() > MAX_MAILBOX_LOCAL_PART_LEN {
+                    return Err(LocalPartTooLong);
+                }
+                Ok((MailboxLocalPart::from_dot_string(dot_string), dot_string.len()))
+            },
+            Err(_) => match utils::parse_quoted_string(s) {
+                Ok((quoted_string, _)) => {
+                    if quoted_string.len() > MAX_MAILBOX_LOCAL_PART_LEN {
+                        return Err(LocalPartTooLong);
+                    }
+                    Ok((MailboxLocalPart::from_quoted_string(quoted_string), quoted_string.len()))
+                },
+                Err(_) => Err(LocalPartUnrecognized(ParseError::new(0, "dot-string or quoted-string")))
+            }
+        }
+    }
+
     /// Create a local part from a dot-string.
     fn from_dot_string(dot_string: &str) -> MailboxLocalPart {
         MailboxLocalPart {
@@ -72,6 +110,71 @@ impl MailboxLocalPart {
             smtp_string: utils::simplify_quoted_string(quoted_string)
         }
     }
+
+    /// Create a local part from its unescaped form, quoting it if needed.
+    fn from_human_string(human_string: &str) -> MailboxLocalPart {
+        let mut quoted_string = String::from_str("\"");
+        for c in human_string.chars() {
+            if c == '\\' || c == '"' {
+                quoted_string.push('\\');
+            }
+            quoted_string.push(c);
+        }
+        quoted_string.push('"');
+        MailboxLocalPart::from_quoted_string(quoted_string.as_slice())
+    }
+
+    /// Returns the local part as it should be sent in SMTP.
+    ///
+    /// This is either a dot-string or a quoted-string, whatever is shortest as
+    /// recommended in RFC 5321.
+    pub fn smtp_string(&self) -> &str {
+        self.smtp_string.as_slice()
+    }
+
+    /// Returns the local part completely unescaped.
+    ///
+    /// It is human readable but not suitable for use in SMTP.
+    pub fn human_string(&self) -> &str {
+        self.human_string.as_slice()
+    }
+
+    /// Splits the local part into a base user and a detail, aka sub-address, ie. `support` and
+    /// `billing` for `support+billing`. The detail starts after the first of the given
+    /// separators.
+    ///
+    /// The split is done on the unescaped form of the local part, so `"support+big bills"` gives
+    /// `support` and `big bills`. The detail is unescaped and the base is re-encoded for SMTP.
+    /// If no separator is found, or if the local part starts with one, there is no detail.
+    pub fn split_detail(&self, separators: &[char]) -> (MailboxLocalPart, Option<String>) {
+        match self.human_string.as_slice().find(separators) {
+            Some(pos) if pos > 0 => {
+                let human_string = self.human_string.as_slice();
+                let separator_len = human_string.char_at(pos).len_utf8_bytes();
+                (
+                    MailboxLocalPart::from_human_string(human_string.slice_to(pos)),
+                    Some(human_string.slice_from(pos + separator_len).into_string())
+                )
+            },
+            _ => (self.clone(), None)
+        }
+    }
+
+    /// If the local part is `Postmaster`, without regard for case, returns it all lowercase.
+    fn normalize_postmaster(self) -> MailboxLocalPart {
+        if self.human_string.is_ascii() &&
+            self.human_string.clone().into_ascii_lower().as_slice() == "postmaster" {
+            MailboxLocalPart::from_dot_string("postmaster")
+        } else {
+            self
+        }
+    }
+}
+
+impl fmt::Show for MailboxLocalPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.smtp_string)
+    }
 }
 
 #[test]
@@ -106,15 +209,406 @@ fn test_local_part() {
 
     assert_eq!(lp5.smtp_string.as_slice(), "\"rust\\\\b;.c\\\"ool\"");
     assert_eq!(lp5.human_string.as_slice(), "rust\\b;.c\"ool");
+
+    assert!(MailboxLocalPart::from_human_string("rust.cool") == lp1);
+    assert!(MailboxLocalPart::from_human_string("rust a cool") == lp2);
+    assert!(MailboxLocalPart::from_human_string("rust\\b;.c\"ool") == lp5);
+}
+
+#[test]
+fn test_local_part_public_api() {
+    let lp = MailboxLocalPart::parse("\"rust\\ is\"").unwrap();
+    assert_eq!("\"rust is\"", lp.smtp_string());
+    assert_eq!("rust is", lp.human_string());
+    assert_eq!("\"rust is\"", format!("{}", lp).as_slice());
+
+    let lp = MailboxLocalPart::parse("rust.is").unwrap();
+    assert_eq!("rust.is", lp.smtp_string());
+    assert_eq!("rust.is", lp.human_string());
+
+    let separators = ['+', '-'];
+    let (base, detail) = MailboxLocalPart::parse("support+billing").unwrap().split_detail(separators);
+    assert_eq!("support", base.smtp_string());
+    assert_eq!(Some("billing".into_string()), detail);
+    let (base, detail) = MailboxLocalPart::parse("support-billing+eu").unwrap().split_detail(separators);
+    assert_eq!("support", base.smtp_string());
+    assert_eq!(Some("billing+eu".into_string()), detail);
+    let (base, detail) = MailboxLocalPart::parse("support+").unwrap().split_detail(separators);
+    assert_eq!("support", base.smtp_string());
+    assert_eq!(Some("".into_string()), detail);
+    let (base, detail) = MailboxLocalPart::parse("\"big support+big bills\"").unwrap().split_detail(separators);
+    assert_eq!("\"big support\"", base.smtp_string());
+    assert_eq!("big support", base.human_string());
+    assert_eq!(Some("big bills".into_string()), detail);
+    let (base, detail) = MailboxLocalPart::parse("\"support+\\\"bills\\\"\"").unwrap().split_detail(separators);
+    assert_eq!("support", base.smtp_string());
+    assert_eq!(Some("\"bills\"".into_string()), detail);
+    let (base, detail) = MailboxLocalPart::parse("+support").unwrap().split_detail(separators);
+    assert_eq!("+support", base.smtp_string());
+    assert_eq!(None, detail);
+    let (base, detail) = MailboxLocalPart::parse("support").unwrap().split_detail([]);
+    assert_eq!("support", base.smtp_string());
+    assert_eq!(None, detail);
+
+    assert_eq!(
+        Err(LocalPartUnrecognized(ParseError::new(0, "dot-string or quoted-string"))),
+        MailboxLocalPart::parse("")
+    );
+    assert_eq!(
+        Err(LocalPartUnrecognized(ParseError::new(4, "end of local part"))),
+        MailboxLocalPart::parse("rust is")
+    );
+    assert_eq!(
+        Err(LocalPartUnrecognized(ParseError::new(7, "end of local part"))),
+        MailboxLocalPart::parse("rust.is.")
+    );
+    assert_eq!(Err(LocalPartTooLong), MailboxLocalPart::parse(
+        String::from_char(MAX_MAILBOX_LOCAL_PART_LEN + 1, 'a').as_slice()
+    ));
 }
 
 /// Represents the foreign part of an email address, aka the host.
-#[deriving(PartialEq, Eq, Clone, Show)]
+///
+/// When formatted with `{}`, a foreign part is shown as it should be sent in SMTP, ie.
+/// `rustastic.org`, `[127.0.0.1]` or `[IPv6:::1]`.
+#[deriving(PartialEq, Eq, Clone)]
 pub enum MailboxForeignPart {
     /// The foreign part is a domain name.
     Domain(String),
     /// The foreign part is an ip address.
-    IpAddr(ip::IpAddr)
+    IpAddr(ip::IpAddr),
+    /// The foreign part is a general address literal, ie. `[x-tag:content]`, for address types
+    /// other than IPv4 and IPv6.
+    GeneralLiteral {
+        /// The standardized tag, ie. `x-tag`.
+        pub tag: String,
+        /// The content after the colon, ie. `content`.
+        pub content: String
+    }
+}
+
+impl MailboxForeignPart {
+    /// Creates a foreign part from a string if it is a valid domain or address literal, ie.
+    /// `rustastic.org` or `[127.0.0.1]`. Otherwise, returns a `MailboxParseError`.
+    pub fn parse(s: &str) -> Result<MailboxForeignPart, MailboxParseError> {
+        let (foreign_part, len) = try!(MailboxForeignPart::parse_start(s));
+        if len != s.len() {
+            Err(ForeignPartUnrecognized(ParseError::new(len, "end of foreign part")))
+        } else {
+            Ok(foreign_part)
+        }
+    }
+
+    /// Parses the foreign part found at the beginning of a string and returns it along with its
+    /// length in the string.
+    fn parse_start(s: &str) -> Result<(MailboxForeignPart, uint), MailboxParseError> {
+        // Internationalized domains are stored in their ASCII form, so that they can be compared
+        // and sent as is. A-labels are converted too, to make sure that they are valid.
+        let idn_len = s.chars().take_while(|&c| {
+            (c as u32) >= 0x80 || utils::is_alnum(c) || c == '-' || c == '.'
+        }).fold(0, |len, c| len + c.len_utf8_bytes());
+        let idn = s.slice_to(idn_len);
+        if idn.bytes().any(|b| b >= 0x80) || idn.split('.').any(|label| {
+            label.len() >= 4 && label.slice_to(4).eq_ignore_ascii_case("xn--")
+        }) {
+            return match idna::to_ascii(idn) {
+                Ok(ref domain) if domain.len() > MAX_DOMAIN_LEN => Err(DomainTooLong),
+                Ok(domain) => Ok((Domain(domain), idn_len)),
+                Err(_) => Err(ForeignPartUnrecognized(ParseError::new(0, "internationalized domain")))
+            };
+        }
+
+        match utils::parse_domain(s) {
+            Ok((domain, _)) => {
+                // Is the domain is too long ?
+                if domain.len() > MAX_DOMAIN_LEN {
+                    return Err(DomainTooLong);
+                }
+                return Ok((Domain(domain.into_string()), domain.len()));
+            },
+            Err(_) => {}
+        }
+
+        if s.starts_with("[") {
+            MailboxForeignPart::parse_address_literal(s)
+        } else {
+            Err(ForeignPartUnrecognized(ParseError::new(0, "domain or address literal")))
+        }
+    }
+
+    /// Parses the address literal found at the beginning of a string, as described
+    /// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.1.3), and returns it along
+    /// with its length in the string.
+    fn parse_address_literal(s: &str) -> Result<(MailboxForeignPart, uint), MailboxParseError> {
+        let end = match s.find(']') {
+            Some(end) => end,
+            None => return Err(ForeignPartUnrecognized(ParseError::new(s.len(), "]")))
+        };
+        let literal = s.slice(1, end);
+
+        match parse_ipv4(literal) {
+            Some([a, b, c, d]) => return Ok((IpAddr(ip::Ipv4Addr(a, b, c, d)), end + 1)),
+            _ => {}
+        }
+
+        let colon = match literal.find(':') {
+            Some(colon) => colon,
+            None => return Err(ForeignPartUnrecognized(ParseError::new(1, "IPv4 address")))
+        };
+        let tag = literal.slice_to(colon);
+        let content = literal.slice_from(colon + 1);
+        if tag.eq_ignore_ascii_case("IPv6") {
+            match parse_ipv6(content) {
+                Some([a, b, c, d, e, f, g, h]) => {
+                    Ok((IpAddr(ip::Ipv6Addr(a, b, c, d, e, f, g, h)), end + 1))
+                },
+                _ => Err(ForeignPartUnrecognized(ParseError::new(colon + 2, "IPv6 address")))
+            }
+        } else if !is_ldh_str(tag) {
+            Err(ForeignPartUnrecognized(ParseError::new(1, "standardized tag")))
+        } else if content.len() == 0 || !content.chars().all(is_dcontent) {
+            Err(ForeignPartUnrecognized(ParseError::new(colon + 2, "address literal content")))
+        } else {
+            Ok((GeneralLiteral { tag: tag.into_string(), content: content.into_string() }, end + 1))
+        }
+    }
+
+    /// Returns the domain name, if the foreign part is a domain name.
+    pub fn domain(&self) -> Option<&str> {
+        match *self {
+            Domain(ref domain) => Some(domain.as_slice()),
+            _ => None
+        }
+    }
+
+    /// Returns the foreign part as it should be shown to humans, ie. with internationalized
+    /// domains in their Unicode form, like `bücher.example` instead of `xn--bcher-kva.example`.
+    pub fn to_unicode(&self) -> String {
+        match *self {
+            Domain(ref domain) => match idna::to_unicode(domain.as_slice()) {
+                Ok(unicode) => unicode,
+                Err(_) => domain.clone()
+            },
+            _ => format!("{}", self)
+        }
+    }
+
+    /// Returns the ip address, if the foreign part is an address literal.
+    pub fn ip(&self) -> Option<ip::IpAddr> {
+        match *self {
+            IpAddr(ip) => Some(ip),
+            _ => None
+        }
+    }
+}
+
+// Parse an IPv4 address as found in address literals, ie. `Snum 3("." Snum)` in RFC 5321.
+fn parse_ipv4(s: &str) -> Option<[u8, ..4]> {
+    let parts: Vec<&str> = s.split('.').collect();
+    if parts.len() != 4 {
+        return None;
+    }
+    let mut ip = [0u8, ..4];
+    for (i, part) in parts.iter().enumerate() {
+        if part.len() == 0 || part.len() > 3 || !part.chars().all(|c| c >= '0' && c <= '9') {
+            return None;
+        }
+        match from_str::<uint>(*part) {
+            Some(n) if n <= 255 => ip[i] = n as u8,
+            _ => return None
+        }
+    }
+    Some(ip)
+}
+
+#[test]
+fn test_parse_ipv4() {
+    assert_eq!(Some([127, 0, 0, 1]), parse_ipv4("127.0.0.1"));
+    assert_eq!(Some([255, 255, 255, 255]), parse_ipv4("255.255.255.255"));
+    assert_eq!(Some([1, 2, 3, 4]), parse_ipv4("001.02.3.4"));
+
+    assert_eq!(None, parse_ipv4(""));
+    assert_eq!(None, parse_ipv4("256.0.0.1"));
+    assert_eq!(None, parse_ipv4("1.2.3"));
+    assert_eq!(None, parse_ipv4("1.2.3.4.5"));
+    assert_eq!(None, parse_ipv4("1.2.3.0001"));
+    assert_eq!(None, parse_ipv4("1..3.4"));
+    assert_eq!(None, parse_ipv4("1.2.3.a"));
+    assert_eq!(None, parse_ipv4("1.2.3.+4"));
+}
+
+// Parse groups of 1 to 4 hex digits separated by colons, ie. `IPv6-hex *(":" IPv6-hex)`.
+fn parse_ipv6_groups(s: &str) -> Option<Vec<u16>> {
+    let mut groups = vec!();
+    if s.len() == 0 {
+        return Some(groups);
+    }
+    for group in s.split(':') {
+        if group.len() == 0 || group.len() > 4 {
+            return None;
+        }
+        let mut value = 0u16;
+        for c in group.chars() {
+            match c.to_digit(16) {
+                Some(digit) => value = value * 16 + digit as u16,
+                None => return None
+            }
+        }
+        groups.push(value);
+    }
+    Some(groups)
+}
+
+// Parse an IPv6 address as found in address literals, ie. `IPv6-addr` in RFC 5321, which is
+// one of `IPv6-full`, `IPv6-comp`, `IPv6v4-full` or `IPv6v4-comp`.
+fn parse_ipv6(s: &str) -> Option<[u16, ..8]> {
+    // Split around the "::", if any.
+    let (head, tail, compressed) = match s.find_str("::") {
+        Some(pos) => (s.slice_to(pos), s.slice_from(pos + 2), true),
+        None => (s, "", false)
+    };
+    if tail.contains("::") {
+        return None;
+    }
+
+    // The last part can end with an IPv4 address, which counts for 2 groups.
+    let last = if compressed { tail } else { head };
+    let (last_groups, ipv4) = if last.contains(".") {
+        let (groups, ipv4) = match last.rfind(':') {
+            Some(pos) => (last.slice_to(pos), last.slice_from(pos + 1)),
+            None => ("", last)
+        };
+        match parse_ipv4(ipv4) {
+            Some(ipv4) => (groups, Some(ipv4)),
+            None => return None
+        }
+    } else {
+        (last, None)
+    };
+
+    let head_groups = match parse_ipv6_groups(if compressed { head } else { last_groups }) {
+        Some(groups) => groups,
+        None => return None
+    };
+    let tail_groups = match parse_ipv6_groups(if compressed { last_groups } else { "" }) {
+        Some(groups) => groups,
+        None => return None
+    };
+
+    // Without "::", all groups must be present. With it, the "::" stands for at least 2 groups.
+    let max_groups = if ipv4.is_some() { 6 } else { 8 };
+    let groups = head_groups.len() + tail_groups.len();
+    if (!compressed && groups != max_groups) || (compressed && groups > max_groups - 2) {
+        return None;
+    }
+
+    let mut ip = [0u16, ..8];
+    for (i, group) in head_groups.iter().enumerate() {
+        ip[i] = *group;
+    }
+    for (i, group) in tail_groups.iter().enumerate() {
+        ip[max_groups - tail_groups.len() + i] = *group;
+    }
+    match ipv4 {
+        Some([a, b, c, d]) => {
+            ip[6] = ((a as u16) << 8) | (b as u16);
+            ip[7] = ((c as u16) << 8) | (d as u16);
+        },
+        _ => {}
+    }
+    Some(ip)
+}
+
+#[test]
+fn test_parse_ipv6() {
+    // IPv6-full.
+    assert_eq!(Some([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]), parse_ipv6("2001:db8:0:0:0:0:0:1"));
+    assert_eq!(Some([0xffff, 0xABCD, 0, 0, 0, 0, 0, 1]), parse_ipv6("FFFF:abcd:0000:0:0:0:0:1"));
+    assert_eq!(None, parse_ipv6("2001:db8:0:0:0:0:1"));
+    assert_eq!(None, parse_ipv6("2001:db8:0:0:0:0:0:0:1"));
+    assert_eq!(None, parse_ipv6("2001:db8:0:0:0:0:0:12345"));
+    assert_eq!(None, parse_ipv6("2001:db8:0:0:0:0:0:g"));
+    assert_eq!(None, parse_ipv6("2001:db8:0:0:0:0:0:"));
+    assert_eq!(None, parse_ipv6(""));
+
+    // IPv6-comp.
+    assert_eq!(Some([0, 0, 0, 0, 0, 0, 0, 1]), parse_ipv6("::1"));
+    assert_eq!(Some([0, 0, 0, 0, 0, 0, 0, 0]), parse_ipv6("::"));
+    assert_eq!(Some([0x2001, 0xdb8, 0, 0, 0, 0, 0, 0]), parse_ipv6("2001:db8::"));
+    assert_eq!(Some([0x2001, 0xdb8, 0, 0, 0xff00, 0x42, 0x8329, 1]), parse_ipv6("2001:db8::ff00:42:8329:1"));
+    assert_eq!(Some([1, 2, 3, 0, 0, 4, 5, 6]), parse_ipv6("1:2:3::4:5:6"));
+    // The "::" must stand for at least 2 groups.
+    assert_eq!(None, parse_ipv6("1:2:3::4:5:6:7"));
+    assert_eq!(None, parse_ipv6("1:2:3:4:5:6:7::"));
+    assert_eq!(None, parse_ipv6("1::2::3"));
+    assert_eq!(None, parse_ipv6(":1::2"));
+    assert_eq!(None, parse_ipv6("1::2:"));
+    assert_eq!(None, parse_ipv6(":::1"));
+
+    // IPv6v4-full.
+    assert_eq!(Some([0, 0, 0, 0, 0, 0xffff, 0xc000, 0x0201]), parse_ipv6("0:0:0:0:0:ffff:192.0.2.1"));
+    assert_eq!(None, parse_ipv6("0:0:0:0:ffff:192.0.2.1"));
+    assert_eq!(None, parse_ipv6("0:0:0:0:0:0:ffff:192.0.2.1"));
+    assert_eq!(None, parse_ipv6("0:0:0:0:0:ffff:192.0.2.256"));
+    assert_eq!(None, parse_ipv6("0:0:0:0:0:192.0.2.1:ffff"));
+
+    // IPv6v4-comp.
+    assert_eq!(Some([0, 0, 0, 0, 0, 0xffff, 0xc000, 0x0201]), parse_ipv6("::ffff:192.0.2.1"));
+    assert_eq!(Some([0, 0, 0, 0, 0, 0, 0xc000, 0x0201]), parse_ipv6("::192.0.2.1"));
+    assert_eq!(Some([1, 2, 0, 0, 3, 4, 0xc000, 0x0201]), parse_ipv6("1:2::3:4:192.0.2.1"));
+    assert_eq!(None, parse_ipv6("1:2:3::4:5:192.0.2.1"));
+    assert_eq!(None, parse_ipv6("1:2:3:4:5::192.0.2.1"));
+    assert_eq!(None, parse_ipv6("192.0.2.1::"));
+    assert_eq!(None, parse_ipv6("::192.0.2"));
+}
+
+// Check if a string is an `Ldh-str` as described in RFC 5321, as used for standardized tags.
+fn is_ldh_str(s: &str) -> bool {
+    s.len() > 0 &&
+        s.chars().all(|c| utils::is_alnum(c) || c == '-') &&
+        utils::is_alnum(s.char_at(s.len() - 1))
+}
+
+#[test]
+fn test_is_ldh_str() {
+    assert!(is_ldh_str("x-tag"));
+    assert!(is_ldh_str("-x1"));
+    assert!(!is_ldh_str(""));
+    assert!(!is_ldh_str("x-"));
+    assert!(!is_ldh_str("x_tag"));
+}
+
+// Check if a character is `dcontent` as described in RFC 5321, ie. printable US-ASCII except
+// `[`, `\` and `]`.
+fn is_dcontent(c: char) -> bool {
+    match c {
+        '!' ... 'Z' | '^' ... '~' => true,
+        _ => false
+    }
+}
+
+#[test]
+fn test_is_dcontent() {
+    assert!(is_dcontent('!'));
+    assert!(is_dcontent('Z'));
+    assert!(is_dcontent('^'));
+    assert!(is_dcontent('~'));
+    assert!(!is_dcontent(' '));
+    assert!(!is_dcontent('['));
+    assert!(!is_dcontent('\\'));
+    assert!(!is_dcontent(']'));
+    assert!(!is_dcontent(127 as char));
+}
+
+impl fmt::Show for MailboxForeignPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Domain(ref domain) => write!(f, "{}", domain),
+            IpAddr(ip @ ip::Ipv4Addr(..)) => write!(f, "[{}]", ip),
+            IpAddr(ip @ ip::Ipv6Addr(..)) => write!(f, "[IPv6:{}]", ip),
+            GeneralLiteral { ref tag, ref content } => write!(f, "[{}:{}]", tag, content)
+        }
+    }
 }
 
 #[test]
@@ -128,6 +622,82 @@ fn test_foreign_part() {
     assert!(domain != Domain(domain_text.into_string() + "bullshit"));
     assert!(domain != ipv4);
     assert!(domain != ipv6);
+
+    assert_eq!(Some("rustastic.org"), domain.domain());
+    assert_eq!(None, domain.ip());
+    assert_eq!(None, ipv4.domain());
+    assert_eq!(Some(ip::Ipv4Addr(127, 0, 0, 1)), ipv4.ip());
+
+    assert_eq!("rustastic.org", format!("{}", domain).as_slice());
+    assert_eq!("[127.0.0.1]", format!("{}", ipv4).as_slice());
+
+    assert_eq!(Ok(domain.clone()), MailboxForeignPart::parse("rustastic.org"));
+    assert_eq!(Ok(ipv4.clone()), MailboxForeignPart::parse("[127.0.0.1]"));
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(0, "domain or address literal"))),
+        MailboxForeignPart::parse("")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(13, "end of foreign part"))),
+        MailboxForeignPart::parse("rustastic.org.")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(10, "]"))),
+        MailboxForeignPart::parse("[127.0.0.1")
+    );
+
+    // Address literals.
+    let ipv6 = IpAddr(ip::Ipv6Addr(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    assert_eq!(Ok(ipv6.clone()), MailboxForeignPart::parse("[IPv6:2001:db8::1]"));
+    assert_eq!(Ok(ipv6.clone()), MailboxForeignPart::parse("[ipv6:2001:DB8:0:0:0:0:0:1]"));
+    assert_eq!(
+        Ok(IpAddr(ip::Ipv6Addr(0, 0, 0, 0, 0, 0xffff, 0xc000, 0x0201))),
+        MailboxForeignPart::parse("[IPv6:::ffff:192.0.2.1]")
+    );
+    let general = GeneralLiteral { tag: "x-tag".into_string(), content: "a=b!c".into_string() };
+    assert_eq!(Ok(general.clone()), MailboxForeignPart::parse("[x-tag:a=b!c]"));
+    assert_eq!("[x-tag:a=b!c]", format!("{}", general).as_slice());
+    assert_eq!("[x-tag:a=b!c]", general.to_unicode().as_slice());
+    assert_eq!(None, general.domain());
+    assert_eq!(None, general.ip());
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(7, "address literal content"))),
+        MailboxForeignPart::parse("[x-tag:]")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(7, "address literal content"))),
+        MailboxForeignPart::parse("[x-tag:a b]")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(1, "standardized tag"))),
+        MailboxForeignPart::parse("[x-:abc]")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(6, "IPv6 address"))),
+        MailboxForeignPart::parse("[IPv6:1:2:3:4:5:6:7::]")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(1, "IPv4 address"))),
+        MailboxForeignPart::parse("[256.0.0.1]")
+    );
+
+    // Internationalized domains.
+    let idn = Domain("xn--bcher-kva.example".into_string());
+    assert_eq!(Ok(idn.clone()), MailboxForeignPart::parse("bücher.example"));
+    assert_eq!(Ok(idn.clone()), MailboxForeignPart::parse("BÜCHER.example"));
+    assert_eq!(Ok(idn.clone()), MailboxForeignPart::parse("xn--bcher-kva.example"));
+    assert_eq!("xn--bcher-kva.example", format!("{}", idn).as_slice());
+    assert_eq!("bücher.example", idn.to_unicode().as_slice());
+    assert_eq!("rustastic.org", domain.to_unicode().as_slice());
+    assert_eq!("[127.0.0.1]", ipv4.to_unicode().as_slice());
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(3, "end of foreign part"))),
+        MailboxForeignPart::parse("bü cher.example")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(0, "internationalized domain"))),
+        MailboxForeignPart::parse("xn--bcher-kva!.example")
+    );
 }
 
 /// Represents an email address, aka "mailbox" in the SMTP spec.
@@ -135,7 +705,10 @@ fn test_foreign_part() {
 /// It is composed of a local part and a foreign part. If the address is sent to the `Postmaster`
 /// address for a domain, then the local part will always be converted `postmaster`, all lowercase.
 /// Since the `Postmaster` address must be handled without regard for case, this makes things simpler.
-#[deriving(PartialEq, Eq, Clone, Show)]
+///
+/// When formatted with `{}`, a mailbox is shown as it should be sent in SMTP, ie.
+/// `rust@rustastic.org` or `"rust is"@[127.0.0.1]`.
+#[deriving(PartialEq, Eq, Clone)]
 pub struct Mailbox {
     local_part: MailboxLocalPart,
     foreign_part: MailboxForeignPart
@@ -146,10 +719,12 @@ pub struct Mailbox {
 pub enum MailboxParseError {
     /// The maximum length of 64 octets [as per RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.5.3.1.1) is exceeded.
     LocalPartTooLong,
-    /// The local part was neither a atom, nor a quoted string.
-    LocalPartUnrecognized,
-    /// The foreign part was neither a domain, nor an IP.
-    ForeignPartUnrecognized,
+    /// The local part was neither a atom, nor a quoted string. The error tells where parsing
+    /// failed in the input and what was expected there.
+    LocalPartUnrecognized(ParseError),
+    /// The foreign part was neither a domain, nor an IP. The error tells where parsing failed in
+    /// the input and what was expected there.
+    ForeignPartUnrecognized(ParseError),
     /// The maximum length of 255 octets [as per RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.5.3.1.2) is exceeded.
     DomainTooLong,
     /// The maximum length of 254 octets (256 - 2 for punctuaction) [as per RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.5.3.1.3) is exceeded.
@@ -158,6 +733,17 @@ pub enum MailboxParseError {
     AtNotFound
 }
 
+impl MailboxParseError {
+    /// Returns the same error, with the position of the parse error moved forward by `offset`.
+    pub fn offset(self, offset: uint) -> MailboxParseError {
+        match self {
+            LocalPartUnrecognized(err) => LocalPartUnrecognized(err.offset(offset)),
+            ForeignPartUnrecognized(err) => ForeignPartUnrecognized(err.offset(offset)),
+            err => err
+        }
+    }
+}
+
 impl Mailbox {
     /// Creates a `Mailbox` from a string if the string contains a valid email
     /// address. Otherwise, returns a `MailboxParseError`.
@@ -167,36 +753,13 @@ impl Mailbox {
     /// This function does *not* expect anything to wrap the passed email
     /// address. For example, this will result in an error:
     /// `<hello@world.com>`
+    ///
+    /// Source routes are not part of a mailbox, so `@relay:hello@world.com` is an error as well.
+    /// Use `Path::parse` to parse a mailbox which may be preceded by a source route.
     pub fn parse(s: &str) -> Result<Mailbox, MailboxParseError> {
-        let mut local_part: MailboxLocalPart;
-        let mut foreign_part: MailboxForeignPart;
-
-        // Skip the source routes as specified in RFC 5321.
-        let mut offset: uint = utils::get_source_route_len(s);
-
         // Get the local part.
-        let dot_string_len = utils::get_dot_string_len(s.slice_from(offset));
-        if dot_string_len > 0 {
-            if dot_string_len > MAX_MAILBOX_LOCAL_PART_LEN {
-                return Err(LocalPartTooLong);
-            }
-            local_part = MailboxLocalPart::from_dot_string(
-                s.slice(offset, offset + dot_string_len)
-            );
-            offset += dot_string_len;
-        } else {
-            let quoted_string_len = utils::get_quoted_string_len(s.slice_from(offset));
-            if quoted_string_len == 0 {
-                return Err(LocalPartUnrecognized);
-            }
-            if quoted_string_len > MAX_MAILBOX_LOCAL_PART_LEN {
-                return Err(LocalPartTooLong);
-            }
-            local_part = MailboxLocalPart::from_quoted_string(
-                s.slice(offset, offset + quoted_string_len)
-            );
-            offset += quoted_string_len;
-        }
+        let (local_part, local_part_len) = try!(MailboxLocalPart::parse_start(s));
+        let mut offset = local_part_len;
 
         // Check if the email address continues to find an @.
         if offset >= s.len() {
@@ -205,70 +768,213 @@ impl Mailbox {
         // If no @ is found, it means we're still in what should be the local
         // part but it is invalid, ie "rust is@rustastic.org".
         if s.char_at(offset) != '@' {
-            return Err(LocalPartUnrecognized);
+            return Err(LocalPartUnrecognized(ParseError::new(offset, "@")));
         }
         offset += 1;
 
-        let domain_len = utils::get_domain_len(s.slice_from(offset));
-        if domain_len > 0 {
-            // Is the domain is too long ?
-            if domain_len > MAX_DOMAIN_LEN {
-                return Err(DomainTooLong);
-            }
-            // Save the domain.
-            foreign_part = Domain(
-                s.slice(offset, offset + domain_len).into_string()
-            );
-            offset += domain_len;
-        } else {
-            let ipv4_len = utils::get_possible_ipv4_len(s.slice_from(offset));
-            if ipv4_len > 0 {
-                match FromStr::from_str(s.slice(offset + 1, offset + ipv4_len - 1)) {
-                    Some(ip) => {
-                        foreign_part = IpAddr(ip);
-                        offset += ipv4_len;
-                    },
-                    _ => return Err(ForeignPartUnrecognized)
-                }
-            } else {
-                let ipv6_len = utils::get_possible_ipv6_len(s.slice_from(offset));
-                if ipv6_len > 0 {
-                    match FromStr::from_str(s.slice(offset + 6, offset + ipv6_len - 1)) {
-                        Some(ip) => {
-                            foreign_part = IpAddr(ip);
-                            offset += ipv6_len;
-                        },
-                        _ => return Err(ForeignPartUnrecognized)
-                    }
-                } else {
-                    return Err(ForeignPartUnrecognized);
-                }
-            }
-        }
+        let (foreign_part, foreign_part_len) = try!(
+            MailboxForeignPart::parse_start(s.slice_from(offset)).map_err(|err| err.offset(offset))
+        );
+        offset += foreign_part_len;
+
+        // The length that matters is the one of the domain in its ASCII form.
+        let len = match foreign_part.domain() {
+            Some(domain) => offset - foreign_part_len + domain.len(),
+            None => offset
+        };
 
         // Example would be "rust.is@rustastic.org{}" where "rustastic.org{}"
         // would be considered an invalid domain name.
         if offset != s.len() {
-            Err(ForeignPartUnrecognized)
+            Err(ForeignPartUnrecognized(ParseError::new(offset, "end of email address")))
         // Overall, is the email address to long? We could test this at the
         // beginning of the function to potentially save processing power, but
         // this shouldn't happen too often and this error doesn't give much
         // information whereas LocalPartTooLong is more precise which allows
         // for more understandable debug messages.
-        } else if offset > MAX_MAILBOX_LEN {
+        } else if len > MAX_MAILBOX_LEN {
             Err(TooLong)
         } else {
-            if local_part.human_string.is_ascii() {
-                if local_part.human_string.clone().into_ascii_lower().as_slice() == "postmaster" {
-                    local_part = MailboxLocalPart::from_dot_string("postmaster");
-                }
-            }
             Ok(Mailbox {
-                local_part: local_part,
+                local_part: local_part.normalize_postmaster(),
                 foreign_part: foreign_part
             })
         }
     }
+
+    /// Creates a `Mailbox` from a local part and a foreign part, as they would be sent in SMTP,
+    /// if they are valid. Otherwise, returns a `MailboxParseError`.
+    ///
+    /// For example, `Mailbox::new("\"rust is\"", "rustastic.org")` gives the same mailbox as
+    /// `Mailbox::parse("\"rust is\"@rustastic.org")`.
+    pub fn new(local_part: &str, foreign_part: &str) -> Result<Mailbox, MailboxParseError> {
+        let local = try!(MailboxLocalPart::parse(local_part));
+        let foreign = try!(MailboxForeignPart::parse(foreign_part));
+
+        let foreign_part_len = match foreign.domain() {
+            Some(domain) => domain.len(),
+            None => foreign_part.len()
+        };
+        if local_part.len() + 1 + foreign_part_len > MAX_MAILBOX_LEN {
+            Err(TooLong)
+        } else {
+            Ok(Mailbox {
+                local_part: local.normalize_postmaster(),
+                foreign_part: foreign
+            })
+        }
+    }
+
+    /// Returns the local part of the email address.
+    pub fn local_part(&self) -> &MailboxLocalPart {
+        &self.local_part
+    }
+
+    /// Returns the foreign part of the email address, ie. the domain or the ip address.
+    pub fn foreign_part(&self) -> &MailboxForeignPart {
+        &self.foreign_part
+    }
+
+    /// Returns the email address as it should be shown to humans, with an internationalized
+    /// domain in its Unicode form, ie. `rust@bücher.example`.
+    pub fn to_unicode(&self) -> String {
+        format!("{}@{}", self.local_part, self.foreign_part.to_unicode())
+    }
+
+    /// Splits the email address into a base mailbox and a detail, aka sub-address, as
+    /// described for `MailboxLocalPart::split_detail`. For example, `support+billing@rustastic.org`
+    /// gives `support@rustastic.org` and `billing`.
+    pub fn split_detail(&self, separators: &[char]) -> (Mailbox, Option<String>) {
+        let (local_part, detail) = self.local_part.split_detail(separators);
+        (
+            Mailbox {
+                local_part: local_part.normalize_postmaster(),
+                foreign_part: self.foreign_part.clone()
+            },
+            detail
+        )
+    }
+
+    /// Returns a normalized copy of the email address, following the given normalization policy.
+    ///
+    /// Two addresses that end up at the same mailbox according to the policy are equal once
+    /// normalized, ie. `Rust+Tag@RUSTASTIC.org` and `rust@rustastic.org` with a policy that
+    /// lowercases local parts and strips sub-addresses.
+    pub fn normalize(&self, normalization: &MailboxNormalization) -> Mailbox {
+        let foreign_part = match self.foreign_part {
+            Domain(ref domain) if normalization.lowercase_domain => {
+                Domain(domain.clone().into_ascii_lower())
+            },
+            ref foreign_part => foreign_part.clone()
+        };
+
+        let (local_part, _) = self.local_part.split_detail(
+            normalization.subaddress_separators.as_slice()
+        );
+        let mut human_string = local_part.human_string.clone();
+        if normalization.lowercase_local_part {
+            human_string = human_string.as_slice().chars().map(|c| c.to_lowercase()).collect();
+        }
+        let ignore_dots = match foreign_part.domain() {
+            // Domains are stored as A-labels, the configured ones may be U-labels.
+            Some(domain) => normalization.dot_insensitive_domains.iter().any(|d| {
+                match idna::to_ascii(d.as_slice()) {
+                    Ok(d) => d.as_slice().eq_ignore_ascii_case(domain),
+                    Err(_) => false
+                }
+            }),
+            None => false
+        };
+        if ignore_dots {
+            let without_dots: String = human_string.as_slice().chars().filter(|&c| c != '.').collect();
+            if without_dots.len() > 0 {
+                human_string = without_dots;
+            }
+        }
+
+        let local_part = if human_string == local_part.human_string {
+            local_part
+        } else {
+            MailboxLocalPart::from_human_string(human_string.as_slice())
+        };
+        Mailbox {
+            local_part: local_part.normalize_postmaster(),
+            foreign_part: foreign_part
+        }
+    }
+
+    /// Returns a string that identifies the mailbox according to the given normalization
+    /// policy, which is suitable for use as a key in a map or a set.
+    ///
+    /// This is the normalized email address, as it would be sent in SMTP.
+    pub fn canonical_key(&self, normalization: &MailboxNormalization) -> String {
+        format!("{}", self.normalize(normalization))
+    }
+}
+
+#[test]
+fn test_mailbox_public_api() {
+    let mailbox = Mailbox::new("\"rust\\ is\"", "rustastic.org").unwrap();
+    assert_eq!(Mailbox::parse("\"rust is\"@rustastic.org").unwrap(), mailbox);
+    assert_eq!("\"rust is\"", mailbox.local_part().smtp_string());
+    assert_eq!("rust is", mailbox.local_part().human_string());
+    assert_eq!(Some("rustastic.org"), mailbox.foreign_part().domain());
+
+    let mailbox = Mailbox::new("PostMaster", "[127.0.0.1]").unwrap();
+    assert_eq!("postmaster", mailbox.local_part().human_string());
+    assert_eq!(Some(ip::Ipv4Addr(127, 0, 0, 1)), mailbox.foreign_part().ip());
+
+    let mailbox = Mailbox::new("rust", "bücher.example").unwrap();
+    assert_eq!(Mailbox::parse("rust@xn--bcher-kva.example").unwrap(), mailbox);
+    assert_eq!(Mailbox::parse("rust@Bücher.example").unwrap(), mailbox);
+    assert_eq!("rust@xn--bcher-kva.example", format!("{}", mailbox).as_slice());
+    assert_eq!("rust@bücher.example", mailbox.to_unicode().as_slice());
+
+    let (base, detail) = Mailbox::parse("support+billing@rustastic.org").unwrap().split_detail(['+']);
+    assert_eq!(Mailbox::parse("support@rustastic.org").unwrap(), base);
+    assert_eq!(Some("billing".into_string()), detail);
+    let (base, detail) = Mailbox::parse("PostMaster+abuse@rustastic.org").unwrap().split_detail(['+']);
+    assert_eq!("postmaster@rustastic.org", format!("{}", base).as_slice());
+    assert_eq!(Some("abuse".into_string()), detail);
+
+    assert_eq!(
+        Err(LocalPartUnrecognized(ParseError::new(4, "end of local part"))),
+        Mailbox::new("rust@is", "rustastic.org")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(13, "end of foreign part"))),
+        Mailbox::new("rust", "rustastic.org@")
+    );
+    assert_eq!(Err(TooLong), Mailbox::new(
+        "rust",
+        String::from_char(MAX_MAILBOX_LEN - 4, 'a').as_slice()
+    ));
+}
+
+impl fmt::Show for Mailbox {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}@{}", self.local_part, self.foreign_part)
+    }
+}
+
+#[test]
+fn test_mailbox_show() {
+    assert_eq!(
+        "rust.is@rustastic.org",
+        format!("{}", Mailbox::parse("rust.is@rustastic.org").unwrap()).as_slice()
+    );
+    assert_eq!(
+        "\"rust is\"@rustastic.org",
+        format!("{}", Mailbox::parse("\"rust\\ is\"@rustastic.org").unwrap()).as_slice()
+    );
+    assert_eq!(
+        "postmaster@[127.0.0.1]",
+        format!("{}", Mailbox::parse("POSTMASTER@[127.0.0.1]").unwrap()).as_slice()
+    );
+    assert_eq!(
+        "rust@[IPv6:::1]",
+        format!("{}", Mailbox::parse("rust@[Ipv6:::1]").unwrap()).as_slice()
+    );
 }
 
 #[test]
@@ -296,10 +1002,26 @@ fn test_mailbox() {
     let mut s = String::from_char(MAX_MAILBOX_LOCAL_PART_LEN + 1, 'a');
     s.push_str("@t.com");
     assert_eq!(Err(LocalPartTooLong), Mailbox::parse(s.as_slice()));
-    assert_eq!(Err(LocalPartUnrecognized), Mailbox::parse("t @t.com{"));
-    assert_eq!(Err(LocalPartUnrecognized), Mailbox::parse("t "));
-    assert_eq!(Err(ForeignPartUnrecognized), Mailbox::parse("t@{}"));
-    assert_eq!(Err(ForeignPartUnrecognized), Mailbox::parse("t@t.com{"));
+    assert_eq!(
+        Err(LocalPartUnrecognized(ParseError::new(1, "@"))),
+        Mailbox::parse("t @t.com{")
+    );
+    assert_eq!(
+        Err(LocalPartUnrecognized(ParseError::new(1, "@"))),
+        Mailbox::parse("t ")
+    );
+    assert_eq!(
+        Err(LocalPartUnrecognized(ParseError::new(0, "dot-string or quoted-string"))),
+        Mailbox::parse("@relay:t@t.com")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(2, "domain or address literal"))),
+        Mailbox::parse("t@{}")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(7, "end of email address"))),
+        Mailbox::parse("t@t.com{")
+    );
     // The check here is to expect something else than DomainTooLong.
     assert_eq!(Err(TooLong), Mailbox::parse(
         ("rust@".into_string() + String::from_char(MAX_DOMAIN_LEN, 'a'))
@@ -328,9 +1050,109 @@ fn test_mailbox() {
     assert_eq!("postmaster", path_7.local_part.human_string.as_slice());
     assert_eq!("postmaster", path_8.local_part.human_string.as_slice());
 
-    assert_eq!(Err(ForeignPartUnrecognized), Mailbox::parse("rust.is@[127.0.0.1"));
-    assert_eq!(Err(ForeignPartUnrecognized), Mailbox::parse("rust.is@[00.0.1]"));
-    assert_eq!(Err(ForeignPartUnrecognized), Mailbox::parse("rust.is@[::1]"));
-    assert_eq!(Err(ForeignPartUnrecognized), Mailbox::parse("rust.is@[Ipv6: ::1]"));
-    assert_eq!(Err(ForeignPartUnrecognized), Mailbox::parse("rust.is@[Ipv6:::1"));
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(18, "]"))),
+        Mailbox::parse("rust.is@[127.0.0.1")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(9, "IPv4 address"))),
+        Mailbox::parse("rust.is@[00.0.1]")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(9, "standardized tag"))),
+        Mailbox::parse("rust.is@[::1]")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(14, "IPv6 address"))),
+        Mailbox::parse("rust.is@[Ipv6: ::1]")
+    );
+    assert_eq!(
+        Err(ForeignPartUnrecognized(ParseError::new(17, "]"))),
+        Mailbox::parse("rust.is@[Ipv6:::1")
+    );
+}
+
+/// Describes how email addresses should be normalized before being compared, with
+/// `Mailbox::normalize` or `Mailbox::canonical_key`.
+///
+/// Only domains are case insensitive as per RFC 5321, so the other normalizations are disabled
+/// by default and should only be enabled for domains known to behave this way.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub struct MailboxNormalization {
+    /// Lowercase domains. Enabled by default.
+    pub lowercase_domain: bool,
+    /// Lowercase local parts. Disabled by default.
+    pub lowercase_local_part: bool,
+    /// Characters that separate the user from a sub-address in local parts, ie. `+` for
+    /// `rust+tag@rustastic.org`. The sub-address is removed. Empty by default.
+    pub subaddress_separators: Vec<char>,
+    /// Domains for which dots in local parts are ignored, ie. `r.u.s.t@rustastic.org` is the
+    /// same as `rust@rustastic.org`. Internationalized domains may be given in either form, ie.
+    /// `bücher.example` or `xn--bcher-kva.example`. Empty by default.
+    pub dot_insensitive_domains: Vec<String>
+}
+
+impl MailboxNormalization {
+    /// Creates the default normalization policy, which only lowercases domains.
+    pub fn new() -> MailboxNormalization {
+        MailboxNormalization {
+            lowercase_domain: true,
+            lowercase_local_part: false,
+            subaddress_separators: vec!(),
+            dot_insensitive_domains: vec!()
+        }
+    }
+}
+
+#[test]
+fn test_mailbox_normalization() {
+    let default = MailboxNormalization::new();
+    let all = MailboxNormalization {
+        lowercase_domain: true,
+        lowercase_local_part: true,
+        subaddress_separators: vec!('+', '-'),
+        dot_insensitive_domains: vec!("rustastic.org".into_string())
+    };
+    let mailbox = Mailbox::parse("R.u.s.t+Tag@RustAstic.ORG").unwrap();
+
+    // Domains only.
+    assert_eq!(Mailbox::parse("R.u.s.t+Tag@rustastic.org").unwrap(), mailbox.normalize(&default));
+    assert_eq!("R.u.s.t+Tag@rustastic.org", mailbox.canonical_key(&default).as_slice());
+    assert!(mailbox.canonical_key(&default) != Mailbox::parse("r.u.s.t+tag@rustastic.org").unwrap()
+        .canonical_key(&default));
+
+    // Everything.
+    assert_eq!("rust@rustastic.org", mailbox.canonical_key(&all).as_slice());
+    assert_eq!(
+        Mailbox::parse("rust@rustastic.org").unwrap().canonical_key(&all),
+        Mailbox::parse("Rust-Billing@rustastic.org").unwrap().canonical_key(&all)
+    );
+    // Dots are only ignored for the configured domains.
+    assert_eq!("r.u.s.t@example.org", Mailbox::parse("R.u.s.t@example.org").unwrap()
+        .canonical_key(&all).as_slice());
+    // A local part starting with a separator is kept.
+    assert_eq!("+rust@example.org", Mailbox::parse("+rust@example.org").unwrap()
+        .canonical_key(&all).as_slice());
+
+    // Quoted local parts are re-encoded.
+    assert_eq!("\"rust is\"@example.org", Mailbox::parse("\"Rust is+cool\"@example.org").unwrap()
+        .canonical_key(&all).as_slice());
+    assert_eq!("rust.is@example.org", Mailbox::parse("\"rust.is+ cool\"@example.org").unwrap()
+        .canonical_key(&all).as_slice());
+    assert_eq!("postmaster@example.org", Mailbox::parse("\"Postmaster+abuse\"@example.org").unwrap()
+        .canonical_key(&all).as_slice());
+
+    // Address literals are kept as is.
+    assert_eq!("rust@[127.0.0.1]", Mailbox::parse("Rust+tag@[127.0.0.1]").unwrap()
+        .canonical_key(&all).as_slice());
+
+    // Internationalized domains match whatever form they are configured in.
+    let idn = MailboxNormalization {
+        dot_insensitive_domains: vec!("Bücher.example".into_string()),
+        ..MailboxNormalization::new()
+    };
+    assert_eq!("rust@xn--bcher-kva.example", Mailbox::parse("r.u.s.t@bücher.example").unwrap()
+        .canonical_key(&idn).as_slice());
+    assert_eq!("rust@xn--bcher-kva.example", Mailbox::parse("r.u.s.t@xn--bcher-kva.example").unwrap()
+        .canonical_key(&idn).as_slice());
 }
@@ -18,7 +18,18 @@ pub mod stream;
 pub mod mailbox;
 pub mod utils;
 pub mod transaction;
+pub mod dsn;
+pub mod status;
+pub mod address;
+pub mod idna;
+pub mod path;
+pub mod log;
+pub mod transcript;
+mod idna_table;
+#[cfg(feature = "serde-support")]
+mod serde_impls;
 
 pub static MIN_ALLOWED_MESSAGE_SIZE: uint = 65536;
-pub static MIN_ALLOWED_LINE_SIZE: uint = 1001;
+pub static MIN_ALLOWED_COMMAND_LINE_SIZE: uint = 512;
+pub static MIN_ALLOWED_TEXT_LINE_SIZE: uint = 1001;
 pub static MIN_ALLOWED_RECIPIENTS: uint = 100;
@@ -0,0 +1,311 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tools for handling the paths given with `MAIL` and `RCPT`, which are a mailbox optionally
+//! preceded by a source route, as described
+//! [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.1.2).
+//!
+//! `ReversePath` and `ForwardPath` parse and serialize paths as they are sent on the wire, ie.
+//! wrapped in `<` and `>`, so that the server and the client agree on the syntax.
+
+use std::fmt;
+use std::ascii::AsciiExt;
+use super::utils;
+use super::utils::ParseError;
+use super::mailbox::{Mailbox, MailboxParseError};
+
+/// Represents an error that occured while parsing a path.
+#[deriving(PartialEq, Eq, Show)]
+pub enum PathParseError {
+    /// The path starts with `@` but the source route is invalid. The error tells where parsing
+    /// failed in the input and what was expected there.
+    SourceRouteUnrecognized(ParseError),
+    /// The mailbox after the source route is invalid. Positions in the error are relative to
+    /// the beginning of the path, not to the beginning of the mailbox.
+    InvalidMailbox(MailboxParseError),
+    /// The path is not wrapped in `<` and `>`.
+    AngleBracketsNotFound
+}
+
+impl PathParseError {
+    /// Returns the same error, with the position of the parse error moved forward by `offset`.
+    fn offset(self, offset: uint) -> PathParseError {
+        match self {
+            SourceRouteUnrecognized(err) => SourceRouteUnrecognized(err.offset(offset)),
+            InvalidMailbox(err) => InvalidMailbox(err.offset(offset)),
+            err => err
+        }
+    }
+}
+
+/// Represents a path, ie. `@relay1,@relay2:rust@rustastic.org`.
+///
+/// Source routes are deprecated by RFC 5321 but some older systems still send them. They are
+/// kept here so that they can be logged, or refused, instead of being silently dropped.
+#[deriving(PartialEq, Eq, Clone)]
+pub struct Path {
+    /// The domains of the source route, in the order they were given, ie. `relay1` and `relay2`
+    /// for `@relay1,@relay2:rust@rustastic.org`. This is empty if there is no source route.
+    pub source_route: Vec<String>,
+    /// The mailbox the path leads to.
+    pub mailbox: Mailbox
+}
+
+impl Path {
+    /// Creates a `Path` without a source route.
+    pub fn new(mailbox: Mailbox) -> Path {
+        Path {
+            source_route: vec!(),
+            mailbox: mailbox
+        }
+    }
+
+    /// Creates a `Path` from a string if the string contains a valid path. Otherwise, returns a
+    /// `PathParseError`.
+    ///
+    /// The argument should be what is found between `<` and `>`, ie.
+    /// `@relay1,@relay2:rust@rustastic.org` or `rust@rustastic.org`.
+    pub fn parse(s: &str) -> Result<Path, PathParseError> {
+        let (source_route, rest) = if s.len() > 0 && s.char_at(0) == '@' {
+            let (route, rest) = try!(
+                utils::parse_source_route(s).map_err(|err| SourceRouteUnrecognized(err))
+            );
+            // Drop the trailing `:` and the `@` in front of each domain.
+            let domains = route.slice_to(route.len() - 1).split(',').map(|at_domain| {
+                at_domain.slice_from(1).into_string()
+            }).collect();
+            (domains, rest)
+        } else {
+            (vec!(), s)
+        };
+
+        let offset = s.len() - rest.len();
+        let mailbox = try!(Mailbox::parse(rest).map_err(|err| InvalidMailbox(err.offset(offset))));
+
+        Ok(Path {
+            source_route: source_route,
+            mailbox: mailbox
+        })
+    }
+
+    /// Returns `true` if the path has a source route.
+    pub fn has_source_route(&self) -> bool {
+        self.source_route.len() != 0
+    }
+
+    /// Removes the source route, which is what RFC 5321 recommends servers do.
+    pub fn strip_source_route(&mut self) {
+        self.source_route.clear();
+    }
+}
+
+impl fmt::Show for Path {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.has_source_route() {
+            let route: Vec<String> = self.source_route.iter().map(|domain| {
+                format!("@{}", domain)
+            }).collect();
+            try!(write!(f, "{}:", route.connect(",")));
+        }
+        write!(f, "{}", self.mailbox)
+    }
+}
+
+#[test]
+fn test_path() {
+    use super::mailbox::LocalPartUnrecognized;
+
+    let path = Path::parse("rust@rustastic.org").unwrap();
+    assert_eq!(Path::new(Mailbox::parse("rust@rustastic.org").unwrap()), path);
+    assert!(!path.has_source_route());
+    assert_eq!("rust@rustastic.org", format!("{}", path).as_slice());
+
+    let mut path = Path::parse("@relay1,@relay2.rustastic.org:rust@rustastic.org").unwrap();
+    assert_eq!(
+        vec!("relay1".into_string(), "relay2.rustastic.org".into_string()),
+        path.source_route
+    );
+    assert_eq!(Mailbox::parse("rust@rustastic.org").unwrap(), path.mailbox);
+    assert_eq!(
+        "@relay1,@relay2.rustastic.org:rust@rustastic.org",
+        format!("{}", path).as_slice()
+    );
+    path.strip_source_route();
+    assert_eq!(Path::new(Mailbox::parse("rust@rustastic.org").unwrap()), path);
+
+    assert_eq!(
+        Err(SourceRouteUnrecognized(ParseError::new(0, "source route"))),
+        Path::parse("@relay1,rust@rustastic.org")
+    );
+    assert_eq!(
+        Err(SourceRouteUnrecognized(ParseError::new(0, "source route"))),
+        Path::parse("@:rust@rustastic.org")
+    );
+    assert_eq!(
+        Err(InvalidMailbox(LocalPartUnrecognized(ParseError::new(12, "@")))),
+        Path::parse("@relay1:rust is@rustastic.org")
+    );
+}
+
+// Returns what is found between `<` and `>` if they wrap the whole string.
+fn strip_angle_brackets<'a>(s: &'a str) -> Result<&'a str, PathParseError> {
+    if s.len() < 2 || s.char_at(0) != '<' || s.char_at(s.len() - 1) != '>' {
+        Err(AngleBracketsNotFound)
+    } else {
+        Ok(s.slice(1, s.len() - 1))
+    }
+}
+
+#[test]
+fn test_strip_angle_brackets() {
+    assert_eq!(Ok(""), strip_angle_brackets("<>"));
+    assert_eq!(Ok("rust@rustastic.org"), strip_angle_brackets("<rust@rustastic.org>"));
+    assert_eq!(Err(AngleBracketsNotFound), strip_angle_brackets("<"));
+    assert_eq!(Err(AngleBracketsNotFound), strip_angle_brackets("rust@rustastic.org"));
+    assert_eq!(Err(AngleBracketsNotFound), strip_angle_brackets("<rust@rustastic.org"));
+}
+
+/// Represents the reverse-path given with `MAIL`, as described
+/// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.1.2).
+#[deriving(PartialEq, Eq, Clone)]
+pub enum ReversePath {
+    /// The null reverse-path, `<>`, used when sending delivery status notifications so that
+    /// they do not trigger notifications themselves.
+    NullPath,
+    /// A regular path, ie. `<rust@rustastic.org>`.
+    MailboxPath(Path)
+}
+
+impl ReversePath {
+    /// Creates a `ReversePath` from a string if the string contains a valid reverse-path.
+    /// Otherwise, returns a `PathParseError`.
+    ///
+    /// The argument should be wrapped in `<` and `>`, ie. `<>` or `<rust@rustastic.org>`.
+    pub fn parse(s: &str) -> Result<ReversePath, PathParseError> {
+        let inner = try!(strip_angle_brackets(s));
+        if inner.len() == 0 {
+            Ok(NullPath)
+        } else {
+            Path::parse(inner).map(|path| MailboxPath(path)).map_err(|err| err.offset(1))
+        }
+    }
+
+    /// Returns the mailbox of the reverse-path, or `None` if it is null.
+    pub fn mailbox<'a>(&'a self) -> Option<&'a Mailbox> {
+        match *self {
+            NullPath => None,
+            MailboxPath(ref path) => Some(&path.mailbox)
+        }
+    }
+}
+
+impl fmt::Show for ReversePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NullPath => write!(f, "<>"),
+            MailboxPath(ref path) => write!(f, "<{}>", path)
+        }
+    }
+}
+
+#[test]
+fn test_reverse_path() {
+    use super::mailbox::LocalPartUnrecognized;
+
+    assert_eq!(Ok(NullPath), ReversePath::parse("<>"));
+    assert_eq!(None, NullPath.mailbox());
+    assert_eq!("<>", format!("{}", NullPath).as_slice());
+
+    let path = ReversePath::parse("<@relay:rust@rustastic.org>").unwrap();
+    assert_eq!(
+        MailboxPath(Path::parse("@relay:rust@rustastic.org").unwrap()),
+        path
+    );
+    assert_eq!(Some(&Mailbox::parse("rust@rustastic.org").unwrap()), path.mailbox());
+    assert_eq!("<@relay:rust@rustastic.org>", format!("{}", path).as_slice());
+
+    assert_eq!(Err(AngleBracketsNotFound), ReversePath::parse(""));
+    assert_eq!(Err(AngleBracketsNotFound), ReversePath::parse("rust@rustastic.org"));
+    assert_eq!(
+        Err(InvalidMailbox(LocalPartUnrecognized(ParseError::new(5, "@")))),
+        ReversePath::parse("<rust is@rustastic.org>")
+    );
+}
+
+/// Represents the forward-path given with `RCPT`, as described
+/// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.1.1.3).
+#[deriving(PartialEq, Eq, Clone)]
+pub enum ForwardPath {
+    /// The special `<Postmaster>` path, without a domain, which designates the postmaster of
+    /// the server receiving the command.
+    PostmasterPath,
+    /// A regular path, ie. `<rust@rustastic.org>`.
+    RecipientPath(Path)
+}
+
+impl ForwardPath {
+    /// Creates a `ForwardPath` from a string if the string contains a valid forward-path.
+    /// Otherwise, returns a `PathParseError`.
+    ///
+    /// The argument should be wrapped in `<` and `>`, ie. `<Postmaster>` or
+    /// `<rust@rustastic.org>`. `<Postmaster>` is case insensitive.
+    pub fn parse(s: &str) -> Result<ForwardPath, PathParseError> {
+        let inner = try!(strip_angle_brackets(s));
+        if inner.eq_ignore_ascii_case("postmaster") {
+            Ok(PostmasterPath)
+        } else {
+            Path::parse(inner).map(|path| RecipientPath(path)).map_err(|err| err.offset(1))
+        }
+    }
+
+    /// Returns the mailbox of the forward-path, or `None` if it is `<Postmaster>`.
+    pub fn mailbox<'a>(&'a self) -> Option<&'a Mailbox> {
+        match *self {
+            PostmasterPath => None,
+            RecipientPath(ref path) => Some(&path.mailbox)
+        }
+    }
+}
+
+impl fmt::Show for ForwardPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PostmasterPath => write!(f, "<Postmaster>"),
+            RecipientPath(ref path) => write!(f, "<{}>", path)
+        }
+    }
+}
+
+#[test]
+fn test_forward_path() {
+    assert_eq!(Ok(PostmasterPath), ForwardPath::parse("<Postmaster>"));
+    assert_eq!(Ok(PostmasterPath), ForwardPath::parse("<POSTMASTER>"));
+    assert_eq!(None, PostmasterPath.mailbox());
+    assert_eq!("<Postmaster>", format!("{}", PostmasterPath).as_slice());
+
+    let path = ForwardPath::parse("<postmaster@rustastic.org>").unwrap();
+    assert_eq!(
+        RecipientPath(Path::parse("postmaster@rustastic.org").unwrap()),
+        path
+    );
+    assert_eq!(Some(&Mailbox::parse("postmaster@rustastic.org").unwrap()), path.mailbox());
+    assert_eq!("<postmaster@rustastic.org>", format!("{}", path).as_slice());
+
+    assert_eq!(Err(AngleBracketsNotFound), ForwardPath::parse("Postmaster"));
+    assert_eq!(
+        Err(SourceRouteUnrecognized(ParseError::new(1, "source route"))),
+        ForwardPath::parse("<@relay,rust@rustastic.org>")
+    );
+    assert!(ForwardPath::parse("<>").is_err());
+}
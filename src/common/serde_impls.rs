@@ -0,0 +1,101 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `Serialize` and `Deserialize` implementations for the types that are best represented as
+//! strings, only built with the `serde-support` feature.
+//!
+//! Mailboxes and paths are serialized to the same string they would be sent as in SMTP, and
+//! deserialized with their `parse` function, so that invalid values are refused.
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de;
+use super::mailbox::{Mailbox, MailboxLocalPart, MailboxForeignPart};
+use super::path::{Path, ReversePath, ForwardPath};
+
+macro_rules! string_serde_impls(
+    ($ty:ty, $parse:expr) => (
+        impl<S: Serializer<E>, E> Serialize<S, E> for $ty {
+            fn serialize(&self, s: &mut S) -> Result<(), E> {
+                s.serialize_str(self.to_string().as_slice())
+            }
+        }
+
+        impl<D: Deserializer<E>, E> Deserialize<D, E> for $ty {
+            fn deserialize_token(d: &mut D, token: de::Token) -> Result<$ty, E> {
+                let s: String = try!(Deserialize::deserialize_token(d, token));
+                match $parse(s.as_slice()) {
+                    Ok(value) => Ok(value),
+                    Err(_) => Err(d.conversion_error(de::String(s)))
+                }
+            }
+        }
+    )
+)
+
+string_serde_impls!(MailboxLocalPart, MailboxLocalPart::parse)
+string_serde_impls!(MailboxForeignPart, MailboxForeignPart::parse)
+string_serde_impls!(Mailbox, Mailbox::parse)
+string_serde_impls!(Path, Path::parse)
+string_serde_impls!(ReversePath, ReversePath::parse)
+string_serde_impls!(ForwardPath, ForwardPath::parse)
+
+#[test]
+fn test_mailbox() {
+    use serde::json;
+
+    let mailbox = Mailbox::parse("\"rust\\ is\"@rustastic.org").unwrap();
+    let s = json::to_string(&mailbox).unwrap();
+    assert_eq!("\"\\\"rust is\\\"@rustastic.org\"", s.as_slice());
+    assert_eq!(mailbox, json::from_str::<Mailbox>(s.as_slice()).unwrap());
+
+    assert!(json::from_str::<Mailbox>("\"rust is@rustastic.org\"").is_err());
+    assert!(json::from_str::<Mailbox>("\"rustastic.org\"").is_err());
+}
+
+#[test]
+fn test_paths() {
+    use serde::json;
+    use super::path::{NullPath, PostmasterPath};
+
+    assert_eq!("\"<>\"", json::to_string(&NullPath).unwrap().as_slice());
+    assert_eq!(NullPath, json::from_str::<ReversePath>("\"<>\"").unwrap());
+    assert_eq!(PostmasterPath, json::from_str::<ForwardPath>("\"<Postmaster>\"").unwrap());
+
+    let path = ForwardPath::parse("<@relay:rust@rustastic.org>").unwrap();
+    let s = json::to_string(&path).unwrap();
+    assert_eq!("\"<@relay:rust@rustastic.org>\"", s.as_slice());
+    assert_eq!(path, json::from_str::<ForwardPath>(s.as_slice()).unwrap());
+
+    assert!(json::from_str::<ForwardPath>("\"rust@rustastic.org\"").is_err());
+}
+
+#[test]
+fn test_envelope() {
+    use serde::json;
+    use super::transaction::{SmtpEnvelope, SmtpEnvelopeRecipient, Rcpt, SmtpTransactionState};
+    use super::dsn::DsnRcptParams;
+
+    let mut envelope = SmtpEnvelope::new();
+    envelope.sender = Some(ReversePath::parse("<rust@rustastic.org>").unwrap());
+    envelope.recipients.push(SmtpEnvelopeRecipient {
+        path: ForwardPath::parse("<bob+rust@rustastic.org>").unwrap(),
+        detail: Some("rust".into_string()),
+        dsn: DsnRcptParams::new()
+    });
+    let s = json::to_string(&envelope).unwrap();
+    assert_eq!(envelope, json::from_str::<SmtpEnvelope>(s.as_slice()).unwrap());
+
+    let s = json::to_string(&Rcpt).unwrap();
+    assert!(Rcpt == json::from_str::<SmtpTransactionState>(s.as_slice()).unwrap());
+}
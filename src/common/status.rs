@@ -0,0 +1,90 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tools for enhanced status codes, as described
+//! [in RFC 3463](http://tools.ietf.org/html/rfc3463) and sent in replies as described
+//! [in RFC 2034](http://tools.ietf.org/html/rfc2034).
+
+use std::fmt;
+
+/// Represents an enhanced status code of the form `class.subject.detail`, ie. `5.1.1`.
+#[deriving(PartialEq, Eq, Clone)]
+pub struct EnhancedStatusCode {
+    /// The class, which is either 2 for success, 4 for a temporary failure or 5 for a
+    /// permanent failure.
+    pub class: uint,
+    /// The subject, ie. 1 for addressing or 5 for protocol problems.
+    pub subject: uint,
+    /// The detail, which is specific to the subject.
+    pub detail: uint
+}
+
+impl EnhancedStatusCode {
+    /// Creates an enhanced status code.
+    pub fn new(class: uint, subject: uint, detail: uint) -> EnhancedStatusCode {
+        EnhancedStatusCode {
+            class: class,
+            subject: subject,
+            detail: detail
+        }
+    }
+
+    /// Parses an enhanced status code, ie. the beginning of a reply text.
+    ///
+    /// Returns `None` if the class is not 2, 4 or 5 or if the subject or the detail are not
+    /// made of 1 to 3 digits.
+    pub fn parse(s: &str) -> Option<EnhancedStatusCode> {
+        let parts: Vec<&str> = s.split('.').collect();
+        if parts.len() != 3 {
+            return None;
+        }
+        for part in parts.iter() {
+            if part.len() == 0 || part.len() > 3 || !part.chars().all(|c| c >= '0' && c <= '9') {
+                return None;
+            }
+        }
+        let code = EnhancedStatusCode::new(
+            from_str(parts[0]).unwrap(),
+            from_str(parts[1]).unwrap(),
+            from_str(parts[2]).unwrap()
+        );
+        match code.class {
+            2 | 4 | 5 if parts[0].len() == 1 => Some(code),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Show for EnhancedStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.class, self.subject, self.detail)
+    }
+}
+
+#[test]
+fn test_enhanced_status_code() {
+    let code = EnhancedStatusCode::new(5, 1, 1);
+    assert_eq!("5.1.1", format!("{}", code).as_slice());
+    assert_eq!("4.5.3", format!("{}", EnhancedStatusCode::new(4, 5, 3)).as_slice());
+
+    assert_eq!(Some(code), EnhancedStatusCode::parse("5.1.1"));
+    assert_eq!(Some(EnhancedStatusCode::new(2, 100, 999)), EnhancedStatusCode::parse("2.100.999"));
+    assert_eq!(None, EnhancedStatusCode::parse("3.1.1"));
+    assert_eq!(None, EnhancedStatusCode::parse("05.1.1"));
+    assert_eq!(None, EnhancedStatusCode::parse("5.1"));
+    assert_eq!(None, EnhancedStatusCode::parse("5.1.1.1"));
+    assert_eq!(None, EnhancedStatusCode::parse("5.1000.1"));
+    assert_eq!(None, EnhancedStatusCode::parse("5..1"));
+    assert_eq!(None, EnhancedStatusCode::parse("5.a.1"));
+}
@@ -15,15 +15,19 @@
 //! Tools for reading/writing from SMTP clients to SMTP servers and vice-versa.
 
 use std::io::{Reader, Writer, IoResult, IoError, InvalidInput};
+#[allow(unused_imports)]
+use std::io::{EndOfFile, standard_error};
 use std::vec::Vec;
 #[allow(unused_imports)]
 use std::io::{Truncate, Open, Read, Write};
 #[allow(unused_imports)]
 use std::io::fs::File;
 #[allow(unused_imports)]
-use super::{MIN_ALLOWED_LINE_SIZE};
+use super::{MIN_ALLOWED_TEXT_LINE_SIZE};
+use super::transcript::{TranscriptWriter, TranscriptRead, TranscriptWritten};
 
 pub static LINE_TOO_LONG: &'static str = "line too long";
+pub static BARE_LINE_ENDING: &'static str = "bare <CR> or <LF>";
 pub static DATA_TOO_LONG: &'static str = "message too long";
 
 #[test]
@@ -31,6 +35,103 @@ fn test_static_vars() {
     // Already tested in the limits test further down.
 }
 
+/// How a line read by `SmtpStream` ended.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub enum LineEnding {
+    /// `<CRLF>`, the only line ending allowed by RFC 5321.
+    Crlf,
+    /// A `<LF>` which is not preceded by `<CR>`.
+    BareLf,
+    /// A `<CR>` which is not followed by `<LF>`.
+    BareCr
+}
+
+/// What `SmtpStream` does with bare `<LF>` and bare `<CR>`.
+///
+/// Clients and servers which disagree on line endings are what makes
+/// [SMTP smuggling](https://www.postfix.org/smtp-smuggling.html) possible, so only `<CRLF>` ends
+/// the message data unless bare `<LF>` is explicitly accepted.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub enum LineEndingPolicy {
+    /// Lines with a bare `<LF>` or a bare `<CR>` are refused with an error. This is the default.
+    RejectBareLineEndings,
+    /// Bare `<LF>` and bare `<CR>` end lines as if they were `<CRLF>`. The end of the message
+    /// data still has to be `<CRLF>.<CRLF>`.
+    NormalizeBareLineEndings,
+    /// Bare `<LF>` ends lines as if it was `<CRLF>`, including around the dot ending the message
+    /// data. Lines with a bare `<CR>` are refused. This is meant for lenient local submission.
+    AcceptBareLf
+}
+
+impl LineEndingPolicy {
+    /// Returns `true` if a line ending can be part of the `<CRLF>.<CRLF>` sequence which ends the
+    /// message data.
+    pub fn ends_data(&self, ending: LineEnding) -> bool {
+        match (*self, ending) {
+            (_, Crlf) => true,
+            (AcceptBareLf, BareLf) => true,
+            _ => false
+        }
+    }
+}
+
+#[test]
+fn test_line_ending_policy() {
+    assert!(RejectBareLineEndings.ends_data(Crlf));
+    assert!(!RejectBareLineEndings.ends_data(BareLf));
+    assert!(NormalizeBareLineEndings.ends_data(Crlf));
+    assert!(!NormalizeBareLineEndings.ends_data(BareLf));
+    assert!(!NormalizeBareLineEndings.ends_data(BareCr));
+    assert!(AcceptBareLf.ends_data(Crlf));
+    assert!(AcceptBareLf.ends_data(BareLf));
+    assert!(!AcceptBareLf.ends_data(BareCr));
+}
+
+/// Counters of what went through a connection.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub struct SmtpStreamStats {
+    /// The number of bytes read and split into lines, endings and skipped bytes of overlong
+    /// lines included. Input which has been read but not split into lines yet is not counted.
+    pub bytes_read: u64,
+    /// The number of bytes written, endings included.
+    pub bytes_written: u64,
+    /// The number of lines read, including lines which were refused.
+    pub lines_read: u64,
+    /// The number of lines written.
+    pub lines_written: u64
+}
+
+/// Splits input into lines, without doing any IO itself.
+///
+/// `SmtpStream` uses it to read lines from blocking streams, and it can be fed from
+/// non-blocking sockets the same way: write input to `space`, call `filled`, then call
+/// `next_line` until it returns `None`.
+pub struct LineBuffer {
+    /// The maximum size of the next lines to read, including `<CRLF>`. The server changes it
+    /// depending on what it reads, ie. commands or message data.
+    max_line_size: uint,
+    /// Buffer to make reading more efficient and allow pipelining. It is never smaller than
+    /// `max_line_size`, so that a line, `<CRLF>` included, always fits in it.
+    buf: Vec<u8>,
+    /// The position of the first byte of `buf` which has not been returned by `next_line` yet.
+    start: uint,
+    /// The position after the last byte of `buf` which has been given as input.
+    end: uint,
+    /// If `true`, the last line was too long and the rest of it, up to and including the next
+    /// `<CRLF>`, must be skipped before reading another line.
+    skip_line: bool,
+    /// What to do with bare `<LF>` and bare `<CR>`.
+    line_ending_policy: LineEndingPolicy,
+    /// The position of the last line found by `next_line`.
+    line_start: uint,
+    /// The length of the last line found by `next_line`, without its ending.
+    line_len: uint,
+    /// The number of bytes split into lines so far.
+    bytes_read: u64,
+    /// The number of lines found so far.
+    lines_read: u64
+}
+
 /// A stream specially made for reading SMTP commands, messages and writing replies.
 ///
 /// # Example
@@ -38,13 +139,12 @@ fn test_static_vars() {
 /// use std::io::TcpStream;
 /// use rsmtp::common::stream::SmtpStream;
 /// use rsmtp::common::{
-///     MIN_ALLOWED_LINE_SIZE,
+///     MIN_ALLOWED_TEXT_LINE_SIZE,
 /// };
 ///
 /// let mut smtp = SmtpStream::new(
 ///     TcpStream::connect("127.0.0.1", 25).unwrap(),
-///     MIN_ALLOWED_LINE_SIZE,
-///     false
+///     MIN_ALLOWED_TEXT_LINE_SIZE
 /// );
 ///
 /// println!("{}", smtp.read_line().unwrap());
@@ -52,158 +152,524 @@ fn test_static_vars() {
 pub struct SmtpStream<S> {
     /// Underlying stream
     stream: S,
-    /// Must be at least 1001 per RFC 5321, 1000 chars + 1 for transparency
-    /// mechanism.
-    max_line_size: uint,
-    /// Buffer to make reading more efficient and allow pipelining
-    buf: Vec<u8>,
-    /// If `true`, will print debug messages of input and output to the console.
-    debug: bool,
-    /// The position of the `<CRLF>` found at the previous `read_line`.
-    last_crlf: Option<uint>
+    /// Everything but the IO: lines, counters and transcript.
+    buffer: SmtpStreamBuffer
 }
 
-// The state of the `<CRLF>` search inside a buffer. See below.
-enum CRLFState {
-    // We are looking for `<CR>`.
-    Cr,
-    // We are looking for `<LF>`.
-    Lf
+/// Everything an SMTP stream does besides IO: splitting input into lines, counting what is read
+/// and written and recording transcripts.
+///
+/// `SmtpStream` uses it with blocking streams. Servers using non-blocking sockets use it the
+/// same way, so that both count and record exactly the same bytes: write input to `space`, call
+/// `filled`, then call `next_line` until it returns `None`. Replies are formatted with
+/// `format_line` and given to `line_written` once they are sent or queued.
+pub struct SmtpStreamBuffer {
+    /// The input read so far, split into lines.
+    lines: LineBuffer,
+    /// If set, all the bytes read and written are recorded there.
+    transcript: Option<TranscriptWriter>,
+    /// The number of bytes written so far.
+    bytes_written: u64,
+    /// The number of lines written so far.
+    lines_written: u64
 }
 
-// Find the position of the first `<CRLF>` in a buffer.
-fn position_crlf(buf: &[u8]) -> Option<uint> {
-    let mut state = Cr;
-    let mut index = 0;
-
-    for byte in buf.iter() {
-        match state {
-            Cr => {
-                if byte == &13 {
-                    state = Lf;
-                }
-            },
-            Lf => {
-                if byte == &10 {
-                    // Subtract 1 to account for the \r, seen previously.
-                    return Some(index - 1);
-                }
+// Find where the first line of a buffer ends: the length of the line, the length of its ending
+// and the kind of ending. A bare `<CR>` only ends a line if `cr_ends_line` is `true`, otherwise it
+// is part of the line. A `<CR>` at the end of the buffer may be followed by `<LF>`, so we can't
+// tell yet.
+fn find_line_end(buf: &[u8], cr_ends_line: bool) -> Option<(uint, uint, LineEnding)> {
+    let mut i = 0;
+    while i < buf.len() {
+        if buf[i] == 10 {
+            return Some((i, 1, BareLf));
+        } else if buf[i] == 13 {
+            if i + 1 == buf.len() {
+                return None;
+            } else if buf[i + 1] == 10 {
+                return Some((i, 2, Crlf));
+            } else if cr_ends_line {
+                return Some((i, 1, BareCr));
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+#[test]
+fn test_find_line_end() {
+    assert_eq!(None, find_line_end(b"", false));
+    assert_eq!(None, find_line_end(b"hello", false));
+    assert_eq!(None, find_line_end(b"hello\r", false));
+    assert_eq!(None, find_line_end(b"hello\r", true));
+    assert_eq!(Some((5, 2, Crlf)), find_line_end(b"hello\r\nworld\r\n", false));
+    assert_eq!(Some((5, 1, BareLf)), find_line_end(b"hello\nworld\r\n", false));
+    assert_eq!(Some((11, 2, Crlf)), find_line_end(b"hello\rworld\r\n", false));
+    assert_eq!(Some((5, 1, BareCr)), find_line_end(b"hello\rworld\r\n", true));
+    assert_eq!(Some((0, 2, Crlf)), find_line_end(b"\r\n", true));
+}
+
+impl LineBuffer {
+    /// Create a new `LineBuffer` for lines of at most `max_line_size` bytes, `<CRLF>` included.
+    pub fn new(max_line_size: uint) -> LineBuffer {
+        LineBuffer {
+            max_line_size: max_line_size,
+            buf: Vec::from_elem(max_line_size, 0u8),
+            start: 0,
+            end: 0,
+            skip_line: false,
+            line_ending_policy: RejectBareLineEndings,
+            line_start: 0,
+            line_len: 0,
+            bytes_read: 0,
+            lines_read: 0
+        }
+    }
+
+    /// Returns the number of bytes split into lines so far, endings and skipped bytes of
+    /// overlong lines included.
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read
+    }
+
+    /// Returns the number of lines found so far, including lines which were refused.
+    pub fn lines_read(&self) -> u64 {
+        self.lines_read
+    }
+
+    /// Returns the maximum size of lines, including `<CRLF>`.
+    pub fn max_line_size(&self) -> uint {
+        self.max_line_size
+    }
+
+    /// Changes the maximum size of lines, including `<CRLF>`.
+    ///
+    /// This is useful when the same input contains lines with different limits, ie.
+    /// command lines and text lines as described
+    /// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.5.3.1). Input which has
+    /// already been given is kept.
+    pub fn set_max_line_size(&mut self, max_line_size: uint) {
+        let len = self.buf.len();
+        if max_line_size > len {
+            self.buf.grow(max_line_size - len, 0u8);
+        }
+        self.max_line_size = max_line_size;
+    }
+
+    /// Changes what to do with bare `<LF>` and bare `<CR>`. By default, they are refused.
+    pub fn set_line_ending_policy(&mut self, policy: LineEndingPolicy) {
+        self.line_ending_policy = policy;
+    }
+
+    /// Returns the policy for bare `<LF>` and bare `<CR>`.
+    pub fn line_ending_policy(&self) -> LineEndingPolicy {
+        self.line_ending_policy
+    }
+
+    /// Move the bytes which have not been returned yet to the beginning of the buffer, to make
+    /// room for more input without re-allocating.
+    fn compact_buf(&mut self) {
+        if self.start == 0 {
+            return;
+        }
+        let len = self.end - self.start;
+        for i in range(0, len) {
+            let byte = self.buf[self.start + i];
+            self.buf[i] = byte;
+        }
+        self.start = 0;
+        self.end = len;
+    }
+
+    /// Drop the input which has not been returned yet, because it is part of a line which is too
+    /// long. A trailing `<CR>` is kept, since it may be followed by the `<LF>` ending the line.
+    fn discard_buf(&mut self) {
+        if self.end > self.start && self.buf[self.end - 1] == 13 {
+            let len = self.end - 1 - self.start;
+            self.consume(len);
+        } else {
+            let len = self.end - self.start;
+            self.consume(len);
+        }
+    }
+
+    /// Mark the next `len` bytes of input as read.
+    fn consume(&mut self, len: uint) {
+        self.start += len;
+        self.bytes_read += len as u64;
+    }
+
+    /// Returns the part of the buffer where more input should be written, as much as the buffer
+    /// can hold. `filled` must then be called with the number of bytes written.
+    pub fn space(&mut self) -> &mut [u8] {
+        self.compact_buf();
+
+        let cap = self.buf.len();
+        self.buf.slice_mut(self.end, cap)
+    }
+
+    /// Tells that `len` bytes of input have been written to `space`.
+    pub fn filled(&mut self, len: uint) {
+        self.end += len;
+    }
+
+    /// Looks for the next line in the input given so far. If one is found, it is returned by
+    /// `line` until the next call, and its ending is returned. If `None` is returned, more input
+    /// is needed.
+    ///
+    /// If no `<CRLF>` is found within `max_line_size` bytes, the line is too long. In that case,
+    /// the rest of the line is skipped by the next calls, so that reading can go on with the line
+    /// after it. Lines refused because of a bare `<LF>` or `<CR>` are skipped entirely.
+    pub fn next_line(&mut self) -> Option<IoResult<LineEnding>> {
+        let cr_ends_line = self.line_ending_policy == NormalizeBareLineEndings;
+        loop {
+            match find_line_end(self.buf.slice(self.start, self.end), cr_ends_line) {
+                // This is the end of a line which was too long, we skip it and start over.
+                Some((len, ending_len, _)) if self.skip_line => {
+                    self.consume(len + ending_len);
+                    self.skip_line = false;
+                    continue;
+                },
+                // The buffer may be larger than the current limit, so the line may be found but
+                // still be too long.
+                Some((len, ending_len, _)) if len + ending_len > self.max_line_size => {
+                    self.consume(len + ending_len);
+                    self.lines_read += 1;
+                    return Some(Err(IoError {
+                        kind: InvalidInput,
+                        desc: LINE_TOO_LONG,
+                        detail: None
+                    }));
+                },
+                // Bare `<LF>` is only allowed if the policy says so. A `<CR>` inside the line
+                // means it is not followed by `<LF>`, which is never allowed here since bare
+                // `<CR>` would have ended the line if it was.
+                Some((len, ending_len, ending)) if (
+                    ending == BareLf && self.line_ending_policy == RejectBareLineEndings
+                ) || self.buf.slice(self.start, self.start + len).contains(&13) => {
+                    self.consume(len + ending_len);
+                    self.lines_read += 1;
+                    return Some(Err(IoError {
+                        kind: InvalidInput,
+                        desc: BARE_LINE_ENDING,
+                        detail: None
+                    }));
+                },
+                Some((len, ending_len, ending)) => {
+                    self.line_start = self.start;
+                    self.line_len = len;
+                    // The line will not be needed anymore on the next call.
+                    self.consume(len + ending_len);
+                    self.lines_read += 1;
+                    return Some(Ok(ending));
+                },
+                None => {}
+            }
+
+            if self.skip_line {
+                // We are still in a line which was too long, nothing here is worth keeping.
+                self.discard_buf();
+            } else if self.end - self.start >= self.max_line_size {
+                // If the buffer is full and has no `<CRLF>`, the line is too long.
+                self.discard_buf();
+                self.skip_line = true;
+                self.lines_read += 1;
+                return Some(Err(IoError {
+                    kind: InvalidInput,
+                    desc: LINE_TOO_LONG,
+                    detail: None
+                }));
+            }
+
+            return None;
+        }
+    }
+
+    /// Returns the last line found by `next_line`, without its ending.
+    pub fn line(&self) -> &[u8] {
+        self.buf.slice(self.line_start, self.line_start + self.line_len)
+    }
+}
+
+#[test]
+fn test_line_buffer() {
+    let mut lines = LineBuffer::new(16);
+    assert!(lines.next_line().is_none());
+
+    // Input may come in pieces of any size.
+    for &piece in ["HE", "LO a\r", "\nNO", "OP\r\nQUIT\r\n"].iter() {
+        let bytes = piece.as_bytes();
+        for i in range(0, bytes.len()) {
+            lines.space()[i] = bytes[i];
+        }
+        lines.filled(bytes.len());
+        if piece == "\nNO" {
+            assert_eq!(Some(Ok(Crlf)), lines.next_line());
+            assert_eq!(b"HELO a", lines.line());
+        }
+        if piece != "OP\r\nQUIT\r\n" {
+            assert!(lines.next_line().is_none());
+        }
+    }
+    assert_eq!(Some(Ok(Crlf)), lines.next_line());
+    assert_eq!(b"NOOP", lines.line());
+    assert_eq!(Some(Ok(Crlf)), lines.next_line());
+    assert_eq!(b"QUIT", lines.line());
+    assert!(lines.next_line().is_none());
+    assert_eq!(16, lines.space().len());
+    assert_eq!(20, lines.bytes_read());
+    assert_eq!(3, lines.lines_read());
+}
+
+impl SmtpStreamBuffer {
+    /// Create a new `SmtpStreamBuffer` for lines of at most `max_line_size` bytes, `<CRLF>`
+    /// included.
+    pub fn new(max_line_size: uint) -> SmtpStreamBuffer {
+        SmtpStreamBuffer {
+            lines: LineBuffer::new(max_line_size),
+            transcript: None,
+            bytes_written: 0,
+            lines_written: 0
+        }
+    }
+
+    /// Returns the counters of what went through the stream so far.
+    pub fn stats(&self) -> SmtpStreamStats {
+        SmtpStreamStats {
+            bytes_read: self.lines.bytes_read(),
+            bytes_written: self.bytes_written,
+            lines_read: self.lines.lines_read(),
+            lines_written: self.lines_written
+        }
+    }
+
+    /// Record all the bytes read and written from now on to `writer`, ie. a file. See the
+    /// `transcript` module for the format.
+    pub fn record_transcript(&mut self, writer: Box<Writer + Send>) {
+        self.transcript = Some(TranscriptWriter::new(writer));
+    }
+
+    /// Returns the maximum size of lines, including `<CRLF>`.
+    pub fn max_line_size(&self) -> uint {
+        self.lines.max_line_size()
+    }
+
+    /// Changes the maximum size of lines, including `<CRLF>`.
+    ///
+    /// See `LineBuffer::set_max_line_size`.
+    pub fn set_max_line_size(&mut self, max_line_size: uint) {
+        self.lines.set_max_line_size(max_line_size);
+    }
+
+    /// Changes what to do with bare `<LF>` and bare `<CR>`. By default, they are refused.
+    pub fn set_line_ending_policy(&mut self, policy: LineEndingPolicy) {
+        self.lines.set_line_ending_policy(policy);
+    }
+
+    /// Returns the policy for bare `<LF>` and bare `<CR>`.
+    pub fn line_ending_policy(&self) -> LineEndingPolicy {
+        self.lines.line_ending_policy()
+    }
+
+    /// Returns the part of the buffer where more input should be written. `filled` must then be
+    /// called with the number of bytes written.
+    pub fn space(&mut self) -> &mut [u8] {
+        self.lines.space()
+    }
+
+    /// Tells that `len` bytes of input have been written to `space`, and records them in the
+    /// transcript if there is one. If recording fails, the input is dropped.
+    pub fn filled(&mut self, len: uint) -> IoResult<()> {
+        match self.transcript {
+            Some(ref mut transcript) => {
+                let space = self.lines.buf.slice(self.lines.end, self.lines.end + len);
+                try!(transcript.record(TranscriptRead, space));
             },
+            None => {}
         }
-        index += 1;
+        self.lines.filled(len);
+        Ok(())
     }
 
-    None
+    /// Looks for the next line in the input given so far, see `LineBuffer::next_line`.
+    pub fn next_line(&mut self) -> Option<IoResult<LineEnding>> {
+        self.lines.next_line()
+    }
+
+    /// Returns the last line found by `next_line`, without its ending.
+    pub fn line(&self) -> &[u8] {
+        self.lines.line()
+    }
+
+    /// Returns the bytes to send for a line: the line itself ended with `<CRLF>`.
+    pub fn format_line(s: &str) -> String {
+        format!("{}\r\n", s)
+    }
+
+    /// Tells that a line formatted by `format_line` has been written, so that it is counted and
+    /// recorded in the transcript if there is one.
+    pub fn line_written(&mut self, line: &str) -> IoResult<()> {
+        self.bytes_written += line.len() as u64;
+        // The line ends with `<CRLF>`, so there is an empty string after the last one.
+        self.lines_written += line.split_str("\r\n").count() as u64 - 1;
+        match self.transcript {
+            Some(ref mut transcript) => transcript.record(TranscriptWritten, line.as_bytes()),
+            None => Ok(())
+        }
+    }
+}
+
+#[test]
+fn test_stream_buffer() {
+    use std::io::MemWriter;
+    use std::sync::{Arc, Mutex};
+    use super::transcript::read_transcript;
+    use std::io::BufReader;
+
+    // Keeps what is written in a buffer the test can look at afterwards.
+    struct SharedWriter {
+        output: Arc<Mutex<MemWriter>>
+    }
+    impl Writer for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> IoResult<()> {
+            self.output.lock().write(buf)
+        }
+    }
+
+    let output = Arc::new(Mutex::new(MemWriter::new()));
+    let mut buffer = SmtpStreamBuffer::new(16);
+    buffer.record_transcript(box SharedWriter { output: output.clone() });
+
+    let bytes = b"HELO a\r\nNO";
+    for i in range(0, bytes.len()) {
+        buffer.space()[i] = bytes[i];
+    }
+    buffer.filled(bytes.len()).unwrap();
+    assert_eq!(Some(Ok(Crlf)), buffer.next_line());
+    assert_eq!(b"HELO a", buffer.line());
+    assert!(buffer.next_line().is_none());
+
+    let line = SmtpStreamBuffer::format_line("250-rustastic.org\r\n250 DSN");
+    assert_eq!("250-rustastic.org\r\n250 DSN\r\n", line.as_slice());
+    buffer.line_written(line.as_slice()).unwrap();
+    assert_eq!(SmtpStreamStats {
+        bytes_read: 8,
+        bytes_written: 28,
+        lines_read: 1,
+        lines_written: 2
+    }, buffer.stats());
+
+    // The transcript has the input as it was given, not as lines.
+    let recorded = output.lock().get_ref().to_vec();
+    let entries = read_transcript(&mut BufReader::new(recorded.as_slice())).unwrap();
+    assert_eq!(2, entries.len());
+    assert_eq!(b"HELO a\r\nNO".to_vec(), entries[0].data);
+    assert_eq!(b"250-rustastic.org\r\n250 DSN\r\n".to_vec(), entries[1].data);
 }
 
 impl<S: Reader+Writer> SmtpStream<S> {
     /// Create a new `SmtpStream` from another stream.
-    pub fn new(inner: S, max_line_size: uint, debug: bool) -> SmtpStream<S> {
+    pub fn new(inner: S, max_line_size: uint) -> SmtpStream<S> {
         SmtpStream {
             stream: inner,
-            max_line_size: max_line_size,
-            // TODO: make line reading work even with a buffer smaller than the maximum line size.
-            // Currently, this will not work because we only fill the buffer once per line, assuming
-            // that the buffer is large enough.
-            buf: Vec::with_capacity(max_line_size),
-            debug: debug,
-            last_crlf: None
+            buffer: SmtpStreamBuffer::new(max_line_size)
         }
     }
 
-    /// Remove the previous line from the buffer when reading a new line.
-    fn move_buf(&mut self) {
-        // Remove the last line, since we've used it already by now.
-        match self.last_crlf {
-            Some(p) => {
-                // TODO: This could probably be optimised by shifting bytes instead
-                // of re-allocating.
-                self.buf = self.buf.as_slice().slice_from(p + 2).to_vec();
-                self.buf.reserve(self.max_line_size);
-            },
-            _ => {}
-        }
+    /// Returns the counters of what went through the stream so far.
+    pub fn stats(&self) -> SmtpStreamStats {
+        self.buffer.stats()
+    }
 
-        self.last_crlf = None;
+    /// Record all the bytes read and written from now on to `writer`, ie. a file. See the
+    /// `transcript` module for the format.
+    pub fn record_transcript(&mut self, writer: Box<Writer + Send>) {
+        self.buffer.record_transcript(writer);
     }
 
-    /// Fill the buffer to its limit.
-    fn fill_buf(&mut self) -> IoResult<uint> {
-        let len = self.buf.len();
-        let cap = self.buf.capacity();
+    /// Get the underlying stream back.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+
+    /// Returns the maximum size of the lines read by `read_line`, including `<CRLF>`.
+    pub fn max_line_size(&self) -> uint {
+        self.buffer.max_line_size()
+    }
 
-        // Read as much data as the buffer can hold without re-allocation.
-        let res = self.stream.push(cap - len, &mut self.buf);
+    /// Changes the maximum size of the lines read by `read_line`, including `<CRLF>`.
+    ///
+    /// See `LineBuffer::set_max_line_size`.
+    pub fn set_max_line_size(&mut self, max_line_size: uint) {
+        self.buffer.set_max_line_size(max_line_size);
+    }
 
-        res
+    /// Changes what to do with bare `<LF>` and bare `<CR>`. By default, they are refused.
+    pub fn set_line_ending_policy(&mut self, policy: LineEndingPolicy) {
+        self.buffer.set_line_ending_policy(policy);
     }
 
-    /// Read an SMTP command. Ends with `<CRLF>`.
+    /// Returns the policy for bare `<LF>` and bare `<CR>`.
+    pub fn line_ending_policy(&self) -> LineEndingPolicy {
+        self.buffer.line_ending_policy()
+    }
+
+    /// Read an SMTP command. Ends with `<CRLF>`, or a bare `<LF>` or `<CR>` depending on the
+    /// line ending policy.
+    ///
+    /// The stream is read as many times as needed to find `<CRLF>`, so lines may arrive in
+    /// several pieces. If no `<CRLF>` is found within `max_line_size` bytes, the line is too long.
+    /// In that case, the rest of the line is skipped by the next call, so that reading can go on
+    /// with the line after it. Lines refused because of a bare `<LF>` or `<CR>` are skipped
+    /// entirely.
     pub fn read_line(&mut self) -> IoResult<&[u8]> {
-        // Remove the previous line from the buffer before reading a new one.
-        self.move_buf();
+        self.read_line_with_ending().map(|(line, _)| line)
+    }
+
+    /// Read a line like `read_line`, but also tell how it ended. This is needed to check that
+    /// message data really ends with `<CRLF>.<CRLF>`.
+    pub fn read_line_with_ending(&mut self) -> IoResult<(&[u8], LineEnding)> {
+        let ending = try!(self.next_line());
+        Ok((self.buffer.line(), ending))
+    }
+
+    /// Read a line like `read_line_with_ending`, and also return the counters of the stream,
+    /// including this line.
+    pub fn read_line_with_stats(&mut self) -> (IoResult<(&[u8], LineEnding)>, SmtpStreamStats) {
+        let read = match self.next_line() {
+            Ok(ending) => Ok((self.buffer.line(), ending)),
+            Err(err) => Err(err)
+        };
+        (read, self.stats())
+    }
 
-        let read_line = match position_crlf(self.buf.as_slice()) {
+    // Find the next line, reading as much as needed. The line itself is then in `self.buffer`.
+    fn next_line(&mut self) -> IoResult<LineEnding> {
+        loop {
             // First, let's check if the buffer already contains a line. This
             // reduces the number of syscalls.
-            Some(last_crlf) => {
-                let s = self.buf.slice_to(last_crlf);
-                self.last_crlf = Some(last_crlf);
-                Ok(s)
-            },
-            // If we don't have a line in the buffer, we'll read more input
-            // and try again.
-            None => {
-                match self.fill_buf() {
-                    Ok(_) => {
-                        match position_crlf(self.buf.as_slice()) {
-                            Some(last_crlf) => {
-                                let s = self.buf.slice_to(last_crlf);
-                                self.last_crlf = Some(last_crlf);
-                                Ok(s)
-                            },
-                            None => {
-                                // If we didn't find a line, it means we had
-                                // no `<CRLF>` in the buffer, which means that
-                                // the line is too long.
-                                Err(IoError {
-                                    kind: InvalidInput,
-                                    desc: LINE_TOO_LONG,
-                                    detail: None
-                                })                                
-                            }
-                        }
-                    },
-                    Err(err) => {
-                        Err(err)
-                    }
-                }                
+            match self.buffer.next_line() {
+                Some(result) => return result,
+                None => {}
             }
-        };
 
-        // If we read a line, we'll say so in the console, if debug mode is on.
-        match read_line {
-            Ok(bytes) => {
-                if self.debug {
-                    println!("rsmtp: imsg: {}", String::from_utf8_lossy(bytes.as_slice()));
-                }
-            },
-            _ => {}
+            // If we don't have a line in the buffer, we'll read more input
+            // and try again.
+            let len = try!(self.stream.read(self.buffer.space()));
+            try!(self.buffer.filled(len));
         }
-
-        read_line
     }
 
     /// Write a line ended with `<CRLF>`.
     pub fn write_line(&mut self, s: &str) -> IoResult<()> {
-        if self.debug {
-            println!("rsmtp: omsg: {}", s);
-        }
         // We use `format!()` instead of 2 calls to `write_str()` to reduce
         // the amount of syscalls and to send the string as a single packet.
         // I'm not sure if this is the right way to go though. If you think
         // this is wrong, please open a issue on Github.
-        self.stream.write_str(format!("{}\r\n", s).as_slice())
+        let line = SmtpStreamBuffer::format_line(s);
+        try!(self.stream.write_str(line.as_slice()));
+        self.buffer.line_written(line.as_slice())
     }
 }
 
@@ -212,6 +678,80 @@ fn test_new() {
     // This method is already tested via `test_read_line()`.
 }
 
+// A stream which gives its input one byte at a time, like a very slow client would.
+#[cfg(test)]
+struct SlowStream {
+    input: Vec<u8>,
+    pos: uint
+}
+
+#[cfg(test)]
+impl SlowStream {
+    fn new(input: &str) -> SlowStream {
+        SlowStream {
+            input: input.as_bytes().to_vec(),
+            pos: 0
+        }
+    }
+}
+
+#[cfg(test)]
+impl Reader for SlowStream {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<uint> {
+        if self.pos >= self.input.len() {
+            return Err(standard_error(EndOfFile));
+        }
+        buf[0] = self.input[self.pos];
+        self.pos += 1;
+        Ok(1)
+    }
+}
+
+#[cfg(test)]
+impl Writer for SlowStream {
+    fn write(&mut self, _: &[u8]) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_read_line_slow_stream() {
+    let mut stream = SmtpStream::new(
+        SlowStream::new("HELO rustastic.org\r\nMAIL FROM:<rust@rustastic.org>\r\n"),
+        MIN_ALLOWED_TEXT_LINE_SIZE
+    );
+    assert_eq!(b"HELO rustastic.org", stream.read_line().unwrap());
+    assert_eq!(b"MAIL FROM:<rust@rustastic.org>", stream.read_line().unwrap());
+    assert_eq!(EndOfFile, stream.read_line().unwrap_err().kind);
+
+    // The line fits exactly, `<CRLF>` included.
+    let mut stream = SmtpStream::new(SlowStream::new("abc\r\nabcd\r\n"), 5);
+    assert_eq!(b"abc", stream.read_line().unwrap());
+    let err = stream.read_line().unwrap_err();
+    assert_eq!(InvalidInput, err.kind);
+    assert_eq!(LINE_TOO_LONG, err.desc);
+
+    // The limit can change between lines.
+    let mut stream = SmtpStream::new(SlowStream::new("abc\r\nabcdef\r\nabc\r\n"), 5);
+    assert_eq!(b"abc", stream.read_line().unwrap());
+    stream.set_max_line_size(8);
+    assert_eq!(8, stream.max_line_size());
+    assert_eq!(b"abcdef", stream.read_line().unwrap());
+    stream.set_max_line_size(4);
+    assert_eq!(LINE_TOO_LONG, stream.read_line().unwrap_err().desc);
+
+    // Many short lines go through the same small buffer.
+    let mut input = String::new();
+    for _ in range(0u, 100) {
+        input.push_str("ab\r\n");
+    }
+    let mut stream = SmtpStream::new(SlowStream::new(input.as_slice()), 5);
+    for _ in range(0u, 100) {
+        assert_eq!(b"ab", stream.read_line().unwrap());
+    }
+    assert_eq!(EndOfFile, stream.read_line().unwrap_err().kind);
+}
+
 #[test]
 fn test_write_line() {
     // Use a block so the file gets closed at the end of it.
@@ -222,7 +762,7 @@ fn test_write_line() {
 
         path_write = Path::new("tests/stream/write_line");
         file_write = File::open_mode(&path_write, Truncate, Write).unwrap();
-        stream = SmtpStream::new(file_write, MIN_ALLOWED_LINE_SIZE, false);
+        stream = SmtpStream::new(file_write, MIN_ALLOWED_TEXT_LINE_SIZE);
         stream.write_line("HelloWorld").unwrap();
         stream.write_line("ByeBye").unwrap();
     }
@@ -244,7 +784,7 @@ fn test_limits() {
 
     path = Path::new("tests/stream/1line1");
     file = File::open(&path).unwrap();
-    stream = SmtpStream::new(file, 3, false);
+    stream = SmtpStream::new(file, 3);
     match stream.read_line() {
         Ok(_) => fail!(),
         Err(err) => {
@@ -254,6 +794,77 @@ fn test_limits() {
     }
 }
 
+#[test]
+fn test_read_line_bare_line_endings() {
+    let input = "HELO a\r\nHELO b\nHELO c\rHELO d\r\n.\n";
+
+    // By default, lines with bare line endings are refused but the following lines can be read.
+    let mut stream = SmtpStream::new(SlowStream::new(input), MIN_ALLOWED_TEXT_LINE_SIZE);
+    assert_eq!(RejectBareLineEndings, stream.line_ending_policy());
+    assert_eq!(b"HELO a", stream.read_line().unwrap());
+    assert_eq!(BARE_LINE_ENDING, stream.read_line().unwrap_err().desc);
+    assert_eq!(BARE_LINE_ENDING, stream.read_line().unwrap_err().desc);
+    assert_eq!(BARE_LINE_ENDING, stream.read_line().unwrap_err().desc);
+    assert_eq!(EndOfFile, stream.read_line().unwrap_err().kind);
+
+    let mut stream = SmtpStream::new(SlowStream::new(input), MIN_ALLOWED_TEXT_LINE_SIZE);
+    stream.set_line_ending_policy(NormalizeBareLineEndings);
+    assert_eq!((b"HELO a", Crlf), stream.read_line_with_ending().unwrap());
+    assert_eq!((b"HELO b", BareLf), stream.read_line_with_ending().unwrap());
+    assert_eq!((b"HELO c", BareCr), stream.read_line_with_ending().unwrap());
+    assert_eq!((b"HELO d", Crlf), stream.read_line_with_ending().unwrap());
+    assert_eq!((b".", BareLf), stream.read_line_with_ending().unwrap());
+
+    let mut stream = SmtpStream::new(SlowStream::new(input), MIN_ALLOWED_TEXT_LINE_SIZE);
+    stream.set_line_ending_policy(AcceptBareLf);
+    assert_eq!((b"HELO a", Crlf), stream.read_line_with_ending().unwrap());
+    assert_eq!((b"HELO b", BareLf), stream.read_line_with_ending().unwrap());
+    assert_eq!(BARE_LINE_ENDING, stream.read_line().unwrap_err().desc);
+    assert_eq!((b".", BareLf), stream.read_line_with_ending().unwrap());
+}
+
+#[test]
+fn test_read_line_after_too_long() {
+    let mut path: Path;
+    let mut file: File;
+    let mut stream: SmtpStream<File>;
+
+    // The overlong line doesn't fit in the buffer at all.
+    path = Path::new("tests/stream/toolong1");
+    file = File::open(&path).unwrap();
+    stream = SmtpStream::new(file, 8);
+    assert_eq!(b"hi", stream.read_line().unwrap());
+    assert_eq!(LINE_TOO_LONG, stream.read_line().unwrap_err().desc);
+    assert_eq!(b"bye", stream.read_line().unwrap());
+    assert_eq!(EndOfFile, stream.read_line().unwrap_err().kind);
+
+    // The `<CRLF>` of the overlong line is split between two reads.
+    path = Path::new("tests/stream/toolong2");
+    file = File::open(&path).unwrap();
+    stream = SmtpStream::new(file, 8);
+    assert_eq!(b"hi", stream.read_line().unwrap());
+    assert_eq!(LINE_TOO_LONG, stream.read_line().unwrap_err().desc);
+    assert_eq!(b"bye", stream.read_line().unwrap());
+    assert_eq!(EndOfFile, stream.read_line().unwrap_err().kind);
+
+    // Several overlong lines in a row, read one byte at a time.
+    let mut stream = SmtpStream::new(
+        SlowStream::new("abcdefgh\r\nabcdefghijkl\r\nab\r\n"),
+        4
+    );
+    assert_eq!(LINE_TOO_LONG, stream.read_line().unwrap_err().desc);
+    assert_eq!(LINE_TOO_LONG, stream.read_line().unwrap_err().desc);
+    assert_eq!(b"ab", stream.read_line().unwrap());
+    assert_eq!(EndOfFile, stream.read_line().unwrap_err().kind);
+
+    // The limit is lowered below the size of the buffer.
+    let mut stream = SmtpStream::new(SlowStream::new("abc\r\nabcdef\r\nabc\r\n"), 16);
+    assert_eq!(b"abc", stream.read_line().unwrap());
+    stream.set_max_line_size(5);
+    assert_eq!(LINE_TOO_LONG, stream.read_line().unwrap_err().desc);
+    assert_eq!(b"abc", stream.read_line().unwrap());
+}
+
 #[test]
 fn test_read_line() {
     let mut path: Path;
@@ -263,34 +874,34 @@ fn test_read_line() {
 
     path = Path::new("tests/stream/0line1");
     file = File::open(&path).unwrap();
-    stream = SmtpStream::new(file, MIN_ALLOWED_LINE_SIZE, false);
+    stream = SmtpStream::new(file, MIN_ALLOWED_TEXT_LINE_SIZE);
     assert!(!stream.read_line().is_ok());
 
     path = Path::new("tests/stream/0line2");
     file = File::open(&path).unwrap();
-    stream = SmtpStream::new(file, MIN_ALLOWED_LINE_SIZE, false);
+    stream = SmtpStream::new(file, MIN_ALLOWED_TEXT_LINE_SIZE);
     assert!(!stream.read_line().is_ok());
 
     path = Path::new("tests/stream/0line3");
     file = File::open(&path).unwrap();
-    stream = SmtpStream::new(file, MIN_ALLOWED_LINE_SIZE, false);
+    stream = SmtpStream::new(file, MIN_ALLOWED_TEXT_LINE_SIZE);
     assert!(!stream.read_line().is_ok());
 
     path = Path::new("tests/stream/1line1");
     file = File::open(&path).unwrap();
-    stream = SmtpStream::new(file, MIN_ALLOWED_LINE_SIZE, false);
+    stream = SmtpStream::new(file, MIN_ALLOWED_TEXT_LINE_SIZE);
     assert_eq!(String::from_utf8_lossy(stream.read_line().unwrap().as_slice()).into_string().as_slice(), "hello world!");
     assert!(!stream.read_line().is_ok());
 
     path = Path::new("tests/stream/1line2");
     file = File::open(&path).unwrap();
-    stream = SmtpStream::new(file, MIN_ALLOWED_LINE_SIZE, false);
+    stream = SmtpStream::new(file, MIN_ALLOWED_TEXT_LINE_SIZE);
     assert_eq!(String::from_utf8_lossy(stream.read_line().unwrap().as_slice()).into_string().as_slice(), "hello world!");
     assert!(!stream.read_line().is_ok());
 
     path = Path::new("tests/stream/2lines1");
     file = File::open(&path).unwrap();
-    stream = SmtpStream::new(file, MIN_ALLOWED_LINE_SIZE, false);
+    stream = SmtpStream::new(file, MIN_ALLOWED_TEXT_LINE_SIZE);
     assert_eq!(String::from_utf8_lossy(stream.read_line().unwrap().as_slice()).into_string().as_slice(), "hello world!");
     assert_eq!(String::from_utf8_lossy(stream.read_line().unwrap().as_slice()).into_string().as_slice(), "bye bye world!");
     assert!(!stream.read_line().is_ok());
@@ -298,7 +909,7 @@ fn test_read_line() {
     expected = String::from_char(62, 'x');
     path = Path::new("tests/stream/xlines1");
     file = File::open(&path).unwrap();
-    stream = SmtpStream::new(file, MIN_ALLOWED_LINE_SIZE, false);
+    stream = SmtpStream::new(file, MIN_ALLOWED_TEXT_LINE_SIZE);
     assert_eq!(String::from_utf8_lossy(stream.read_line().unwrap().as_slice()).into_string(), expected);
     assert_eq!(String::from_utf8_lossy(stream.read_line().unwrap().as_slice()).into_string(), expected);
     assert_eq!(String::from_utf8_lossy(stream.read_line().unwrap().as_slice()).into_string(), expected);
@@ -310,3 +921,33 @@ fn test_read_line() {
     assert_eq!(String::from_utf8_lossy(stream.read_line().unwrap().as_slice()).into_string(), expected);
     assert!(!stream.read_line().is_ok());
 }
+
+#[test]
+fn test_stats() {
+    let mut stream = SmtpStream::new(SlowStream::new("HELO a\r\nabcdefgh\r\nNOOP\r\nQUIT"), 8);
+    assert_eq!(SmtpStreamStats {
+        bytes_read: 0,
+        bytes_written: 0,
+        lines_read: 0,
+        lines_written: 0
+    }, stream.stats());
+
+    let (read, stats) = stream.read_line_with_stats();
+    assert_eq!(b"HELO a", read.unwrap().val0());
+    assert_eq!(8, stats.bytes_read);
+    assert_eq!(1, stats.lines_read);
+
+    // Overlong lines are counted once, with all their bytes.
+    assert_eq!(LINE_TOO_LONG, stream.read_line().unwrap_err().desc);
+    assert_eq!(b"NOOP", stream.read_line().unwrap());
+    assert_eq!(24, stream.stats().bytes_read);
+    assert_eq!(3, stream.stats().lines_read);
+
+    // Input without `<CRLF>` is not counted.
+    assert_eq!(EndOfFile, stream.read_line().unwrap_err().kind);
+    assert_eq!(24, stream.stats().bytes_read);
+
+    stream.write_line("250-rustastic.org\r\n250 DSN").unwrap();
+    assert_eq!(28, stream.stats().bytes_written);
+    assert_eq!(2, stream.stats().lines_written);
+}
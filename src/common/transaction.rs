@@ -14,6 +14,9 @@
 
 //! Tools for managing the state of a connection between an SMTP client and an SMTP server.
 
+use super::path::{ReversePath, ForwardPath};
+use super::dsn::{DsnMailParams, DsnRcptParams};
+
 // TODO: make transaction states extendable, like:
 // Core(Init) and Custom(XMySmtpState) / Custom("X-MY-SMTP-STATE")
 
@@ -22,6 +25,8 @@
 /// This is useful for checking if an incoming SMTP command is allowed at any given moment
 /// during an SMTP transaction.
 #[deriving(PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde-support", deriving_serializable)]
+#[cfg_attr(feature = "serde-support", deriving_deserializable)]
 pub enum SmtpTransactionState {
     /// The initial state, when no commands have been sent by the client yet.
     Init,
@@ -53,3 +58,66 @@ impl SmtpTransactionState {
 fn test_smtp_transaction_state() {
     // fail!();
 }
+
+/// Represents a recipient of an SMTP transaction, as given with `RCPT`.
+#[deriving(PartialEq, Eq, Clone, Show)]
+#[cfg_attr(feature = "serde-support", deriving_serializable)]
+#[cfg_attr(feature = "serde-support", deriving_deserializable)]
+pub struct SmtpEnvelopeRecipient {
+    /// The forward-path of the recipient, as given by the client, unless the source route was
+    /// stripped.
+    pub path: ForwardPath,
+    /// The detail of the address, aka sub-address, if the server is configured with
+    /// sub-address separators and the address has one. ie. `billing` for
+    /// `support+billing@rustastic.org`.
+    pub detail: Option<String>,
+    /// The DSN parameters given with the recipient.
+    pub dsn: DsnRcptParams
+}
+
+/// Represents the envelope of an SMTP transaction, ie. everything that is known about a
+/// message besides its content.
+#[deriving(PartialEq, Eq, Clone, Show)]
+#[cfg_attr(feature = "serde-support", deriving_serializable)]
+#[cfg_attr(feature = "serde-support", deriving_deserializable)]
+pub struct SmtpEnvelope {
+    /// The reverse-path of the sender, as given with `MAIL`. It is `None` until `MAIL` has been
+    /// accepted.
+    pub sender: Option<ReversePath>,
+    /// The DSN parameters given with the sender.
+    pub dsn: DsnMailParams,
+    /// The recipients accepted so far.
+    pub recipients: Vec<SmtpEnvelopeRecipient>
+}
+
+impl SmtpEnvelope {
+    /// Creates an empty envelope.
+    pub fn new() -> SmtpEnvelope {
+        SmtpEnvelope {
+            sender: None,
+            dsn: DsnMailParams::new(),
+            recipients: vec!()
+        }
+    }
+
+    /// Reset the envelope, which should happen whenever the state is reset.
+    pub fn reset(&mut self) {
+        *self = SmtpEnvelope::new();
+    }
+}
+
+#[test]
+fn test_smtp_envelope() {
+    let mut envelope = SmtpEnvelope::new();
+    envelope.sender = Some(ReversePath::parse("<rust@rustastic.org>").unwrap());
+    envelope.dsn.envid = Some("QQ314159".into_string());
+    envelope.recipients.push(SmtpEnvelopeRecipient {
+        path: ForwardPath::parse("<@relay:bob+rust@rustastic.org>").unwrap(),
+        detail: Some("rust".into_string()),
+        dsn: DsnRcptParams::new()
+    });
+    assert!(envelope != SmtpEnvelope::new());
+
+    envelope.reset();
+    assert!(envelope == SmtpEnvelope::new());
+}
@@ -0,0 +1,147 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Recordings of the exact bytes read and written by an `SmtpStream`.
+//!
+//! A transcript file is a list of entries, in the order the bytes were read or written. Each
+//! entry is a header line, the bytes themselves and a `\n`:
+//!
+//! ```text
+//! R 1415183203042 20
+//! HELO rustastic.org
+//!
+//! ```
+//!
+//! The header contains `R` for bytes read or `W` for bytes written, the time in milliseconds
+//! since the Unix epoch and the number of bytes. Bytes are kept exactly as they were, so that
+//! bare line endings or lines split across several reads can be reproduced.
+
+use std::io::{Writer, Buffer, IoResult, EndOfFile, InvalidInput, standard_error};
+use time;
+
+/// Whether bytes were read from or written to the stream.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub enum TranscriptDirection {
+    /// Bytes read from the stream, ie. sent by the client on a server.
+    TranscriptRead,
+    /// Bytes written to the stream, ie. sent by the server on a server.
+    TranscriptWritten
+}
+
+/// A chunk of bytes read or written at once.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub struct TranscriptEntry {
+    /// Whether the bytes were read or written.
+    pub direction: TranscriptDirection,
+    /// When the bytes were read or written, in milliseconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The bytes themselves.
+    pub data: Vec<u8>
+}
+
+/// Writes transcript entries to an underlying writer, ie. a file.
+pub struct TranscriptWriter {
+    inner: Box<Writer + Send>
+}
+
+impl TranscriptWriter {
+    /// Create a new `TranscriptWriter` which writes to `inner`.
+    pub fn new(inner: Box<Writer + Send>) -> TranscriptWriter {
+        TranscriptWriter {
+            inner: inner
+        }
+    }
+
+    /// Record bytes which have just been read or written.
+    pub fn record(&mut self, direction: TranscriptDirection, data: &[u8]) -> IoResult<()> {
+        let now = time::get_time();
+        let timestamp = now.sec as u64 * 1000 + now.nsec as u64 / 1000000;
+        self.write_entry(direction, timestamp, data)
+    }
+
+    // Write an entry with a given timestamp.
+    fn write_entry(&mut self, direction: TranscriptDirection, timestamp: u64, data: &[u8]) -> IoResult<()> {
+        let direction = match direction {
+            TranscriptRead => 'R',
+            TranscriptWritten => 'W'
+        };
+        try!(self.inner.write_str(format!("{} {} {}\n", direction, timestamp, data.len()).as_slice()));
+        try!(self.inner.write(data));
+        try!(self.inner.write(b"\n"));
+        self.inner.flush()
+    }
+}
+
+/// Read all the entries of a transcript.
+pub fn read_transcript<B: Buffer>(reader: &mut B) -> IoResult<Vec<TranscriptEntry>> {
+    let mut entries = Vec::new();
+    loop {
+        let header = match reader.read_line() {
+            Ok(header) => header,
+            Err(ref err) if err.kind == EndOfFile => return Ok(entries),
+            Err(err) => return Err(err)
+        };
+        let parts: Vec<&str> = header.as_slice().trim_right().split(' ').collect();
+        if parts.len() != 3 {
+            return Err(standard_error(InvalidInput));
+        }
+        let direction = match parts[0] {
+            "R" => TranscriptRead,
+            "W" => TranscriptWritten,
+            _ => return Err(standard_error(InvalidInput))
+        };
+        let (timestamp, len) = match (from_str::<u64>(parts[1]), from_str::<uint>(parts[2])) {
+            (Some(timestamp), Some(len)) => (timestamp, len),
+            _ => return Err(standard_error(InvalidInput))
+        };
+        let data = try!(reader.read_exact(len));
+        // Skip the `\n` after the bytes.
+        try!(reader.read_byte());
+        entries.push(TranscriptEntry {
+            direction: direction,
+            timestamp: timestamp,
+            data: data
+        });
+    }
+}
+
+#[test]
+fn test_transcript() {
+    use std::io::{MemReader, BufferedReader, Truncate, Write};
+    use std::io::fs::File;
+
+    let path = Path::new("tests/transcript/write");
+    {
+        let file = File::open_mode(&path, Truncate, Write).unwrap();
+        let mut writer = TranscriptWriter::new(box file);
+        writer.write_entry(TranscriptWritten, 1415183203042, b"220 rustastic.org\r\n").unwrap();
+        writer.write_entry(TranscriptRead, 1415183203050, b"HELO rust").unwrap();
+        writer.write_entry(TranscriptRead, 1415183203051, b"astic.org\n").unwrap();
+    }
+    assert_eq!(
+        "W 1415183203042 19\n220 rustastic.org\r\n\nR 1415183203050 9\nHELO rust\nR 1415183203051 10\nastic.org\n\n",
+        File::open(&path).read_to_string().unwrap().as_slice()
+    );
+
+    let entries = read_transcript(&mut BufferedReader::new(File::open(&path).unwrap())).unwrap();
+    assert_eq!(3, entries.len());
+    assert_eq!(TranscriptWritten, entries[0].direction);
+    assert_eq!(1415183203042, entries[0].timestamp);
+    assert_eq!(b"220 rustastic.org\r\n", entries[0].data.as_slice());
+    assert_eq!(TranscriptRead, entries[2].direction);
+    assert_eq!(b"astic.org\n", entries[2].data.as_slice());
+
+    assert!(read_transcript(&mut MemReader::new(b"X 1 1\na\n".to_vec())).is_err());
+    assert!(read_transcript(&mut MemReader::new(b"R 1 10\na\n".to_vec())).is_err());
+}
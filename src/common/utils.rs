@@ -14,6 +14,8 @@
 
 //! Utility functions used in SMTP clients and SMTP servers.
 
+use std::fmt;
+
 /// Returns a completely unescaped version of a quoted string.
 ///
 /// This is useful for showing the email to a human, as it is easier to read.
@@ -49,8 +51,8 @@ fn test_unescape_quoted_string() {
 pub fn simplify_quoted_string(s: &str) -> String {
     let mut out = unescape_quoted_string(s);
 
-    // If we have a valid dot-string, return that.
-    if get_dot_string_len(out.as_slice()) == out.len() {
+    // If we have a valid dot-string, or nothing at all, return that.
+    if out.len() == 0 || get_dot_string_len(out.as_slice()) == Some(out.len()) {
         return out;
     }
 
@@ -94,44 +96,40 @@ fn test_simplify_quoted_string() {
 ///
 /// A subdomain is as described
 /// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.1.2).
-pub fn get_subdomain_len(s: &str) -> uint {
-    let mut i = 0u;
-    let mut confirmed_min = 0u;
-    if s.len() == 0 {
-        return 0
+pub fn get_subdomain_len(s: &str) -> Option<uint> {
+    if s.len() == 0 || !is_alnum(s.char_at(0)) {
+        return None;
     }
-    if is_alnum(s.char_at(0)) {
-        i += 1;
-        confirmed_min = i;
-        while i < s.len() {
-            if is_alnum(s.char_at(i)) {
+    let mut i = 1u;
+    let mut confirmed_min = 1u;
+    while i < s.len() {
+        if is_alnum(s.char_at(i)) {
+            i += 1;
+            confirmed_min = i;
+        } else if s.char_at(i) == '-' {
+            while i < s.len() && s.char_at(i) == '-' {
                 i += 1;
-                confirmed_min = i;
-            } else if s.char_at(i) == '-' {
-                while i < s.len() && s.char_at(i) == '-' {
-                    i += 1;
-                }
-            } else {
-                break;
             }
+        } else {
+            break;
         }
     }
-    confirmed_min
+    Some(confirmed_min)
 }
 
 #[test]
 fn test_get_subdomain_len() {
     // Allow alnum and dashes in the middle, no points.
-    assert_eq!(11, get_subdomain_len("helZo-4-you&&&"));
-    assert_eq!(11, get_subdomain_len("hePRo-4-you.abc"));
+    assert_eq!(Some(11), get_subdomain_len("helZo-4-you&&&"));
+    assert_eq!(Some(11), get_subdomain_len("hePRo-4-you.abc"));
 
     // Test with no content at the end.
-    assert_eq!(10, get_subdomain_len("5---a-U-65"));
-    assert_eq!(0, get_subdomain_len(""));
+    assert_eq!(Some(10), get_subdomain_len("5---a-U-65"));
+    assert_eq!(None, get_subdomain_len(""));
 
     // Disallow dash at the end.
-    assert_eq!(5, get_subdomain_len("heS1o-&&&"));
-    assert_eq!(0, get_subdomain_len("-hello-world"));
+    assert_eq!(Some(5), get_subdomain_len("heS1o-&&&"));
+    assert_eq!(None, get_subdomain_len("-hello-world"));
 }
 
 /// Returns the length of the longest domain found at the beginning of
@@ -139,43 +137,40 @@ fn test_get_subdomain_len() {
 ///
 /// A domain is as described
 /// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.1.2).
-pub fn get_domain_len(s: &str) -> uint {
-    // We don't need to check if s.len() == 0 since get_subdomain_len(s)
-    // already does it.
-    let mut confirmed_min = get_subdomain_len(s);
-    if confirmed_min > 0 {
-        while confirmed_min < s.len() && s.char_at(confirmed_min) == '.' {
-            let len = get_subdomain_len(s.slice_from(confirmed_min + 1));
-            if len > 0 {
-                confirmed_min += 1 + len;
-            } else {
-                break;
-            }
+pub fn get_domain_len(s: &str) -> Option<uint> {
+    let mut confirmed_min = match get_subdomain_len(s) {
+        Some(len) => len,
+        None => return None
+    };
+    while confirmed_min < s.len() && s.char_at(confirmed_min) == '.' {
+        match get_subdomain_len(s.slice_from(confirmed_min + 1)) {
+            Some(len) => confirmed_min += 1 + len,
+            None => break
         }
     }
-    confirmed_min
+    Some(confirmed_min)
 }
 
 #[test]
 fn test_get_domain_len() {
     // Invalid domain.
-    assert_eq!(0, get_domain_len(".hello"));
-    assert_eq!(0, get_domain_len(""));
-    assert_eq!(0, get_domain_len("----"));
+    assert_eq!(None, get_domain_len(".hello"));
+    assert_eq!(None, get_domain_len(""));
+    assert_eq!(None, get_domain_len("----"));
 
     // Valid domains with dots and dashes.
-    assert_eq!(18, get_domain_len("hello-rust.is.N1C3"));
-    assert_eq!(18, get_domain_len("hello-rust.is.N1C3."));
-    assert_eq!(18, get_domain_len("hello-rust.is.N1C3-"));
-    assert_eq!(18, get_domain_len("hello-rust.is.N1C3-."));
-    assert_eq!(18, get_domain_len("hello-rust.is.N1C3-&"));
-    assert_eq!(18, get_domain_len("hello-rust.is.N1C3.&"));
+    assert_eq!(Some(18), get_domain_len("hello-rust.is.N1C3"));
+    assert_eq!(Some(18), get_domain_len("hello-rust.is.N1C3."));
+    assert_eq!(Some(18), get_domain_len("hello-rust.is.N1C3-"));
+    assert_eq!(Some(18), get_domain_len("hello-rust.is.N1C3-."));
+    assert_eq!(Some(18), get_domain_len("hello-rust.is.N1C3-&"));
+    assert_eq!(Some(18), get_domain_len("hello-rust.is.N1C3.&"));
 
     // Valid domains without dashes.
-    assert_eq!(9, get_domain_len("hello.bla."));
+    assert_eq!(Some(9), get_domain_len("hello.bla."));
 
     // Valid domains without dots.
-    assert_eq!(9, get_domain_len("hello-bla."));
+    assert_eq!(Some(9), get_domain_len("hello-bla."));
 }
 
 /// Returns the length of the longest atom found at the beginning of
@@ -183,7 +178,7 @@ fn test_get_domain_len() {
 ///
 /// An atom is as described
 /// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.1.2).
-pub fn get_atom_len(s: &str) -> uint {
+pub fn get_atom_len(s: &str) -> Option<uint> {
     let mut len = 0u;
     while len < s.len() {
         if is_atext(s.char_at(len)) {
@@ -192,15 +187,19 @@ pub fn get_atom_len(s: &str) -> uint {
             break;
         }
     }
-    len
+    if len > 0 {
+        Some(len)
+    } else {
+        None
+    }
 }
 
 #[test]
 fn test_get_atom_len() {
-    assert_eq!(0, get_atom_len(" ---"));
-    assert_eq!(4, get_atom_len("!a{`\\"));
-    assert_eq!(4, get_atom_len("!a{`"));
-    assert_eq!(0, get_atom_len(""));
+    assert_eq!(None, get_atom_len(" ---"));
+    assert_eq!(Some(4), get_atom_len("!a{`\\"));
+    assert_eq!(Some(4), get_atom_len("!a{`"));
+    assert_eq!(None, get_atom_len(""));
 }
 
 /// Returns the length of the longest dot-string found at the beginning
@@ -208,30 +207,27 @@ fn test_get_atom_len() {
 ///
 /// A dot-string is as described
 /// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.1.2).
-pub fn get_dot_string_len(s: &str) -> uint {
-    // We don't need to check if s.len() == 0 since get_atom_len(s)
-    // already does it.
-    let mut confirmed_min = get_atom_len(s);
-    if confirmed_min > 0 {
-        while confirmed_min < s.len() && s.char_at(confirmed_min) == '.' {
-            let len = get_atom_len(s.slice_from(confirmed_min + 1));
-            if len > 0 {
-                confirmed_min += 1 + len;
-            } else {
-                break;
-            }
+pub fn get_dot_string_len(s: &str) -> Option<uint> {
+    let mut confirmed_min = match get_atom_len(s) {
+        Some(len) => len,
+        None => return None
+    };
+    while confirmed_min < s.len() && s.char_at(confirmed_min) == '.' {
+        match get_atom_len(s.slice_from(confirmed_min + 1)) {
+            Some(len) => confirmed_min += 1 + len,
+            None => break
         }
     }
-    confirmed_min
+    Some(confirmed_min)
 }
 
 #[test]
 fn test_get_dot_string_len() {
-    assert_eq!(0, get_dot_string_len(""));
-    assert_eq!(0, get_dot_string_len(" fwefwe"));
-    assert_eq!(10, get_dot_string_len("-`-.bla.ok "));
-    assert_eq!(10, get_dot_string_len("-`-.bla.ok"));
-    assert_eq!(10, get_dot_string_len("-`-.bla.ok."));
+    assert_eq!(None, get_dot_string_len(""));
+    assert_eq!(None, get_dot_string_len(" fwefwe"));
+    assert_eq!(Some(10), get_dot_string_len("-`-.bla.ok "));
+    assert_eq!(Some(10), get_dot_string_len("-`-.bla.ok"));
+    assert_eq!(Some(10), get_dot_string_len("-`-.bla.ok."));
 }
 
 /// Checks whether a character is valid `atext` as described
@@ -336,10 +332,10 @@ fn test_is_alnum() {
 ///
 /// A quoted-string is as described
 /// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.1.2).
-pub fn get_quoted_string_len(s: &str) -> uint {
+pub fn get_quoted_string_len(s: &str) -> Option<uint> {
     // We need at least "".
     if s.len() < 2 || s.char_at(0) != '"' {
-        return 0
+        return None;
     }
     // Length of 1 since we have the opening quote.
     let mut len = 1u;
@@ -356,27 +352,27 @@ pub fn get_quoted_string_len(s: &str) -> uint {
         }
     }
     if len < s.len() && s.char_at(len) == '"' {
-        len + 1
+        Some(len + 1)
     } else {
-        0
+        None
     }
 }
 
 #[test]
 fn test_get_quoted_string_len() {
     // Invalid.
-    assert_eq!(0, get_quoted_string_len(""));
-    assert_eq!(0, get_quoted_string_len(" "));
-    assert_eq!(0, get_quoted_string_len("  "));
-    assert_eq!(0, get_quoted_string_len(" \""));
-    assert_eq!(0, get_quoted_string_len(" \" \""));
-    assert_eq!(0, get_quoted_string_len("\""));
-    assert_eq!(0, get_quoted_string_len("\"Rust{\\\\\\\"\\a}\\stic"));
+    assert_eq!(None, get_quoted_string_len(""));
+    assert_eq!(None, get_quoted_string_len(" "));
+    assert_eq!(None, get_quoted_string_len("  "));
+    assert_eq!(None, get_quoted_string_len(" \""));
+    assert_eq!(None, get_quoted_string_len(" \" \""));
+    assert_eq!(None, get_quoted_string_len("\""));
+    assert_eq!(None, get_quoted_string_len("\"Rust{\\\\\\\"\\a}\\stic"));
 
     // Valid.
-    assert_eq!(2, get_quoted_string_len("\"\""));
-    assert_eq!(19, get_quoted_string_len("\"Rust{\\\\\\\"\\a}\\stic\""));
-    assert_eq!(19, get_quoted_string_len("\"Rust{\\\\\\\"\\a}\\stic\" "));
+    assert_eq!(Some(2), get_quoted_string_len("\"\""));
+    assert_eq!(Some(19), get_quoted_string_len("\"Rust{\\\\\\\"\\a}\\stic\""));
+    assert_eq!(Some(19), get_quoted_string_len("\"Rust{\\\\\\\"\\a}\\stic\" "));
 }
 
 /// Checks whether a character is valid `qtextSMTP` as described
@@ -426,28 +422,22 @@ fn test_is_quoted_pair_smtp() {
 ///
 /// An at-domain is as described
 /// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.1.2).
-pub fn get_at_domain_len(s: &str) -> uint {
+pub fn get_at_domain_len(s: &str) -> Option<uint> {
     if s.len() < 1 || s.char_at(0) != '@' {
-        return 0
+        return None;
     }
-    let len = get_domain_len(s.slice_from(1));
-
     // If we found a valid domain, we return its length plus 1 for the @.
-    if len > 0 {
-        len + 1
-    } else {
-        0
-    }
+    get_domain_len(s.slice_from(1)).map(|len| len + 1)
 }
 
 #[test]
 fn test_get_at_domain_len() {
-    assert_eq!(0, get_at_domain_len(""));
-    assert_eq!(0, get_at_domain_len("@"));
-    assert_eq!(0, get_at_domain_len("@@"));
-    assert_eq!(5, get_at_domain_len("@rust"));
-    assert_eq!(5, get_at_domain_len("@rust{}"));
-    assert_eq!(14, get_at_domain_len("@rustastic.org"));
+    assert_eq!(None, get_at_domain_len(""));
+    assert_eq!(None, get_at_domain_len("@"));
+    assert_eq!(None, get_at_domain_len("@@"));
+    assert_eq!(Some(5), get_at_domain_len("@rust"));
+    assert_eq!(Some(5), get_at_domain_len("@rust{}"));
+    assert_eq!(Some(14), get_at_domain_len("@rustastic.org"));
 }
 
 /// Returns the length of the source routes found at the beginning of
@@ -455,103 +445,328 @@ fn test_get_at_domain_len() {
 ///
 /// Source routes are as described
 /// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.1.2).
-pub fn get_source_route_len(s: &str) -> uint {
+pub fn get_source_route_len(s: &str) -> Option<uint> {
     // The total length we have found for source routes.
     let mut len = 0u;
 
-    // The length of the source route currently being checked in loop.
-    let mut curr_len;
-
     loop {
         // Get the current source route.
-        curr_len = get_at_domain_len(s.slice_from(len));
-        if curr_len > 0 {
-            len += curr_len;
-            // Check if another source route is coming, if not, stop looking
-            // for more source routes.
-            if len < s.len() && s.char_at(len) == ',' {
-                len += 1;
-            } else {
-                break;
-            }
-        } else {
-            break;
+        match get_at_domain_len(s.slice_from(len)) {
+            Some(curr_len) => {
+                len += curr_len;
+                // Check if another source route is coming, if not, stop looking
+                // for more source routes.
+                if len < s.len() && s.char_at(len) == ',' {
+                    len += 1;
+                } else {
+                    break;
+                }
+            },
+            None => break
         }
     }
 
     // Expect the source route declaration to end with ':'.
-    if len < s.len() && s.char_at(len) == ':' {
-        len + 1
+    if len > 0 && len < s.len() && s.char_at(len) == ':' {
+        Some(len + 1)
     } else {
-        0
+        None
     }
 }
 
 #[test]
 fn test_get_source_route_len() {
     // Invalid.
-    assert_eq!(0, get_source_route_len(""));
-    assert_eq!(0, get_source_route_len("@rust,"));
-    assert_eq!(0, get_source_route_len("@rust"));
-    assert_eq!(0, get_source_route_len("@,@:"));
-    assert_eq!(0, get_source_route_len("@rust,@troll"));
-    assert_eq!(0, get_source_route_len("@rust,@tro{ll:"));
+    assert_eq!(None, get_source_route_len(""));
+    assert_eq!(None, get_source_route_len(":"));
+    assert_eq!(None, get_source_route_len("@rust,"));
+    assert_eq!(None, get_source_route_len("@rust"));
+    assert_eq!(None, get_source_route_len("@,@:"));
+    assert_eq!(None, get_source_route_len("@rust,@troll"));
+    assert_eq!(None, get_source_route_len("@rust,@tro{ll:"));
 
     // Valid.
-    assert_eq!(13, get_source_route_len("@rust,@troll:"));
-    assert_eq!(16, get_source_route_len("@rust.is,@troll:"));
+    assert_eq!(Some(13), get_source_route_len("@rust,@troll:"));
+    assert_eq!(Some(16), get_source_route_len("@rust.is,@troll:"));
 }
 
-/// If the string starts with an ipv6 as present in email addresses, ie `[Ipv6:...]`, get its
-/// length. Else return `0`.
-pub fn get_possible_ipv6_len(ip: &str) -> uint {
-    if ip.len() < 7 || ip.slice_to(6) != "[Ipv6:" {
-        0
-    } else {
-        let mut i = 6u;
-        while i < ip.len() && ip.char_at(i) != ']' {
-            i += 1;
+/// Returns the length of the path found at the beginning of the passed string, ie `<...>`,
+/// including the angle brackets.
+///
+/// The content of the path is not validated. A `>` inside a quoted-string does not end the
+/// path though, so that what follows the path, like ESMTP parameters, can be found reliably.
+pub fn get_path_len(s: &str) -> Option<uint> {
+    let bytes = s.as_bytes();
+    if bytes.len() < 2 || bytes[0] != '<' as u8 {
+        return None;
+    }
+    let mut quoted = false;
+    let mut i = 1u;
+    while i < bytes.len() {
+        if quoted && bytes[i] == '\\' as u8 {
+            // Skip the escaped char, whatever it is.
+            i += 2;
+            continue;
         }
-        if i < ip.len() && ip.char_at(i) == ']' {
-            i + 1
-        } else {
-            0
+        if bytes[i] == '"' as u8 {
+            quoted = !quoted;
+        } else if !quoted && bytes[i] == '>' as u8 {
+            return Some(i + 1);
         }
+        i += 1;
     }
+    None
 }
 
 #[test]
-fn test_get_possible_ipv6_len() {
-    assert_eq!(10, get_possible_ipv6_len("[Ipv6:434]"));
-    assert_eq!(10, get_possible_ipv6_len("[Ipv6:434][]"));
-    assert_eq!(0, get_possible_ipv6_len("[Ipv6:434"));
-    assert_eq!(7, get_possible_ipv6_len("[Ipv6:]"));
-    assert_eq!(7, get_possible_ipv6_len("[Ipv6:]a"));
-    assert_eq!(0, get_possible_ipv6_len("[Ipv"));
-}
-
-/// If the string starts with an ipv4 as present in email addresses, ie `[...]`, get its
-/// length. Else return `0`.
-pub fn get_possible_ipv4_len(ip: &str) -> uint {
-    if ip.len() < 3 || ip.char_at(0) != '[' || ip.char_at(1) > '9' || ip.char_at(1) < '0' {
-        0
-    } else {
-        let mut i = 1u;
-        while i < ip.len() && ip.char_at(i) != ']' {
+fn test_get_path_len() {
+    // Invalid.
+    assert_eq!(None, get_path_len(""));
+    assert_eq!(None, get_path_len("<"));
+    assert_eq!(None, get_path_len("rust@rustastic.org>"));
+    assert_eq!(None, get_path_len("<rust@rustastic.org"));
+    assert_eq!(None, get_path_len("<\"rust>\"@rustastic.org"));
+
+    // Valid.
+    assert_eq!(Some(2), get_path_len("<>"));
+    assert_eq!(Some(2), get_path_len("<> RET=FULL"));
+    assert_eq!(Some(20), get_path_len("<rust@rustastic.org> RET=FULL"));
+    assert_eq!(Some(23), get_path_len("<\"rust>\"@rustastic.org>"));
+    assert_eq!(Some(25), get_path_len("<\"ru\\\"st>\"@rustastic.org> NOTIFY=NEVER"));
+}
+
+/// Splits ESMTP parameters, as found after the path of `MAIL` and `RCPT` commands, into
+/// keywords and optional values.
+///
+/// ESMTP parameters are as described
+/// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-4.1.2).
+pub fn split_esmtp_params<'a>(s: &'a str) -> Vec<(&'a str, Option<&'a str>)> {
+    let mut params = vec!();
+    for param in s.split(' ') {
+        if param.len() == 0 {
+            continue;
+        }
+        match param.find('=') {
+            Some(pos) => params.push((param.slice_to(pos), Some(param.slice_from(pos + 1)))),
+            None => params.push((param, None))
+        }
+    }
+    params
+}
+
+#[test]
+fn test_split_esmtp_params() {
+    assert_eq!(0, split_esmtp_params("").len());
+    assert_eq!(0, split_esmtp_params("  ").len());
+    assert_eq!(
+        vec!(("RET", Some("FULL")), ("BODY", Some("")), ("SMTPUTF8", None)),
+        split_esmtp_params(" RET=FULL  BODY= SMTPUTF8")
+    );
+    assert_eq!(vec!(("ORCPT", Some("rfc822;a=b"))), split_esmtp_params("ORCPT=rfc822;a=b"));
+}
+
+/// Checks whether a character is a valid `xchar` as described
+/// [in RFC 3461](http://tools.ietf.org/html/rfc3461#section-4).
+pub fn is_xchar(c: char) -> bool {
+    match c as int {
+        33 ... 42 | 44 ... 60 | 62 ... 126 => true,
+        _ => false
+    }
+}
+
+#[test]
+fn test_is_xchar() {
+    assert!(!is_xchar(' '));
+    assert!(is_xchar('!'));
+    assert!(is_xchar('*'));
+    assert!(!is_xchar('+'));
+    assert!(is_xchar(','));
+    assert!(is_xchar('<'));
+    assert!(!is_xchar('='));
+    assert!(is_xchar('>'));
+    assert!(is_xchar('~'));
+    assert!(!is_xchar(127 as char));
+}
+
+/// Decodes a string encoded as `xtext` as described
+/// [in RFC 3461](http://tools.ietf.org/html/rfc3461#section-4).
+///
+/// Returns `None` if the string is not valid `xtext` or if it does not decode to UTF-8.
+pub fn decode_xtext(s: &str) -> Option<String> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0u;
+    while i < bytes.len() {
+        if bytes[i] == '+' as u8 {
+            if i + 2 >= bytes.len() {
+                return None;
+            }
+            match (get_hexchar_value(bytes[i + 1] as char), get_hexchar_value(bytes[i + 2] as char)) {
+                (Some(high), Some(low)) => {
+                    out.push(high * 16 + low);
+                    i += 3;
+                },
+                _ => return None
+            }
+        } else if is_xchar(bytes[i] as char) {
+            out.push(bytes[i]);
             i += 1;
+        } else {
+            return None;
         }
-        if i < ip.len() && ip.char_at(i) == ']' {
-            i + 1
+    }
+    String::from_utf8(out).ok()
+}
+
+// Get the value of an uppercase hexadecimal digit, as used in `xtext`.
+fn get_hexchar_value(c: char) -> Option<u8> {
+    match c {
+        '0' ... '9' => Some(c as u8 - '0' as u8),
+        'A' ... 'F' => Some(c as u8 - 'A' as u8 + 10),
+        _ => None
+    }
+}
+
+#[test]
+fn test_decode_xtext() {
+    assert_eq!(Some("".into_string()), decode_xtext(""));
+    assert_eq!(Some("rust@rustastic.org".into_string()), decode_xtext("rust@rustastic.org"));
+    assert_eq!(Some("a+b=c d".into_string()), decode_xtext("a+2Bb+3Dc+20d"));
+    assert_eq!(None, decode_xtext("a+2bb"));
+    assert_eq!(None, decode_xtext("a+2"));
+    assert_eq!(None, decode_xtext("a+"));
+    assert_eq!(None, decode_xtext("a=b"));
+    assert_eq!(None, decode_xtext("a b"));
+    assert_eq!(None, decode_xtext("+FF"));
+}
+
+/// Encodes a string as `xtext` as described
+/// [in RFC 3461](http://tools.ietf.org/html/rfc3461#section-4).
+pub fn encode_xtext(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for byte in s.as_bytes().iter() {
+        if is_xchar(*byte as char) {
+            out.push(*byte as char);
         } else {
-            0
+            out.push_str(format!("+{:02X}", *byte).as_slice());
         }
     }
+    out
+}
+
+#[test]
+fn test_encode_xtext() {
+    assert_eq!("", encode_xtext("").as_slice());
+    assert_eq!("rust@rustastic.org", encode_xtext("rust@rustastic.org").as_slice());
+    assert_eq!("a+2Bb+3Dc+20d", encode_xtext("a+b=c d").as_slice());
+    assert_eq!("+C3+A9", encode_xtext("é").as_slice());
+}
+
+/// Represents an error that occured while parsing, ie. where parsing failed and what was
+/// expected there.
+#[deriving(PartialEq, Eq, Clone)]
+pub struct ParseError {
+    /// The position in the input where parsing failed, in bytes.
+    pub position: uint,
+    /// A description of what was expected at this position, ie. `"domain"`.
+    pub expected: &'static str
+}
+
+impl ParseError {
+    /// Creates a parse error.
+    pub fn new(position: uint, expected: &'static str) -> ParseError {
+        ParseError {
+            position: position,
+            expected: expected
+        }
+    }
+
+    /// Returns the same error with its position moved forward by `offset`.
+    ///
+    /// This is useful when the input of a parser is only a part of the input of the caller.
+    pub fn offset(self, offset: uint) -> ParseError {
+        ParseError::new(self.position + offset, self.expected)
+    }
+}
+
+impl fmt::Show for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected {} at position {}", self.expected, self.position)
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    let err = ParseError::new(3, "domain");
+    assert_eq!(ParseError::new(10, "domain"), err.clone().offset(7));
+    assert_eq!("expected domain at position 3", format!("{}", err).as_slice());
+}
+
+/// The result of a parser: either the parsed value and the rest of the input, or the position
+/// where parsing failed, relative to the start of the input.
+pub type ParseResult<'a, T> = Result<(T, &'a str), ParseError>;
+
+/// Turns the length of something found at the beginning of a string into a `ParseResult`.
+fn parse_len<'a>(s: &'a str, len: Option<uint>, expected: &'static str) -> ParseResult<'a, &'a str> {
+    match len {
+        Some(len) => Ok((s.slice_to(len), s.slice_from(len))),
+        None => Err(ParseError::new(0, expected))
+    }
+}
+
+/// Parses the domain at the beginning of the passed string, as for `get_domain_len`.
+pub fn parse_domain<'a>(s: &'a str) -> ParseResult<'a, &'a str> {
+    parse_len(s, get_domain_len(s), "domain")
+}
+
+#[test]
+fn test_parse_domain() {
+    assert_eq!(Ok(("rustastic.org", " ok")), parse_domain("rustastic.org ok"));
+    assert_eq!(Err(ParseError::new(0, "domain")), parse_domain(".rustastic.org"));
+}
+
+/// Parses the dot-string at the beginning of the passed string, as for `get_dot_string_len`.
+pub fn parse_dot_string<'a>(s: &'a str) -> ParseResult<'a, &'a str> {
+    parse_len(s, get_dot_string_len(s), "dot-string")
+}
+
+#[test]
+fn test_parse_dot_string() {
+    assert_eq!(Ok(("rust.is", "@rustastic.org")), parse_dot_string("rust.is@rustastic.org"));
+    assert_eq!(Err(ParseError::new(0, "dot-string")), parse_dot_string("\"rust is\""));
+}
+
+/// Parses the quoted-string at the beginning of the passed string, as for
+/// `get_quoted_string_len`.
+pub fn parse_quoted_string<'a>(s: &'a str) -> ParseResult<'a, &'a str> {
+    parse_len(s, get_quoted_string_len(s), "quoted-string")
+}
+
+#[test]
+fn test_parse_quoted_string() {
+    assert_eq!(Ok(("\"rust is\"", "@rustastic.org")), parse_quoted_string("\"rust is\"@rustastic.org"));
+    assert_eq!(Err(ParseError::new(0, "quoted-string")), parse_quoted_string("\"rust is"));
+}
+
+/// Parses the source routes at the beginning of the passed string, as for
+/// `get_source_route_len`.
+pub fn parse_source_route<'a>(s: &'a str) -> ParseResult<'a, &'a str> {
+    parse_len(s, get_source_route_len(s), "source route")
+}
+
+#[test]
+fn test_parse_source_route() {
+    assert_eq!(Ok(("@rust,@troll:", "rust@rustastic.org")), parse_source_route("@rust,@troll:rust@rustastic.org"));
+    assert_eq!(Err(ParseError::new(0, "source route")), parse_source_route("rust@rustastic.org"));
+}
+
+/// Parses the path at the beginning of the passed string, as for `get_path_len`.
+pub fn parse_path<'a>(s: &'a str) -> ParseResult<'a, &'a str> {
+    parse_len(s, get_path_len(s), "path")
 }
 
 #[test]
-fn test_get_possible_ipv4_len() {
-    assert_eq!(0, get_possible_ipv4_len("[Ipv6:]"));
-    assert_eq!(3, get_possible_ipv4_len("[1]"));
-    assert_eq!(3, get_possible_ipv4_len("[1]1"));
-    assert_eq!(0, get_possible_ipv4_len("[]"));
+fn test_parse_path() {
+    assert_eq!(Ok(("<rust@rustastic.org>", " RET=FULL")), parse_path("<rust@rustastic.org> RET=FULL"));
+    assert_eq!(Err(ParseError::new(0, "path")), parse_path("rust@rustastic.org"));
 }
@@ -24,10 +24,15 @@
 //! extern crate rsmtp;
 //!
 //! use rsmtp::server::{SmtpServer, SmtpServerEventHandler, SmtpServerConfig};
-//! use rsmtp::common::mailbox::Mailbox;
+//! use rsmtp::common::path::ReversePath;
+//! use rsmtp::common::dsn::DsnMailParams;
+//! use rsmtp::common::status::EnhancedStatusCode;
+//! use rsmtp::common::stream::RejectBareLineEndings;
+//! use rsmtp::common::log::{LogDebug, StdoutLogSink};
 //! use rsmtp::common::{
 //!     MIN_ALLOWED_MESSAGE_SIZE,
-//!     MIN_ALLOWED_LINE_SIZE,
+//!     MIN_ALLOWED_COMMAND_LINE_SIZE,
+//!     MIN_ALLOWED_TEXT_LINE_SIZE,
 //!     MIN_ALLOWED_RECIPIENTS
 //! };
 //! use std::io::net::ip::IpAddr;
@@ -39,7 +44,7 @@
 //!     fn handle_connection(&mut self, client_ip: &IpAddr) -> Result<(), ()> {
 //!         Ok(())
 //!     }
-//!     fn handle_sender_address(&mut self, mailbox: Option<&Mailbox>) -> Result<(), ()> {
+//!     fn handle_sender_address(&mut self, reverse_path: &ReversePath, dsn: &DsnMailParams) -> Result<(), Option<EnhancedStatusCode>> {
 //!         Ok(())
 //!     }
 //! }
@@ -51,20 +56,44 @@
 //!         port: 25,
 //!         max_recipients: MIN_ALLOWED_RECIPIENTS,
 //!         max_message_size: MIN_ALLOWED_MESSAGE_SIZE,
-//!         max_line_size: MIN_ALLOWED_LINE_SIZE,
-//!         debug: true
+//!         max_command_line_size: MIN_ALLOWED_COMMAND_LINE_SIZE,
+//!         max_text_line_size: MIN_ALLOWED_TEXT_LINE_SIZE,
+//!         extensions: vec!(),
+//!         vrfy_enabled: false,
+//!         expn_enabled: false,
+//!         help_topics: vec!(),
+//!         subaddress_separators: vec!(),
+//!         line_ending_policy: RejectBareLineEndings,
+//!         log_level: LogDebug,
+//!         log_sink: box StdoutLogSink,
+//!         log_redact_auth: true,
+//!         log_message_data: false,
+//!         transcript_dir: None,
+//!         proxy_protocol: None
 //!     };
 //!     let mut server = SmtpServer::new(config, Handler).unwrap();
 //!     server.run();
 //! }
 //! ```
 
+#![feature(struct_variant)]
+#![feature(phase, macro_rules)]
+
 #![deny(unnecessary_qualification)]
 #![deny(non_uppercase_statics)]
 #![deny(unnecessary_typecast)]
 #![deny(missing_doc)]
 #![deny(unused_result)]
 
+#[cfg(feature = "serde-support")]
+#[phase(plugin)]
+extern crate serde_macros;
+#[cfg(feature = "serde-support")]
+extern crate serde;
+#[cfg(feature = "async")]
+extern crate mio;
+extern crate time;
+
 pub mod client;
 pub mod common;
 pub mod server;
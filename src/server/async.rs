@@ -0,0 +1,532 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An SMTP server which handles all its clients on a single event loop, only built with the
+//! `async` feature.
+//!
+//! `SmtpServer` uses a thread per client, which is simple but does not scale to thousands of
+//! mostly idle clients. `AsyncSmtpServer` uses non-blocking sockets instead, and runs the same
+//! commands, state machine and event handler as `SmtpServer`. To use several threads, run
+//! several servers on the same port, ie. with `SO_REUSEPORT`, or on different ports.
+
+use std::sync::Arc;
+use std::io::{IoResult, InvalidInput, EndOfFile, standard_error};
+use std::io::net::ip::{IpAddr, Ipv4Addr, Ipv6Addr};
+use mio::{EventLoop, Handler, Token, ReadHint, IoReader, IoWriter, IoAcceptor};
+use mio::{Interest, PollOpt, Ready, WouldBlock};
+use mio::net::InetAddr;
+use mio::net::tcp::{TcpSocket, TcpAcceptor};
+use mio::util::Slab;
+use super::{SmtpServerConfig, SmtpServerEventHandler, SmtpServerError};
+use super::{BindFailed, ListenFailed};
+use super::{check_config, log_record, create_transcript};
+use super::handler;
+use super::session::{SmtpServerSession, SessionReply, SessionContinue, SessionClose};
+use super::proxy::{ProxyHeaderParse, ProxyIncomplete, ProxyComplete};
+use super::proxy::{ProxyHeaderError, ProxyIoError, parse_proxy_header};
+use super::super::common::stream::SmtpStreamBuffer;
+use super::super::common::log::{LogDebug, LogInfo, LogWarning, LogEvent};
+
+// The token of the listening socket. Clients get the following ones.
+static ACCEPTOR: Token = Token(0);
+
+// The maximum number of clients handled at the same time.
+static MAX_CLIENTS: uint = 65536;
+
+// The number of bytes of replies waiting to be written to a client above which its input is not
+// handled anymore until it reads them.
+static MAX_PENDING_OUTPUT: uint = 65536;
+
+// A client and the state of its session.
+struct AsyncSmtpClient<E: SmtpServerEventHandler> {
+    socket: TcpSocket,
+    session_id: u64,
+    peer_ip: IpAddr,
+    // The PROXY protocol header read so far, if the client is a trusted load balancer.
+    proxy_header: Vec<u8>,
+    // Splits the input into lines, counts bytes and lines and records the transcript, exactly
+    // like `SmtpStream` does for `SmtpServer`.
+    buffer: SmtpStreamBuffer,
+    // Only started once the PROXY protocol header, if any, has been read.
+    session: Option<SmtpServerSession<E>>,
+    // Replies which could not be written to the socket yet, starting at `output_pos`.
+    output: Vec<u8>,
+    output_pos: uint,
+    // If `true`, the connection is closed once `output` has been written.
+    closing: bool
+}
+
+impl<E: SmtpServerEventHandler> AsyncSmtpClient<E> {
+    // Queue a reply to be written to the client.
+    fn write_line(&mut self, s: &str) {
+        let line = SmtpStreamBuffer::format_line(s);
+        self.output.push_all(line.as_bytes());
+        // Like with `SmtpStream`, a transcript which can't be written ends the session.
+        if self.buffer.line_written(line.as_slice()).is_err() {
+            self.closing = true;
+        }
+    }
+
+    // Returns the number of bytes of replies which have not been written yet.
+    fn pending_output(&self) -> uint {
+        self.output.len() - self.output_pos
+    }
+
+    // Read the PROXY protocol header. Only the bytes the header still needs are read, so that
+    // none of the SMTP input is consumed.
+    fn read_proxy_header(&mut self) -> Result<ProxyHeaderParse, ProxyHeaderError> {
+        loop {
+            let missing = match try!(parse_proxy_header(self.proxy_header.as_slice())) {
+                ProxyIncomplete(missing) => missing,
+                complete => return Ok(complete)
+            };
+            let mut buf = Vec::from_elem(missing, 0u8);
+            match self.socket.read_slice(buf.as_mut_slice()) {
+                Ok(Ready(0)) => return Err(ProxyIoError(standard_error(EndOfFile))),
+                Ok(Ready(len)) => self.proxy_header.push_all(buf.slice_to(len)),
+                Ok(WouldBlock) => return Ok(ProxyIncomplete(missing)),
+                Err(err) => return Err(ProxyIoError(err.as_io_error()))
+            }
+        }
+    }
+
+    // Read as much input as possible and handle the lines it contains. Returns `false` once
+    // the connection must be closed.
+    fn read(&mut self) -> bool {
+        loop {
+            // First, handle the lines already in the buffer.
+            loop {
+                // A client which sends commands without reading the replies is not read from
+                // until it catches up, so that the replies don't pile up in memory.
+                if self.pending_output() > MAX_PENDING_OUTPUT {
+                    if !self.flush() {
+                        return false;
+                    }
+                    if self.pending_output() > MAX_PENDING_OUTPUT {
+                        return true;
+                    }
+                }
+
+                let action = match self.buffer.next_line() {
+                    Some(Ok(ending)) => {
+                        let stats = self.buffer.stats();
+                        self.session.as_mut().unwrap().handle_read(Ok((self.buffer.line(), ending)), stats)
+                    },
+                    Some(Err(err)) => {
+                        let stats = self.buffer.stats();
+                        self.session.as_mut().unwrap().handle_read(Err(err), stats)
+                    },
+                    None => break
+                };
+                match action {
+                    SessionReply(msg) => {
+                        self.write_line(msg.as_slice());
+                        if self.closing {
+                            return self.flush();
+                        }
+                    },
+                    SessionContinue => {},
+                    SessionClose(msg) => {
+                        match msg {
+                            Some(msg) => self.write_line(msg.as_slice()),
+                            None => {}
+                        }
+                        self.closing = true;
+                        return self.flush();
+                    }
+                }
+                // Commands may have changed the limit, ie. `DATA` to read text lines.
+                self.buffer.set_max_line_size(self.session.as_ref().unwrap().max_line_size());
+            }
+
+            // Then, read more input until the socket has none left. If the client is gone, the
+            // session is told when the client is closed.
+            let len = match self.socket.read_slice(self.buffer.space()) {
+                Ok(Ready(0)) => return false,
+                Ok(Ready(len)) => len,
+                Ok(WouldBlock) => return self.flush(),
+                Err(_) => return false
+            };
+            if self.buffer.filled(len).is_err() {
+                return false;
+            }
+        }
+    }
+
+    // Write as much of the pending output as possible. Returns `false` once the connection
+    // must be closed.
+    fn flush(&mut self) -> bool {
+        while self.pending_output() > 0 {
+            match self.socket.write_slice(self.output.slice_from(self.output_pos)) {
+                Ok(Ready(len)) => self.output_pos += len,
+                // We'll be told when the socket is writable again.
+                Ok(WouldBlock) => return true,
+                Err(_) => return false
+            }
+        }
+        // Everything has been written, the buffer can be reused without moving bytes around.
+        self.output.clear();
+        self.output_pos = 0;
+        !self.closing
+    }
+}
+
+/// An SMTP server which handles all its clients on a single event loop.
+pub struct AsyncSmtpServer<E: SmtpServerEventHandler> {
+    acceptor: TcpAcceptor,
+    config: Arc<SmtpServerConfig>,
+    // Cloned for each client, like with `SmtpServer`.
+    event_handler: E,
+    handlers: Arc<Vec<handler::SmtpHandler<E>>>,
+    clients: Slab<AsyncSmtpClient<E>>,
+    // The ID of the next session, to tell sessions apart in logs.
+    next_session_id: u64
+}
+
+impl<E: SmtpServerEventHandler + Clone> AsyncSmtpServer<E> {
+    /// Creates a new SMTP server that listens on the IP and port set in the config.
+    pub fn new(config: SmtpServerConfig, event_handler: E) -> Result<AsyncSmtpServer<E>, SmtpServerError> {
+        try!(check_config(&config));
+
+        // Unlike `TcpListener`, the socket is created before binding, so it must already be of
+        // the family of the address.
+        let ip = match from_str::<IpAddr>(config.ip) {
+            Some(ip) => ip,
+            None => return Err(BindFailed(standard_error(InvalidInput)))
+        };
+        let addr = InetAddr(ip, config.port);
+        let socket = match ip {
+            Ipv4Addr(..) => TcpSocket::v4(),
+            Ipv6Addr(..) => TcpSocket::v6()
+        };
+        let socket = match socket {
+            Ok(socket) => socket,
+            Err(err) => return Err(BindFailed(err.as_io_error()))
+        };
+        match socket.bind(&addr) {
+            Ok(()) => {
+                log_record(&config, LogInfo, LogEvent, None, format!("binding on ip {}", config.ip).as_slice());
+            },
+            Err(err) => return Err(BindFailed(err.as_io_error()))
+        }
+        let acceptor = match socket.listen(256) {
+            Ok(acceptor) => {
+                log_record(&config, LogInfo, LogEvent, None, format!("listening on port {}", config.port).as_slice());
+                acceptor
+            },
+            Err(err) => return Err(ListenFailed(err.as_io_error()))
+        };
+
+        Ok(AsyncSmtpServer {
+            acceptor: acceptor,
+            config: Arc::new(config),
+            event_handler: event_handler,
+            handlers: Arc::new(handler::get_handlers::<E>()),
+            clients: Slab::new_starting_at(Token(1), MAX_CLIENTS),
+            next_session_id: 1
+        })
+    }
+
+    /// Run the SMTP server on the current thread.
+    pub fn run(self) -> IoResult<()> {
+        let mut event_loop = match EventLoop::new() {
+            Ok(event_loop) => event_loop,
+            Err(err) => return Err(err.as_io_error())
+        };
+        match event_loop.register_opt(&self.acceptor, ACCEPTOR, Interest::readable(), PollOpt::edge()) {
+            Ok(()) => {},
+            Err(err) => return Err(err.as_io_error())
+        }
+        match event_loop.run(self) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err.error.as_io_error())
+        }
+    }
+
+    // Accept all the pending clients.
+    fn accept(&mut self, event_loop: &mut EventLoop<uint, ()>) {
+        loop {
+            let socket = match self.acceptor.accept() {
+                Ok(Ready(socket)) => socket,
+                // Ignore accept error. Is this right? If you think not, please open an issue on Github.
+                Ok(WouldBlock) | Err(_) => return
+            };
+
+            let peer_ip = match socket.peer_addr() {
+                Ok(InetAddr(ip, _)) => ip,
+                _ => continue
+            };
+            let session_id = self.next_session_id;
+            self.next_session_id += 1;
+            let mut buffer = SmtpStreamBuffer::new(self.config.max_command_line_size);
+            buffer.set_line_ending_policy(self.config.line_ending_policy);
+            let client = AsyncSmtpClient {
+                socket: socket,
+                session_id: session_id,
+                peer_ip: peer_ip,
+                proxy_header: Vec::new(),
+                buffer: buffer,
+                session: None,
+                output: Vec::new(),
+                output_pos: 0,
+                closing: false
+            };
+
+            let token = match self.clients.insert(client) {
+                Ok(token) => token,
+                // Too many clients, this one is dropped, which closes its socket.
+                Err(_) => continue
+            };
+            let registered = event_loop.register_opt(
+                &self.clients[token].socket,
+                token,
+                Interest::readable() | Interest::writable(),
+                PollOpt::edge()
+            );
+            if registered.is_err() {
+                self.close(event_loop, token);
+                continue;
+            }
+
+            // Load balancers tell us who the real client is before anything else, so wait for
+            // their header before starting the session.
+            let proxied = match self.config.proxy_protocol {
+                Some(ref proxy) => proxy.is_trusted(&peer_ip),
+                None => false
+            };
+            if !proxied && (!self.start_session(token) || !self.clients[token].flush()) {
+                self.close(event_loop, token);
+            }
+        }
+    }
+
+    // Start the session of a client and send the opening welcome message. Returns `false` if
+    // the event handler refuses the client, which must then be closed.
+    fn start_session(&mut self, token: Token) -> bool {
+        let mut event_handler = self.event_handler.clone();
+        let client = &mut self.clients[token];
+        if event_handler.handle_connection(&client.peer_ip).is_err() {
+            log_record(
+                self.config.deref(),
+                LogInfo,
+                LogEvent,
+                Some((client.session_id, client.peer_ip)),
+                "connection refused by the event handler"
+            );
+            return false;
+        }
+
+        let mut session = SmtpServerSession::new(
+            client.session_id,
+            client.peer_ip,
+            self.config.clone(),
+            event_handler,
+            self.handlers.clone()
+        );
+        // Created once the PROXY protocol header is read, like with `SmtpServer`, so that the
+        // transcript starts with the SMTP session.
+        match create_transcript(self.config.deref(), client.session_id, client.peer_ip) {
+            Some(file) => client.buffer.record_transcript(box file),
+            None => {}
+        }
+        let greeting = session.greeting();
+        client.buffer.set_max_line_size(session.max_line_size());
+        client.session = Some(session);
+        client.write_line(greeting.as_slice());
+        true
+    }
+
+    // Read the PROXY protocol header of a client and start its session once it is complete.
+    // Returns `false` once the connection must be closed.
+    fn read_proxy_header(&mut self, token: Token) -> bool {
+        let (session_id, peer_ip) = (self.clients[token].session_id, self.clients[token].peer_ip);
+        match self.clients[token].read_proxy_header() {
+            Ok(ProxyIncomplete(_)) => true,
+            Ok(ProxyComplete(_, addr)) => {
+                match addr {
+                    Some(addr) => {
+                        log_record(
+                            self.config.deref(),
+                            LogDebug,
+                            LogEvent,
+                            Some((session_id, addr.ip)),
+                            format!("proxied by {}", peer_ip).as_slice()
+                        );
+                        self.clients[token].peer_ip = addr.ip;
+                    },
+                    None => {}
+                }
+                self.start_session(token)
+            },
+            Err(err) => {
+                log_record(
+                    self.config.deref(),
+                    LogWarning,
+                    LogEvent,
+                    Some((session_id, peer_ip)),
+                    format!("invalid PROXY protocol header: {}", err).as_slice()
+                );
+                false
+            }
+        }
+    }
+
+    // Forget about a client, which closes its socket.
+    fn close(&mut self, event_loop: &mut EventLoop<uint, ()>, token: Token) {
+        let _ = event_loop.deregister(&self.clients[token].socket);
+        match self.clients.remove(token) {
+            Some(mut client) => {
+                let stats = client.buffer.stats();
+                match client.session {
+                    Some(ref mut session) => session.handle_disconnect(stats),
+                    // The PROXY protocol header was never read, nothing else happened.
+                    None => {}
+                }
+            },
+            None => {}
+        }
+    }
+}
+
+impl<E: SmtpServerEventHandler + Clone> Handler<uint, ()> for AsyncSmtpServer<E> {
+    fn readable(&mut self, event_loop: &mut EventLoop<uint, ()>, token: Token, _: ReadHint) {
+        if token == ACCEPTOR {
+            self.accept(event_loop);
+        } else if self.clients.contains(token) {
+            if self.clients[token].session.is_none() && !self.read_proxy_header(token) {
+                self.close(event_loop, token);
+                return;
+            }
+            // The header may not be complete yet.
+            if self.clients[token].session.is_some() && !self.clients[token].read() {
+                self.close(event_loop, token);
+            }
+        }
+    }
+
+    fn writable(&mut self, event_loop: &mut EventLoop<uint, ()>, token: Token) {
+        if !self.clients.contains(token) {
+            return;
+        }
+        if !self.clients[token].flush() {
+            self.close(event_loop, token);
+            return;
+        }
+        // The client may have been left unread because of its pending output, the socket won't
+        // tell us again that there is input.
+        if self.clients[token].session.is_some() && !self.clients[token].read() {
+            self.close(event_loop, token);
+        }
+    }
+}
+
+#[test]
+fn test_async_smtp_server_new() {
+    use super::{MaxMessageSizeTooLow, get_test_config};
+
+    #[deriving(Clone)]
+    struct AsyncHandler;
+    impl SmtpServerEventHandler for AsyncHandler {}
+
+    // The config is checked before binding.
+    let mut config = get_test_config();
+    config.max_message_size = 0;
+    match AsyncSmtpServer::new(config, AsyncHandler) {
+        Err(MaxMessageSizeTooLow(0)) => {},
+        _ => fail!()
+    }
+}
+
+#[test]
+fn test_async_smtp_server_run() {
+    use std::io::{TcpStream, BufferedReader};
+    use std::io::timer::sleep;
+    use std::time::Duration;
+    use std::sync::Mutex;
+    use super::{SmtpSessionStats, get_test_config};
+
+    struct Counts {
+        started: uint,
+        aborted: uint,
+        disconnected: uint
+    }
+
+    // Counts the calls to the body hooks of all the sessions.
+    #[deriving(Clone)]
+    struct AsyncHandler {
+        counts: Arc<Mutex<Counts>>
+    }
+    impl SmtpServerEventHandler for AsyncHandler {
+        fn handle_body_start(&mut self) -> Result<(), ()> {
+            self.counts.lock().started += 1;
+            Ok(())
+        }
+        fn handle_body_abort(&mut self) {
+            self.counts.lock().aborted += 1;
+        }
+        #[allow(unused_variable)]
+        fn handle_disconnect(&mut self, stats: &SmtpSessionStats) {
+            self.counts.lock().disconnected += 1;
+        }
+    }
+
+    let counts = Arc::new(Mutex::new(Counts { started: 0, aborted: 0, disconnected: 0 }));
+    let handler = AsyncHandler { counts: counts.clone() };
+    let mut config = get_test_config();
+    config.ip = "127.0.0.1";
+    config.port = 25251;
+    spawn(proc() {
+        let _ = AsyncSmtpServer::new(config, handler).unwrap().run();
+    });
+
+    // The server may not be listening yet.
+    let mut stream = None;
+    for _ in range(0u, 100) {
+        match TcpStream::connect("127.0.0.1", 25251) {
+            Ok(s) => {
+                stream = Some(s);
+                break;
+            },
+            Err(_) => sleep(Duration::milliseconds(10))
+        }
+    }
+    let stream = stream.unwrap();
+    let mut writer = stream.clone();
+    let mut reader = BufferedReader::new(stream);
+    assert!(reader.read_line().unwrap().as_slice().starts_with("220 "));
+    for &(command, reply) in [
+        ("HELO rustastic.org", "250 "),
+        ("MAIL FROM:<>", "250 "),
+        ("RCPT TO:<bob@rustastic.org>", "250 "),
+        ("DATA", "354 ")
+    ].iter() {
+        writer.write_str(format!("{}\r\n", command).as_slice()).unwrap();
+        assert!(reader.read_line().unwrap().as_slice().starts_with(reply));
+    }
+
+    // The connection is lost in the middle of the message, which must be dropped.
+    writer.write_str("Subject: Hello\r\n").unwrap();
+    drop(writer);
+    drop(reader);
+    for _ in range(0u, 100) {
+        if counts.lock().disconnected > 0 {
+            break;
+        }
+        sleep(Duration::milliseconds(10));
+    }
+    let counts = counts.lock();
+    assert_eq!(1, counts.disconnected);
+    assert_eq!(1, counts.started);
+    assert_eq!(1, counts.aborted);
+}
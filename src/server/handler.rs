@@ -14,231 +14,887 @@
 
 use super::SmtpServerConfig;
 use super::SmtpServerEventHandler;
-use super::super::common::stream::{SmtpStream};
+use super::{SmtpLookupEntry, SmtpLookupResult};
+use super::{LookupFound, LookupAmbiguous, LookupNotFound, LookupRefused};
+use super::{AcceptSourceRoute, StripSourceRoute, RejectSourceRouteSyntax, RejectSourceRouteNotAllowed};
+use super::super::common::stream::{LineEnding, LINE_TOO_LONG, BARE_LINE_ENDING};
 use super::super::common::utils;
-use super::super::common::mailbox::Mailbox;
-use super::super::common::transaction::{SmtpTransactionState, Init, Helo, Mail, Rcpt, Data};
+use super::super::common::path::{Path, ReversePath, ForwardPath};
+use super::super::common::path::{NullPath, MailboxPath, PostmasterPath, RecipientPath};
+use super::super::common::transaction::{SmtpTransactionState, SmtpEnvelope, SmtpEnvelopeRecipient};
+use super::super::common::transaction::{Init, Helo, Mail, Rcpt, Data};
+use super::super::common::dsn::{DsnMailParams, DsnRcptParams, DsnParamError};
+use super::super::common::dsn::{UnknownParam, DuplicateParam, InvalidParamValue};
+use super::super::common::status::EnhancedStatusCode;
+use std::ascii::AsciiExt;
+use std::io::{IoResult, InvalidInput};
 
 // TODO: make SMTP handlers registerable by the library user so we can easily
 // add commands and make the server extendable.
-pub struct SmtpHandler<S: Writer+Reader, E: SmtpServerEventHandler> {
+pub struct SmtpHandler<E: SmtpServerEventHandler> {
     pub command_start: String,
+    // The text sent in reply to `HELP <command>`. The first line shows the syntax.
+    pub help: &'static str,
     pub allowed_states: Vec<SmtpTransactionState>,
-    pub callback: fn(&mut SmtpStream<S>, &mut SmtpTransactionState, &SmtpServerConfig, &mut E, &str) -> Result<String, Option<String>>
+    // Called with all the handlers of the server, so that `HELP` can list them.
+    pub callback: fn(&mut SmtpTransactionState, &mut SmtpEnvelope, &SmtpServerConfig, &[SmtpHandler<E>], &mut E, &str) -> Result<String, Option<String>>
 }
 
-impl<S: Writer+Reader, E: SmtpServerEventHandler> SmtpHandler<S, E> {
-    fn new(command_start: &str, allowed_states: &[SmtpTransactionState], callback: fn(&mut SmtpStream<S>, &mut SmtpTransactionState, &SmtpServerConfig, &mut E, &str) -> Result<String, Option<String>>) -> SmtpHandler<S, E> {
+impl<E: SmtpServerEventHandler> SmtpHandler<E> {
+    fn new(command_start: &str, help: &'static str, allowed_states: &[SmtpTransactionState], callback: fn(&mut SmtpTransactionState, &mut SmtpEnvelope, &SmtpServerConfig, &[SmtpHandler<E>], &mut E, &str) -> Result<String, Option<String>>) -> SmtpHandler<E> {
         SmtpHandler {
             command_start: command_start.into_string(),
+            help: help,
             allowed_states: allowed_states.to_vec(),
             callback: callback
         }
     }
+
+    // Get the name of the command, ie. `MAIL` for `MAIL FROM:`.
+    pub fn get_command_name(&self) -> &str {
+        self.command_start.as_slice().split(|c: char| c == ' ' || c == ':').next().unwrap()
+    }
 }
 
-pub fn get_handlers<S: Writer+Reader, E: SmtpServerEventHandler>() -> Vec<SmtpHandler<S, E>> {
+pub fn get_handlers<E: SmtpServerEventHandler>() -> Vec<SmtpHandler<E>> {
     let all = [Init, Helo, Mail, Rcpt, Data];
     let handlers = vec!(
-        SmtpHandler::new("HELO ", [Init], handle_command_helo),
-        SmtpHandler::new("EHLO ", [Init], handle_command_helo),
-        SmtpHandler::new("MAIL FROM:", [Helo], handle_command_mail),
-        SmtpHandler::new("RCPT TO:", [Mail, Rcpt], handle_command_rcpt),
-        SmtpHandler::new("DATA", [Rcpt], handle_command_data),
-        SmtpHandler::new("RSET", all, handle_command_rset),
-        SmtpHandler::new("VRFY ", all, handle_command_vrfy),
-        SmtpHandler::new("EXPN ", all, handle_command_expn),
-        SmtpHandler::new("HELP", all, handle_command_help),
-        SmtpHandler::new("NOOP", all, handle_command_noop),
-        SmtpHandler::new("QUIT", all, handle_command_quit)
+        SmtpHandler::new("HELO ", HELP_HELO, [Init], handle_command_helo),
+        SmtpHandler::new("EHLO ", HELP_EHLO, [Init], handle_command_ehlo),
+        SmtpHandler::new("MAIL FROM:", HELP_MAIL, [Helo], handle_command_mail),
+        SmtpHandler::new("RCPT TO:", HELP_RCPT, [Mail, Rcpt], handle_command_rcpt),
+        SmtpHandler::new("DATA", HELP_DATA, [Rcpt], handle_command_data),
+        SmtpHandler::new("RSET", HELP_RSET, all, handle_command_rset),
+        SmtpHandler::new("VRFY", HELP_VRFY, all, handle_command_vrfy),
+        SmtpHandler::new("EXPN", HELP_EXPN, all, handle_command_expn),
+        SmtpHandler::new("HELP", HELP_HELP, all, handle_command_help),
+        SmtpHandler::new("NOOP", HELP_NOOP, all, handle_command_noop),
+        SmtpHandler::new("QUIT", HELP_QUIT, all, handle_command_quit)
     );
     handlers
 }
 
-#[allow(unused_variable)]
-fn handle_command_helo<S: Writer+Reader, E: SmtpServerEventHandler>(stream: &mut SmtpStream<S>,
-                       state: &mut SmtpTransactionState,
-                       config: &SmtpServerConfig,
-                       event_handler: &mut E,
-                       line: &str) -> Result<String, Option<String>> {
+// Help texts of the built-in commands, sent in reply to `HELP <command>`. Each line of text is
+// sent as a separate line of the reply.
+static HELP_HELO: &'static str = "HELO <domain>\nIdentifies the client to the server.";
+static HELP_EHLO: &'static str = "EHLO <domain>\nIdentifies the client to the server and lists the supported extensions.";
+static HELP_MAIL: &'static str = "MAIL FROM:<reverse-path> [RET=FULL|HDRS] [ENVID=<xtext>]\nStarts a mail transaction. Use <> for a null reverse-path.";
+static HELP_RCPT: &'static str = "RCPT TO:<forward-path> [NOTIFY=NEVER|SUCCESS,FAILURE,DELAY] [ORCPT=<type>;<xtext>]\nAdds a recipient to the mail transaction.";
+static HELP_DATA: &'static str = "DATA\nStarts sending the message, which ends with a line containing a single dot.";
+static HELP_RSET: &'static str = "RSET\nAborts the current mail transaction.";
+static HELP_VRFY: &'static str = "VRFY <string>\nVerifies a user name or a mailbox.";
+static HELP_EXPN: &'static str = "EXPN <string>\nExpands a mailing list.";
+static HELP_HELP: &'static str = "HELP [<command>]\nLists the commands, or shows help about a command.";
+static HELP_NOOP: &'static str = "NOOP\nDoes nothing.";
+static HELP_QUIT: &'static str = "QUIT\nCloses the connection.";
+
+// ESMTP extensions advertised in the reply to `EHLO`, with the number of bytes each of them
+// adds to the maximum command line size. DSN adds up to 500 bytes to `RCPT` and 100 bytes to
+// `MAIL`, as described in RFC 3461. We can't know which command is coming before reading it, so
+// the largest increase is used for both.
+static EHLO_EXTENSIONS: &'static [(&'static str, uint)] = &[
+    ("DSN", 500),
+    ("ENHANCEDSTATUSCODES", 0)
+];
+
+/// Returns the maximum size of command lines, including `<CRLF>`: the one from the config,
+/// raised by the built-in extensions and the ones added in the config.
+pub fn get_max_command_line_size(config: &SmtpServerConfig) -> uint {
+    let size = EHLO_EXTENSIONS.iter().fold(config.max_command_line_size, |size, &(_, increase)| {
+        size + increase
+    });
+    config.extensions.iter().fold(size, |size, &(_, increase)| size + increase)
+}
+
+#[test]
+fn test_get_max_command_line_size() {
+    let mut config = super::get_test_config();
+    assert_eq!(512 + 500, get_max_command_line_size(&config));
+
+    config.extensions.push(("XCLIENT".into_string(), 100));
+    assert_eq!(512 + 500 + 100, get_max_command_line_size(&config));
+}
+
+/// Build the reply sent when a line is refused because of a bare `<CR>` or `<LF>`.
+pub fn get_bare_line_ending_reply() -> String {
+    get_status_reply(500, EnhancedStatusCode::new(5, 5, 2), "Bare <CR> or <LF> not allowed")
+}
+
+#[test]
+fn test_get_bare_line_ending_reply() {
+    assert_eq!(
+        "500 5.5.2 Bare <CR> or <LF> not allowed",
+        get_bare_line_ending_reply().as_slice()
+    );
+}
+
+/// Build a reply with an enhanced status code, as described in RFC 2034.
+///
+/// Per RFC 2034, the greeting, `354` and successful replies to `HELO` and `EHLO` are the
+/// only replies that don't carry an enhanced status code. Sessions remove them from the
+/// replies sent to clients which did not send `EHLO`.
+pub fn get_status_reply(code: uint, status: EnhancedStatusCode, text: &str) -> String {
+    format!("{} {} {}", code, status, text)
+}
+
+#[test]
+fn test_get_status_reply() {
+    assert_eq!(
+        "550 5.1.1 Mailbox not available",
+        get_status_reply(550, EnhancedStatusCode::new(5, 1, 1), "Mailbox not available").as_slice()
+    );
+}
+
+/// Remove the enhanced status codes from a reply. Per RFC 2034, clients which did not send
+/// `EHLO` must not get any.
+pub fn strip_status_codes(reply: &str) -> String {
+    let lines: Vec<String> = reply.split_str("\r\n").map(|line| {
+        if line.len() > 4 && line.is_char_boundary(4) {
+            let text = line.slice_from(4);
+            let end = text.find(' ').unwrap_or(text.len());
+            if EnhancedStatusCode::parse(text.slice_to(end)).is_some() {
+                let rest = if end < text.len() { text.slice_from(end + 1) } else { "" };
+                return format!("{}{}", line.slice_to(4), rest);
+            }
+        }
+        line.into_string()
+    }).collect();
+    lines.connect("\r\n")
+}
+
+#[test]
+fn test_strip_status_codes() {
+    assert_eq!("550 Mailbox not available", strip_status_codes("550 5.1.1 Mailbox not available").as_slice());
+    assert_eq!("221 ", strip_status_codes("221 2.0.0").as_slice());
+    assert_eq!(
+        "214-Commands supported:\r\n214 HELO NOOP",
+        strip_status_codes("214-2.0.0 Commands supported:\r\n214 2.0.0 HELO NOOP").as_slice()
+    );
+    assert_eq!(
+        "250-rustastic.org\r\n250 DSN",
+        strip_status_codes("250-rustastic.org\r\n250 DSN").as_slice()
+    );
+    assert_eq!("250 OK", strip_status_codes("250 OK").as_slice());
+    assert_eq!("250 1.2.3 OK", strip_status_codes("250 1.2.3 OK").as_slice());
+}
+
+// Build the reply sent when the event handler refuses something, with the status code it gave
+// or `default`. A temporary `4.x.x` code gets a 450 reply and a permanent `5.x.x` code gets a
+// 550 reply. Other codes make no sense for a refusal and are replaced by `default`.
+fn get_refusal_reply(status: Option<EnhancedStatusCode>, default: EnhancedStatusCode, text: &str) -> String {
+    let status = match status {
+        Some(status) if status.class == 4 || status.class == 5 => status,
+        _ => default
+    };
+    let code = if status.class == 4 { 450 } else { 550 };
+    get_status_reply(code, status, text)
+}
+
+#[test]
+fn test_get_refusal_reply() {
+    let default = EnhancedStatusCode::new(5, 7, 1);
+    assert_eq!("550 5.7.1 No", get_refusal_reply(None, default.clone(), "No").as_slice());
+    assert_eq!(
+        "550 5.1.1 No",
+        get_refusal_reply(Some(EnhancedStatusCode::new(5, 1, 1)), default.clone(), "No").as_slice()
+    );
+    assert_eq!(
+        "450 4.2.1 No",
+        get_refusal_reply(Some(EnhancedStatusCode::new(4, 2, 1)), default.clone(), "No").as_slice()
+    );
+    assert_eq!(
+        "550 5.7.1 No",
+        get_refusal_reply(Some(EnhancedStatusCode::new(2, 0, 0)), default.clone(), "No").as_slice()
+    );
+}
+
+// Build a reply that spans several lines, as described in RFC 5321 section 4.2.1.
+fn get_multiline_reply(code: uint, lines: &[String]) -> String {
+    let mut reply = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        if i + 1 < lines.len() {
+            reply.push_str(format!("{}-{}\r\n", code, line).as_slice());
+        } else {
+            reply.push_str(format!("{} {}", code, line).as_slice());
+        }
+    }
+    reply
+}
+
+#[test]
+fn test_get_multiline_reply() {
+    assert_eq!("250 OK", get_multiline_reply(250, ["OK".into_string()]).as_slice());
+    assert_eq!(
+        "250-rustastic.org\r\n250-DSN\r\n250 HELP",
+        get_multiline_reply(250, [
+            "rustastic.org".into_string(),
+            "DSN".into_string(),
+            "HELP".into_string()
+        ]).as_slice()
+    );
+}
+
+// Check the domain sent with `HELO` or `EHLO`. If it is accepted, the state is updated and
+// `None` is returned. Else, the error reply is returned.
+fn check_helo_domain<E: SmtpServerEventHandler>(state: &mut SmtpTransactionState,
+                                                event_handler: &mut E,
+                                                line: &str) -> Option<String> {
     if line.len() == 0 {
-        Ok("501 Domain name not provided".into_string())
-    } else if utils::get_domain_len(line) != line.len() {
-        Ok("501 Domain name is invalid".into_string())
+        Some(get_status_reply(501, EnhancedStatusCode::new(5, 5, 4), "Domain name not provided"))
+    } else if utils::get_domain_len(line) != Some(line.len()) {
+        Some(get_status_reply(501, EnhancedStatusCode::new(5, 5, 4), "Domain name is invalid"))
     } else {
         match event_handler.handle_domain(line) {
             Ok(_) => {
                 *state = Helo;
-                Ok("250 OK".into_string())
+                None
             },
-            Err(_) => {
-                Ok("550 Domain not taken".into_string())
+            Err(status) => {
+                Some(get_refusal_reply(status, EnhancedStatusCode::new(5, 7, 1), "Domain not taken"))
             }
         }
     }
 }
 
+#[allow(unused_variable)]
+fn handle_command_helo<E: SmtpServerEventHandler>(state: &mut SmtpTransactionState,
+                       envelope: &mut SmtpEnvelope,
+                       config: &SmtpServerConfig,
+                       handlers: &[SmtpHandler<E>],
+                       event_handler: &mut E,
+                       line: &str) -> Result<String, Option<String>> {
+    match check_helo_domain(state, event_handler, line) {
+        Some(reply) => Ok(reply),
+        None => Ok("250 OK".into_string())
+    }
+}
+
 #[test]
 fn test_command_helo() {
     // fail!();
 }
 
 #[allow(unused_variable)]
-fn handle_command_mail<S: Writer+Reader, E: SmtpServerEventHandler>(stream: &mut SmtpStream<S>,
-                       state: &mut SmtpTransactionState,
+fn handle_command_ehlo<E: SmtpServerEventHandler>(state: &mut SmtpTransactionState,
+                       envelope: &mut SmtpEnvelope,
                        config: &SmtpServerConfig,
+                       handlers: &[SmtpHandler<E>],
                        event_handler: &mut E,
                        line: &str) -> Result<String, Option<String>> {
-    if line.len() < 2 || line.char_at(0) != '<' || line.char_at(line.len() - 1) != '>' {
-        Ok("501 Email address invalid, must start with < and end with >".into_string())
-    } else if line == "<>" {
-        match event_handler.handle_sender_address(None) {
-            Ok(_) => {
-                *state = Mail;
-                Ok("250 OK".into_string())
-            },
-            Err(_) => {
-                Ok("550 Mailnot available".into_string())
+    match check_helo_domain(state, event_handler, line) {
+        Some(reply) => Ok(reply),
+        None => {
+            let mut lines = vec!(config.domain.into_string());
+            for &(keyword, _) in EHLO_EXTENSIONS.iter() {
+                lines.push(keyword.into_string());
             }
+            for &(ref keyword, _) in config.extensions.iter() {
+                lines.push(keyword.clone());
+            }
+            Ok(get_multiline_reply(250, lines.as_slice()))
         }
-    } else {
-        let mailbox_res = Mailbox::parse(line.slice(1, line.len() - 1));
-        match mailbox_res {
-            Err(err) => {
-                Ok(format!("553 Email address invalid: {}", err))
-            },
-            Ok(mailbox) => {
-                match event_handler.handle_sender_address(Some(&mailbox)) {
-                    Ok(_) => {
-                        *state = Mail;
-                        Ok("250 OK".into_string())
-                    },
-                    Err(_) => {
-                        Ok("550 Mailnot taken".into_string())
-                    }
-                }
+    }
+}
+
+#[test]
+fn test_command_ehlo() {
+    let mut config = super::get_test_config();
+    config.extensions.push(("XCLIENT".into_string(), 0));
+    let mut state = Init;
+    let mut envelope = SmtpEnvelope::new();
+
+    assert_eq!(
+        Ok("501 5.5.4 Domain name not provided".into_string()),
+        handle_command_ehlo(&mut state, &mut envelope, &config, &[], &mut LookupHandler, "")
+    );
+    assert!(state == Init);
+
+    assert_eq!(
+        Ok("250-rustastic.org\r\n250-DSN\r\n250-ENHANCEDSTATUSCODES\r\n250 XCLIENT".into_string()),
+        handle_command_ehlo(&mut state, &mut envelope, &config, &[], &mut LookupHandler, "rustastic.org")
+    );
+    assert!(state == Helo);
+}
+
+// Get the reply to send when the ESMTP parameters of `MAIL` or `RCPT` are invalid.
+fn get_dsn_param_error_reply(err: DsnParamError) -> String {
+    match err {
+        UnknownParam(keyword) => get_status_reply(
+            555,
+            EnhancedStatusCode::new(5, 5, 4),
+            format!("Parameter {} not recognized or not implemented", keyword).as_slice()
+        ),
+        DuplicateParam(keyword) => get_status_reply(
+            501,
+            EnhancedStatusCode::new(5, 5, 4),
+            format!("Parameter {} given more than once", keyword).as_slice()
+        ),
+        InvalidParamValue(keyword) => get_status_reply(
+            501,
+            EnhancedStatusCode::new(5, 5, 4),
+            format!("Parameter {} has an invalid value", keyword).as_slice()
+        )
+    }
+}
+
+#[test]
+fn test_get_dsn_param_error_reply() {
+    assert_eq!(
+        "555 5.5.4 Parameter SIZE not recognized or not implemented",
+        get_dsn_param_error_reply(UnknownParam("SIZE".into_string())).as_slice()
+    );
+    assert_eq!(
+        "501 5.5.4 Parameter RET given more than once",
+        get_dsn_param_error_reply(DuplicateParam("RET".into_string())).as_slice()
+    );
+    assert_eq!(
+        "501 5.5.4 Parameter ORCPT has an invalid value",
+        get_dsn_param_error_reply(InvalidParamValue("ORCPT".into_string())).as_slice()
+    );
+}
+
+// Applies the source route policy of the event handler to a path which was just parsed. If the
+// path is refused, the reply to send is returned. `status` is the enhanced status code of a
+// refusal by policy, which depends on whether the path is the sender or a recipient.
+fn check_source_route<E: SmtpServerEventHandler>(event_handler: &mut E,
+                                                 path: &mut Path,
+                                                 status: EnhancedStatusCode) -> Option<String> {
+    if !path.has_source_route() {
+        return None;
+    }
+    match event_handler.handle_source_route(path) {
+        AcceptSourceRoute => None,
+        StripSourceRoute => {
+            path.strip_source_route();
+            None
+        },
+        RejectSourceRouteSyntax => {
+            Some(get_status_reply(501, EnhancedStatusCode::new(5, 5, 4), "Source routes are not accepted"))
+        },
+        RejectSourceRouteNotAllowed => {
+            Some(get_status_reply(553, status, "Source routes are not accepted"))
+        }
+    }
+}
+
+// Answers all source routes with the same action.
+#[cfg(test)]
+struct SourceRouteHandler(super::SmtpSourceRouteAction);
+
+#[cfg(test)]
+impl SmtpServerEventHandler for SourceRouteHandler {
+    #[allow(unused_variable)]
+    fn handle_source_route(&mut self, path: &Path) -> super::SmtpSourceRouteAction {
+        let SourceRouteHandler(ref action) = *self;
+        action.clone()
+    }
+}
+
+#[test]
+fn test_check_source_route() {
+    let routed = Path::parse("@relay1,@relay2:rust@rustastic.org").unwrap();
+    let status = EnhancedStatusCode::new(5, 1, 7);
+
+    // Paths without a source route are left alone, whatever the policy.
+    let mut path = Path::parse("rust@rustastic.org").unwrap();
+    let mut handler = SourceRouteHandler(RejectSourceRouteNotAllowed);
+    assert_eq!(None, check_source_route(&mut handler, &mut path, status.clone()));
+
+    let mut path = routed.clone();
+    let mut handler = SourceRouteHandler(AcceptSourceRoute);
+    assert_eq!(None, check_source_route(&mut handler, &mut path, status.clone()));
+    assert_eq!(routed, path);
+
+    let mut path = routed.clone();
+    let mut handler = SourceRouteHandler(StripSourceRoute);
+    assert_eq!(None, check_source_route(&mut handler, &mut path, status.clone()));
+    assert!(!path.has_source_route());
+    assert_eq!(routed.mailbox, path.mailbox);
+
+    let mut path = routed.clone();
+    let mut handler = SourceRouteHandler(RejectSourceRouteSyntax);
+    assert_eq!(
+        Some("501 5.5.4 Source routes are not accepted".into_string()),
+        check_source_route(&mut handler, &mut path, status.clone())
+    );
+    assert_eq!(routed, path);
+
+    let mut path = routed.clone();
+    let mut handler = SourceRouteHandler(RejectSourceRouteNotAllowed);
+    assert_eq!(
+        Some("553 5.1.7 Source routes are not accepted".into_string()),
+        check_source_route(&mut handler, &mut path, status.clone())
+    );
+    assert_eq!(routed, path);
+}
+
+#[allow(unused_variable)]
+fn handle_command_mail<E: SmtpServerEventHandler>(state: &mut SmtpTransactionState,
+                       envelope: &mut SmtpEnvelope,
+                       config: &SmtpServerConfig,
+                       handlers: &[SmtpHandler<E>],
+                       event_handler: &mut E,
+                       line: &str) -> Result<String, Option<String>> {
+    // Everything after the reverse-path are ESMTP parameters.
+    let (path, params) = match utils::parse_path(line) {
+        Ok((path, params)) if params.len() == 0 || params.char_at(0) == ' ' => (path, params),
+        _ => {
+            return Ok(get_status_reply(501, EnhancedStatusCode::new(5, 1, 7), "Email address invalid, must start with < and end with >"));
+        }
+    };
+
+    let dsn = match DsnMailParams::parse(params) {
+        Ok(dsn) => dsn,
+        Err(err) => return Ok(get_dsn_param_error_reply(err))
+    };
+
+    let mut reverse_path = match ReversePath::parse(path) {
+        Ok(reverse_path) => reverse_path,
+        Err(err) => {
+            return Ok(get_status_reply(
+                553,
+                EnhancedStatusCode::new(5, 1, 7),
+                format!("Email address invalid: {}", err).as_slice()
+            ));
+        }
+    };
+    match reverse_path {
+        MailboxPath(ref mut path) => {
+            match check_source_route(event_handler, path, EnhancedStatusCode::new(5, 1, 7)) {
+                Some(reply) => return Ok(reply),
+                None => {}
             }
+        },
+        NullPath => {}
+    }
+
+    let res = event_handler.handle_sender_address(&reverse_path, &dsn);
+    match res {
+        Ok(_) => {
+            *state = Mail;
+            envelope.sender = Some(reverse_path);
+            envelope.dsn = dsn;
+            Ok(get_status_reply(250, EnhancedStatusCode::new(2, 1, 0), "OK"))
+        },
+        Err(status) => {
+            Ok(get_refusal_reply(status, EnhancedStatusCode::new(5, 7, 1), "Mailbox not taken"))
         }
     }
 }
 
 #[test]
 fn test_command_mail() {
-    // fail!();
+    let config = super::get_test_config();
+    let mut state = Helo;
+    let mut envelope = SmtpEnvelope::new();
+
+    let invalid = Ok("501 5.1.7 Email address invalid, must start with < and end with >".into_string());
+    assert_eq!(invalid, handle_command_mail(&mut state, &mut envelope, &config, &[], &mut LookupHandler, ""));
+    assert_eq!(invalid, handle_command_mail(&mut state, &mut envelope, &config, &[], &mut LookupHandler, "rust@rustastic.org"));
+    assert_eq!(invalid, handle_command_mail(&mut state, &mut envelope, &config, &[], &mut LookupHandler, "<rust@rustastic.org>RET=FULL"));
+    assert!(state == Helo);
+
+    assert_eq!(
+        Ok("250 2.1.0 OK".into_string()),
+        handle_command_mail(&mut state, &mut envelope, &config, &[], &mut LookupHandler, "<rust@rustastic.org> RET=FULL")
+    );
+    assert!(state == Mail);
+    assert_eq!(Some(ReversePath::parse("<rust@rustastic.org>").unwrap()), envelope.sender);
 }
 
 #[allow(unused_variable)]
-fn handle_command_rcpt<S: Writer+Reader, E: SmtpServerEventHandler>(stream: &mut SmtpStream<S>,
-                       state: &mut SmtpTransactionState,
+fn handle_command_rcpt<E: SmtpServerEventHandler>(state: &mut SmtpTransactionState,
+                       envelope: &mut SmtpEnvelope,
                        config: &SmtpServerConfig,
+                       handlers: &[SmtpHandler<E>],
                        event_handler: &mut E,
                        line: &str) -> Result<String, Option<String>> {
     // TODO: check maximum number of recipients? Maybe after the event handler
     // sends back `Ok(())`?
     if false {
-        Ok("452 Too many recipients".into_string())
-    } else if line.char_at(0) != '<' || line.char_at(line.len() - 1) != '>' {
-        Ok("501 Email address invalid, must start with < and end with >".into_string())
-    } else {
-        let mailbox_res = Mailbox::parse(line.slice(1, line.len() - 1));
-        match mailbox_res {
-            Err(err) => {
-                Ok(format!("553 Email address invalid: {}", err))
-            },
-            Ok(mailbox) => {
-                match event_handler.handle_receiver_address(&mailbox) {
-                    Ok(_) => {
-                        *state = Rcpt;
-                        Ok("250 OK".into_string())
-                    },
-                    Err(_) => {
-                        Ok("550 Mailnot available".into_string())
-                    }
-                }
+        return Ok(get_status_reply(452, EnhancedStatusCode::new(4, 5, 3), "Too many recipients"));
+    }
+
+    // Everything after the forward-path are ESMTP parameters.
+    let (path, params) = match utils::parse_path(line) {
+        Ok((path, params)) if params.len() == 0 || params.char_at(0) == ' ' => (path, params),
+        _ => {
+            return Ok(get_status_reply(501, EnhancedStatusCode::new(5, 1, 3), "Email address invalid, must start with < and end with >"));
+        }
+    };
+
+    let dsn = match DsnRcptParams::parse(params) {
+        Ok(dsn) => dsn,
+        Err(err) => return Ok(get_dsn_param_error_reply(err))
+    };
+
+    let mut forward_path = match ForwardPath::parse(path) {
+        Ok(forward_path) => forward_path,
+        Err(err) => {
+            return Ok(get_status_reply(
+                553,
+                EnhancedStatusCode::new(5, 1, 3),
+                format!("Email address invalid: {}", err).as_slice()
+            ));
+        }
+    };
+
+    // The event handler gets the base mailbox, the envelope keeps the path as given.
+    let (base_path, detail) = match forward_path {
+        RecipientPath(ref mut path) => {
+            match check_source_route(event_handler, path, EnhancedStatusCode::new(5, 1, 3)) {
+                Some(reply) => return Ok(reply),
+                None => {}
             }
+            let (base, detail) = path.mailbox.split_detail(config.subaddress_separators.as_slice());
+            let base_path = Path {
+                source_route: path.source_route.clone(),
+                mailbox: base
+            };
+            (RecipientPath(base_path), detail)
+        },
+        PostmasterPath => (PostmasterPath, None)
+    };
+
+    let res = event_handler.handle_receiver_address(
+        &base_path,
+        detail.as_ref().map(|detail| detail.as_slice()),
+        &dsn
+    );
+    match res {
+        Ok(_) => {
+            *state = Rcpt;
+            envelope.recipients.push(SmtpEnvelopeRecipient {
+                path: forward_path,
+                detail: detail,
+                dsn: dsn
+            });
+            Ok(get_status_reply(250, EnhancedStatusCode::new(2, 1, 5), "OK"))
+        },
+        Err(status) => {
+            Ok(get_refusal_reply(status, EnhancedStatusCode::new(5, 1, 1), "Mailbox not available"))
         }
     }
 }
 
 #[test]
 fn test_command_rcpt() {
-    // fail!();
+    let config = super::get_test_config();
+    let mut state = Mail;
+    let mut envelope = SmtpEnvelope::new();
+
+    let invalid = Ok("501 5.1.3 Email address invalid, must start with < and end with >".into_string());
+    assert_eq!(invalid, handle_command_rcpt(&mut state, &mut envelope, &config, &[], &mut LookupHandler, ""));
+    assert_eq!(invalid, handle_command_rcpt(&mut state, &mut envelope, &config, &[], &mut LookupHandler, "<bob@rustastic.org>NOTIFY=NEVER"));
+    assert!(state == Mail);
+
+    assert_eq!(
+        Ok("250 2.1.5 OK".into_string()),
+        handle_command_rcpt(&mut state, &mut envelope, &config, &[], &mut LookupHandler, "<bob@rustastic.org> NOTIFY=NEVER")
+    );
+    assert!(state == Rcpt);
+    assert_eq!(1, envelope.recipients.len());
 }
 
 #[allow(unused_variable)]
-fn handle_command_data<S: Writer+Reader, E: SmtpServerEventHandler>(stream: &mut SmtpStream<S>,
-                       state: &mut SmtpTransactionState,
+fn handle_command_data<E: SmtpServerEventHandler>(state: &mut SmtpTransactionState,
+                       envelope: &mut SmtpEnvelope,
                        config: &SmtpServerConfig,
+                       handlers: &[SmtpHandler<E>],
                        event_handler: &mut E,
                        line: &str) -> Result<String, Option<String>> {
     if line.len() != 0 {
-        Ok("501 No arguments allowed".into_string())
+        Ok(get_status_reply(501, EnhancedStatusCode::new(5, 5, 4), "No arguments allowed"))
     } else {
-        stream.write_line("354 Start mail input; end with <CRLF>.<CRLF>").unwrap();
-
         // Inform our event handler that mail data is about to be received.
         event_handler.handle_body_start().unwrap();
-        
-        let mut size = 0;
-        loop {
-            let read_line = stream.read_line();
-            let ok = read_line.is_ok();
-
-            if ok {
-                let read_line = read_line.unwrap();
-
-                // Here, we check that we have already got some data, which
-                // means that we have read a line, which means we have just
-                // seen `<CRLF>`. And then, we check if the current line
-                // which we know to end with `<CRLF>` as well contains a
-                // single dot.
-                // All in all, this means we check for `<CRLF>.<CRLF>`.
-                if size != 0 && read_line == &['.' as u8] {
-                    break;
-                }
-                // TODO: support transparency. Here or in the reader ?
 
-                event_handler.handle_body_part(read_line).unwrap();
+        // From now on, lines are message data and go to `handle_data_line`.
+        *state = Data;
+        Ok("354 Start mail input; end with <CRLF>.<CRLF>".into_string())
+    }
+}
 
-                size += read_line.len();
+#[test]
+fn test_command_data() {
+    let config = super::get_test_config();
+    let mut state = Rcpt;
+    let mut envelope = SmtpEnvelope::new();
 
-                if size > config.max_message_size {
-                    // TODO: add an error handler in the event handler?
-                    return Ok(format!(
-                        "552 Too much mail data, max {} bytes",
-                        config.max_message_size
-                    ));
-                }
+    assert_eq!(
+        Ok("501 5.5.4 No arguments allowed".into_string()),
+        handle_command_data(&mut state, &mut envelope, &config, &[], &mut BodyHandler::new(), " now")
+    );
+    assert!(state == Rcpt);
+
+    assert_eq!(
+        Ok("354 Start mail input; end with <CRLF>.<CRLF>".into_string()),
+        handle_command_data(&mut state, &mut envelope, &config, &[], &mut BodyHandler::new(), "")
+    );
+    assert!(state == Data);
+}
+
+/// The progress of reading message data, after `DATA` has been accepted.
+pub struct SmtpDataState {
+    // The number of bytes read by the stream before the message data, to tell the size of
+    // the message data from the number of bytes read so far.
+    start: u64,
+    // The number of dots added by the client for transparency, which are not part of the
+    // message.
+    stuffed_dots: u64,
+    // If the message fails while it is being read, the reply to send once the final dot
+    // is found. Until then, the rest of the message is read and ignored.
+    failure: Option<String>,
+    // Whether the previous line ended in a way that may precede the final dot.
+    ends_data: bool
+}
+
+impl SmtpDataState {
+    /// Creates the state of a message whose data has not been read yet, after the stream has
+    /// read `bytes_read` bytes. The `DATA` line itself is considered to end with `<CRLF>`.
+    pub fn new(bytes_read: u64) -> SmtpDataState {
+        SmtpDataState {
+            start: bytes_read,
+            stuffed_dots: 0,
+            failure: None,
+            ends_data: true
+        }
+    }
+}
+
+/// Handles a line of message data, or the error which happened instead of reading it.
+/// `bytes_read` is the number of bytes read by the stream so far, including this line.
+///
+/// Returns `None` while the message goes on, otherwise the reply to send once the final dot
+/// has been found. If `Err` is returned, the connection should be closed.
+pub fn handle_data_line<E: SmtpServerEventHandler>(data: &mut SmtpDataState,
+                        state: &mut SmtpTransactionState,
+                        envelope: &mut SmtpEnvelope,
+                        config: &SmtpServerConfig,
+                        event_handler: &mut E,
+                        read: IoResult<(&[u8], LineEnding)>,
+                        bytes_read: u64) -> Option<Result<String, Option<String>>> {
+    let policy = config.line_ending_policy;
+    match read {
+        Ok((read_line, ending)) => {
+            // A line containing a single dot, ending with `<CRLF>` and following a line
+            // ending with `<CRLF>` means we have found `<CRLF>.<CRLF>`. Other line
+            // endings only count if the policy explicitly allows them.
+            if data.ends_data && read_line == &['.' as u8] && policy.ends_data(ending) {
+                return Some(Ok(end_data(data, state, envelope, event_handler)));
+            }
+            data.ends_data = policy.ends_data(ending);
+
+            // Clients add a dot to lines starting with a dot, so that they are not mistaken
+            // for the final dot. See RFC 5321 section 4.5.2.
+            let line = if read_line.len() > 0 && read_line[0] == '.' as u8 {
+                data.stuffed_dots += 1;
+                read_line.slice_from(1)
             } else {
-                return Err(None);
+                read_line
+            };
+
+            if data.failure.is_some() {
+                return None;
+            }
+
+            // Per RFC 1870, the size of the message includes line endings, but neither the
+            // dots added for transparency nor the final dot.
+            if bytes_read - data.start - data.stuffed_dots > config.max_message_size as u64 {
+                fail_data(data, event_handler, get_status_reply(
+                    552,
+                    EnhancedStatusCode::new(5, 3, 4),
+                    format!("Too much mail data, max {} bytes", config.max_message_size).as_slice()
+                ));
+                return None;
             }
+
+            event_handler.handle_body_part(line).unwrap();
+        },
+        // The stream skips the rest of an overlong line by itself, so we can keep
+        // reading until the final dot.
+        Err(ref err) if err.kind == InvalidInput && err.desc == LINE_TOO_LONG => {
+            // The stream has skipped up to the end of the line.
+            data.ends_data = true;
+            if data.failure.is_none() {
+                fail_data(data, event_handler, get_status_reply(
+                    500,
+                    EnhancedStatusCode::new(5, 5, 2),
+                    format!("Text line too long, max is {} bytes", config.max_text_line_size).as_slice()
+                ));
+            }
+        },
+        Err(ref err) if err.kind == InvalidInput && err.desc == BARE_LINE_ENDING => {
+            data.ends_data = false;
+            if data.failure.is_none() {
+                fail_data(data, event_handler, get_bare_line_ending_reply());
+            }
+        },
+        // The message won't be finished. The event handler is told when the session ends, see
+        // `SmtpServerSession::handle_disconnect`.
+        Err(_) => return Some(Err(None))
+    }
+    None
+}
+
+// Counts the calls to the body hooks.
+#[cfg(test)]
+struct BodyHandler {
+    parts: Vec<Vec<u8>>,
+    ended: uint,
+    aborted: uint
+}
+
+#[cfg(test)]
+impl BodyHandler {
+    fn new() -> BodyHandler {
+        BodyHandler {
+            parts: vec!(),
+            ended: 0,
+            aborted: 0
         }
+    }
+}
 
-        // Inform our event handler that all data has been received.
-        event_handler.handle_body_end().unwrap();
+#[cfg(test)]
+impl SmtpServerEventHandler for BodyHandler {
+    fn handle_body_part(&mut self, part: &[u8]) -> Result<(), ()> {
+        self.parts.push(part.to_vec());
+        Ok(())
+    }
 
-        // We're all good !
-        state.reset();
-        Ok("250 OK".into_string())
+    fn handle_body_end(&mut self) -> Result<(), ()> {
+        self.ended += 1;
+        Ok(())
+    }
+
+    fn handle_body_abort(&mut self) {
+        self.aborted += 1;
     }
 }
 
 #[test]
-fn test_command_data() {
-    // fail!();
+fn test_handle_data_line() {
+    use std::io::{IoError, EndOfFile};
+    use super::super::common::stream::Crlf;
+
+    let config = super::get_test_config();
+    let mut state = Data;
+    let mut envelope = SmtpEnvelope::new();
+    let mut handler = BodyHandler::new();
+
+    // Dot-stuffed lines are given without the added dot, the final dot ends the message.
+    let mut data = SmtpDataState::new(6);
+    assert_eq!(None, handle_data_line(&mut data, &mut state, &mut envelope, &config, &mut handler,
+                                      Ok(("Subject: Hello".as_bytes(), Crlf)), 22));
+    assert_eq!(None, handle_data_line(&mut data, &mut state, &mut envelope, &config, &mut handler,
+                                      Ok(("..".as_bytes(), Crlf)), 26));
+    assert_eq!(Some(Ok("250 2.0.0 OK".into_string())),
+               handle_data_line(&mut data, &mut state, &mut envelope, &config, &mut handler,
+                                Ok((".".as_bytes(), Crlf)), 29));
+    assert_eq!(vec!(b"Subject: Hello".to_vec(), b".".to_vec()), handler.parts);
+    assert_eq!((1, 0), (handler.ended, handler.aborted));
+    assert!(state == Helo);
+
+    // After an overlong line, the rest of the message is ignored and the handler is told once
+    // that the message is dropped.
+    let mut handler = BodyHandler::new();
+    let mut data = SmtpDataState::new(6);
+    state = Data;
+    assert_eq!(None, handle_data_line(&mut data, &mut state, &mut envelope, &config, &mut handler,
+                                      Ok(("Subject: Hello".as_bytes(), Crlf)), 22));
+    let too_long = IoError { kind: InvalidInput, desc: LINE_TOO_LONG, detail: None };
+    assert_eq!(None, handle_data_line(&mut data, &mut state, &mut envelope, &config, &mut handler,
+                                      Err(too_long), 2022));
+    let bare = IoError { kind: InvalidInput, desc: BARE_LINE_ENDING, detail: None };
+    assert_eq!(None, handle_data_line(&mut data, &mut state, &mut envelope, &config, &mut handler,
+                                      Err(bare), 2030));
+    assert_eq!(None, handle_data_line(&mut data, &mut state, &mut envelope, &config, &mut handler,
+                                      Ok(("more".as_bytes(), Crlf)), 2036));
+    assert_eq!(Some(Ok("500 5.5.2 Text line too long, max is 1001 bytes".into_string())),
+               handle_data_line(&mut data, &mut state, &mut envelope, &config, &mut handler,
+                                Ok((".".as_bytes(), Crlf)), 2039));
+    assert_eq!(vec!(b"Subject: Hello".to_vec()), handler.parts);
+    assert_eq!((0, 1), (handler.ended, handler.aborted));
+    assert!(state == Helo);
+
+    // So does a message larger than allowed, the size is only known once it has been read.
+    let mut handler = BodyHandler::new();
+    let mut data = SmtpDataState::new(6);
+    let max = config.max_message_size as u64;
+    state = Data;
+    assert_eq!(None, handle_data_line(&mut data, &mut state, &mut envelope, &config, &mut handler,
+                                      Ok(("Subject: Hello".as_bytes(), Crlf)), 22));
+    assert_eq!(None, handle_data_line(&mut data, &mut state, &mut envelope, &config, &mut handler,
+                                      Ok(("big".as_bytes(), Crlf)), 6 + max + 1));
+    assert_eq!(None, handle_data_line(&mut data, &mut state, &mut envelope, &config, &mut handler,
+                                      Ok(("bigger".as_bytes(), Crlf)), 6 + max + 9));
+    assert_eq!(Some(Ok(format!("552 5.3.4 Too much mail data, max {} bytes", max))),
+               handle_data_line(&mut data, &mut state, &mut envelope, &config, &mut handler,
+                                Ok((".".as_bytes(), Crlf)), 6 + max + 12));
+    assert_eq!(vec!(b"Subject: Hello".to_vec()), handler.parts);
+    assert_eq!((0, 1), (handler.ended, handler.aborted));
+    assert!(state == Helo);
+
+    // A lost connection ends the session, which drops the message.
+    let mut data = SmtpDataState::new(6);
+    state = Data;
+    let closed = IoError { kind: EndOfFile, desc: "end of file", detail: None };
+    assert_eq!(Some(Err(None)), handle_data_line(&mut data, &mut state, &mut envelope, &config,
+                                                 &mut handler, Err(closed), 6));
+}
+
+// Drops the message being read. The reply is sent once the final dot is found, until then the
+// rest of the message is read and ignored.
+fn fail_data<E: SmtpServerEventHandler>(data: &mut SmtpDataState, event_handler: &mut E, reply: String) {
+    data.failure = Some(reply);
+    event_handler.handle_body_abort();
+}
+
+// Tells the event handler that the message being read is dropped, if the session ends before its
+// final dot. Messages which already failed were dropped when they failed.
+pub fn abort_data<E: SmtpServerEventHandler>(data: &SmtpDataState,
+                                             state: &SmtpTransactionState,
+                                             event_handler: &mut E) {
+    if *state == Data && data.failure.is_none() {
+        event_handler.handle_body_abort();
+    }
+}
+
+// Finishes the transaction once the final dot has been found and returns the reply to send.
+fn end_data<E: SmtpServerEventHandler>(data: &mut SmtpDataState,
+                                       state: &mut SmtpTransactionState,
+                                       envelope: &mut SmtpEnvelope,
+                                       event_handler: &mut E) -> String {
+    // The transaction is over, whether the message was accepted or not.
+    state.reset();
+    envelope.reset();
+
+    match data.failure.take() {
+        Some(reply) => reply,
+        None => {
+            // Inform our event handler that all data has been received.
+            event_handler.handle_body_end().unwrap();
+
+            // We're all good !
+            get_status_reply(250, EnhancedStatusCode::new(2, 0, 0), "OK")
+        }
+    }
 }
 
 #[allow(unused_variable)]
-fn handle_command_rset<S: Writer+Reader, E: SmtpServerEventHandler>(stream: &mut SmtpStream<S>,
-                       state: &mut SmtpTransactionState,
+fn handle_command_rset<E: SmtpServerEventHandler>(state: &mut SmtpTransactionState,
+                       envelope: &mut SmtpEnvelope,
                        config: &SmtpServerConfig,
+                       handlers: &[SmtpHandler<E>],
                        event_handler: &mut E,
                        line: &str) -> Result<String, Option<String>> {
     if line.len() != 0 {
-        Ok("501 No arguments allowed".into_string())
+        Ok(get_status_reply(501, EnhancedStatusCode::new(5, 5, 4), "No arguments allowed"))
     } else {
         state.reset();
-        Ok("250 OK".into_string())
+        envelope.reset();
+        Ok(get_status_reply(250, EnhancedStatusCode::new(2, 0, 0), "OK"))
     }
 }
 
@@ -247,62 +903,393 @@ fn test_command_rset() {
     // fail!();
 }
 
+// Format a mailbox found by `VRFY` or `EXPN`, ie. `Fred Smith <fred@rustastic.org>`.
+fn get_lookup_entry_text(entry: &SmtpLookupEntry) -> String {
+    match entry.display_name {
+        Some(ref name) => {
+            if name.as_slice().chars().all(|c| c == ' ' || utils::is_atext(c)) {
+                format!("{} <{}>", name, entry.mailbox)
+            } else {
+                let mut quoted = String::new();
+                for c in name.as_slice().chars() {
+                    if c == '"' || c == '\\' {
+                        quoted.push('\\');
+                    }
+                    quoted.push(c);
+                }
+                format!("\"{}\" <{}>", quoted, entry.mailbox)
+            }
+        },
+        None => format!("<{}>", entry.mailbox)
+    }
+}
+
+#[test]
+fn test_get_lookup_entry_text() {
+    use super::super::common::mailbox::Mailbox;
+
+    let mut entry = SmtpLookupEntry {
+        display_name: None,
+        mailbox: Mailbox::parse("fred@rustastic.org").unwrap()
+    };
+    assert_eq!("<fred@rustastic.org>", get_lookup_entry_text(&entry).as_slice());
+
+    entry.display_name = Some("Fred Smith".into_string());
+    assert_eq!("Fred Smith <fred@rustastic.org>", get_lookup_entry_text(&entry).as_slice());
+
+    entry.display_name = Some("Smith, Fred \"The Rust\"".into_string());
+    assert_eq!(
+        "\"Smith, Fred \\\"The Rust\\\"\" <fred@rustastic.org>",
+        get_lookup_entry_text(&entry).as_slice()
+    );
+}
+
+// Build the reply to a `VRFY` or `EXPN` lookup, as described in RFC 5321 section 3.5.
+fn get_lookup_reply(result: SmtpLookupResult, single: bool) -> String {
+    match result {
+        LookupFound(ref entries) if entries.len() == 1 || (!single && entries.len() > 0) => {
+            let status = EnhancedStatusCode::new(2, 1, 5);
+            let lines: Vec<String> = entries.iter().map(|entry| {
+                format!("{} {}", status, get_lookup_entry_text(entry))
+            }).collect();
+            get_multiline_reply(250, lines.as_slice())
+        },
+        LookupFound(ref entries) | LookupAmbiguous(ref entries) if entries.len() > 0 => {
+            let status = EnhancedStatusCode::new(5, 1, 4);
+            let mut lines = vec!(format!("{} Ambiguous; Possibilities are", status));
+            for entry in entries.iter() {
+                lines.push(format!("{} {}", status, get_lookup_entry_text(entry)));
+            }
+            get_multiline_reply(553, lines.as_slice())
+        },
+        LookupRefused => {
+            get_status_reply(
+                252,
+                EnhancedStatusCode::new(2, 0, 0),
+                "Cannot verify, but will accept message and attempt delivery"
+            )
+        },
+        _ => {
+            get_status_reply(550, EnhancedStatusCode::new(5, 1, 1), "String does not match anything")
+        }
+    }
+}
+
+#[test]
+fn test_get_lookup_reply() {
+    use super::super::common::mailbox::Mailbox;
+
+    let fred = SmtpLookupEntry {
+        display_name: Some("Fred Smith".into_string()),
+        mailbox: Mailbox::parse("fred@rustastic.org").unwrap()
+    };
+    let joe = SmtpLookupEntry {
+        display_name: None,
+        mailbox: Mailbox::parse("joe@rustastic.org").unwrap()
+    };
+
+    assert_eq!(
+        "250 2.1.5 Fred Smith <fred@rustastic.org>",
+        get_lookup_reply(LookupFound(vec!(fred.clone())), true).as_slice()
+    );
+    assert_eq!(
+        "250-2.1.5 Fred Smith <fred@rustastic.org>\r\n250 2.1.5 <joe@rustastic.org>",
+        get_lookup_reply(LookupFound(vec!(fred.clone(), joe.clone())), false).as_slice()
+    );
+    assert_eq!(
+        "553-5.1.4 Ambiguous; Possibilities are\r\n\
+         553-5.1.4 Fred Smith <fred@rustastic.org>\r\n\
+         553 5.1.4 <joe@rustastic.org>",
+        get_lookup_reply(LookupFound(vec!(fred.clone(), joe.clone())), true).as_slice()
+    );
+    assert_eq!(
+        "553-5.1.4 Ambiguous; Possibilities are\r\n553 5.1.4 <joe@rustastic.org>",
+        get_lookup_reply(LookupAmbiguous(vec!(joe.clone())), false).as_slice()
+    );
+    assert_eq!(
+        "550 5.1.1 String does not match anything",
+        get_lookup_reply(LookupNotFound, true).as_slice()
+    );
+    assert_eq!(
+        "550 5.1.1 String does not match anything",
+        get_lookup_reply(LookupFound(vec!()), false).as_slice()
+    );
+    assert_eq!(
+        "252 2.0.0 Cannot verify, but will accept message and attempt delivery",
+        get_lookup_reply(LookupRefused, false).as_slice()
+    );
+}
+
 #[allow(unused_variable)]
-fn handle_command_vrfy<S: Writer+Reader, E: SmtpServerEventHandler>(stream: &mut SmtpStream<S>,
-                       state: &mut SmtpTransactionState,
+fn handle_command_vrfy<E: SmtpServerEventHandler>(state: &mut SmtpTransactionState,
+                       envelope: &mut SmtpEnvelope,
                        config: &SmtpServerConfig,
+                       handlers: &[SmtpHandler<E>],
                        event_handler: &mut E,
                        line: &str) -> Result<String, Option<String>> {
-    Ok("252 Cannot VRFY user".into_string())
+    if line.len() != 0 && line.char_at(0) != ' ' {
+        Ok(get_status_reply(500, EnhancedStatusCode::new(5, 5, 1), "Command unrecognized"))
+    } else if !config.vrfy_enabled {
+        Ok(get_status_reply(502, EnhancedStatusCode::new(5, 5, 1), "Command not implemented"))
+    } else if line.trim().len() == 0 {
+        Ok(get_status_reply(501, EnhancedStatusCode::new(5, 5, 4), "Argument required"))
+    } else {
+        Ok(get_lookup_reply(event_handler.handle_verify(line.trim()), true))
+    }
+}
+
+// Finds `<query>@rustastic.org` for `VRFY` and `<query>-owner@rustastic.org` and
+// `<query>@rustastic.org` for `EXPN`.
+#[cfg(test)]
+struct LookupHandler;
+
+#[cfg(test)]
+fn get_test_lookup_entry(user: &str) -> SmtpLookupEntry {
+    use super::super::common::mailbox::Mailbox;
+
+    SmtpLookupEntry {
+        display_name: None,
+        mailbox: Mailbox::parse(format!("{}@rustastic.org", user).as_slice()).unwrap()
+    }
+}
+
+#[cfg(test)]
+impl SmtpServerEventHandler for LookupHandler {
+    fn handle_verify(&mut self, query: &str) -> SmtpLookupResult {
+        LookupFound(vec!(get_test_lookup_entry(query)))
+    }
+
+    fn handle_expand(&mut self, query: &str) -> SmtpLookupResult {
+        LookupFound(vec!(
+            get_test_lookup_entry(format!("{}-owner", query).as_slice()),
+            get_test_lookup_entry(query)
+        ))
+    }
 }
 
 #[test]
 fn test_command_vrfy() {
-    // fail!();
+    let mut config = super::get_test_config();
+    let mut state = Helo;
+    let mut envelope = SmtpEnvelope::new();
+
+    assert_eq!(
+        Ok("502 5.5.1 Command not implemented".into_string()),
+        handle_command_vrfy(&mut state, &mut envelope, &config, &[], &mut LookupHandler, " fred")
+    );
+
+    config.vrfy_enabled = true;
+    assert_eq!(
+        Ok("250 2.1.5 <fred@rustastic.org>".into_string()),
+        handle_command_vrfy(&mut state, &mut envelope, &config, &[], &mut LookupHandler, " fred")
+    );
+    assert_eq!(
+        Ok("501 5.5.4 Argument required".into_string()),
+        handle_command_vrfy(&mut state, &mut envelope, &config, &[], &mut LookupHandler, "")
+    );
+    assert_eq!(
+        Ok("501 5.5.4 Argument required".into_string()),
+        handle_command_vrfy(&mut state, &mut envelope, &config, &[], &mut LookupHandler, " ")
+    );
+    assert_eq!(
+        Ok("500 5.5.1 Command unrecognized".into_string()),
+        handle_command_vrfy(&mut state, &mut envelope, &config, &[], &mut LookupHandler, "X fred")
+    );
 }
 
 #[allow(unused_variable)]
-fn handle_command_expn<S: Writer+Reader, E: SmtpServerEventHandler>(stream: &mut SmtpStream<S>,
-                       state: &mut SmtpTransactionState,
+fn handle_command_expn<E: SmtpServerEventHandler>(state: &mut SmtpTransactionState,
+                       envelope: &mut SmtpEnvelope,
                        config: &SmtpServerConfig,
+                       handlers: &[SmtpHandler<E>],
                        event_handler: &mut E,
                        line: &str) -> Result<String, Option<String>> {
-    Ok("252 Cannot EXPN mailing list".into_string())
+    if line.len() != 0 && line.char_at(0) != ' ' {
+        Ok(get_status_reply(500, EnhancedStatusCode::new(5, 5, 1), "Command unrecognized"))
+    } else if !config.expn_enabled {
+        Ok(get_status_reply(502, EnhancedStatusCode::new(5, 5, 1), "Command not implemented"))
+    } else if line.trim().len() == 0 {
+        Ok(get_status_reply(501, EnhancedStatusCode::new(5, 5, 4), "Argument required"))
+    } else {
+        Ok(get_lookup_reply(event_handler.handle_expand(line.trim()), false))
+    }
 }
 
 #[test]
 fn test_command_expn() {
-    // fail!();
+    let mut config = super::get_test_config();
+    let mut state = Helo;
+    let mut envelope = SmtpEnvelope::new();
+
+    assert_eq!(
+        Ok("502 5.5.1 Command not implemented".into_string()),
+        handle_command_expn(&mut state, &mut envelope, &config, &[], &mut LookupHandler, " rust")
+    );
+
+    config.expn_enabled = true;
+    assert_eq!(
+        Ok("250-2.1.5 <rust-owner@rustastic.org>\r\n250 2.1.5 <rust@rustastic.org>".into_string()),
+        handle_command_expn(&mut state, &mut envelope, &config, &[], &mut LookupHandler, " rust")
+    );
+    assert_eq!(
+        Ok("501 5.5.4 Argument required".into_string()),
+        handle_command_expn(&mut state, &mut envelope, &config, &[], &mut LookupHandler, "")
+    );
+    assert_eq!(
+        Ok("500 5.5.1 Command unrecognized".into_string()),
+        handle_command_expn(&mut state, &mut envelope, &config, &[], &mut LookupHandler, "X rust")
+    );
+}
+
+// Build the reply to `HELP`, given the name and help text of each command.
+//
+// If `topic` is empty, the commands are listed. Otherwise, the help text of the command is sent,
+// unless it is overriden in `help_topics`.
+fn get_help_reply(commands: &[(&str, &str)],
+                  help_topics: &[(String, String)],
+                  topic: &str) -> String {
+    let status = EnhancedStatusCode::new(2, 0, 0);
+
+    if topic.len() == 0 {
+        let mut names: Vec<&str> = vec!();
+        for &(name, _) in commands.iter() {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        return get_multiline_reply(214, [
+            format!("{} Commands supported:", status),
+            format!("{} {}", status, names.connect(" ")),
+            format!("{} Use HELP <command> for more information", status)
+        ]);
+    }
+
+    let mut help = None;
+    for &(name, text) in commands.iter() {
+        if name.eq_ignore_ascii_case(topic) {
+            help = Some(text);
+            break;
+        }
+    }
+    if help.is_none() {
+        return get_status_reply(
+            504,
+            EnhancedStatusCode::new(5, 5, 4),
+            format!("No help available for {}", topic).as_slice()
+        );
+    }
+    for &(ref name, ref text) in help_topics.iter() {
+        if name.as_slice().eq_ignore_ascii_case(topic) {
+            help = Some(text.as_slice());
+            break;
+        }
+    }
+
+    let lines: Vec<String> = help.unwrap().lines_any().map(|line| {
+        format!("{} {}", status, line)
+    }).collect();
+    get_multiline_reply(214, lines.as_slice())
+}
+
+#[test]
+fn test_get_help_reply() {
+    let commands = [("HELO", HELP_HELO), ("MAIL", HELP_MAIL), ("HELO", HELP_HELO), ("NOOP", HELP_NOOP)];
+
+    assert_eq!(
+        "214-2.0.0 Commands supported:\r\n\
+         214-2.0.0 HELO MAIL NOOP\r\n\
+         214 2.0.0 Use HELP <command> for more information",
+        get_help_reply(commands.as_slice(), [], "").as_slice()
+    );
+    assert_eq!(
+        "214-2.0.0 NOOP\r\n214 2.0.0 Does nothing.",
+        get_help_reply(commands.as_slice(), [], "noop").as_slice()
+    );
+    assert_eq!(
+        "214 2.0.0 Talk to postmaster@rustastic.org",
+        get_help_reply(
+            commands.as_slice(),
+            [("NOOP".into_string(), "Talk to postmaster@rustastic.org".into_string())],
+            "NOOP"
+        ).as_slice()
+    );
+    assert_eq!(
+        "504 5.5.4 No help available for RCPT",
+        get_help_reply(
+            commands.as_slice(),
+            [("RCPT".into_string(), "Overrides don't add commands".into_string())],
+            "RCPT"
+        ).as_slice()
+    );
 }
 
 #[allow(unused_variable)]
-fn handle_command_help<S: Writer+Reader, E: SmtpServerEventHandler>(stream: &mut SmtpStream<S>,
-                       state: &mut SmtpTransactionState,
+fn handle_command_help<E: SmtpServerEventHandler>(state: &mut SmtpTransactionState,
+                       envelope: &mut SmtpEnvelope,
                        config: &SmtpServerConfig,
+                       handlers: &[SmtpHandler<E>],
                        event_handler: &mut E,
                        line: &str) -> Result<String, Option<String>> {
     if line.len() == 0 || line.char_at(0) == ' ' {
-        Ok("502 Command not implemented".into_string())
+        // Disabled commands are not advertised.
+        let commands: Vec<(&str, &str)> = handlers.iter().map(|h| {
+            (h.get_command_name(), h.help)
+        }).filter(|&(name, _)| {
+            (name != "VRFY" || config.vrfy_enabled) && (name != "EXPN" || config.expn_enabled)
+        }).collect();
+        Ok(get_help_reply(commands.as_slice(), config.help_topics.as_slice(), line.trim()))
     } else {
-        Ok("500 Command unrecognized".into_string())
+        Ok(get_status_reply(500, EnhancedStatusCode::new(5, 5, 1), "Command unrecognized"))
     }
 }
 
 #[test]
 fn test_command_help() {
-    // fail!();
+    let mut config = super::get_test_config();
+    let mut state = Init;
+    let mut envelope = SmtpEnvelope::new();
+    let handlers = get_handlers::<LookupHandler>();
+
+    assert_eq!(
+        Ok("214-2.0.0 Commands supported:\r\n\
+            214-2.0.0 HELO EHLO MAIL RCPT DATA RSET HELP NOOP QUIT\r\n\
+            214 2.0.0 Use HELP <command> for more information".into_string()),
+        handle_command_help(&mut state, &mut envelope, &config, handlers.as_slice(), &mut LookupHandler, "")
+    );
+    assert_eq!(
+        Ok("504 5.5.4 No help available for VRFY".into_string()),
+        handle_command_help(&mut state, &mut envelope, &config, handlers.as_slice(), &mut LookupHandler, " VRFY")
+    );
+
+    config.vrfy_enabled = true;
+    config.help_topics = vec!(("VRFY".into_string(), "Only for postmaster@rustastic.org".into_string()));
+    assert_eq!(
+        Ok("214-2.0.0 Commands supported:\r\n\
+            214-2.0.0 HELO EHLO MAIL RCPT DATA RSET VRFY HELP NOOP QUIT\r\n\
+            214 2.0.0 Use HELP <command> for more information".into_string()),
+        handle_command_help(&mut state, &mut envelope, &config, handlers.as_slice(), &mut LookupHandler, "")
+    );
+    assert_eq!(
+        Ok("214 2.0.0 Only for postmaster@rustastic.org".into_string()),
+        handle_command_help(&mut state, &mut envelope, &config, handlers.as_slice(), &mut LookupHandler, " vrfy")
+    );
+    assert_eq!(
+        Ok("500 5.5.1 Command unrecognized".into_string()),
+        handle_command_help(&mut state, &mut envelope, &config, handlers.as_slice(), &mut LookupHandler, "X")
+    );
 }
 
 #[allow(unused_variable)]
-fn handle_command_noop<S: Writer+Reader, E: SmtpServerEventHandler>(stream: &mut SmtpStream<S>,
-                       state: &mut SmtpTransactionState,
+fn handle_command_noop<E: SmtpServerEventHandler>(state: &mut SmtpTransactionState,
+                       envelope: &mut SmtpEnvelope,
                        config: &SmtpServerConfig,
+                       handlers: &[SmtpHandler<E>],
                        event_handler: &mut E,
                        line: &str) -> Result<String, Option<String>> {
     if line.len() == 0 || line.char_at(0) == ' ' {
-        Ok("250 OK".into_string())
+        Ok(get_status_reply(250, EnhancedStatusCode::new(2, 0, 0), "OK"))
     } else {
-        Ok("500 Command unrecognized".into_string())
+        Ok(get_status_reply(500, EnhancedStatusCode::new(5, 5, 1), "Command unrecognized"))
     }
 }
 
@@ -312,12 +1299,13 @@ fn test_command_noop() {
 }
 
 #[allow(unused_variable)]
-fn handle_command_quit<S: Writer+Reader, E: SmtpServerEventHandler>(stream: &mut SmtpStream<S>,
-                       state: &mut SmtpTransactionState,
+fn handle_command_quit<E: SmtpServerEventHandler>(state: &mut SmtpTransactionState,
+                       envelope: &mut SmtpEnvelope,
                        config: &SmtpServerConfig,
+                       handlers: &[SmtpHandler<E>],
                        event_handler: &mut E,
                        line: &str) -> Result<String, Option<String>> {
-    Err(Some(format!("221 {}", config.domain)))
+    Err(Some(get_status_reply(221, EnhancedStatusCode::new(2, 0, 0), config.domain)))
 }
 
 #[test]
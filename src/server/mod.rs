@@ -17,19 +17,71 @@
 
 use std::io::net::tcp::{TcpListener, TcpAcceptor, TcpStream};
 use std::io::net::ip::{IpAddr};
-use std::io::{Listener, Acceptor, IoError, Reader, Writer, InvalidInput};
-use super::common::stream::{SmtpStream};
+use std::io::{Listener, Acceptor, IoError, Reader, Writer};
+use super::common::stream::{SmtpStream, SmtpStreamStats, LineEndingPolicy};
+use super::common::log::{SmtpLogSink, SmtpLogRecord, SmtpLogLevel, SmtpLogKind};
+use super::common::log::{LogDebug, LogInfo, LogWarning, LogError, LogEvent};
+use std::io::fs::File;
 use std::sync::Arc;
-use std::ascii::OwnedAsciiExt;
-use super::common::transaction::{SmtpTransactionState, Init};
+use self::session::{SmtpServerSession, SessionReply, SessionContinue, SessionClose};
+use self::proxy::{ProxyProtocolConfig, read_proxy_header};
 use super::common::mailbox::Mailbox;
+use super::common::path::{Path, ReversePath, ForwardPath};
+use super::common::dsn::{DsnMailParams, DsnRcptParams};
+use super::common::status::EnhancedStatusCode;
 use super::common::{
     MIN_ALLOWED_MESSAGE_SIZE,
-    MIN_ALLOWED_LINE_SIZE,
+    MIN_ALLOWED_COMMAND_LINE_SIZE,
+    MIN_ALLOWED_TEXT_LINE_SIZE,
     MIN_ALLOWED_RECIPIENTS
 };
 
 mod handler;
+mod session;
+pub mod replay;
+pub mod proxy;
+#[cfg(feature = "async")]
+pub mod async;
+
+/// A mailbox found while handling a `VRFY` or `EXPN` command.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub struct SmtpLookupEntry {
+    /// The name of the owner of the mailbox, ie. `Fred Smith`, if known.
+    pub display_name: Option<String>,
+    /// The mailbox itself.
+    pub mailbox: Mailbox
+}
+
+/// The result of the lookup done when handling a `VRFY` or `EXPN` command.
+///
+/// The replies sent for each result are described
+/// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-3.5).
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub enum SmtpLookupResult {
+    /// The mailboxes that were found. For `VRFY`, there should be a single one, otherwise the
+    /// result is considered ambiguous. For `EXPN`, these are the members of the mailing list.
+    LookupFound(Vec<SmtpLookupEntry>),
+    /// The argument matches several mailboxes, which are sent to the client as possibilities.
+    LookupAmbiguous(Vec<SmtpLookupEntry>),
+    /// The argument does not match anything.
+    LookupNotFound,
+    /// The lookup is refused, ie. to avoid giving out information to spammers.
+    LookupRefused
+}
+
+/// What to do with a `MAIL` or `RCPT` path which has a source route.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub enum SmtpSourceRouteAction {
+    /// Keep the source route, it will be in the envelope.
+    AcceptSourceRoute,
+    /// Remove the source route and only keep the mailbox, as recommended
+    /// [in RFC 5321](http://tools.ietf.org/html/rfc5321#section-3.3).
+    StripSourceRoute,
+    /// Refuse the path with a 501 response, as a syntax error.
+    RejectSourceRouteSyntax,
+    /// Refuse the path with a 553 response, as not allowed by policy.
+    RejectSourceRouteNotAllowed
+}
 
 /// Hooks into different places of the SMTP server to allow its customization.
 ///
@@ -49,35 +101,79 @@ pub trait SmtpServerEventHandler {
 
     /// Called when we know the domain the client identifies itself with.
     ///
-    /// If `Err(())` is returned, the connection is aborted.
+    /// If `Ok(())` is returned, a 250 response is sent. If `Err(None)` is returned, a 550
+    /// response is sent with the enhanced status code `5.7.1`. If `Err(Some(code))` is
+    /// returned, `code` is used instead, with a 450 response if it is a temporary `4.x.x` code.
     #[allow(unused_variable)]
-    fn handle_domain(&mut self, domain: &str) -> Result<(), ()> {
+    fn handle_domain(&mut self, domain: &str) -> Result<(), Option<EnhancedStatusCode>> {
         Ok(())
     }
 
+    /// Called after getting a MAIL or RCPT command whose path has a source route, ie.
+    /// `<@relay1,@relay2:rust@rustastic.org>`, before the address itself is handled.
+    ///
+    /// This can be used to log legacy gateways. By default, the source route is stripped.
+    #[allow(unused_variable)]
+    fn handle_source_route(&mut self, path: &Path) -> SmtpSourceRouteAction {
+        StripSourceRoute
+    }
+
     /// Called after getting a MAIL command with a sender address.
     ///
-    /// The sender address is either `Some(Mailbox)` or `None`. If it is `None`,
-    /// it means that the reverse-path (as described in RFC 5321) was null,
-    /// which can happen when an email server sends a delivery failure
-    /// notification.
+    /// The reverse-path (as described in RFC 5321) is either a `MailboxPath` or a `NullPath`.
+    /// It is null when an email server sends a delivery failure notification.
     ///
-    /// If `Ok(())` is returned, a 250 response is sent. If `Err(())` is returned, a 550 response
-    /// is sent and the sender is discarded.
+    /// The `RET` and `ENVID` parameters of the DSN extension are passed along,
+    /// if the client sent them.
+    ///
+    /// If `Ok(())` is returned, a 250 response is sent. If `Err(None)` is returned, a 550
+    /// response is sent with the enhanced status code `5.7.1` and the sender is discarded. If
+    /// `Err(Some(code))` is returned, `code` is used instead, with a 450 response if it is a
+    /// temporary `4.x.x` code.
     #[allow(unused_variable)]
-    fn handle_sender_address(&mut self, mailbox: Option<&Mailbox>) -> Result<(), ()> {
+    fn handle_sender_address(&mut self, reverse_path: &ReversePath, dsn: &DsnMailParams) -> Result<(), Option<EnhancedStatusCode>> {
         Ok(())
     }
 
     /// Called after getting a RCPT command.
     ///
-    /// If `Ok(())` is returned, a 250 response is sent. If `Err(())` is returned, a 550 response
-    /// is sent and the recipient is discarded.
+    /// The forward-path is either a `RecipientPath` or a `PostmasterPath`, for the special
+    /// domainless `<Postmaster>` form which must always be accepted.
+    ///
+    /// If sub-address separators are set in the config, the mailbox of the path is the base
+    /// mailbox and the detail is passed separately, ie. `support@rustastic.org` and `billing` for
+    /// `support+billing@rustastic.org`, so that routing can be done on the base mailbox.
+    ///
+    /// The `NOTIFY` and `ORCPT` parameters of the DSN extension are passed along,
+    /// if the client sent them.
+    ///
+    /// If `Ok(())` is returned, a 250 response is sent. If `Err(None)` is returned, a 550
+    /// response is sent with the enhanced status code `5.1.1` and the recipient is discarded.
+    /// If `Err(Some(code))` is returned, `code` is used instead, with a 450 response if it is a
+    /// temporary `4.x.x` code.
     #[allow(unused_variable)]
-    fn handle_receiver_address(&mut self, mailbox: &Mailbox) -> Result<(), ()> {
+    fn handle_receiver_address(&mut self, forward_path: &ForwardPath, detail: Option<&str>, dsn: &DsnRcptParams) -> Result<(), Option<EnhancedStatusCode>> {
         Ok(())
     }
 
+    /// Called after getting a VRFY command, if it is enabled in the config.
+    ///
+    /// The argument is the string given by the client, which can be a mailbox or just a user
+    /// name. By default, the lookup is refused and a 252 response is sent.
+    #[allow(unused_variable)]
+    fn handle_verify(&mut self, query: &str) -> SmtpLookupResult {
+        LookupRefused
+    }
+
+    /// Called after getting an EXPN command, if it is enabled in the config.
+    ///
+    /// The argument is the name of the mailing list given by the client. By default, the
+    /// lookup is refused and a 252 response is sent.
+    #[allow(unused_variable)]
+    fn handle_expand(&mut self, query: &str) -> SmtpLookupResult {
+        LookupRefused
+    }
+
     /// Called when we know the first body part is coming, ie. when we get the
     /// DATA or BDAT command from the client.
     ///
@@ -115,24 +211,99 @@ pub trait SmtpServerEventHandler {
     fn handle_body_end(&mut self) -> Result<(), ()> {
         Ok(())
     }
+
+    /// Called instead of `handle_body_end` when the message is dropped after
+    /// `handle_body_start`, ie. because of an overlong line or a bare line ending, because the
+    /// message is larger than `max_message_size`, or because the connection was lost.
+    ///
+    /// The body parts received so far should be discarded.
+    fn handle_body_abort(&mut self) {
+    }
+
+    /// Called when the connection is closed, whether the client quit or the connection was
+    /// lost.
+    ///
+    /// The counters of the session can be used for accounting or to detect abusive clients.
+    #[allow(unused_variable)]
+    fn handle_disconnect(&mut self, stats: &SmtpSessionStats) {
+    }
+}
+
+/// Counters of a session, given to the event handler when the client is gone.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub struct SmtpSessionStats {
+    /// The counters of the connection.
+    pub stream: SmtpStreamStats,
+    /// The number of commands read. Streams can't tell commands from message data, so this is
+    /// counted by the session.
+    pub commands: u64
 }
 
 /// Represents the configuration of an SMTP server.
 pub struct SmtpServerConfig {
     /// Port on which to listen for incoming messages.
     pub port: u16,
-    /// If `true`, debug messages will be printed to the console during transactions.
-    pub debug: bool,
-    /// The IP on which to `bind (2)` the `TcpListener`.
+    /// The IP on which to `bind (2)` the `TcpListener`, IPv4 or IPv6. `AsyncSmtpServer` only
+    /// accepts IP addresses, not host names.
     pub ip: &'static str,
     /// The domain name used to identify the SMTP server.
     pub domain: &'static str,
-    /// The maximum message size, including headers and ending sequence.
+    /// The maximum message size, including headers and line endings, as defined by RFC 1870.
+    /// Larger messages are refused with a 552 reply once the client has sent all of them.
     pub max_message_size: uint,
-    /// The maximum line size, including `<CRLF>`. At least 1000 per RFC 5321.
-    pub max_line_size: uint,
+    /// The maximum size of command lines, including `<CRLF>`. At least 512 per RFC 5321. The
+    /// extensions the server supports raise it further, ie. by 500 for DSN.
+    pub max_command_line_size: uint,
+    /// The maximum size of text lines in message data, including `<CRLF>`. At least 1001 per
+    /// RFC 5321, 1000 chars + 1 for the transparency mechanism.
+    pub max_text_line_size: uint,
+    /// ESMTP extensions to advertise in the reply to `EHLO` besides the built-in ones, with the
+    /// number of bytes each of them adds to the maximum command line size, ie.
+    /// `("XCLIENT".into_string(), 0)`.
+    pub extensions: Vec<(String, uint)>,
     /// Maximum number of recipients per SMTP transaction.
     pub max_recipients: uint,
+    /// If `false`, `VRFY` is answered with a 502 response without calling the event handler.
+    pub vrfy_enabled: bool,
+    /// If `false`, `EXPN` is answered with a 502 response without calling the event handler.
+    pub expn_enabled: bool,
+    /// Replacement help texts for `HELP <command>`, as pairs of command name and text, ie.
+    /// `("VRFY", "Only postmasters may use VRFY.")`. Each line of text is sent as a separate
+    /// line of the reply. The texts can be loaded at runtime, ie. from a file.
+    pub help_topics: Vec<(String, String)>,
+    /// Characters that separate the user from a detail in recipient addresses, ie. `+` for
+    /// `support+billing@rustastic.org`. If empty, recipients are passed to the event handler
+    /// as is.
+    pub subaddress_separators: Vec<char>,
+    /// What to do with bare `<LF>` and bare `<CR>` in commands and message data. Unless you
+    /// need to support broken local clients, use `RejectBareLineEndings`.
+    pub line_ending_policy: LineEndingPolicy,
+    /// Log records less important than this are dropped. `LogDebug` includes protocol
+    /// transcripts.
+    pub log_level: SmtpLogLevel,
+    /// Where log records are sent, ie. `box StdoutLogSink` to print them to the console.
+    pub log_sink: Box<SmtpLogSink + Send + Sync>,
+    /// If `true`, the credentials sent with `AUTH`, and the lines sent in response to a `334`
+    /// challenge, are replaced by `<redacted>` in the protocol lines sent to `log_sink`. Transcript
+    /// files, see `transcript_dir`, are never redacted.
+    pub log_redact_auth: bool,
+    /// If `false`, message data is left out of the protocol lines sent to `log_sink`. Only
+    /// commands and replies are logged. Transcript files always contain it.
+    pub log_message_data: bool,
+    /// If set, the exact bytes sent and received during each session are recorded in a file
+    /// named after the session ID in this directory, ie. `42.transcript`. See the `transcript`
+    /// module for the format and the `replay` module, or the `replay` example, to replay them.
+    /// Both `SmtpServer` and `AsyncSmtpServer` record them.
+    ///
+    /// Transcripts are exact, so that they can be replayed: they contain the credentials sent
+    /// with `AUTH` and the message data in clear text, whatever `log_redact_auth` and
+    /// `log_message_data` say. Only use them where that data may be stored.
+    pub transcript_dir: Option<&'static str>,
+    /// If set, clients from the trusted networks, ie. load balancers, must start with a PROXY
+    /// protocol header giving the address of the real client. This address is then given to
+    /// the event handler and used in logs. Each server has its own listener and config, so
+    /// that it can be enabled on one port and not on another.
+    pub proxy_protocol: Option<ProxyProtocolConfig>,
     //pub timeout: uint, // at least 5 minutes
     //pub max_clients: uint, // maximum clients to handle at any given time
     //pub max_pending_clients: uint, // maximum clients to put on hold while handling other clients
@@ -153,7 +324,9 @@ pub struct SmtpServer<S: 'static + Writer + Reader, A: Acceptor<S>, E: 'static +
     event_handler: E,
     // Since the handler are function pointers, these are immutable and can safely
     // be stored in an Arc.
-    handlers: Arc<Vec<handler::SmtpHandler<S, E>>>
+    handlers: Arc<Vec<handler::SmtpHandler<E>>>,
+    // The ID of the next session, to tell sessions apart in logs.
+    next_session_id: u64
 }
 
 /// Represents an error during creation of an SMTP server.
@@ -165,35 +338,164 @@ pub enum SmtpServerError {
     ListenFailed(IoError),
     /// The max message size set in the config is too low.
     MaxMessageSizeTooLow(uint),
-    /// The max line size set in the config is too low.
-    MaxLineSizeTooLow(uint),
+    /// The max command line size set in the config is too low.
+    MaxCommandLineSizeTooLow(uint),
+    /// The max text line size set in the config is too low.
+    MaxTextLineSizeTooLow(uint),
     /// The max number of recipients set in the config is too low.
     MaxRecipientsTooLow(uint)
 }
 
+// The config used by tests, with the lowest limits allowed.
+#[cfg(test)]
+fn get_test_config() -> SmtpServerConfig {
+    use super::common::stream::RejectBareLineEndings;
+    use super::common::log::NullLogSink;
+
+    SmtpServerConfig {
+        ip: "0.0.0.0",
+        domain: "rustastic.org",
+        port: 25,
+        max_recipients: MIN_ALLOWED_RECIPIENTS,
+        max_message_size: MIN_ALLOWED_MESSAGE_SIZE,
+        max_command_line_size: MIN_ALLOWED_COMMAND_LINE_SIZE,
+        max_text_line_size: MIN_ALLOWED_TEXT_LINE_SIZE,
+        extensions: vec!(),
+        vrfy_enabled: false,
+        expn_enabled: false,
+        help_topics: vec!(),
+        subaddress_separators: vec!(),
+        line_ending_policy: RejectBareLineEndings,
+        log_level: LogError,
+        log_sink: box NullLogSink,
+        log_redact_auth: true,
+        log_message_data: false,
+        transcript_dir: None,
+        proxy_protocol: None
+    }
+}
+
 #[test]
 fn test_smtp_server_error() {
     // fail!();
 }
 
+// Check that the limits set in the config are allowed by the RFCs.
+fn check_config(config: &SmtpServerConfig) -> Result<(), SmtpServerError> {
+    if config.max_message_size < MIN_ALLOWED_MESSAGE_SIZE {
+        Err(MaxMessageSizeTooLow(config.max_message_size))
+    } else if config.max_command_line_size < MIN_ALLOWED_COMMAND_LINE_SIZE {
+        Err(MaxCommandLineSizeTooLow(config.max_command_line_size))
+    } else if config.max_text_line_size < MIN_ALLOWED_TEXT_LINE_SIZE {
+        Err(MaxTextLineSizeTooLow(config.max_text_line_size))
+    } else if config.max_recipients < MIN_ALLOWED_RECIPIENTS {
+        Err(MaxRecipientsTooLow(config.max_recipients))
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_check_config() {
+    assert!(check_config(&get_test_config()).is_ok());
+
+    let mut config = get_test_config();
+    config.max_message_size = MIN_ALLOWED_MESSAGE_SIZE - 1;
+    match check_config(&config) {
+        Err(MaxMessageSizeTooLow(size)) => assert_eq!(MIN_ALLOWED_MESSAGE_SIZE - 1, size),
+        _ => fail!()
+    }
+
+    let mut config = get_test_config();
+    config.max_command_line_size = MIN_ALLOWED_COMMAND_LINE_SIZE - 1;
+    match check_config(&config) {
+        Err(MaxCommandLineSizeTooLow(size)) => assert_eq!(MIN_ALLOWED_COMMAND_LINE_SIZE - 1, size),
+        _ => fail!()
+    }
+
+    let mut config = get_test_config();
+    config.max_text_line_size = MIN_ALLOWED_TEXT_LINE_SIZE - 1;
+    match check_config(&config) {
+        Err(MaxTextLineSizeTooLow(size)) => assert_eq!(MIN_ALLOWED_TEXT_LINE_SIZE - 1, size),
+        _ => fail!()
+    }
+
+    let mut config = get_test_config();
+    config.max_recipients = MIN_ALLOWED_RECIPIENTS - 1;
+    match check_config(&config) {
+        Err(MaxRecipientsTooLow(size)) => assert_eq!(MIN_ALLOWED_RECIPIENTS - 1, size),
+        _ => fail!()
+    }
+}
+
+// Send a log record to the sink set in the config, unless it is less important than the
+// configured level. `session` is the ID and client IP of the session the record belongs to.
+fn log_record(config: &SmtpServerConfig,
+              level: SmtpLogLevel,
+              kind: SmtpLogKind,
+              session: Option<(u64, IpAddr)>,
+              message: &str) {
+    if level < config.log_level {
+        return;
+    }
+    config.log_sink.log(&SmtpLogRecord {
+        level: level,
+        kind: kind,
+        session_id: session.map(|(id, _)| id),
+        peer_ip: session.map(|(_, ip)| ip),
+        message: message
+    });
+}
+
+// Keeps the log records it gets as strings, so that tests can check them.
+#[cfg(test)]
+struct RecordingSink {
+    records: Arc<::std::sync::Mutex<Vec<String>>>
+}
+
+#[cfg(test)]
+impl SmtpLogSink for RecordingSink {
+    fn log(&self, record: &SmtpLogRecord) {
+        self.records.lock().push(record.to_string());
+    }
+}
+
+#[test]
+fn test_log_record() {
+    use std::io::net::ip::Ipv4Addr;
+    use std::sync::Mutex;
+    use super::common::log::LogClientLine;
+
+    let records = Arc::new(Mutex::new(vec!()));
+    let mut config = get_test_config();
+    config.log_level = LogInfo;
+    config.log_sink = box RecordingSink { records: records.clone() };
+
+    log_record(&config, LogDebug, LogClientLine, Some((2, Ipv4Addr(127, 0, 0, 1))), "NOOP");
+    log_record(&config, LogInfo, LogEvent, None, "listening on port 25");
+    log_record(&config, LogWarning, LogEvent, Some((2, Ipv4Addr(127, 0, 0, 1))), "line too long");
+    log_record(&config, LogError, LogEvent, None, "could not create transcript");
+    assert_eq!(
+        vec!(
+            "rsmtp: info: listening on port 25".into_string(),
+            "rsmtp: warning: #2 127.0.0.1: line too long".into_string(),
+            "rsmtp: error: could not create transcript".into_string()
+        ),
+        *records.lock()
+    );
+}
+
 impl<S: Writer + Reader + Send, A: Acceptor<S>, E: SmtpServerEventHandler+Clone+Send> SmtpServer<S, A, E> {
     /// Creates a new SMTP server from an `Acceptor` implementor. Useful for testing.
     fn new_from_acceptor(acceptor: A, config: SmtpServerConfig, event_handler: E) -> Result<SmtpServer<S, A, E>, SmtpServerError> {
-        if config.max_message_size < MIN_ALLOWED_MESSAGE_SIZE {
-            Err(MaxMessageSizeTooLow(config.max_message_size))
-        } else if config.max_line_size < MIN_ALLOWED_LINE_SIZE {
-            Err(MaxLineSizeTooLow(config.max_line_size))
-        } else if config.max_recipients < MIN_ALLOWED_RECIPIENTS {
-            Err(MaxRecipientsTooLow(config.max_recipients))
-        } else {
-            Ok(SmtpServer {
-                acceptor: acceptor,
-                config: Arc::new(config),
-                event_handler: event_handler,
-                handlers: Arc::new(handler::get_handlers::<S, E>())
-            })
-        }
-
+        try!(check_config(&config));
+        Ok(SmtpServer {
+            acceptor: acceptor,
+            config: Arc::new(config),
+            event_handler: event_handler,
+            handlers: Arc::new(handler::get_handlers::<E>()),
+            next_session_id: 1
+        })
     }
 }
 
@@ -202,14 +504,10 @@ impl<E: SmtpServerEventHandler + Clone + Send> SmtpServer<TcpStream, TcpAcceptor
     pub fn new(config: SmtpServerConfig, event_handler: E) -> Result<SmtpServer<TcpStream, TcpAcceptor, E>, SmtpServerError> {
         match TcpListener::bind(config.ip, config.port) {
             Ok(listener) => {
-                if config.debug {
-                    println!("rsmtp: info: binding on ip {}", config.ip);
-                }
+                log_record(&config, LogInfo, LogEvent, None, format!("binding on ip {}", config.ip).as_slice());
                 match listener.listen() {
                     Ok(acceptor) => {
-                        if config.debug {
-                            println!("rsmtp: info: listening on port {}", config.port);
-                        }
+                        log_record(&config, LogInfo, LogEvent, None, format!("listening on port {}", config.port).as_slice());
                         SmtpServer::new_from_acceptor(acceptor, config, event_handler)
                     },
                     Err(err) => Err(ListenFailed(err))
@@ -226,14 +524,17 @@ impl<E: SmtpServerEventHandler + Clone + Send> SmtpServer<TcpStream, TcpAcceptor
                 Ok(stream) => {
                     let mut stream = stream.clone();
                     let config = self.config.clone();
-                    let mut event_handler = self.event_handler.clone();
+                    let event_handler = self.event_handler.clone();
                     let handlers = self.handlers.clone();
+                    let session_id = self.next_session_id;
+                    self.next_session_id += 1;
 
                     spawn(proc() {
                         SmtpServer::handle_client(
                             &mut stream,
+                            session_id,
                             config,
-                            &mut event_handler,
+                            event_handler,
                             handlers
                         );
                     })
@@ -247,134 +548,194 @@ impl<E: SmtpServerEventHandler + Clone + Send> SmtpServer<TcpStream, TcpAcceptor
     // Handle one client inside a separate thread
     fn handle_client(
             stream: &mut TcpStream,
+            session_id: u64,
             config: Arc<SmtpServerConfig>,
-            event_handler: &mut E,
-            handlers: Arc<Vec<handler::SmtpHandler<TcpStream, E>>>) {
+            mut event_handler: E,
+            handlers: Arc<Vec<handler::SmtpHandler<E>>>) {
         // TODO: remove unwrap and handle error
-        event_handler.handle_connection(&stream.peer_name().unwrap().ip).unwrap();
+        let mut peer_ip = stream.peer_name().unwrap().ip;
+
+        // Load balancers tell us who the real client is before anything else.
+        match config.proxy_protocol {
+            Some(ref proxy) if proxy.is_trusted(&peer_ip) => {
+                match read_proxy_header(stream) {
+                    Ok(Some(addr)) => {
+                        log_record(
+                            config.deref(),
+                            LogDebug,
+                            LogEvent,
+                            Some((session_id, addr.ip)),
+                            format!("proxied by {}", peer_ip).as_slice()
+                        );
+                        peer_ip = addr.ip;
+                    },
+                    Ok(None) => {},
+                    Err(err) => {
+                        log_record(
+                            config.deref(),
+                            LogWarning,
+                            LogEvent,
+                            Some((session_id, peer_ip)),
+                            format!("invalid PROXY protocol header: {}", err).as_slice()
+                        );
+                        return;
+                    }
+                }
+            },
+            _ => {}
+        }
 
-        let mut stream = SmtpStream::new(stream.clone(), config.max_line_size, config.debug);
+        if event_handler.handle_connection(&peer_ip).is_err() {
+            log_record(
+                config.deref(),
+                LogInfo,
+                LogEvent,
+                Some((session_id, peer_ip)),
+                "connection refused by the event handler"
+            );
+            return;
+        }
+
+        let mut session = SmtpServerSession::new(session_id, peer_ip, config.clone(), event_handler, handlers);
+        let mut stream = SmtpStream::new(stream.clone(), session.max_line_size());
+        stream.set_line_ending_policy(config.line_ending_policy);
+
+        match create_transcript(config.deref(), session_id, peer_ip) {
+            Some(file) => stream.record_transcript(box file),
+            None => {}
+        }
 
         // TODO: WAIT FOR: https://github.com/rust-lang/rust/issues/15802
         //stream.stream.set_deadline(local_config.timeout);
 
-        // Send the opening welcome message.
-        stream.write_line(format!("220 {}", config.domain).as_slice()).unwrap();
-        
-
-        // Loop over incoming commands and process them.
-        SmtpServer::inner_loop(
-            &mut stream,
-            config,
-            event_handler,
-            handlers
-        );
+        run_session(&mut stream, &mut session);
     }
+}
 
-    // Get the right handler for a given command line.
-    fn get_handler_for_line<'a>(
-            handlers: &'a [handler::SmtpHandler<TcpStream, E>],
-            line: &str) -> Option<&'a handler::SmtpHandler<TcpStream, E>> {
-        for h in handlers.iter() {
-            // Don't check lines shorter than required. This also avoids getting an
-            // out of bounds error below.
-            if line.len() < h.command_start.len() {
-                continue;
+// Create the transcript file of a session if the config sets a transcript directory. Failures
+// are logged and the session goes on without a transcript.
+fn create_transcript(config: &SmtpServerConfig, session_id: u64, peer_ip: IpAddr) -> Option<File> {
+    match config.transcript_dir {
+        Some(dir) => {
+            let path = ::std::path::Path::new(dir).join(format!("{}.transcript", session_id));
+            match File::create(&path) {
+                Ok(file) => Some(file),
+                Err(err) => {
+                    log_record(
+                        config,
+                        LogError,
+                        LogEvent,
+                        Some((session_id, peer_ip)),
+                        format!("could not create transcript: {}", err).as_slice()
+                    );
+                    None
+                }
             }
-            let line_start = line.as_slice().slice_to(h.command_start.len())
-                .into_string().into_ascii_upper();
-            // Check that the begining of the command matches an existing SMTP
-            // command. This could be something like "HELO " or "RCPT TO:".
-            if line_start.as_slice().starts_with(h.command_start.as_slice()) {
-                return Some(h);
+        },
+        None => None
+    }
+}
+
+// Talk with a client until it quits or the connection is lost.
+fn run_session<S: Reader + Writer, E: SmtpServerEventHandler>(stream: &mut SmtpStream<S>,
+                                                             session: &mut SmtpServerSession<E>) {
+    // Send the opening welcome message. If the client is already gone, there is no session
+    // to run but the event handler is still told about the disconnection.
+    let mut connected = stream.write_line(session.greeting().as_slice()).is_ok();
+
+    // Forever, looooop over lines and handle them.
+    while connected {
+        // Commands may have changed the limit, ie. `DATA` to read text lines.
+        stream.set_max_line_size(session.max_line_size());
+
+        let action = {
+            let (read, stats) = stream.read_line_with_stats();
+            session.handle_read(read, stats)
+        };
+        match action {
+            SessionReply(msg) => {
+                // The client may be gone, ie. if it reset the connection.
+                connected = stream.write_line(msg.as_slice()).is_ok();
+            },
+            SessionContinue => {},
+            SessionClose(msg) => {
+                match msg {
+                    Some(msg) => {
+                        // The client may already be gone, there is nothing to do about it.
+                        let _ = stream.write_line(msg.as_slice());
+                    },
+                    None => {}
+                }
+                break;
             }
         }
-        None
     }
 
-    fn get_line_and_handler<'a>(
-            stream: &mut SmtpStream<TcpStream>,
-            handlers: &'a [handler::SmtpHandler<TcpStream, E>]) -> Result<(String, Option<&'a handler::SmtpHandler<TcpStream, E>>), IoError> {
-        match stream.read_line() {
-            Ok(bytes) => {
-                let line = String::from_utf8_lossy(bytes.as_slice()).into_string();
-                let handler = SmtpServer::get_handler_for_line(handlers, line.as_slice());
+    session.handle_disconnect(stream.stats());
+}
 
-                Ok((line, handler))
-            },
-            Err(err) => {
-                Err(err)
+#[test]
+fn test_run_session() {
+    // Most of it is tested by replaying transcripts, see the `replay` module.
+    use std::io::{IoResult, BrokenPipe, standard_error};
+    use std::io::net::ip::Ipv4Addr;
+
+    // A client which sends commands but resets the connection after reading the greeting.
+    struct ResetStream {
+        input: Vec<u8>,
+        writes: uint
+    }
+    impl Reader for ResetStream {
+        fn read(&mut self, buf: &mut [u8]) -> IoResult<uint> {
+            let len = ::std::cmp::min(buf.len(), self.input.len());
+            for i in range(0, len) {
+                buf[i] = self.input[i];
             }
+            self.input = self.input.slice_from(len).to_vec();
+            Ok(len)
         }
     }
-
-    fn get_reply(
-            stream: &mut SmtpStream<TcpStream>,
-            handlers: &[handler::SmtpHandler<TcpStream, E>],
-            state: &mut SmtpTransactionState,
-            config: &SmtpServerConfig,
-            event_handler: &mut E) -> Result<String, Option<String>> {
-        match SmtpServer::get_line_and_handler(stream, handlers) {
-            Ok((line, Some(handler))) => {
-                if handler.allowed_states.contains(state) {
-                    let rest = line.as_slice().slice_from(handler.command_start.len());
-                    (handler.callback)(
-                        stream,
-                        state,
-                        config,
-                        event_handler,
-                        rest
-                    )
-                } else {
-                    Ok("503 Bad sequence of commands".into_string())
-                }
-            },
-            Ok((_, None)) => {
-                Ok("500 Command unrecognized".into_string())
-            },
-            Err(err) => {
-                // If the line was too long, notify the client.
-                match err.kind {
-                    InvalidInput => {
-                        // TODO: check error desc to make sure this is right
-                        Ok("500 Command line too long, max is 512 bytes".into_string())
-                    },
-                    _ => {
-                        // If we get here, the error is unexpected. What to do with it?
-                        Err(Some(err.to_string()))
-                    }
-                }
+    impl Writer for ResetStream {
+        #[allow(unused_variable)]
+        fn write(&mut self, buf: &[u8]) -> IoResult<()> {
+            self.writes += 1;
+            if self.writes > 1 {
+                return Err(standard_error(BrokenPipe));
             }
+            Ok(())
         }
     }
 
-    // Forever, looooop over command lines and handle them.
-    fn inner_loop(
-            stream: &mut SmtpStream<TcpStream>,
-            config: Arc<SmtpServerConfig>,
-            event_handler: &mut E,
-            handlers: Arc<Vec<handler::SmtpHandler<TcpStream, E>>>) {
-        // Setup the initial transaction state for this client.
-        let mut state = Init;
-        'main_loop: loop {
-            let reply = SmtpServer::get_reply(
-                stream,
-                handlers.as_slice(),
-                &mut state,
-                config.deref(),
-                event_handler
-            );
-
-            match reply {
-                Ok(msg) => {
-                    stream.write_line(msg.as_slice()).unwrap();
-                },
-                Err(err) => {
-                    fail!(err);
-                }
-            }
+    struct DisconnectHandler {
+        stats: Option<SmtpSessionStats>
+    }
+    impl SmtpServerEventHandler for DisconnectHandler {
+        fn handle_disconnect(&mut self, stats: &SmtpSessionStats) {
+            self.stats = Some(stats.clone());
         }
     }
+
+    let mut session = SmtpServerSession::new(
+        1,
+        Ipv4Addr(127, 0, 0, 1),
+        Arc::new(get_test_config()),
+        DisconnectHandler { stats: None },
+        Arc::new(handler::get_handlers::<DisconnectHandler>())
+    );
+    let mut stream = SmtpStream::new(
+        ResetStream { input: b"HELO rustastic.org\r\nNOOP\r\n".to_vec(), writes: 0 },
+        session.max_line_size()
+    );
+    run_session(&mut stream, &mut session);
+    assert_eq!(Some(SmtpSessionStats {
+        stream: SmtpStreamStats {
+            bytes_read: 20,
+            bytes_written: 19,
+            lines_read: 1,
+            lines_written: 1
+        },
+        commands: 1
+    }), session.event_handler().stats);
 }
 
 #[test]
@@ -0,0 +1,351 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for the [PROXY protocol](http://www.haproxy.org/download/1.5/doc/proxy-protocol.txt),
+//! versions 1 and 2.
+//!
+//! Load balancers which use it send a header before anything else, with the address of the
+//! client they are forwarding. Since anybody could send such a header, it is only read from
+//! connections coming from trusted networks.
+
+use std::io::{Reader, IoError};
+use std::io::net::ip::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+// Version 2 headers start with these bytes.
+static V2_SIGNATURE: &'static [u8] = b"\r\n\r\n\x00\r\nQUIT\n";
+
+// Version 1 headers start with these bytes.
+static V1_PREFIX: &'static [u8] = b"PROXY ";
+
+// The maximum size of a version 1 header, `<CRLF>` included.
+static V1_MAX_SIZE: uint = 107;
+
+/// A network, ie. `10.0.0.0/8`.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub struct IpNetwork {
+    addr: IpAddr,
+    prefix_len: uint
+}
+
+impl IpNetwork {
+    /// Create a network from its address and the number of bits of its prefix.
+    pub fn new(addr: IpAddr, prefix_len: uint) -> IpNetwork {
+        IpNetwork {
+            addr: addr,
+            prefix_len: prefix_len
+        }
+    }
+
+    /// Parse a network in CIDR notation, ie. `10.0.0.0/8` or `2001:db8::/32`. A single address
+    /// is a network containing only this address.
+    pub fn parse(s: &str) -> Option<IpNetwork> {
+        let mut parts = s.splitn(1, '/');
+        let addr: IpAddr = match parts.next().and_then(from_str) {
+            Some(addr) => addr,
+            None => return None
+        };
+        let max_prefix_len = get_ip_bytes(&addr).len() * 8;
+        let prefix_len = match parts.next() {
+            Some(len) => match from_str::<uint>(len) {
+                Some(len) if len <= max_prefix_len => len,
+                _ => return None
+            },
+            None => max_prefix_len
+        };
+        Some(IpNetwork::new(addr, prefix_len))
+    }
+
+    /// Returns `true` if the network contains the given address. IPv4 addresses are never part
+    /// of IPv6 networks and vice-versa.
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        let network = get_ip_bytes(&self.addr);
+        let ip = get_ip_bytes(ip);
+        if network.len() != ip.len() {
+            return false;
+        }
+        for i in range(0, network.len()) {
+            let bits = if self.prefix_len >= (i + 1) * 8 {
+                8
+            } else if self.prefix_len > i * 8 {
+                self.prefix_len - i * 8
+            } else {
+                return true;
+            };
+            // The first `bits` bits of the byte are part of the prefix.
+            let mask = ((0xff00u16 >> bits) & 0xff) as u8;
+            if network[i] & mask != ip[i] & mask {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+// Get the bytes of an address, 4 for IPv4 and 16 for IPv6.
+fn get_ip_bytes(ip: &IpAddr) -> Vec<u8> {
+    match *ip {
+        Ipv4Addr(a, b, c, d) => vec!(a, b, c, d),
+        Ipv6Addr(a, b, c, d, e, f, g, h) => {
+            let mut bytes = Vec::new();
+            for &part in [a, b, c, d, e, f, g, h].iter() {
+                bytes.push((part >> 8) as u8);
+                bytes.push(part as u8);
+            }
+            bytes
+        }
+    }
+}
+
+#[test]
+fn test_ip_network() {
+    let network = IpNetwork::parse("10.1.0.0/16").unwrap();
+    assert_eq!(IpNetwork::new(Ipv4Addr(10, 1, 0, 0), 16), network);
+    assert!(network.contains(&Ipv4Addr(10, 1, 2, 3)));
+    assert!(!network.contains(&Ipv4Addr(10, 2, 2, 3)));
+    assert!(!network.contains(&Ipv6Addr(0, 0, 0, 0, 0, 0, 0, 1)));
+
+    let network = IpNetwork::parse("192.168.0.128/25").unwrap();
+    assert!(network.contains(&Ipv4Addr(192, 168, 0, 200)));
+    assert!(!network.contains(&Ipv4Addr(192, 168, 0, 127)));
+
+    let network = IpNetwork::parse("127.0.0.1").unwrap();
+    assert!(network.contains(&Ipv4Addr(127, 0, 0, 1)));
+    assert!(!network.contains(&Ipv4Addr(127, 0, 0, 2)));
+
+    let network = IpNetwork::parse("2001:db8::/32").unwrap();
+    assert!(network.contains(&Ipv6Addr(0x2001, 0xdb8, 1, 0, 0, 0, 0, 1)));
+    assert!(!network.contains(&Ipv6Addr(0x2001, 0xdb9, 0, 0, 0, 0, 0, 1)));
+
+    assert!(IpNetwork::parse("0.0.0.0/0").unwrap().contains(&Ipv4Addr(1, 2, 3, 4)));
+    assert_eq!(None, IpNetwork::parse("10.0.0.0/33"));
+    assert_eq!(None, IpNetwork::parse("rustastic.org"));
+}
+
+/// The PROXY protocol settings of a server.
+#[deriving(Clone, Show)]
+pub struct ProxyProtocolConfig {
+    /// The networks the load balancers connect from. Only clients from these networks must send
+    /// a PROXY protocol header, others are considered to connect directly.
+    pub trusted_networks: Vec<IpNetwork>
+}
+
+impl ProxyProtocolConfig {
+    /// Returns `true` if a PROXY protocol header is expected from a client with this address.
+    pub fn is_trusted(&self, ip: &IpAddr) -> bool {
+        self.trusted_networks.iter().any(|network| network.contains(ip))
+    }
+}
+
+/// An error while reading a PROXY protocol header.
+#[deriving(Show)]
+pub enum ProxyHeaderError {
+    /// The header could not be read.
+    ProxyIoError(IoError),
+    /// The header is not valid.
+    ProxyInvalidHeader
+}
+
+/// The result of parsing the beginning of a connection's input.
+#[deriving(PartialEq, Eq, Show)]
+pub enum ProxyHeaderParse {
+    /// The header is incomplete. At least this many more bytes are needed.
+    ProxyIncomplete(uint),
+    /// The header is complete and this many bytes long. If the load balancer forwarded a client,
+    /// its address is given. Otherwise, ie. for health checks, the address of the connection
+    /// should be used.
+    ProxyComplete(uint, Option<SocketAddr>)
+}
+
+/// Parse a PROXY protocol header, version 1 or 2, at the beginning of `buf`.
+pub fn parse_proxy_header(buf: &[u8]) -> Result<ProxyHeaderParse, ProxyHeaderError> {
+    if buf.len() < V2_SIGNATURE.len() && V2_SIGNATURE.starts_with(buf) {
+        Ok(ProxyIncomplete(V2_SIGNATURE.len() - buf.len()))
+    } else if buf.starts_with(V2_SIGNATURE) {
+        parse_v2(buf)
+    } else if buf.len() < V1_PREFIX.len() && V1_PREFIX.starts_with(buf) {
+        Ok(ProxyIncomplete(V1_PREFIX.len() - buf.len()))
+    } else if buf.starts_with(V1_PREFIX) {
+        parse_v1(buf)
+    } else {
+        Err(ProxyInvalidHeader)
+    }
+}
+
+// Parse a version 1 header, ie. `PROXY TCP4 192.0.2.1 198.51.100.1 56324 25`.
+fn parse_v1(buf: &[u8]) -> Result<ProxyHeaderParse, ProxyHeaderError> {
+    let mut len = 0;
+    while len + 1 < buf.len() && !(buf[len] == 13 && buf[len + 1] == 10) {
+        len += 1;
+    }
+    if len + 1 >= buf.len() {
+        return if buf.len() >= V1_MAX_SIZE {
+            Err(ProxyInvalidHeader)
+        } else {
+            Ok(ProxyIncomplete(1))
+        };
+    }
+    if len + 2 > V1_MAX_SIZE {
+        return Err(ProxyInvalidHeader);
+    }
+
+    let line = match String::from_utf8(buf.slice_to(len).to_vec()) {
+        Ok(line) => line,
+        Err(_) => return Err(ProxyInvalidHeader)
+    };
+    let words: Vec<&str> = line.as_slice().split(' ').collect();
+
+    // The load balancer doesn't know the client, ie. for health checks. The spec says that
+    // the rest of the line must be ignored, only its size and ending have been checked.
+    if words[1] == "UNKNOWN" {
+        return Ok(ProxyComplete(len + 2, None));
+    }
+    // Otherwise, we get the family, the source and destination addresses and ports. We only
+    // use the source, but a header with an invalid destination is not trusted either.
+    if words.len() != 6 {
+        return Err(ProxyInvalidHeader);
+    }
+    let addresses = (from_str::<IpAddr>(words[2]), from_str::<IpAddr>(words[3]));
+    let ports = (from_str::<u16>(words[4]), from_str::<u16>(words[5]));
+    match (words[1], addresses, ports) {
+        ("TCP4", (Some(ip @ Ipv4Addr(..)), Some(Ipv4Addr(..))), (Some(port), Some(_))) |
+        ("TCP6", (Some(ip @ Ipv6Addr(..)), Some(Ipv6Addr(..))), (Some(port), Some(_))) => {
+            Ok(ProxyComplete(len + 2, Some(SocketAddr { ip: ip, port: port })))
+        },
+        _ => Err(ProxyInvalidHeader)
+    }
+}
+
+// Parse a version 2 header, which is binary.
+fn parse_v2(buf: &[u8]) -> Result<ProxyHeaderParse, ProxyHeaderError> {
+    // The signature is followed by the version and command, the address family and the
+    // length of the rest of the header.
+    if buf.len() < 16 {
+        return Ok(ProxyIncomplete(16 - buf.len()));
+    }
+    let len = 16 + ((buf[14] as uint) << 8 | buf[15] as uint);
+    if buf.len() < len {
+        return Ok(ProxyIncomplete(len - buf.len()));
+    }
+    if buf[12] >> 4 != 2 {
+        return Err(ProxyInvalidHeader);
+    }
+    let addresses = buf.slice(16, len);
+    match (buf[12] & 0x0f, buf[13] >> 4) {
+        // LOCAL, the load balancer is talking for itself, ie. for health checks.
+        (0, _) => Ok(ProxyComplete(len, None)),
+        // PROXY over IPv4: source and destination addresses, then ports.
+        (1, 1) if addresses.len() >= 12 => {
+            let ip = Ipv4Addr(addresses[0], addresses[1], addresses[2], addresses[3]);
+            let port = (addresses[8] as u16) << 8 | addresses[9] as u16;
+            Ok(ProxyComplete(len, Some(SocketAddr { ip: ip, port: port })))
+        },
+        // PROXY over IPv6: source and destination addresses, then ports.
+        (1, 2) if addresses.len() >= 36 => {
+            let mut parts = [0u16, ..8];
+            for i in range(0, 8) {
+                parts[i] = (addresses[i * 2] as u16) << 8 | addresses[i * 2 + 1] as u16;
+            }
+            let ip = Ipv6Addr(parts[0], parts[1], parts[2], parts[3], parts[4], parts[5], parts[6], parts[7]);
+            let port = (addresses[32] as u16) << 8 | addresses[33] as u16;
+            Ok(ProxyComplete(len, Some(SocketAddr { ip: ip, port: port })))
+        },
+        // Other families, ie. UNIX sockets, don't tell anything useful about the client.
+        (1, 0) | (1, 3) => Ok(ProxyComplete(len, None)),
+        _ => Err(ProxyInvalidHeader)
+    }
+}
+
+#[test]
+fn test_parse_proxy_header() {
+    let v1 = b"PROXY TCP4 192.0.2.1 198.51.100.1 56324 25\r\nEHLO";
+    for i in range(0, 44) {
+        match parse_proxy_header(v1.slice_to(i)) {
+            Ok(ProxyIncomplete(_)) => {},
+            other => fail!("{} bytes: {}", i, other)
+        }
+    }
+    assert_eq!(
+        ProxyComplete(44, Some(SocketAddr { ip: Ipv4Addr(192, 0, 2, 1), port: 56324 })),
+        parse_proxy_header(v1).unwrap()
+    );
+    assert_eq!(
+        ProxyComplete(45, Some(SocketAddr { ip: Ipv6Addr(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), port: 56324 })),
+        parse_proxy_header(b"PROXY TCP6 2001:db8::1 2001:db8::2 56324 25\r\n").unwrap()
+    );
+    assert_eq!(ProxyComplete(15, None), parse_proxy_header(b"PROXY UNKNOWN\r\n").unwrap());
+    assert_eq!(
+        ProxyComplete(47, None),
+        parse_proxy_header(b"PROXY UNKNOWN 192.0.2.1 anything goes here 25\r\n").unwrap()
+    );
+    let overlong = format!("PROXY UNKNOWN {}\r\n", String::from_char(100, 'x'));
+    assert!(parse_proxy_header(overlong.as_bytes()).is_err());
+    assert!(parse_proxy_header(b"PROXY TCP4 2001:db8::1 2001:db8::2 56324 25\r\n").is_err());
+    assert!(parse_proxy_header(b"PROXY TCP4 192.0.2.1 2001:db8::2 56324 25\r\n").is_err());
+    assert!(parse_proxy_header(b"PROXY TCP4 192.0.2.1 198.51.100 56324 25\r\n").is_err());
+    assert!(parse_proxy_header(b"PROXY TCP6 2001:db8::1 198.51.100.1 56324 25\r\n").is_err());
+    assert!(parse_proxy_header(b"PROXY TCP4 192.0.2.1 198.51.100.1 56324 65536\r\n").is_err());
+    assert!(parse_proxy_header(b"PROXY TCP4 192.0.2.1\r\n").is_err());
+    assert!(parse_proxy_header(b"EHLO rustastic.org\r\n").is_err());
+    let overlong = format!("PROXY {}", String::from_char(101, 'x'));
+    assert!(parse_proxy_header(overlong.as_bytes()).is_err());
+
+    let mut v2 = V2_SIGNATURE.to_vec();
+    v2.push_all([0x21, 0x11, 0, 12, 192, 0, 2, 1, 198, 51, 100, 1, 0xdc, 0x04, 0, 25]);
+    assert_eq!(ProxyIncomplete(4), parse_proxy_header(v2.slice_to(12)).unwrap());
+    assert_eq!(ProxyIncomplete(12), parse_proxy_header(v2.slice_to(16)).unwrap());
+    assert_eq!(
+        ProxyComplete(28, Some(SocketAddr { ip: Ipv4Addr(192, 0, 2, 1), port: 56324 })),
+        parse_proxy_header(v2.as_slice()).unwrap()
+    );
+
+    let mut local = V2_SIGNATURE.to_vec();
+    local.push_all([0x20, 0x00, 0, 0]);
+    assert_eq!(ProxyComplete(16, None), parse_proxy_header(local.as_slice()).unwrap());
+
+    let mut v3 = V2_SIGNATURE.to_vec();
+    v3.push_all([0x31, 0x11, 0, 0]);
+    assert!(parse_proxy_header(v3.as_slice()).is_err());
+}
+
+/// Read a PROXY protocol header from a blocking stream. Never reads more than the header, so
+/// that the stream can then be used for SMTP.
+pub fn read_proxy_header<R: Reader>(reader: &mut R) -> Result<Option<SocketAddr>, ProxyHeaderError> {
+    let mut buf = Vec::new();
+    loop {
+        match try!(parse_proxy_header(buf.as_slice())) {
+            ProxyIncomplete(len) => {
+                match reader.read_exact(len) {
+                    Ok(bytes) => buf.push_all(bytes.as_slice()),
+                    Err(err) => return Err(ProxyIoError(err))
+                }
+            },
+            ProxyComplete(_, addr) => return Ok(addr)
+        }
+    }
+}
+
+#[test]
+fn test_read_proxy_header() {
+    use std::io::MemReader;
+
+    let mut reader = MemReader::new(b"PROXY TCP4 192.0.2.1 198.51.100.1 56324 25\r\nEHLO".to_vec());
+    assert_eq!(
+        Some(SocketAddr { ip: Ipv4Addr(192, 0, 2, 1), port: 56324 }),
+        read_proxy_header(&mut reader).unwrap()
+    );
+    // The rest of the input is left for SMTP.
+    assert_eq!(b"EHLO".to_vec(), reader.read_to_end().unwrap());
+
+    assert!(read_proxy_header(&mut MemReader::new(b"PROXY TCP4".to_vec())).is_err());
+}
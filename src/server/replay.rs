@@ -0,0 +1,265 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replay recorded transcripts against a server.
+//!
+//! The bytes the client sent are fed to a session, in the same chunks as they were read, and
+//! the replies of the session are compared with the recorded ones. Since replies depend on the
+//! event handler, use the same config and an event handler which behaves like the one of the
+//! server the transcript was recorded on.
+
+use std::cmp;
+use std::io::{Reader, Writer, IoResult, EndOfFile, standard_error};
+use std::io::net::ip::IpAddr;
+use std::sync::Arc;
+use super::{SmtpServerConfig, SmtpServerEventHandler};
+use super::{handler, run_session};
+use super::session::SmtpServerSession;
+use super::super::common::stream::SmtpStream;
+use super::super::common::transcript::{TranscriptEntry, TranscriptRead, TranscriptWritten};
+
+/// A reply line which differs between the transcript and the replay.
+#[deriving(PartialEq, Eq, Clone, Show)]
+pub struct ReplayDifference {
+    /// The index of the line among all the lines written by the server, starting at 0.
+    pub line: uint,
+    /// The line in the transcript, if any.
+    pub expected: Option<String>,
+    /// The line written during the replay, if any.
+    pub actual: Option<String>
+}
+
+// A stream which gives the recorded input in the recorded chunks and keeps what is written.
+struct ReplayStream {
+    input: Vec<Vec<u8>>,
+    // The chunk being read and the position in it.
+    chunk: uint,
+    pos: uint,
+    output: Vec<u8>
+}
+
+impl Reader for ReplayStream {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<uint> {
+        if self.chunk >= self.input.len() {
+            return Err(standard_error(EndOfFile));
+        }
+        let chunk = self.input[self.chunk].slice_from(self.pos);
+        let len = cmp::min(chunk.len(), buf.len());
+        for i in range(0, len) {
+            buf[i] = chunk[i];
+        }
+        self.pos += len;
+        if self.pos == self.input[self.chunk].len() {
+            self.chunk += 1;
+            self.pos = 0;
+        }
+        Ok(len)
+    }
+}
+
+impl Writer for ReplayStream {
+    fn write(&mut self, buf: &[u8]) -> IoResult<()> {
+        self.output.push_all(buf);
+        Ok(())
+    }
+}
+
+/// Feed the client side of a transcript to a new session and return the reply lines which
+/// differ from the transcript. `peer_ip` is passed to the event handler as the client IP.
+///
+/// If the event handler refuses the connection, nothing is written, like with `SmtpServer`
+/// which closes the connection right away, so every recorded reply is a difference.
+pub fn replay<E: SmtpServerEventHandler>(entries: &[TranscriptEntry],
+                                         peer_ip: IpAddr,
+                                         config: SmtpServerConfig,
+                                         mut event_handler: E) -> Vec<ReplayDifference> {
+    let mut input = Vec::new();
+    let mut expected = Vec::new();
+    for entry in entries.iter() {
+        match entry.direction {
+            TranscriptRead => input.push(entry.data.clone()),
+            TranscriptWritten => expected.push_all(entry.data.as_slice())
+        }
+    }
+
+    if event_handler.handle_connection(&peer_ip).is_err() {
+        return diff_lines(expected.as_slice(), &[]);
+    }
+
+    let config = Arc::new(config);
+    let mut session = SmtpServerSession::new(
+        0,
+        peer_ip,
+        config.clone(),
+        event_handler,
+        Arc::new(handler::get_handlers::<E>())
+    );
+    let mut stream = SmtpStream::new(
+        ReplayStream {
+            input: input,
+            chunk: 0,
+            pos: 0,
+            output: Vec::new()
+        },
+        session.max_line_size()
+    );
+    stream.set_line_ending_policy(config.line_ending_policy);
+    run_session(&mut stream, &mut session);
+
+    diff_lines(expected.as_slice(), stream.into_inner().output.as_slice())
+}
+
+// Split an output into lines ended with `<CRLF>`.
+fn split_lines(output: &[u8]) -> Vec<String> {
+    let output = String::from_utf8_lossy(output).into_string();
+    let mut lines: Vec<String> = output.as_slice().split_str("\r\n").map(|line| line.into_string()).collect();
+    // The last line ends with `<CRLF>` too, there is nothing after it.
+    if lines.last().map_or(false, |line| line.len() == 0) {
+        let len = lines.len();
+        lines.truncate(len - 1);
+    }
+    lines
+}
+
+// Compare two outputs line by line.
+fn diff_lines(expected: &[u8], actual: &[u8]) -> Vec<ReplayDifference> {
+    let expected = split_lines(expected);
+    let actual = split_lines(actual);
+
+    let mut differences = Vec::new();
+    for i in range(0, cmp::max(expected.len(), actual.len())) {
+        let e = expected.as_slice().get(i).map(|line| line.clone());
+        let a = actual.as_slice().get(i).map(|line| line.clone());
+        if e != a {
+            differences.push(ReplayDifference {
+                line: i,
+                expected: e,
+                actual: a
+            });
+        }
+    }
+    differences
+}
+
+#[test]
+fn test_diff_lines() {
+    assert_eq!(0, diff_lines(b"220 a\r\n250 OK\r\n", b"220 a\r\n250 OK\r\n").len());
+    assert_eq!(
+        vec!(ReplayDifference { line: 1, expected: Some("250 OK".into_string()), actual: Some("550 No".into_string()) }),
+        diff_lines(b"220 a\r\n250 OK\r\n", b"220 a\r\n550 No\r\n")
+    );
+    assert_eq!(
+        vec!(ReplayDifference { line: 1, expected: None, actual: Some("250 OK".into_string()) }),
+        diff_lines(b"220 a\r\n", b"220 a\r\n250 OK\r\n")
+    );
+}
+
+#[cfg(test)]
+struct ReplayHandler;
+
+#[cfg(test)]
+impl SmtpServerEventHandler for ReplayHandler {}
+
+#[cfg(test)]
+fn replay_file(path: &str) -> Vec<ReplayDifference> {
+    use std::io::BufferedReader;
+    use std::io::fs::File;
+    use std::io::net::ip::Ipv4Addr;
+    use super::super::common::transcript::read_transcript;
+
+    let file = File::open(&Path::new(path)).unwrap();
+    let entries = read_transcript(&mut BufferedReader::new(file)).unwrap();
+    replay(entries.as_slice(), Ipv4Addr(127, 0, 0, 1), super::get_test_config(), ReplayHandler)
+}
+
+#[test]
+fn test_replay() {
+    assert_eq!(Vec::new(), replay_file("tests/transcript/session1"));
+    assert_eq!(
+        vec!(ReplayDifference {
+            line: 5,
+            expected: Some("550 5.1.1 No".into_string()),
+            actual: Some("250 2.1.5 OK".into_string())
+        }),
+        replay_file("tests/transcript/session2")
+    );
+}
+
+#[test]
+fn test_replay_refused() {
+    use std::io::BufferedReader;
+    use std::io::fs::File;
+    use std::io::net::ip::{IpAddr, Ipv4Addr};
+    use super::super::common::transcript::read_transcript;
+
+    struct RefusingHandler;
+    impl SmtpServerEventHandler for RefusingHandler {
+        #[allow(unused_variable)]
+        fn handle_connection(&mut self, client_ip: &IpAddr) -> Result<(), ()> {
+            Err(())
+        }
+    }
+
+    let file = File::open(&Path::new("tests/transcript/session1")).unwrap();
+    let entries = read_transcript(&mut BufferedReader::new(file)).unwrap();
+    let differences = replay(entries.as_slice(), Ipv4Addr(127, 0, 0, 1), super::get_test_config(), RefusingHandler);
+    assert_eq!(
+        ReplayDifference { line: 0, expected: Some("220 rustastic.org".into_string()), actual: None },
+        differences[0]
+    );
+    assert!(differences.iter().all(|difference| difference.actual.is_none()));
+}
+
+// Replay a transaction sending `data` as message data, expecting `reply` at the final dot.
+#[cfg(test)]
+fn replay_data(data: &str, reply: &str) -> Vec<ReplayDifference> {
+    use std::io::net::ip::Ipv4Addr;
+
+    let input = format!(
+        "EHLO rustastic.org\r\nMAIL FROM:<rust@rustastic.org>\r\nRCPT TO:<bob@rustastic.org>\r\nDATA\r\n{}.\r\nQUIT\r\n",
+        data
+    );
+    let output = format!(
+        "220 rustastic.org\r\n250-rustastic.org\r\n250-DSN\r\n250 ENHANCEDSTATUSCODES\r\n250 2.1.0 OK\r\n250 2.1.5 OK\r\n354 Start mail input; end with <CRLF>.<CRLF>\r\n{}\r\n221 2.0.0 rustastic.org\r\n",
+        reply
+    );
+    let entries = [
+        TranscriptEntry { direction: TranscriptRead, timestamp: 0, data: input.into_bytes() },
+        TranscriptEntry { direction: TranscriptWritten, timestamp: 0, data: output.into_bytes() }
+    ];
+    replay(entries.as_slice(), Ipv4Addr(127, 0, 0, 1), super::get_test_config(), ReplayHandler)
+}
+
+#[test]
+fn test_replay_message_size() {
+    use super::super::common::MIN_ALLOWED_MESSAGE_SIZE;
+
+    // Lines of 64 bytes, `<CRLF>` included. The first one starts with a dot, which the client
+    // doubles and which is not counted twice.
+    let mut data = String::new();
+    data.push_str("..");
+    data.push_str(String::from_char(61, 'x').as_slice());
+    data.push_str("\r\n");
+    for _ in range(1, MIN_ALLOWED_MESSAGE_SIZE / 64) {
+        data.push_str(String::from_char(62, 'x').as_slice());
+        data.push_str("\r\n");
+    }
+    assert_eq!(Vec::new(), replay_data(data.as_slice(), "250 2.0.0 OK"));
+
+    // One more line is too much. The rest of the message is still read, so that the session
+    // can go on.
+    data.push_str("x\r\n");
+    let reply = format!("552 5.3.4 Too much mail data, max {} bytes", MIN_ALLOWED_MESSAGE_SIZE);
+    assert_eq!(Vec::new(), replay_data(data.as_slice(), reply.as_slice()));
+}
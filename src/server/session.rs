@@ -0,0 +1,541 @@
+// Copyright 2014 The Rustastic SMTP Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The state machine of an SMTP session, independent of how lines are read and replies are
+//! written. Both the blocking server and the event loop based one drive it.
+
+use std::io::{IoResult, InvalidInput};
+use std::io::net::ip::IpAddr;
+use std::sync::Arc;
+use std::ascii::OwnedAsciiExt;
+use super::{SmtpServerConfig, SmtpServerEventHandler, SmtpSessionStats};
+use super::log_record;
+use super::handler;
+use super::handler::{SmtpHandler, SmtpDataState};
+use super::super::common::stream::{LineEnding, SmtpStreamStats, LINE_TOO_LONG, BARE_LINE_ENDING};
+use super::super::common::transaction::{SmtpTransactionState, SmtpEnvelope, Init, Data};
+use super::super::common::status::EnhancedStatusCode;
+use super::super::common::log::{SmtpLogLevel, SmtpLogKind, LogDebug, LogInfo, LogWarning};
+use super::super::common::log::{LogClientLine, LogServerLine, LogEvent, redact_auth};
+
+/// What to do after a line has been handled by a session.
+#[deriving(PartialEq, Eq, Show)]
+pub enum SmtpSessionAction {
+    /// Send this reply and keep reading lines.
+    SessionReply(String),
+    /// Keep reading lines without replying, ie. in the middle of message data.
+    SessionContinue,
+    /// Send the reply, if any, and close the connection.
+    SessionClose(Option<String>)
+}
+
+/// A session with a single client.
+pub struct SmtpServerSession<E: SmtpServerEventHandler> {
+    // Tells sessions apart in logs.
+    id: u64,
+    peer_ip: IpAddr,
+    config: Arc<SmtpServerConfig>,
+    event_handler: E,
+    // Shared by all the sessions of a server since they are only function pointers.
+    handlers: Arc<Vec<SmtpHandler<E>>>,
+    state: SmtpTransactionState,
+    envelope: SmtpEnvelope,
+    // Only meaningful while the state is `Data`.
+    data: SmtpDataState,
+    // The number of commands read so far, since the stream can't tell them from message data.
+    commands: u64,
+    // Whether the client greeted with `EHLO`. Per RFC 2034, enhanced status codes are only
+    // sent to such clients.
+    extended: bool,
+    // Whether the last reply was a `334` challenge, in which case the next line is a SASL
+    // response, ie. credentials.
+    in_auth: bool
+}
+
+impl<E: SmtpServerEventHandler> SmtpServerSession<E> {
+    /// Creates a session for a client which has just connected.
+    pub fn new(id: u64,
+               peer_ip: IpAddr,
+               config: Arc<SmtpServerConfig>,
+               event_handler: E,
+               handlers: Arc<Vec<SmtpHandler<E>>>) -> SmtpServerSession<E> {
+        let session = SmtpServerSession {
+            id: id,
+            peer_ip: peer_ip,
+            config: config,
+            event_handler: event_handler,
+            handlers: handlers,
+            state: Init,
+            envelope: SmtpEnvelope::new(),
+            data: SmtpDataState::new(0),
+            commands: 0,
+            extended: false,
+            in_auth: false
+        };
+        session.log(LogInfo, LogEvent, "connected");
+        session
+    }
+
+    // Send a log record about this session.
+    fn log(&self, level: SmtpLogLevel, kind: SmtpLogKind, message: &str) {
+        log_record(self.config.deref(), level, kind, Some((self.id, self.peer_ip)), message);
+    }
+
+    /// Get the event handler of this session.
+    pub fn event_handler(&mut self) -> &mut E {
+        &mut self.event_handler
+    }
+
+    /// Get the opening welcome message to send to the client.
+    pub fn greeting(&self) -> String {
+        let greeting = format!("220 {}", self.config.domain);
+        self.log(LogDebug, LogServerLine, greeting.as_slice());
+        greeting
+    }
+
+    /// Get the maximum size of the next line, which depends on whether it is a command or
+    /// message data.
+    pub fn max_line_size(&self) -> uint {
+        match self.state {
+            Data => self.config.max_text_line_size,
+            _ => handler::get_max_command_line_size(self.config.deref())
+        }
+    }
+
+    /// Handles a line read from the client, or the error which happened instead of reading it.
+    /// `stats` are the counters of the stream, including this line.
+    pub fn handle_read(&mut self, read: IoResult<(&[u8], LineEnding)>, stats: SmtpStreamStats) -> SmtpSessionAction {
+        match read {
+            Ok((ref bytes, _)) => {
+                // Message data is only logged if asked for, it may be big and private.
+                if self.state != Data {
+                    let line = String::from_utf8_lossy(*bytes).into_string();
+                    if self.config.log_redact_auth && self.in_auth {
+                        self.log(LogDebug, LogClientLine, "<redacted>");
+                    } else if self.config.log_redact_auth {
+                        self.log(LogDebug, LogClientLine, redact_auth(line.as_slice()).as_slice());
+                    } else {
+                        self.log(LogDebug, LogClientLine, line.as_slice());
+                    }
+                } else if self.config.log_message_data {
+                    self.log(LogDebug, LogClientLine, String::from_utf8_lossy(*bytes).as_slice());
+                }
+            },
+            Err(ref err) if err.kind == InvalidInput => {
+                self.log(LogWarning, LogEvent, err.desc);
+            },
+            Err(ref err) => {
+                self.log(LogInfo, LogEvent, format!("read failed: {}", err).as_slice());
+            }
+        }
+
+        let reply = if self.state == Data {
+            match handler::handle_data_line(
+                &mut self.data,
+                &mut self.state,
+                &mut self.envelope,
+                self.config.deref(),
+                &mut self.event_handler,
+                read,
+                stats.bytes_read
+            ) {
+                Some(reply) => reply,
+                None => return SessionContinue
+            }
+        } else {
+            match read {
+                Ok((bytes, _)) => {
+                    let line = String::from_utf8_lossy(bytes).into_string();
+                    self.commands += 1;
+                    let reply = self.handle_command(line.as_slice());
+                    // `DATA` has been accepted, message data is read from a clean state.
+                    if self.state == Data {
+                        self.data = SmtpDataState::new(stats.bytes_read);
+                    }
+                    reply
+                },
+                Err(ref err) if err.kind == InvalidInput && err.desc == LINE_TOO_LONG => {
+                    Ok(handler::get_status_reply(
+                        500,
+                        EnhancedStatusCode::new(5, 5, 2),
+                        format!(
+                            "Command line too long, max is {} bytes",
+                            handler::get_max_command_line_size(self.config.deref())
+                        ).as_slice()
+                    ))
+                },
+                Err(ref err) if err.kind == InvalidInput && err.desc == BARE_LINE_ENDING => {
+                    Ok(handler::get_bare_line_ending_reply())
+                },
+                // If we get here, the error is unexpected, ie. the client is gone.
+                Err(_) => Err(None)
+            }
+        };
+
+        let reply = if self.extended {
+            reply
+        } else {
+            match reply {
+                Ok(msg) => Ok(handler::strip_status_codes(msg.as_slice())),
+                Err(Some(msg)) => Err(Some(handler::strip_status_codes(msg.as_slice()))),
+                Err(None) => Err(None)
+            }
+        };
+
+        match reply {
+            Ok(msg) => {
+                self.log(LogDebug, LogServerLine, msg.as_slice());
+                // An authentication exchange goes on until a reply other than a challenge.
+                self.in_auth = msg.as_slice().starts_with("334");
+                SessionReply(msg)
+            },
+            Err(msg) => {
+                match msg {
+                    Some(ref msg) => self.log(LogDebug, LogServerLine, msg.as_slice()),
+                    None => {}
+                }
+                self.log(LogInfo, LogEvent, "disconnected");
+                SessionClose(msg)
+            }
+        }
+    }
+
+    /// Tells the event handler that the client is gone, with the final counters of the stream.
+    /// If the client was sending a message, the event handler is told that it is dropped first.
+    pub fn handle_disconnect(&mut self, stats: SmtpStreamStats) {
+        handler::abort_data(&self.data, &self.state, &mut self.event_handler);
+        self.event_handler.handle_disconnect(&SmtpSessionStats {
+            stream: stats,
+            commands: self.commands
+        });
+    }
+
+    // Find the handler for a command line and call it.
+    fn handle_command(&mut self, line: &str) -> Result<String, Option<String>> {
+        for h in self.handlers.iter() {
+            // Don't check lines shorter than required. This also avoids getting an
+            // out of bounds error below.
+            if line.len() < h.command_start.len() {
+                continue;
+            }
+            let line_start = line.slice_to(h.command_start.len())
+                .into_string().into_ascii_upper();
+            // Check that the begining of the command matches an existing SMTP
+            // command. This could be something like "HELO " or "RCPT TO:".
+            if !line_start.as_slice().starts_with(h.command_start.as_slice()) {
+                continue;
+            }
+            if !h.allowed_states.contains(&self.state) {
+                return Ok(handler::get_status_reply(
+                    503,
+                    EnhancedStatusCode::new(5, 5, 1),
+                    "Bad sequence of commands"
+                ));
+            }
+            let greeted = self.state != Init;
+            let reply = (h.callback)(
+                &mut self.state,
+                &mut self.envelope,
+                self.config.deref(),
+                self.handlers.as_slice(),
+                &mut self.event_handler,
+                line.slice_from(h.command_start.len())
+            );
+            // `HELO` and `EHLO` are only accepted once, in the `Init` state.
+            if !greeted && self.state != Init {
+                self.extended = h.get_command_name() == "EHLO";
+            }
+            return reply;
+        }
+        Ok(handler::get_status_reply(
+            500,
+            EnhancedStatusCode::new(5, 5, 1),
+            "Command unrecognized"
+        ))
+    }
+}
+
+#[cfg(test)]
+struct SessionHandler;
+
+#[cfg(test)]
+impl SmtpServerEventHandler for SessionHandler {}
+
+#[cfg(test)]
+fn get_test_session() -> SmtpServerSession<SessionHandler> {
+    use std::io::net::ip::Ipv4Addr;
+
+    SmtpServerSession::new(
+        1,
+        Ipv4Addr(127, 0, 0, 1),
+        Arc::new(super::get_test_config()),
+        SessionHandler,
+        Arc::new(handler::get_handlers::<SessionHandler>())
+    )
+}
+
+#[cfg(test)]
+fn get_test_stats(bytes_read: u64) -> SmtpStreamStats {
+    SmtpStreamStats {
+        bytes_read: bytes_read,
+        bytes_written: 0,
+        lines_read: 0,
+        lines_written: 0
+    }
+}
+
+// Give a line ending with `<CRLF>` to a session, counting the bytes read so far.
+#[cfg(test)]
+fn read_test_line(session: &mut SmtpServerSession<SessionHandler>,
+                  bytes_read: &mut u64,
+                  line: &str) -> SmtpSessionAction {
+    use super::super::common::stream::Crlf;
+
+    *bytes_read += line.len() as u64 + 2;
+    session.handle_read(Ok((line.as_bytes(), Crlf)), get_test_stats(*bytes_read))
+}
+
+#[test]
+fn test_session() {
+    use super::super::common::MIN_ALLOWED_TEXT_LINE_SIZE;
+
+    let mut session = get_test_session();
+    let mut read = 0;
+    assert_eq!("220 rustastic.org", session.greeting().as_slice());
+
+    assert_eq!(
+        SessionReply("503 Bad sequence of commands".into_string()),
+        read_test_line(&mut session, &mut read, "DATA")
+    );
+    assert_eq!(
+        SessionReply("250-rustastic.org\r\n250-DSN\r\n250 ENHANCEDSTATUSCODES".into_string()),
+        read_test_line(&mut session, &mut read, "EHLO rustastic.org")
+    );
+    assert_eq!(
+        SessionReply("250 2.1.0 OK".into_string()),
+        read_test_line(&mut session, &mut read, "MAIL FROM:<rust@rustastic.org>")
+    );
+    assert_eq!(
+        SessionReply("250 2.1.5 OK".into_string()),
+        read_test_line(&mut session, &mut read, "RCPT TO:<bob@rustastic.org>")
+    );
+    assert_eq!(
+        SessionReply("354 Start mail input; end with <CRLF>.<CRLF>".into_string()),
+        read_test_line(&mut session, &mut read, "DATA")
+    );
+    assert_eq!(MIN_ALLOWED_TEXT_LINE_SIZE, session.max_line_size());
+    assert_eq!(SessionContinue, read_test_line(&mut session, &mut read, "Subject: Hello"));
+    assert_eq!(SessionContinue, read_test_line(&mut session, &mut read, ""));
+    assert_eq!(SessionContinue, read_test_line(&mut session, &mut read, "QUIT"));
+    assert_eq!(
+        SessionReply("250 2.0.0 OK".into_string()),
+        read_test_line(&mut session, &mut read, ".")
+    );
+    assert_eq!(handler::get_max_command_line_size(session.config.deref()), session.max_line_size());
+    assert_eq!(
+        SessionReply("503 5.5.1 Bad sequence of commands".into_string()),
+        read_test_line(&mut session, &mut read, "RCPT TO:<bob@rustastic.org>")
+    );
+    assert_eq!(
+        SessionClose(Some("221 2.0.0 rustastic.org".into_string())),
+        read_test_line(&mut session, &mut read, "QUIT")
+    );
+    assert_eq!(7, session.commands);
+}
+
+#[test]
+fn test_session_helo() {
+    use std::io::{IoError, EndOfFile};
+
+    // Clients which don't send `EHLO` get no enhanced status codes.
+    let mut session = get_test_session();
+    let mut read = 0;
+    assert_eq!(
+        SessionReply("500 Command unrecognized".into_string()),
+        read_test_line(&mut session, &mut read, "HELLO")
+    );
+    assert_eq!(SessionReply("250 OK".into_string()), read_test_line(&mut session, &mut read, "HELO rustastic.org"));
+    assert_eq!(
+        SessionReply("250 OK".into_string()),
+        read_test_line(&mut session, &mut read, "MAIL FROM:<>")
+    );
+    assert_eq!(
+        SessionReply("503 Bad sequence of commands".into_string()),
+        read_test_line(&mut session, &mut read, "EHLO rustastic.org")
+    );
+
+    // The client is gone.
+    let closed = IoError { kind: EndOfFile, desc: "end of file", detail: None };
+    assert_eq!(SessionClose(None), session.handle_read(Err(closed), get_test_stats(read)));
+}
+
+#[test]
+fn test_session_disconnect_in_data() {
+    use std::io::{IoError, EndOfFile};
+    use std::io::net::ip::Ipv4Addr;
+    use super::super::common::stream::Crlf;
+
+    // Counts the calls to the body hooks.
+    struct AbortHandler {
+        started: uint,
+        aborted: uint
+    }
+    impl SmtpServerEventHandler for AbortHandler {
+        fn handle_body_start(&mut self) -> Result<(), ()> {
+            self.started += 1;
+            Ok(())
+        }
+        fn handle_body_abort(&mut self) {
+            self.aborted += 1;
+        }
+    }
+
+    let mut session = SmtpServerSession::new(
+        1,
+        Ipv4Addr(127, 0, 0, 1),
+        Arc::new(super::get_test_config()),
+        AbortHandler { started: 0, aborted: 0 },
+        Arc::new(handler::get_handlers::<AbortHandler>())
+    );
+    let mut read = 0;
+    for line in ["HELO rustastic.org", "MAIL FROM:<>", "RCPT TO:<bob@rustastic.org>", "DATA", "Subject: Hello"].iter() {
+        read += line.len() as u64 + 2;
+        let _ = session.handle_read(Ok((line.as_bytes(), Crlf)), get_test_stats(read));
+    }
+
+    // The connection is lost before the final dot, the message is dropped once.
+    let closed = IoError { kind: EndOfFile, desc: "end of file", detail: None };
+    assert_eq!(SessionClose(None), session.handle_read(Err(closed), get_test_stats(read)));
+    session.handle_disconnect(get_test_stats(read));
+    let handler = session.event_handler();
+    assert_eq!((1, 1), (handler.started, handler.aborted));
+}
+
+// Answers `AUTH` with a challenge, like the first step of `AUTH LOGIN`.
+#[cfg(test)]
+#[allow(unused_variable)]
+fn handle_test_auth(state: &mut SmtpTransactionState,
+                    envelope: &mut SmtpEnvelope,
+                    config: &SmtpServerConfig,
+                    handlers: &[SmtpHandler<SessionHandler>],
+                    event_handler: &mut SessionHandler,
+                    line: &str) -> Result<String, Option<String>> {
+    Ok("334 VXNlcm5hbWU6".into_string())
+}
+
+#[test]
+fn test_session_log_redact_auth() {
+    use std::io::net::ip::Ipv4Addr;
+    use std::sync::Mutex;
+    use super::RecordingSink;
+    use super::super::common::transaction::Helo;
+
+    let records = Arc::new(Mutex::new(vec!()));
+    let mut config = super::get_test_config();
+    config.log_level = LogDebug;
+    config.log_sink = box RecordingSink { records: records.clone() };
+    let mut handlers = handler::get_handlers::<SessionHandler>();
+    handlers.push(SmtpHandler {
+        command_start: "AUTH ".into_string(),
+        help: "AUTH <mechanism>",
+        allowed_states: vec!(Helo),
+        callback: handle_test_auth
+    });
+    let mut session = SmtpServerSession::new(
+        1,
+        Ipv4Addr(127, 0, 0, 1),
+        Arc::new(config),
+        SessionHandler,
+        Arc::new(handlers)
+    );
+
+    let mut read = 0;
+    let _ = read_test_line(&mut session, &mut read, "EHLO rustastic.org");
+    let _ = read_test_line(&mut session, &mut read, "AUTH PLAIN AHJ1c3QAc2VjcmV0");
+    let _ = read_test_line(&mut session, &mut read, "cnVzdA==");
+    let _ = read_test_line(&mut session, &mut read, "AUTH LOGIN");
+    let _ = read_test_line(&mut session, &mut read, "c2VjcmV0");
+    let _ = read_test_line(&mut session, &mut read, "NOOP");
+
+    let client_lines: Vec<String> = records.lock().iter().filter(|record| {
+        record.as_slice().contains(" imsg: ")
+    }).map(|record| record.clone()).collect();
+    assert_eq!(
+        vec!(
+            "rsmtp: debug: #1 127.0.0.1: imsg: EHLO rustastic.org".into_string(),
+            "rsmtp: debug: #1 127.0.0.1: imsg: AUTH PLAIN <redacted>".into_string(),
+            "rsmtp: debug: #1 127.0.0.1: imsg: <redacted>".into_string(),
+            "rsmtp: debug: #1 127.0.0.1: imsg: AUTH LOGIN".into_string(),
+            "rsmtp: debug: #1 127.0.0.1: imsg: <redacted>".into_string(),
+            "rsmtp: debug: #1 127.0.0.1: imsg: NOOP".into_string()
+        ),
+        client_lines
+    );
+}
+
+#[test]
+fn test_session_line_buffer() {
+    use super::super::common::stream::LineBuffer;
+
+    // Input is given to the session like `AsyncSmtpClient::read` does, in chunks which don't
+    // match lines.
+    let chunks = [
+        "EHLO rust",
+        "astic.org\r\nMAIL FROM:<rust@rustastic.org>\r",
+        "\nRCPT TO:<bob@rustastic.org>\r\nDATA\r\nHello\r\n.",
+        "\r\nQUIT\r\n"
+    ];
+    let mut session = get_test_session();
+    let mut lines = LineBuffer::new(session.max_line_size());
+    let mut actions = vec!();
+    for chunk in chunks.iter() {
+        let bytes = chunk.as_bytes();
+        {
+            let space = lines.space();
+            for i in range(0, bytes.len()) {
+                space[i] = bytes[i];
+            }
+        }
+        lines.filled(bytes.len());
+
+        loop {
+            let action = match lines.next_line() {
+                Some(Ok(ending)) => {
+                    let stats = get_test_stats(lines.bytes_read());
+                    session.handle_read(Ok((lines.line(), ending)), stats)
+                },
+                Some(Err(err)) => {
+                    let stats = get_test_stats(lines.bytes_read());
+                    session.handle_read(Err(err), stats)
+                },
+                None => break
+            };
+            actions.push(action);
+            lines.set_max_line_size(session.max_line_size());
+        }
+    }
+
+    assert_eq!(
+        vec!(
+            SessionReply("250-rustastic.org\r\n250-DSN\r\n250 ENHANCEDSTATUSCODES".into_string()),
+            SessionReply("250 2.1.0 OK".into_string()),
+            SessionReply("250 2.1.5 OK".into_string()),
+            SessionReply("354 Start mail input; end with <CRLF>.<CRLF>".into_string()),
+            SessionContinue,
+            SessionReply("250 2.0.0 OK".into_string()),
+            SessionClose(Some("221 2.0.0 rustastic.org".into_string()))
+        ),
+        actions
+    );
+}